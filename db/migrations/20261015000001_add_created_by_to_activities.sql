@@ -0,0 +1,9 @@
+-- migrate:up
+ALTER TABLE activities ADD COLUMN created_by VARCHAR(255);
+
+-- Create an index on the created_by column for filtering activities by user
+CREATE INDEX idx_activities_created_by ON activities(created_by);
+
+-- migrate:down
+DROP INDEX IF EXISTS idx_activities_created_by;
+ALTER TABLE activities DROP COLUMN IF EXISTS created_by;
@@ -0,0 +1,5 @@
+-- migrate:up
+ALTER TABLE accounting_categories ADD COLUMN hourly_rate DOUBLE PRECISION;
+
+-- migrate:down
+ALTER TABLE accounting_categories DROP COLUMN IF EXISTS hourly_rate;
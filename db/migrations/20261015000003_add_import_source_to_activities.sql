@@ -0,0 +1,9 @@
+-- migrate:up
+ALTER TABLE activities ADD COLUMN import_source VARCHAR(50);
+
+-- Create an index on the import_source column for filtering activities by origin
+CREATE INDEX idx_activities_import_source ON activities(import_source);
+
+-- migrate:down
+DROP INDEX IF EXISTS idx_activities_import_source;
+ALTER TABLE activities DROP COLUMN IF EXISTS import_source;
@@ -0,0 +1,5 @@
+-- migrate:up
+ALTER TABLE accounting_categories ADD COLUMN description TEXT;
+
+-- migrate:down
+ALTER TABLE accounting_categories DROP COLUMN IF EXISTS description;
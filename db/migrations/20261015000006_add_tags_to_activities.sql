@@ -0,0 +1,9 @@
+-- migrate:up
+ALTER TABLE activities ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}';
+
+-- Create a GIN index on the tags column for filtering activities by tag
+CREATE INDEX idx_activities_tags ON activities USING gin (tags);
+
+-- migrate:down
+DROP INDEX IF EXISTS idx_activities_tags;
+ALTER TABLE activities DROP COLUMN IF EXISTS tags;
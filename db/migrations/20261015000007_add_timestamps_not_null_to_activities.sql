@@ -0,0 +1,16 @@
+-- migrate:up
+UPDATE activities SET created_at = NOW() WHERE created_at IS NULL;
+UPDATE activities SET updated_at = created_at WHERE updated_at IS NULL;
+
+ALTER TABLE activities
+    ALTER COLUMN created_at SET DEFAULT NOW(),
+    ALTER COLUMN created_at SET NOT NULL,
+    ALTER COLUMN updated_at SET DEFAULT NOW(),
+    ALTER COLUMN updated_at SET NOT NULL;
+
+-- migrate:down
+ALTER TABLE activities
+    ALTER COLUMN created_at DROP NOT NULL,
+    ALTER COLUMN created_at SET DEFAULT CURRENT_TIMESTAMP,
+    ALTER COLUMN updated_at DROP NOT NULL,
+    ALTER COLUMN updated_at SET DEFAULT CURRENT_TIMESTAMP;
@@ -22,7 +22,10 @@ CREATE TABLE public.accounting_categories (
     id uuid DEFAULT gen_random_uuid() NOT NULL,
     name character varying(255) NOT NULL,
     created_at timestamp with time zone DEFAULT CURRENT_TIMESTAMP,
-    updated_at timestamp with time zone DEFAULT CURRENT_TIMESTAMP
+    updated_at timestamp with time zone DEFAULT CURRENT_TIMESTAMP,
+    hourly_rate double precision,
+    description text,
+    archived boolean DEFAULT false NOT NULL
 );
 
 
@@ -38,8 +41,11 @@ CREATE TABLE public.activities (
     category_id uuid NOT NULL,
     task text NOT NULL,
     comment text,
-    created_at timestamp with time zone DEFAULT CURRENT_TIMESTAMP,
-    updated_at timestamp with time zone DEFAULT CURRENT_TIMESTAMP
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    created_by character varying(255),
+    import_source character varying(50),
+    tags text[] DEFAULT '{}'::text[] NOT NULL
 );
 
 
@@ -98,6 +104,13 @@ CREATE INDEX idx_accounting_categories_name ON public.accounting_categories USIN
 CREATE INDEX idx_activities_category_id ON public.activities USING btree (category_id);
 
 
+--
+-- Name: idx_activities_created_by; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_activities_created_by ON public.activities USING btree (created_by);
+
+
 --
 -- Name: idx_activities_date; Type: INDEX; Schema: public; Owner: -
 --
@@ -112,6 +125,20 @@ CREATE INDEX idx_activities_date ON public.activities USING btree (date);
 CREATE INDEX idx_activities_date_category ON public.activities USING btree (date, category_id);
 
 
+--
+-- Name: idx_activities_import_source; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_activities_import_source ON public.activities USING btree (import_source);
+
+
+--
+-- Name: idx_activities_tags; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_activities_tags ON public.activities USING gin (tags);
+
+
 --
 -- Name: activities activities_category_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
@@ -132,4 +159,11 @@ ALTER TABLE ONLY public.activities
 INSERT INTO public.schema_migrations (version) VALUES
     ('20241016000001'),
     ('20241016000002'),
-    ('20241016000003');
+    ('20241016000003'),
+    ('20261015000001'),
+    ('20261015000002'),
+    ('20261015000003'),
+    ('20261015000004'),
+    ('20261015000005'),
+    ('20261015000006'),
+    ('20261015000007');
@@ -0,0 +1,53 @@
+-- migrate:up
+-- SQLite has no UUID, array or time zone types: IDs are stored as 16 byte BLOBs,
+-- dates and times as ISO 8601 TEXT and tags as a JSON array.
+CREATE TABLE accounting_categories (
+    id BLOB PRIMARY KEY NOT NULL DEFAULT (randomblob(16)),
+    name VARCHAR(255) NOT NULL UNIQUE,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    hourly_rate DOUBLE PRECISION,
+    description TEXT,
+    archived BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+-- Create an index on the name column for faster lookups
+CREATE INDEX idx_accounting_categories_name ON accounting_categories(name);
+
+CREATE TABLE activities (
+    id BLOB PRIMARY KEY NOT NULL DEFAULT (randomblob(16)),
+    date DATE NOT NULL,
+    start_time TIME NOT NULL,
+    end_time TIME,
+    category_id BLOB NOT NULL REFERENCES accounting_categories(id) ON DELETE CASCADE,
+    task TEXT NOT NULL,
+    comment TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    created_by VARCHAR(255),
+    import_source VARCHAR(50),
+    tags TEXT NOT NULL DEFAULT '[]'
+);
+
+-- Create indexes for better query performance
+CREATE INDEX idx_activities_date ON activities(date);
+CREATE INDEX idx_activities_category_id ON activities(category_id);
+CREATE INDEX idx_activities_date_category ON activities(date, category_id);
+CREATE INDEX idx_activities_created_by ON activities(created_by);
+CREATE INDEX idx_activities_import_source ON activities(import_source);
+
+-- Add some default accounting categories
+INSERT INTO accounting_categories (name) VALUES
+    ('Development'),
+    ('Meetings'),
+    ('Documentation'),
+    ('Testing'),
+    ('Code Review'),
+    ('Planning'),
+    ('Research'),
+    ('Support'),
+    ('Administration');
+
+-- migrate:down
+DROP TABLE IF EXISTS activities;
+DROP TABLE IF EXISTS accounting_categories;
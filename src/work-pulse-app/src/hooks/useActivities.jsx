@@ -16,10 +16,10 @@ export const useActivities = () => {
 
     try {
       const response = await axios.get(
-        `${API_BASE_URL}/api/v1/activities?start_date=${startDate}&end_date=${endDate}`,
+        `${API_BASE_URL}/api/v1/activities?start_date=${startDate}&end_date=${endDate}&page_size=500`,
       )
 
-      setActivities(response.data)
+      setActivities(response.data.data)
 
       console.log('Activities refreshed successfully!')
     } catch (error) {
@@ -0,0 +1,82 @@
+use anyhow::{bail, Result};
+
+use crate::{activity_service::{Activity, ActivityService, AsyncActivityService}, config::CliConfig};
+
+/// Lists the activities of an optional date range, ordered by date and start time.
+///
+/// # Arguments
+///
+/// - `start_date`: The optional first date of the range (YYYY-MM-DD).
+/// - `end_date`: The optional last date of the range (YYYY-MM-DD).
+/// - `config`: The CLI configuration with the server URL.
+pub async fn list(start_date: Option<&str>, end_date: Option<&str>, config: &CliConfig) -> Result<()> {
+    let mut activities = AsyncActivityService::new(config).get_activities(start_date, end_date).await?;
+    activities.sort_by(|a, b| (a.date(), a.start_time()).cmp(&(b.date(), b.start_time())));
+
+    for activity in &activities {
+        println!("{}", format_activity(activity));
+    }
+
+    println!("{} activities found", activities.len());
+
+    Ok(())
+}
+
+/// Prints the activity with the given ID.
+///
+/// # Arguments
+///
+/// - `id`: The ID of the activity.
+/// - `config`: The CLI configuration with the server URL.
+pub fn show(id: &str, config: &CliConfig) -> Result<()> {
+    match ActivityService::new(config).get_activity_by_id(id)? {
+        Some(activity) => {
+            println!("{}", format_activity(&activity));
+            if let Some(comment) = activity.comment() {
+                println!("  {}", comment);
+            }
+            Ok(())
+        }
+        None => bail!("Activity not found: {}", id),
+    }
+}
+
+/// Deletes the activity with the given ID.
+///
+/// # Arguments
+///
+/// - `id`: The ID of the activity.
+/// - `config`: The CLI configuration with the server URL.
+pub fn delete(id: &str, config: &CliConfig) -> Result<()> {
+    ActivityService::new(config).delete_activity(id)?;
+
+    println!("Deleted activity {}", id);
+
+    Ok(())
+}
+
+/// Formats an activity as a single line with its ID, date, time range, category and task.
+fn format_activity(activity: &Activity) -> String {
+    format!(
+        "{} {} {}-{} [{}] {}",
+        activity.id().unwrap_or("-"),
+        activity.date(),
+        activity.start_time(),
+        activity.end_time().unwrap_or(""),
+        activity.pam_category_id(),
+        activity.task())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_activity_should_render_single_line() {
+        let activity = Activity::with_id("a-1".to_string(), "2025-11-03".to_string(), "09:00:00".to_string(), Some("10:00:00".to_string()), "cat-1".to_string(), "Code Review".to_string());
+        let open_activity = Activity::new("2025-11-03".to_string(), "10:00:00".to_string(), None, "cat-2".to_string(), "Support".to_string());
+
+        assert_eq!(format_activity(&activity), "a-1 2025-11-03 09:00:00-10:00:00 [cat-1] Code Review");
+        assert_eq!(format_activity(&open_activity), "- 2025-11-03 10:00:00- [cat-2] Support");
+    }
+}
@@ -1,14 +1,19 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::config::CliConfig;
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Activity {
     id: Option<String>,
     date: String,
     start_time: String,
     end_time: Option<String>,
+    #[serde(rename = "accounting_category_id")]
     pam_category_id: String,
     task: String,
+    #[serde(default)]
+    comment: Option<String>,
 }
 
 impl Activity {
@@ -20,6 +25,7 @@ impl Activity {
             end_time,
             pam_category_id,
             task,
+            comment: None,
         }
     }
 
@@ -31,6 +37,7 @@ impl Activity {
             end_time,
             pam_category_id,
             task,
+            comment: None,
         }
     }
 
@@ -57,46 +64,297 @@ impl Activity {
     pub fn task(&self) -> &str {
         &self.task
     }
+
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
 }
 
-const ACTIVITY_SERVICE_URL: &str = "http://localhost:8080/api/v1/activities";
+/// A single page of activities as returned by the activities endpoint.
+#[derive(Debug, Deserialize)]
+struct ActivityPage {
+    data: Vec<Activity>,
+    total_pages: u32,
+}
+
+/// A row of an uploaded CSV file that the service could not import.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RowImportError {
+    row_number: usize,
+    field: String,
+    reason: String,
+}
+
+impl std::fmt::Display for RowImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "row {}, {}: {}", self.row_number, self.field, self.reason)
+    }
+}
+
+/// The response of the service if only some rows of an uploaded file could be imported.
+#[derive(Debug, Deserialize)]
+struct PartialImport {
+    errors: Vec<RowImportError>,
+}
+
+/// The number of activities requested per page when fetching activities.
+const PAGE_SIZE: u32 = 500;
 
 pub struct ActivityService {
     client: reqwest::blocking::Client,
     base_url: String,
 }
 
-impl Default for ActivityService {
-    fn default() -> Self {
-        Self::new()
+impl ActivityService {
+    pub fn new(config: &CliConfig) -> Self {
+        Self::with_base_url(&config.activity_service_url())
     }
-}
 
-impl ActivityService {
-    pub fn new() -> Self {
+    pub fn with_base_url(base_url: &str) -> Self {
         Self {
             client: reqwest::blocking::Client::new(),
-            base_url: ACTIVITY_SERVICE_URL.to_string(),
+            base_url: base_url.to_string(),
         }
     }
 
-    #[allow(dead_code)]
+    /// Fetches all activities of an optional date range, following the pagination of the service.
+    ///
+    /// # Arguments
+    ///
+    /// - `start_date`: The optional first date of the range (inclusive), in format YYYY-MM-DD.
+    /// - `end_date`: The optional last date of the range (inclusive), in format YYYY-MM-DD.
+    pub fn get_activities(&self, start_date: Option<&str>, end_date: Option<&str>) -> Result<Vec<Activity>> {
+        let mut activities = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let activity_page = self.get_activities_page(start_date, end_date, page)?;
+            activities.extend(activity_page.data);
+
+            if page >= activity_page.total_pages {
+                return Ok(activities);
+            }
+            page += 1;
+        }
+    }
+
+    fn get_activities_page(&self, start_date: Option<&str>, end_date: Option<&str>, page: u32) -> Result<ActivityPage> {
+        let mut query = Vec::new();
+        if let Some(start_date) = start_date {
+            query.push(("start_date", start_date));
+        }
+        if let Some(end_date) = end_date {
+            query.push(("end_date", end_date));
+        }
+
+        let response = self.client.get(&self.base_url)
+            .query(&query)
+            .query(&[("page", page), ("page_size", PAGE_SIZE)])
+            .send()
+            .with_context(|| format!("Failed to fetch activities from {} for {:?} - {:?}", self.base_url, start_date, end_date))?;
+
+        if response.status().is_success() {
+            let activity_page: ActivityPage = response
+                .json()
+                .with_context(|| "Failed to parse activities from response")?;
+            Ok(activity_page)
+        } else {
+            Err(anyhow::anyhow!(
+                "Failed to fetch activities: HTTP {}",
+                response.status()
+            ))
+        }
+    }
+
+    /// Fetches a single activity by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: The ID of the activity.
+    ///
+    /// Returns `None` if there is no activity with the ID.
+    pub fn get_activity_by_id(&self, id: &str) -> Result<Option<Activity>> {
+        let url = format!("{}/{}", self.base_url, id);
+        let response = self.client.get(&url)
+            .send()
+            .with_context(|| format!("Failed to fetch activity from {}", url))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            Ok(None)
+        } else if response.status().is_success() {
+            let activity: Activity = response
+                .json()
+                .with_context(|| "Failed to parse activity from response")?;
+            Ok(Some(activity))
+        } else {
+            Err(anyhow::anyhow!(
+                "Failed to fetch activity {}: HTTP {}",
+                id,
+                response.status()
+            ))
+        }
+    }
+
+    /// Deletes the activity with the ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: The ID of the activity.
+    pub fn delete_activity(&self, id: &str) -> Result<()> {
+        let url = format!("{}/{}", self.base_url, id);
+        let response = self.client.delete(&url)
+            .send()
+            .with_context(|| format!("Failed to delete activity at {}", url))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "Failed to delete activity {}: HTTP {}",
+                id,
+                response.status()
+            ))
+        }
+    }
+
+    pub fn create_activity(&self, date: String, start_time: String, end_time: Option<String>, pam_category_id: String, task: String) -> Result<Activity> {
+        let response = self.client.post(&self.base_url)
+            .json(&Activity::new(date.clone(), start_time.clone(), end_time.clone(), pam_category_id.clone(), task.clone()))
+            .send()
+            .with_context(|| format!("Failed to create activity: date={}, start_time={}, end_time={:?}, pam_category_id={}, task={}", date, start_time, end_time, pam_category_id, task))?;
+
+        if response.status().is_success() {
+            let created_activity: Activity = response
+                .json()
+                .with_context(|| "Failed to parse created activity from response")?;
+            Ok(created_activity)
+        } else {
+            Err(anyhow::anyhow!(
+                "Failed to create activity: HTTP {}",
+                response.status()
+            ))
+        }
+    }
+
+    /// Uploads a CSV file of activities as `multipart/form-data` in a single request. The service
+    /// resolves the accounting categories by name and creates all activities at once.
+    ///
+    /// # Arguments
+    ///
+    /// - `file_name`: The name of the uploaded file.
+    /// - `csv_data`: The content of the CSV file.
+    /// - `year`: The year of the activities, as the CSV dates only contain day and month.
+    ///
+    /// Returns the rows the service could not import, which is empty if all rows were imported.
+    pub fn upload_csv(&self, file_name: &str, csv_data: Vec<u8>, year: u32) -> Result<Vec<RowImportError>> {
+        let url = format!("{}/upload-csv", self.base_url);
+        let file = reqwest::blocking::multipart::Part::bytes(csv_data)
+            .file_name(file_name.to_string())
+            .mime_str("text/csv")?;
+        let response = self.client.post(&url)
+            .query(&[("activities_year", year)])
+            .multipart(reqwest::blocking::multipart::Form::new().part("file", file))
+            .send()
+            .with_context(|| format!("Failed to upload CSV file {} to {}", file_name, url))?;
+
+        if response.status() == reqwest::StatusCode::MULTI_STATUS {
+            let partial_import: PartialImport = response
+                .json()
+                .with_context(|| "Failed to parse partial import from response")?;
+            Ok(partial_import.errors)
+        } else if response.status().is_success() {
+            Ok(Vec::new())
+        } else {
+            Err(anyhow::anyhow!(
+                "Failed to upload CSV file: HTTP {}: {}",
+                response.status(),
+                response.text().unwrap_or_default()
+            ))
+        }
+    }
+}
+
+/// A non-blocking counterpart of `ActivityService` for commands running on a tokio runtime.
+pub struct AsyncActivityService {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl AsyncActivityService {
+    pub fn new(config: &CliConfig) -> Self {
+        Self::with_base_url(&config.activity_service_url())
+    }
+
     pub fn with_base_url(base_url: &str) -> Self {
         Self {
-            client: reqwest::blocking::Client::new(),
+            client: reqwest::Client::new(),
             base_url: base_url.to_string(),
         }
     }
 
-    pub fn create_activity(&self, date: String, start_time: String, end_time: Option<String>, pam_category_id: String, task: String) -> Result<Activity> {
+    /// Fetches all activities of an optional date range, following the pagination of the service.
+    ///
+    /// # Arguments
+    ///
+    /// - `start_date`: The optional first date of the range (inclusive), in format YYYY-MM-DD.
+    /// - `end_date`: The optional last date of the range (inclusive), in format YYYY-MM-DD.
+    pub async fn get_activities(&self, start_date: Option<&str>, end_date: Option<&str>) -> Result<Vec<Activity>> {
+        let mut activities = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let activity_page = self.get_activities_page(start_date, end_date, page).await?;
+            activities.extend(activity_page.data);
+
+            if page >= activity_page.total_pages {
+                return Ok(activities);
+            }
+            page += 1;
+        }
+    }
+
+    async fn get_activities_page(&self, start_date: Option<&str>, end_date: Option<&str>, page: u32) -> Result<ActivityPage> {
+        let mut query = Vec::new();
+        if let Some(start_date) = start_date {
+            query.push(("start_date", start_date));
+        }
+        if let Some(end_date) = end_date {
+            query.push(("end_date", end_date));
+        }
+
+        let response = self.client.get(&self.base_url)
+            .query(&query)
+            .query(&[("page", page), ("page_size", PAGE_SIZE)])
+            .send()
+            .await
+            .with_context(|| format!("Failed to fetch activities from {} for {:?} - {:?}", self.base_url, start_date, end_date))?;
+
+        if response.status().is_success() {
+            let activity_page: ActivityPage = response
+                .json()
+                .await
+                .with_context(|| "Failed to parse activities from response")?;
+            Ok(activity_page)
+        } else {
+            Err(anyhow::anyhow!(
+                "Failed to fetch activities: HTTP {}",
+                response.status()
+            ))
+        }
+    }
+
+    #[allow(dead_code)]
+    pub async fn create_activity(&self, date: String, start_time: String, end_time: Option<String>, pam_category_id: String, task: String) -> Result<Activity> {
         let response = self.client.post(&self.base_url)
             .json(&Activity::new(date.clone(), start_time.clone(), end_time.clone(), pam_category_id.clone(), task.clone()))
             .send()
+            .await
             .with_context(|| format!("Failed to create activity: date={}, start_time={}, end_time={:?}, pam_category_id={}, task={}", date, start_time, end_time, pam_category_id, task))?;
 
         if response.status().is_success() {
             let created_activity: Activity = response
                 .json()
+                .await
                 .with_context(|| "Failed to parse created activity from response")?;
             Ok(created_activity)
         } else {
@@ -107,3 +365,212 @@ impl ActivityService {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use super::*;
+
+    const ACTIVITY_JSON: &str = r#"{"id":"42","date":"2025-01-15","start_time":"09:00:00","end_time":"10:30:00","accounting_category_id":"7","task":"Code Review","comment":null}"#;
+
+    fn activity() -> Activity {
+        Activity::with_id("42".to_string(), "2025-01-15".to_string(), "09:00:00".to_string(), Some("10:30:00".to_string()), "7".to_string(), "Code Review".to_string())
+    }
+
+    #[test]
+    fn get_activities_should_send_date_range_and_parse_page() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/api/v1/activities")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("start_date".to_string(), "2025-01-01".to_string()),
+                Matcher::UrlEncoded("end_date".to_string(), "2025-01-31".to_string()),
+                Matcher::UrlEncoded("page".to_string(), "1".to_string()),
+            ]))
+            .with_header("content-type", "application/json")
+            .with_body(format!(r#"{{"data":[{}],"total":1,"page":1,"page_size":500,"total_pages":1}}"#, ACTIVITY_JSON))
+            .create();
+        let service = ActivityService::with_base_url(&format!("{}/api/v1/activities", server.url()));
+
+        let activities = service.get_activities(Some("2025-01-01"), Some("2025-01-31")).unwrap();
+
+        mock.assert();
+        assert_eq!(activities, vec![activity()]);
+    }
+
+    #[test]
+    fn get_activity_by_id_should_return_activity() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/api/v1/activities/42")
+            .with_header("content-type", "application/json")
+            .with_body(ACTIVITY_JSON)
+            .create();
+        let service = ActivityService::with_base_url(&format!("{}/api/v1/activities", server.url()));
+
+        let found = service.get_activity_by_id("42").unwrap();
+
+        mock.assert();
+        assert_eq!(found, Some(activity()));
+    }
+
+    #[test]
+    fn get_activity_by_id_should_return_none_for_unknown_activity() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/api/v1/activities/43")
+            .with_status(404)
+            .create();
+        let service = ActivityService::with_base_url(&format!("{}/api/v1/activities", server.url()));
+
+        let found = service.get_activity_by_id("43").unwrap();
+
+        mock.assert();
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn delete_activity_should_send_delete_request() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("DELETE", "/api/v1/activities/42")
+            .with_status(204)
+            .create();
+        let service = ActivityService::with_base_url(&format!("{}/api/v1/activities", server.url()));
+
+        service.delete_activity("42").unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    fn delete_activity_should_fail_for_unknown_activity() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("DELETE", "/api/v1/activities/43")
+            .with_status(404)
+            .create();
+        let service = ActivityService::with_base_url(&format!("{}/api/v1/activities", server.url()));
+
+        let error = service.delete_activity("43").unwrap_err();
+
+        mock.assert();
+        assert!(error.to_string().contains("HTTP 404"));
+    }
+
+    const CSV: &str = "CW,Date,Check In,Check Out,PAM Category,Topic,Comment\n44,03.11.,09:00,10:00,Development,Coding,\n";
+
+    #[test]
+    fn upload_csv_should_send_file_as_multipart_form_data() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/api/v1/activities/upload-csv")
+            .match_query(Matcher::UrlEncoded("activities_year".to_string(), "2025".to_string()))
+            .match_header("content-type", Matcher::Regex("^multipart/form-data; boundary=".to_string()))
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex(r#"Content-Disposition: form-data; name="file"; filename="activities.csv""#.to_string()),
+                Matcher::Regex("Content-Type: text/csv".to_string()),
+                Matcher::Regex("44,03.11.,09:00,10:00,Development,Coding,".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#""Successfully imported 1 activities""#)
+            .create();
+        let service = ActivityService::with_base_url(&format!("{}/api/v1/activities", server.url()));
+
+        let errors = service.upload_csv("activities.csv", CSV.as_bytes().to_vec(), 2025).unwrap();
+
+        mock.assert();
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn upload_csv_should_return_rows_of_partial_import() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/api/v1/activities/upload-csv")
+            .match_query(Matcher::Any)
+            .with_status(207)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"imported":[],"errors":[{"row_number":1,"field":"PAM Category","reason":"unknown category"}]}"#)
+            .create();
+        let service = ActivityService::with_base_url(&format!("{}/api/v1/activities", server.url()));
+
+        let errors = service.upload_csv("activities.csv", CSV.as_bytes().to_vec(), 2025).unwrap();
+
+        mock.assert();
+        assert_eq!(errors, vec![RowImportError { row_number: 1, field: "PAM Category".to_string(), reason: "unknown category".to_string() }]);
+        assert_eq!(errors[0].to_string(), "row 1, PAM Category: unknown category");
+    }
+
+    #[test]
+    fn upload_csv_should_fail_for_rejected_file() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/api/v1/activities/upload-csv")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_body("Invalid CSV file")
+            .create();
+        let service = ActivityService::with_base_url(&format!("{}/api/v1/activities", server.url()));
+
+        let error = service.upload_csv("activities.csv", CSV.as_bytes().to_vec(), 2025).unwrap_err();
+
+        mock.assert();
+        assert!(error.to_string().contains("HTTP 400"));
+    }
+
+    #[tokio::test]
+    async fn async_create_activity_should_post_activity_and_parse_response() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server.mock("POST", "/api/v1/activities")
+            .match_body(Matcher::PartialJson(serde_json::json!({
+                "date": "2025-01-15",
+                "start_time": "09:00:00",
+                "end_time": "10:30:00",
+                "accounting_category_id": "7",
+                "task": "Code Review",
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(ACTIVITY_JSON)
+            .create_async()
+            .await;
+        let service = AsyncActivityService::with_base_url(&format!("{}/api/v1/activities", server.url()));
+
+        let created = service.create_activity("2025-01-15".to_string(), "09:00:00".to_string(), Some("10:30:00".to_string()), "7".to_string(), "Code Review".to_string()).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(created, activity());
+    }
+
+    #[tokio::test]
+    async fn async_create_activity_should_fail_for_rejected_activity() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server.mock("POST", "/api/v1/activities")
+            .with_status(400)
+            .create_async()
+            .await;
+        let service = AsyncActivityService::with_base_url(&format!("{}/api/v1/activities", server.url()));
+
+        let error = service.create_activity("2025-01-15".to_string(), "10:30:00".to_string(), Some("09:00:00".to_string()), "7".to_string(), "Code Review".to_string()).await.unwrap_err();
+
+        mock.assert_async().await;
+        assert!(error.to_string().contains("HTTP 400"));
+    }
+
+    #[tokio::test]
+    async fn async_get_activities_should_follow_pagination() {
+        let mut server = mockito::Server::new_async().await;
+        let mut mocks = Vec::new();
+        for page in 1..=2 {
+            mocks.push(server.mock("GET", "/api/v1/activities")
+                .match_query(Matcher::UrlEncoded("page".to_string(), page.to_string()))
+                .with_header("content-type", "application/json")
+                .with_body(format!(r#"{{"data":[{}],"total":2,"page":{},"page_size":500,"total_pages":2}}"#, ACTIVITY_JSON, page))
+                .create_async()
+                .await);
+        }
+        let service = AsyncActivityService::with_base_url(&format!("{}/api/v1/activities", server.url()));
+
+        let activities = service.get_activities(None, None).await.unwrap();
+
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+        assert_eq!(activities, vec![activity(), activity()]);
+    }
+}
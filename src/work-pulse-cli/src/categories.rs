@@ -0,0 +1,25 @@
+use anyhow::{Context, Result};
+
+use crate::{category_service::CategoryService, config::CliConfig};
+
+/// Imports the accounting categories of a CSV file with a `name` column and the optional
+/// columns `description` and `hourly_rate`. Categories that already exist are skipped.
+///
+/// # Arguments
+///
+/// - `file_path`: The path to the CSV file to import.
+/// - `config`: The CLI configuration with the server URL.
+pub fn import(file_path: &str, config: &CliConfig) -> Result<()> {
+    let csv_data = std::fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read categories file: {}", file_path))?;
+
+    let created = CategoryService::new(config).import_categories_csv(csv_data)?;
+
+    for category in &created {
+        println!("Created category: {}", category.name());
+    }
+
+    println!("{} categories created", created.len());
+
+    Ok(())
+}
@@ -1,20 +1,53 @@
+use anyhow::{Context, Result, bail};
 use once_cell::sync::Lazy;
+use serde::Deserialize;
 use std::collections::HashMap;
 
+/// The name of the environment variable with additional category mappings as a JSON object,
+/// e.g. `{"MyAlias":"Standard Name"}`.
+pub const CATEGORY_MAP_ENV_VAR: &str = "WORK_PULSE_CATEGORY_MAP";
+
 /// A static category mapping table that maps category names to standardized categories
 /// This is initialized once and can be accessed globally throughout the application
-pub static CATEGORY_MAP: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
+/// The compiled-in mappings are extended and overridden by the mappings of `WORK_PULSE_CATEGORY_MAP`
+pub static CATEGORY_MAP: Lazy<HashMap<String, String>> = Lazy::new(|| {
     let mut map = HashMap::new();
     
-    map.insert("CurrentVersion", "Current Version");
-    map.insert("NextVersion", "Next Version");
-    map.insert("SWATrainer", "SWA Trainer");
-    map.insert("Sonstiges", "Other");
-    map.insert("TechnoCluster", "TC: SW-Defined Innovation");
+    map.insert("CurrentVersion".to_string(), "Current Version".to_string());
+    map.insert("NextVersion".to_string(), "Next Version".to_string());
+    map.insert("SWATrainer".to_string(), "SWA Trainer".to_string());
+    map.insert("Sonstiges".to_string(), "Other".to_string());
+    map.insert("TechnoCluster".to_string(), "TC: SW-Defined Innovation".to_string());
+
+    map.extend(load_from_env());
     
     map
 });
 
+/// Loads additional category mappings from the `WORK_PULSE_CATEGORY_MAP` environment variable.
+///
+/// Returns no mappings if the variable is not set or empty. If it does not contain a JSON object
+/// of strings, a warning is printed and no mappings are returned.
+pub fn load_from_env() -> HashMap<String, String> {
+    parse_env_mappings(&std::env::var(CATEGORY_MAP_ENV_VAR).unwrap_or_default())
+}
+
+/// Parses the category mappings of the `WORK_PULSE_CATEGORY_MAP` environment variable.
+///
+/// # Arguments
+///
+/// - `value`: The value of the environment variable, a JSON object like `{"MyAlias":"Standard Name"}`.
+fn parse_env_mappings(value: &str) -> HashMap<String, String> {
+    if value.trim().is_empty() {
+        return HashMap::new();
+    }
+
+    serde_json::from_str(value).unwrap_or_else(|err| {
+        eprintln!("Warning: ignoring {}, it is not a JSON object of category names: {}", CATEGORY_MAP_ENV_VAR, err);
+        HashMap::new()
+    })
+}
+
 /// Maps a category name to its standardized form.
 /// 
 /// # Arguments
@@ -22,20 +55,89 @@ pub static CATEGORY_MAP: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|
 /// - `category`: The category name to map, case-insensitive.
 /// 
 /// Returns the mapped category if found, otherwise returns the original input
+#[allow(dead_code)]
 pub fn map_category(category: &str) -> Option<&str> {
-    CATEGORY_MAP.get(category).copied()
+    CATEGORY_MAP.get(category).map(String::as_str)
+}
+
+/// Maps a category name to its standardized form using the given mappings.
+///
+/// # Arguments
+///
+/// - `mappings`: The category mappings, e.g. from `load_mappings`.
+/// - `category`: The category name to map.
+///
+/// Returns the mapped category if found, otherwise `None`.
+pub fn map_category_with<'a>(mappings: &'a HashMap<String, String>, category: &str) -> Option<&'a str> {
+    mappings.get(category).map(String::as_str)
+}
+
+/// The content of a category map file.
+#[derive(Debug, Deserialize)]
+struct CategoryMapFile {
+    /// The category mappings, e.g. `CurrentVersion = "Current Version"`.
+    mappings: HashMap<String, String>,
+}
+
+/// Loads category mappings from the `[mappings]` table of a TOML file.
+///
+/// # Arguments
+///
+/// - `path`: The path to the TOML file.
+///
+/// Returns an error if the file cannot be read, is not valid TOML or contains no mappings.
+pub fn load_from_toml(path: &str) -> Result<HashMap<String, String>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read category map file: {}", path))?;
+
+    let file: CategoryMapFile = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse category map file: {}", path))?;
+
+    if file.mappings.is_empty() {
+        bail!("The category map file contains no mappings: {}", path);
+    }
+
+    Ok(file.mappings)
+}
+
+/// Merges the given mappings with the defaults of `CATEGORY_MAP`. The given mappings take precedence.
+///
+/// # Arguments
+///
+/// - `overrides`: The mappings to merge into the defaults.
+pub fn merge_with_defaults(overrides: HashMap<String, String>) -> HashMap<String, String> {
+    let mut mappings = CATEGORY_MAP.clone();
+
+    mappings.extend(overrides);
+
+    mappings
+}
+
+/// Loads the category mappings: the defaults of `CATEGORY_MAP`, extended and overridden by the
+/// mappings of the category map file, if given.
+///
+/// # Arguments
+///
+/// - `category_map_file`: The optional path to a TOML file with additional mappings.
+pub fn load_mappings(category_map_file: Option<&str>) -> Result<HashMap<String, String>> {
+    let overrides = match category_map_file {
+        Some(path) => load_from_toml(path)?,
+        None => HashMap::new(),
+    };
+
+    Ok(merge_with_defaults(overrides))
 }
 
 /// Gets all available category mappings as a vector of (input, output) pairs.
 #[allow(dead_code)]
 pub fn get_all_mappings() -> Vec<(&'static str, &'static str)> {
-    CATEGORY_MAP.iter().map(|(&k, &v)| (k, v)).collect()
+    CATEGORY_MAP.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect()
 }
 
 /// Gets all unique standardized categories.
 #[allow(dead_code)]
 pub fn get_standard_categories() -> Vec<&'static str> {
-    let mut categories: Vec<_> = CATEGORY_MAP.values().cloned().collect();
+    let mut categories: Vec<_> = CATEGORY_MAP.values().map(String::as_str).collect();
     categories.sort();
     categories.dedup();
     categories
@@ -100,4 +202,95 @@ mod tests {
     fn has_mapping_should_return_false_if_mapping_does_not_exist() {
         assert!(!has_mapping("nonexistent"));
     }
+
+    /// Writes the content to a new file in the temporary directory and returns its path.
+    fn write_toml(name: &str, content: &str) -> String {
+        let path = std::env::temp_dir().join(format!("work-pulse-cli-{}-{}.toml", name, std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn load_from_toml_should_load_mappings() {
+        let path = write_toml("load", "[mappings]\nCurrentVersion = \"Current Version\"\nMeeting = \"Meetings\"\n");
+
+        let mappings = load_from_toml(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(mappings.len(), 2);
+        assert_eq!(mappings.get("Meeting"), Some(&"Meetings".to_string()));
+    }
+
+    #[test]
+    fn load_from_toml_should_fail_for_missing_file() {
+        let error = load_from_toml("does-not-exist.toml").unwrap_err();
+
+        assert!(error.to_string().contains("Failed to read category map file"));
+    }
+
+    #[test]
+    fn load_from_toml_should_fail_for_invalid_toml() {
+        let path = write_toml("invalid", "[mappings]\nMeeting = Meetings\n");
+
+        let error = load_from_toml(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(error.to_string().contains("Failed to parse category map file"));
+    }
+
+    #[test]
+    fn load_from_toml_should_fail_for_empty_mappings() {
+        let path = write_toml("empty", "[mappings]\n");
+
+        let error = load_from_toml(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(error.to_string().contains("contains no mappings"));
+    }
+
+    #[test]
+    fn merge_with_defaults_should_keep_defaults_and_add_new_mappings() {
+        let mappings = merge_with_defaults(HashMap::from([("Meeting".to_string(), "Meetings".to_string())]));
+
+        assert_eq!(mappings.len(), 6);
+        assert_eq!(map_category_with(&mappings, "Meeting"), Some("Meetings"));
+        assert_eq!(map_category_with(&mappings, "NextVersion"), Some("Next Version"));
+    }
+
+    #[test]
+    fn merge_with_defaults_should_prefer_given_mappings() {
+        let mappings = merge_with_defaults(HashMap::from([("Sonstiges".to_string(), "Miscellaneous".to_string())]));
+
+        assert_eq!(mappings.len(), 5);
+        assert_eq!(map_category_with(&mappings, "Sonstiges"), Some("Miscellaneous"));
+    }
+
+    #[test]
+    fn parse_env_mappings_should_parse_json_object() {
+        let mappings = parse_env_mappings(r#"{"MyAlias":"Standard Name","Sonstiges":"Miscellaneous"}"#);
+
+        assert_eq!(mappings.len(), 2);
+        assert_eq!(map_category_with(&mappings, "MyAlias"), Some("Standard Name"));
+        assert_eq!(map_category_with(&mappings, "Sonstiges"), Some("Miscellaneous"));
+    }
+
+    #[test]
+    fn parse_env_mappings_should_ignore_invalid_json() {
+        assert!(parse_env_mappings("MyAlias=Standard Name").is_empty());
+        assert!(parse_env_mappings(r#"{"MyAlias": 42}"#).is_empty());
+    }
+
+    #[test]
+    fn parse_env_mappings_should_return_no_mappings_for_empty_value() {
+        assert!(parse_env_mappings("").is_empty());
+        assert!(parse_env_mappings("  ").is_empty());
+    }
+
+    #[test]
+    fn load_mappings_should_return_defaults_without_file() {
+        let mappings = load_mappings(None).unwrap();
+
+        assert_eq!(mappings.len(), 5);
+        assert_eq!(map_category_with(&mappings, "CurrentVersion"), Some("Current Version"));
+    }
 }
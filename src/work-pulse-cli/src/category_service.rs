@@ -1,6 +1,8 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::config::CliConfig;
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Category {
     id: Option<String>,
@@ -32,28 +34,16 @@ impl Category {
     }
 }
 
-const CATEGORY_SERVICE_URL: &str = "http://localhost:8080/api/v1/accounting-categories";
-
 pub struct CategoryService {
     client: reqwest::blocking::Client,
     base_url: String,
 }
 
-impl Default for CategoryService {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 impl CategoryService {
-    pub fn new() -> Self {
-        Self {
-            client: reqwest::blocking::Client::new(),
-            base_url: CATEGORY_SERVICE_URL.to_string(),
-        }
+    pub fn new(config: &CliConfig) -> Self {
+        Self::with_base_url(&config.category_service_url())
     }
 
-    #[allow(dead_code)]
     pub fn with_base_url(base_url: &str) -> Self {
         Self {
             client: reqwest::blocking::Client::new(),
@@ -97,4 +87,64 @@ impl CategoryService {
             ))
         }
     }
+
+    pub fn import_categories_csv(&self, csv_data: String) -> Result<Vec<Category>> {
+        let url = format!("{}/import-csv", self.base_url);
+        let response = self.client.post(&url)
+            .header(reqwest::header::CONTENT_TYPE, "text/csv")
+            .body(csv_data)
+            .send()
+            .with_context(|| format!("Failed to import accounting categories to {}", url))?;
+
+        if response.status().is_success() {
+            let created_categories: Vec<Category> = response
+                .json()
+                .with_context(|| "Failed to parse imported categories from response")?;
+            Ok(created_categories)
+        } else {
+            Err(anyhow::anyhow!(
+                "Failed to import accounting categories: HTTP {}",
+                response.status()
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use super::*;
+
+    #[test]
+    fn import_categories_csv_should_post_csv_and_parse_created_categories() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/api/v1/accounting-categories/import-csv")
+            .match_header("content-type", "text/csv")
+            .match_body(Matcher::Exact("name\nDevelopment\n".to_string()))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"id":"7","name":"Development","hourly_rate":null,"description":null,"archived":false}]"#)
+            .create();
+        let service = CategoryService::with_base_url(&format!("{}/api/v1/accounting-categories", server.url()));
+
+        let created = service.import_categories_csv("name\nDevelopment\n".to_string()).unwrap();
+
+        mock.assert();
+        assert_eq!(created, vec![Category::with_id("7".to_string(), "Development".to_string())]);
+    }
+
+    #[test]
+    fn import_categories_csv_should_fail_for_malformed_csv() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/api/v1/accounting-categories/import-csv")
+            .with_status(400)
+            .create();
+        let service = CategoryService::with_base_url(&format!("{}/api/v1/accounting-categories", server.url()));
+
+        let result = service.import_categories_csv("description\nCoding\n".to_string());
+
+        mock.assert();
+        assert!(result.is_err());
+    }
 }
\ No newline at end of file
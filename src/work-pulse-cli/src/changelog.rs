@@ -0,0 +1,108 @@
+use std::{collections::BTreeMap, fs};
+
+use anyhow::{Context, Result};
+
+use crate::{activity_service::{Activity, ActivityService}, category_service::{Category, CategoryService}, config::CliConfig};
+
+/// Generates a Markdown changelog for all activities in the given date range and writes it to a file.
+///
+/// # Arguments
+///
+/// - `start_date`: The first date of the range (inclusive), in format YYYY-MM-DD.
+/// - `end_date`: The last date of the range (inclusive), in format YYYY-MM-DD.
+/// - `output_path`: The path of the Markdown file to write.
+/// - `config`: The CLI configuration providing the server URL.
+pub fn generate(start_date: &str, end_date: &str, output_path: &str, config: &CliConfig) -> Result<()> {
+    println!("Generating changelog for {} - {} into {}", start_date, end_date, output_path);
+
+    let activities = ActivityService::new(config).get_activities(Some(start_date), Some(end_date))?;
+    let categories = CategoryService::new(config).get_categories()?;
+
+    let changelog = render_changelog(&activities, &categories);
+
+    fs::write(output_path, changelog)
+        .with_context(|| format!("Failed to write changelog file: {}", output_path))?;
+
+    println!("Changelog with {} activities written to {}", activities.len(), output_path);
+
+    Ok(())
+}
+
+/// Renders the activities as Markdown, grouped by accounting category and sorted by date.
+///
+/// # Arguments
+///
+/// - `activities`: The activities to include in the changelog.
+/// - `categories`: The accounting categories used to resolve the category names.
+///
+/// Returns the changelog with a `## Category Name` heading per category and a `- {date}: {task}` line per activity.
+fn render_changelog(activities: &[Activity], categories: &[Category]) -> String {
+    let mut activities_per_category: BTreeMap<&str, Vec<&Activity>> = BTreeMap::new();
+
+    for activity in activities {
+        let category_name = categories
+            .iter()
+            .find(|c| c.id() == Some(activity.pam_category_id()))
+            .map(|c| c.name())
+            .unwrap_or(activity.pam_category_id());
+
+        activities_per_category
+            .entry(category_name)
+            .or_default()
+            .push(activity);
+    }
+
+    let mut changelog = String::from("# Changelog\n");
+
+    for (category_name, mut activities) in activities_per_category {
+        activities.sort_by(|a, b| (a.date(), a.start_time()).cmp(&(b.date(), b.start_time())));
+
+        changelog.push_str(&format!("\n## {}\n\n", category_name));
+        for activity in activities {
+            changelog.push_str(&format!("- {}: {}\n", activity.date(), activity.task()));
+        }
+    }
+
+    changelog
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_changelog_should_group_by_category_and_sort_by_date() {
+        let categories = vec![
+            Category::with_id("cat-1".to_string(), "Meetings".to_string()),
+            Category::with_id("cat-2".to_string(), "Development".to_string()),
+        ];
+        let activities = vec![
+            Activity::new("2025-10-14".to_string(), "09:00:00".to_string(), None, "cat-2".to_string(), "Fix bug".to_string()),
+            Activity::new("2025-10-13".to_string(), "10:00:00".to_string(), None, "cat-1".to_string(), "Sprint planning".to_string()),
+            Activity::new("2025-10-13".to_string(), "09:00:00".to_string(), None, "cat-2".to_string(), "Implement feature".to_string()),
+        ];
+
+        let changelog = render_changelog(&activities, &categories);
+
+        assert_eq!(
+            changelog,
+            "# Changelog\n\
+             \n## Development\n\n\
+             - 2025-10-13: Implement feature\n\
+             - 2025-10-14: Fix bug\n\
+             \n## Meetings\n\n\
+             - 2025-10-13: Sprint planning\n"
+        );
+    }
+
+    #[test]
+    fn render_changelog_should_fall_back_to_category_id_for_unknown_category() {
+        let activities = vec![
+            Activity::new("2025-10-13".to_string(), "09:00:00".to_string(), None, "unknown".to_string(), "Task".to_string()),
+        ];
+
+        let changelog = render_changelog(&activities, &[]);
+
+        assert_eq!(changelog, "# Changelog\n\n## unknown\n\n- 2025-10-13: Task\n");
+    }
+}
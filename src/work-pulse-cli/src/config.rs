@@ -0,0 +1,70 @@
+/// The server URL used if no other server URL is configured.
+pub const DEFAULT_SERVER_URL: &str = "http://localhost:8080";
+
+/// The configuration of the CLI.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliConfig {
+    /// The base URL of the work-pulse service, e.g. `http://localhost:8080`.
+    server_url: String,
+}
+
+impl Default for CliConfig {
+    fn default() -> Self {
+        Self {
+            server_url: DEFAULT_SERVER_URL.to_string(),
+        }
+    }
+}
+
+impl CliConfig {
+    /// Loads the CLI configuration.
+    ///
+    /// The server URL given on the command line takes precedence over the default. Reading
+    /// `~/.work-pulse/config.toml` will be added together with the `config` subcommand.
+    ///
+    /// # Arguments
+    ///
+    /// - `server_url`: The server URL given by the `--server-url` argument, if any.
+    pub fn load(server_url: Option<String>) -> Self {
+        match server_url {
+            Some(server_url) => Self {
+                server_url: server_url.trim_end_matches('/').to_string(),
+            },
+            None => Self::default(),
+        }
+    }
+
+    /// Returns the URL of the activities endpoint.
+    pub fn activity_service_url(&self) -> String {
+        format!("{}/api/v1/activities", self.server_url)
+    }
+
+    /// Returns the URL of the accounting categories endpoint.
+    pub fn category_service_url(&self) -> String {
+        format!("{}/api/v1/accounting-categories", self.server_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_should_fall_back_to_default_server_url() {
+        let config = CliConfig::load(None);
+
+        assert_eq!(config.server_url, DEFAULT_SERVER_URL);
+        assert_eq!(config.activity_service_url(), "http://localhost:8080/api/v1/activities");
+    }
+
+    #[test]
+    fn load_should_use_given_server_url_without_trailing_slash() {
+        let config = CliConfig::load(Some("https://work-pulse.example.com/".to_string()));
+
+        assert_eq!(config.server_url, "https://work-pulse.example.com");
+        assert_eq!(
+            config.category_service_url(),
+            "https://work-pulse.example.com/api/v1/accounting-categories"
+        );
+    }
+}
@@ -1,7 +1,152 @@
-use anyhow::Result;
-
-pub fn export(file_path: &str) -> Result<()> {
-    println!("Exporting activities to CSV file: {}", file_path);
-
-    Ok(())
-}
+use std::fs;
+
+use anyhow::{Context, Result};
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use encoding_rs::Encoding;
+
+use crate::{activity_service::{Activity, ActivityService}, category_service::{Category, CategoryService}, config::CliConfig, csv_import::ActivityTableRecord};
+
+/// Exports the activities of an optional date range to a CSV file in the activity table format of the import.
+///
+/// # Arguments
+///
+/// - `file_path`: The path to the CSV file to write.
+/// - `start_date`: The optional first date of the range (YYYY-MM-DD).
+/// - `end_date`: The optional last date of the range (YYYY-MM-DD).
+/// - `config`: The CLI configuration with the server URL.
+pub fn export(file_path: &str, start_date: Option<&str>, end_date: Option<&str>, config: &CliConfig) -> Result<()> {
+    println!("Exporting activities to CSV file: {}", file_path);
+
+    let activities = ActivityService::new(config).get_activities(start_date, end_date)?;
+    let categories = CategoryService::new(config).get_categories()?;
+
+    let csv_data = render_csv(&activities, &categories)?;
+
+    fs::write(file_path, &csv_data)
+        .with_context(|| format!("Failed to write CSV file: {}", file_path))?;
+
+    println!("Exported {} activities to {}", activities.len(), file_path);
+
+    Ok(())
+}
+
+/// Renders the activities as latin-1 encoded CSV in the activity table format, sorted by date and start time.
+///
+/// # Arguments
+///
+/// - `activities`: The activities to export.
+/// - `categories`: The accounting categories used to resolve the category names.
+fn render_csv(activities: &[Activity], categories: &[Category]) -> Result<Vec<u8>> {
+    let mut activities = activities.iter().collect::<Vec<_>>();
+    activities.sort_by(|a, b| (a.date(), a.start_time()).cmp(&(b.date(), b.start_time())));
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for activity in activities {
+        let category_name = categories
+            .iter()
+            .find(|c| c.id() == Some(activity.pam_category_id()))
+            .map(|c| c.name())
+            .unwrap_or(activity.pam_category_id());
+
+        csv_writer
+            .serialize(to_table_record(activity, category_name)?)
+            .with_context(|| "Failed to serialize CSV record")?;
+    }
+
+    let csv_data = String::from_utf8(csv_writer.into_inner().with_context(|| "Failed to write CSV records")?)?;
+
+    // encode the content using latin-1 encoding, as expected by the import
+    let enc = Encoding::for_label(b"latin1")
+        .with_context(|| "Failed to find encoding for latin1")?;
+    let (encoded_content, _, _) = enc.encode(&csv_data);
+
+    Ok(encoded_content.into_owned())
+}
+
+/// Converts an activity into a row of the activity table format.
+///
+/// # Arguments
+///
+/// - `activity`: The activity to convert.
+/// - `category_name`: The name of the accounting category of the activity.
+fn to_table_record(activity: &Activity, category_name: &str) -> Result<ActivityTableRecord> {
+    let date = NaiveDate::parse_from_str(activity.date(), "%Y-%m-%d")
+        .with_context(|| format!("Invalid activity date: {}", activity.date()))?;
+
+    Ok(ActivityTableRecord {
+        cw: date.iso_week().week() as u8,
+        date: date.format("%d.%m.").to_string(),
+        check_in: format_time(activity.start_time())?,
+        check_out: activity.end_time().map(format_time).transpose()?.unwrap_or_default(),
+        pam_category: category_name.to_string(),
+        task: activity.task().to_string(),
+        comment: activity.comment().unwrap_or_default().to_string(),
+    })
+}
+
+/// Formats a time in format HH:MM:SS as HH:MM.
+fn format_time(time: &str) -> Result<String> {
+    let time = NaiveTime::parse_from_str(time, "%H:%M:%S")
+        .with_context(|| format!("Invalid activity time: {}", time))?;
+
+    Ok(time.format("%H:%M").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::csv_import::read_csv;
+
+    #[test]
+    fn render_csv_should_write_activity_table_format_in_latin1() {
+        let categories = vec![
+            Category::with_id("cat-1".to_string(), "Meetings".to_string()),
+            Category::with_id("cat-2".to_string(), "Development".to_string()),
+        ];
+        let activities = vec![
+            Activity::new("2025-11-04".to_string(), "10:00:00".to_string(), Some("11:30:00".to_string()), "cat-1".to_string(), "Sprint Planning".to_string()),
+            Activity::new("2025-11-03".to_string(), "09:00:00".to_string(), Some("10:00:00".to_string()), "cat-2".to_string(), "Code Review für Kollegen".to_string()),
+            Activity::new("2025-11-04".to_string(), "13:00:00".to_string(), None, "unknown".to_string(), "Support".to_string()),
+        ];
+
+        let csv_data = render_csv(&activities, &categories).unwrap();
+
+        // "ü" is a single byte in latin-1
+        assert!(csv_data.contains(&0xFC));
+        let (decoded, _, _) = encoding_rs::WINDOWS_1252.decode(&csv_data);
+        assert_eq!(
+            decoded,
+            "CW,Date,Check In,Check Out,PAM Category,Topic,Comment\n\
+             45,03.11.,09:00,10:00,Development,Code Review für Kollegen,\n\
+             45,04.11.,10:00,11:30,Meetings,Sprint Planning,\n\
+             45,04.11.,13:00,,unknown,Support,\n"
+        );
+    }
+
+    #[test]
+    fn render_csv_should_be_readable_by_import() {
+        let categories = vec![Category::with_id("cat-1".to_string(), "Entwicklung & Qualität".to_string())];
+        let activities = vec![
+            Activity::new("2025-10-13".to_string(), "09:00:00".to_string(), Some("12:00:00".to_string()), "cat-1".to_string(), "Implement feature".to_string()),
+        ];
+
+        let path = std::env::temp_dir().join(format!("work-pulse-cli-export-{}.csv", std::process::id()));
+        std::fs::write(&path, render_csv(&activities, &categories).unwrap()).unwrap();
+        let records = read_csv(&path.to_string_lossy()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            records,
+            vec![ActivityTableRecord {
+                cw: 42,
+                date: "13.10.".to_string(),
+                check_in: "09:00".to_string(),
+                check_out: "12:00".to_string(),
+                pam_category: "Entwicklung & Qualität".to_string(),
+                task: "Implement feature".to_string(),
+                comment: String::new(),
+            }]
+        );
+    }
+}
@@ -1,19 +1,83 @@
-use std::{fs::File, io::{BufReader, Read}};
+use std::{collections::HashMap, fs::File, io::{self, BufReader, Read, Write}, path::Path};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use chrono::NaiveDate;
+use clap::ValueEnum;
 use encoding_rs::Encoding;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::{activity_service::ActivityService, category_mapper, category_service::CategoryService};
+use crate::{activity_service::{Activity, ActivityService}, category_mapper, category_service::{Category, CategoryService}, config::CliConfig};
 
-pub fn import(file_path: &str) -> Result<()> {
-    println!("Importing CSV file: {}", file_path);
+/// The format of the output of the CSV import.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable progress messages.
+    #[default]
+    Text,
 
+    /// A single JSON summary of the import, e.g. for scripts.
+    Json,
+}
+
+/// The summary of a CSV import, printed in the `json` output format.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ImportResult {
+    /// The number of activities created, or that would be created in a dry run.
+    imported_count: usize,
+
+    /// The number of categories created, or that would be created if missing in a dry run.
+    categories_created: usize,
+
+    /// The records that could not be imported.
+    errors: Vec<String>,
+}
+
+/// Imports the activities of a CSV file and prints the progress in the given output format.
+/// Records that cannot be imported are reported and skipped, and make the import fail at the end.
+///
+/// # Arguments
+///
+/// - `file_path`: The path to the CSV file to import.
+/// - `year`: The year of the activities, as the CSV dates only contain day and month.
+/// - `dry_run`: Whether to only print what would be created.
+/// - `category_map_file`: The optional path to a TOML file with additional category mappings.
+/// - `output_format`: The format of the printed output.
+/// - `upload_via_api`: Whether to upload all activities in a single request instead of creating
+///   them one by one.
+/// - `config`: The CLI configuration with the server URL.
+pub fn import(file_path: &str, year: u32, dry_run: bool, category_map_file: Option<&str>, output_format: OutputFormat, upload_via_api: bool, config: &CliConfig) -> Result<()> {
+    import_to(&mut io::stdout(), file_path, year, dry_run, category_map_file, output_format, upload_via_api, config)
+}
+
+/// Runs the import like `import`, writing the output to `out`.
+#[allow(clippy::too_many_arguments)]
+fn import_to(out: &mut dyn Write, file_path: &str, year: u32, dry_run: bool, category_map_file: Option<&str>, output_format: OutputFormat, upload_via_api: bool, config: &CliConfig) -> Result<()> {
+    let result = match output_format {
+        OutputFormat::Text => run_import(out, file_path, year, dry_run, category_map_file, upload_via_api, config)?,
+        OutputFormat::Json => {
+            let result = run_import(&mut io::sink(), file_path, year, dry_run, category_map_file, upload_via_api, config)?;
+            writeln!(out, "{}", serde_json::to_string_pretty(&result)?)?;
+            result
+        }
+    };
+
+    if !result.errors.is_empty() {
+        bail!("{} records could not be imported", result.errors.len());
+    }
+
+    Ok(())
+}
+
+/// Runs the import, writing human readable progress messages to `out`.
+fn run_import(out: &mut dyn Write, file_path: &str, year: u32, dry_run: bool, category_map_file: Option<&str>, upload_via_api: bool, config: &CliConfig) -> Result<ImportResult> {
+    writeln!(out, "Importing CSV file: {} (year {})", file_path, year)?;
+
+    let mappings = category_mapper::load_mappings(category_map_file)?;
     let records = read_csv(file_path)?;
 
     for record in records.iter() {
-        println!(
+        writeln!(
+            out,
             "CW: {}, Date: {}, Check In: {}, Check Out: {}, PAM Category: {}, Task: {}, Comment: {}",
             record.cw,
             record.date,
@@ -22,63 +86,166 @@ pub fn import(file_path: &str) -> Result<()> {
             record.pam_category,
             record.task,
             record.comment
-        );
+        )?;
+    }
+
+    if dry_run {
+        let (categories_count, activities_count) = print_dry_run(out, &records, year, &mappings)?;
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "[DRY RUN] {} categories (if missing) and {} activities would be created.",
+            categories_count, activities_count
+        )?;
+
+        return Ok(ImportResult {
+            imported_count: activities_count,
+            categories_created: categories_count,
+            errors: Vec::new(),
+        });
     }
 
-    check_and_create_pam_categories(&records)?;
+    let mut result = ImportResult {
+        categories_created: check_and_create_pam_categories(out, &records, &mappings, config)?,
+        ..ImportResult::default()
+    };
 
-    println!();
-    println!("Categories from Service:");
+    if upload_via_api {
+        writeln!(out)?;
+        writeln!(out, "Uploading Activities from CSV records...")?;
 
-    let categories_from_service = CategoryService::new().get_categories()?;
+        let csv_data = to_csv_with_mapped_categories(&records, &mappings)?;
+        let file_name = Path::new(file_path).file_name().map_or(file_path.into(), |name| name.to_string_lossy());
+        let errors = ActivityService::new(config).upload_csv(&file_name, csv_data, year)?;
+        for error in errors.iter() {
+            writeln!(out, "Failed to import record: {}", error)?;
+        }
+
+        result.imported_count = records.len().saturating_sub(errors.len());
+        result.errors = errors.iter().map(ToString::to_string).collect();
+        writeln!(out, "Uploaded {} activities.", result.imported_count)?;
+
+        return Ok(result);
+    }
+
+    writeln!(out)?;
+    writeln!(out, "Categories from Service:")?;
+
+    let categories_from_service = CategoryService::new(config).get_categories()?;
     for category in categories_from_service.iter() {
-        println!("  {}: {}", category.id().unwrap(), category.name());
+        writeln!(out, "  {}: {}", category.id().unwrap_or("N/A"), category.name())?;
     }
 
-    println!();
-    println!("Creating Activities from CSV records...");
-    println!("This might take a while, depending on the number of records in the CSV file.");
+    writeln!(out)?;
+    writeln!(out, "Creating Activities from CSV records...")?;
+    writeln!(out, "This might take a while, depending on the number of records in the CSV file.")?;
 
-    let activity_service = ActivityService::new();
+    let activity_service = ActivityService::new(config);
 
     for record in records.iter() {
-        let date = convert_date_format(&record.date)
-            .with_context(|| format!("Failed to convert date format for record: {}", record.date))?;
+        match create_activity(&activity_service, &categories_from_service, &mappings, record, year) {
+            Ok(activity) => {
+                result.imported_count += 1;
+                writeln!(
+                    out,
+                    "Created Activity: ID: {}, Date: {}, Start Time: {}, End Time: {}, PAM Category ID: {}, Task: {}",
+                    activity.id().unwrap_or("N/A"),
+                    activity.date(),
+                    activity.start_time(),
+                    activity.end_time().unwrap_or("N/A"),
+                    activity.pam_category_id(),
+                    activity.task()
+                )?;
+            }
+            Err(err) => {
+                let error = format!("{} {}-{} {}: {:#}", record.date, record.check_in, record.check_out, record.task, err);
+                writeln!(out, "Failed to import record: {}", error)?;
+                result.errors.push(error);
+            }
+        }
+    }
+
+    Ok(result)
+}
 
-        let pam_category_id = categories_from_service
-            .iter()
-            .find(|c| c.name() == category_mapper::map_category(&record.pam_category).unwrap_or(&record.pam_category))
-            .map(|c| c.id().unwrap())
-            .unwrap()
-            .to_string();
+/// Writes the records as UTF-8 encoded CSV, with the categories replaced by their mapped names,
+/// so the service resolves the same categories as the import of single activities.
+fn to_csv_with_mapped_categories(records: &[ActivityTableRecord], mappings: &HashMap<String, String>) -> Result<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for record in records.iter() {
+        let category_name = category_mapper::map_category_with(mappings, &record.pam_category).unwrap_or(&record.pam_category);
+        writer
+            .serialize(ActivityTableRecord { pam_category: category_name.to_string(), ..record.clone() })
+            .with_context(|| "Failed to serialize CSV record")?;
+    }
+
+    writer.into_inner().with_context(|| "Failed to write CSV data")
+}
+
+/// Creates the activity of a CSV record in the service.
+fn create_activity(activity_service: &ActivityService, categories: &[Category], mappings: &HashMap<String, String>, record: &ActivityTableRecord, year: u32) -> Result<Activity> {
+    let date = convert_date_format(&record.date, year)
+        .with_context(|| format!("Failed to convert date format for record: {}", record.date))?;
 
-        let activity = activity_service.create_activity(
+    let category_name = category_mapper::map_category_with(mappings, &record.pam_category).unwrap_or(&record.pam_category);
+    let Some(pam_category_id) = categories
+        .iter()
+        .find(|c| c.name() == category_name)
+        .and_then(|c| c.id())
+    else {
+        bail!("Category not found in service: {}", category_name);
+    };
+
+    activity_service.create_activity(
+        date,
+        record.check_in.clone(),
+        Some(record.check_out.clone()),
+        pam_category_id.to_string(),
+        record.task.clone(),
+    )
+}
+
+/// Prints the categories and activities an import of the records would create, without
+/// contacting the service.
+///
+/// Returns the number of categories and activities that would be created.
+fn print_dry_run(out: &mut dyn Write, records: &[ActivityTableRecord], year: u32, mappings: &HashMap<String, String>) -> Result<(usize, usize)> {
+    writeln!(out)?;
+
+    let mut category_names = get_pam_categories(records)
+        .iter()
+        .map(|pam_category| category_mapper::map_category_with(mappings, pam_category).unwrap_or(pam_category).to_string())
+        .collect::<Vec<String>>();
+    category_names.sort();
+    category_names.dedup();
+
+    for category_name in category_names.iter() {
+        writeln!(out, "[DRY RUN] Would create: Category: {} (if missing)", category_name)?;
+    }
+
+    for record in records.iter() {
+        let date = convert_date_format(&record.date, year)
+            .with_context(|| format!("Failed to convert date format for record: {}", record.date))?;
+        let category_name = category_mapper::map_category_with(mappings, &record.pam_category).unwrap_or(&record.pam_category);
+
+        writeln!(
+            out,
+            "[DRY RUN] Would create: Activity: Date: {}, Start Time: {}, End Time: {}, PAM Category: {}, Task: {}",
             date,
-            record.check_in.clone(),
-            Some(record.check_out.clone()),
-            pam_category_id.clone(),
-            record.task.clone(),
+            record.check_in,
+            record.check_out,
+            category_name,
+            record.task
         )?;
-
-        println!(
-            "Created Activity: ID: {}, Date: {}, Start Time: {}, End Time: {}, PAM Category ID: {}, Task: {}",
-            activity.id().unwrap_or("N/A"),
-            activity.date(),
-            activity.start_time(),
-            activity.end_time().unwrap_or("N/A"),
-            activity.pam_category_id(),
-            activity.task()
-        );
     }
 
-    Ok(())
+    Ok((category_names.len(), records.len()))
 }
 
-const ACTIVITIES_YEAR: &str = "2025";
-
-fn convert_date_format(date: &str) -> Result<String> {
+fn convert_date_format(date: &str, year: u32) -> Result<String> {
     // add the year of the activity
-    let date = format!("{}{}", date, ACTIVITIES_YEAR);
+    let date = format!("{}{}", date, year);
 
     // Parse the date in "dd.mm.yyyy" format
     let parsed_date = NaiveDate::parse_from_str(&date, "%d.%m.%Y")?;
@@ -87,8 +254,9 @@ fn convert_date_format(date: &str) -> Result<String> {
     Ok(parsed_date.format("%Y-%m-%d").to_string())
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize)]
-struct ActivityTableRecord {
+/// A row of the activity table CSV format, as used for importing and exporting activities.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ActivityTableRecord {
     #[serde(rename = "CW")]
     pub cw: u8,
 
@@ -111,7 +279,7 @@ struct ActivityTableRecord {
     pub comment: String,
 }
 
-fn read_csv(file_path: &str) -> Result<Vec<ActivityTableRecord>> {
+pub(crate) fn read_csv(file_path: &str) -> Result<Vec<ActivityTableRecord>> {
     // FIXME It might be required to detect the encoding of the CSV file, if we run that on Linux OS.
 
     let file = File::open(file_path)
@@ -139,31 +307,38 @@ fn read_csv(file_path: &str) -> Result<Vec<ActivityTableRecord>> {
     )
 }
 
-fn check_and_create_pam_categories(records: &[ActivityTableRecord]) -> Result<()> {
-    println!();
-    println!("Checking PAM Categories against Service Categories:");
+/// Creates the categories of the records that are missing in the service.
+///
+/// Returns the number of created categories.
+fn check_and_create_pam_categories(out: &mut dyn Write, records: &[ActivityTableRecord], mappings: &HashMap<String, String>, config: &CliConfig) -> Result<usize> {
+    writeln!(out)?;
+    writeln!(out, "Checking PAM Categories against Service Categories:")?;
 
+    let mut created_count = 0;
     let pam_categories = get_pam_categories(&records);
-    let pam_categories_from_service = CategoryService::new().get_categories()?;
+    let category_service = CategoryService::new(config);
+    let pam_categories_from_service = category_service.get_categories()?;
     for pam_category in pam_categories.iter() {
-        let category_name = category_mapper::map_category(&pam_category)
+        let category_name = category_mapper::map_category_with(mappings, pam_category)
             .unwrap_or(&pam_category);
 
         if !pam_categories_from_service.iter().any(|c| c.name() == category_name) {
-            println!(
+            writeln!(
+                out,
                 "  {} -> {} (not found in service categories)",
                 pam_category, category_name
-            );
+            )?;
 
-            CategoryService::new()
+            category_service
                 .create_category(category_name)
                 .with_context(|| format!("Failed to create category: {}", category_name))?;
+            created_count += 1;
         } else {
-            println!("  {} -> {}", pam_category, category_name);
+            writeln!(out, "  {} -> {}", pam_category, category_name)?;
         }
     }
 
-    Ok(())
+    Ok(created_count)
 }
 
 fn get_pam_categories(records: &[ActivityTableRecord]) -> Vec<String> {
@@ -177,3 +352,151 @@ fn get_pam_categories(records: &[ActivityTableRecord]) -> Vec<String> {
 
     categories
 }
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use super::*;
+
+    #[test]
+    fn convert_date_format_should_embed_given_year() {
+        assert_eq!(convert_date_format("03.11.", 2025).unwrap(), "2025-11-03");
+        assert_eq!(convert_date_format("03.11.", 2026).unwrap(), "2026-11-03");
+    }
+
+    /// Writes the CSV content to a new file in the temporary directory and returns its path.
+    fn write_csv(name: &str, content: &str) -> String {
+        let path = std::env::temp_dir().join(format!("work-pulse-cli-{}-{}.csv", name, std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    const CSV: &str = "CW,Date,Check In,Check Out,PAM Category,Topic,Comment\n\
+        44,03.11.,09:00,10:00,Development,Coding,\n\
+        44,04.11.,10:00,11:00,Development,Code Review,Team\n\
+        44,04.11.,11:00,12:00,Meetings,Sprint Planning,\n";
+
+    #[test]
+    fn print_dry_run_should_count_categories_and_activities() {
+        let path = write_csv("dry-run-count", CSV);
+        let records = read_csv(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mappings = category_mapper::load_mappings(None).unwrap();
+
+        assert_eq!(print_dry_run(&mut io::sink(), &records, 2025, &mappings).unwrap(), (2, 3));
+    }
+
+    #[test]
+    fn import_with_dry_run_should_not_contact_service() {
+        let path = write_csv("dry-run-import", CSV);
+        // Nothing listens on the discard port, so any request to the service would fail.
+        let config = CliConfig::load(Some("http://127.0.0.1:9".to_string()));
+
+        let result = import(&path, 2025, true, None, OutputFormat::Text, false, &config);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn import_with_text_output_should_print_progress() {
+        let path = write_csv("text-output", CSV);
+        let config = CliConfig::load(Some("http://127.0.0.1:9".to_string()));
+        let mut out = Vec::new();
+
+        let result = import_to(&mut out, &path, 2025, true, None, OutputFormat::Text, false, &config);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_ok());
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.starts_with("Importing CSV file: "));
+        assert!(output.contains("[DRY RUN] 2 categories (if missing) and 3 activities would be created."));
+    }
+
+    #[test]
+    fn import_with_json_output_should_print_dry_run_counts() {
+        let path = write_csv("json-dry-run", CSV);
+        let config = CliConfig::load(Some("http://127.0.0.1:9".to_string()));
+        let mut out = Vec::new();
+
+        let result = import_to(&mut out, &path, 2025, true, None, OutputFormat::Json, false, &config);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_ok());
+        let import_result: ImportResult = serde_json::from_slice(&out).unwrap();
+        assert_eq!(import_result, ImportResult { imported_count: 3, categories_created: 2, errors: Vec::new() });
+    }
+
+    #[test]
+    fn import_with_json_output_should_print_import_counts() {
+        let mut server = mockito::Server::new();
+        let categories_mock = server.mock("GET", "/api/v1/accounting-categories")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"id":"1","name":"Development"},{"id":"2","name":"Meetings"}]"#)
+            .expect(2)
+            .create();
+        let activities_mock = server.mock("POST", "/api/v1/activities")
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":"42","date":"2025-11-03","start_time":"09:00:00","end_time":"10:00:00","accounting_category_id":"1","task":"Coding"}"#)
+            .expect(3)
+            .create();
+        let path = write_csv("json-import", CSV);
+        let config = CliConfig::load(Some(server.url()));
+        let mut out = Vec::new();
+
+        let result = import_to(&mut out, &path, 2025, false, None, OutputFormat::Json, false, &config);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_ok());
+        categories_mock.assert();
+        activities_mock.assert();
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.starts_with("{\n"));
+        let import_result: ImportResult = serde_json::from_str(&output).unwrap();
+        assert_eq!(import_result, ImportResult { imported_count: 3, categories_created: 0, errors: Vec::new() });
+    }
+
+    #[test]
+    fn import_via_api_should_upload_all_records_at_once() {
+        let mut server = mockito::Server::new();
+        let categories_mock = server.mock("GET", "/api/v1/accounting-categories")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"id":"1","name":"Development"},{"id":"2","name":"Meetings"}]"#)
+            .create();
+        let activities_mock = server.mock("POST", "/api/v1/activities")
+            .expect(0)
+            .create();
+        let upload_mock = server.mock("POST", "/api/v1/activities/upload-csv")
+            .match_query(Matcher::UrlEncoded("activities_year".to_string(), "2025".to_string()))
+            .match_body(Matcher::Regex("Sprint Planning".to_string()))
+            .with_status(207)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"imported":[],"errors":[{"row_number":3,"field":"Date","reason":"invalid date"}]}"#)
+            .create();
+        let path = write_csv("api-upload", CSV);
+        let config = CliConfig::load(Some(server.url()));
+        let mut out = Vec::new();
+
+        let result = import_to(&mut out, &path, 2025, false, None, OutputFormat::Json, true, &config);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+        categories_mock.assert();
+        activities_mock.assert();
+        upload_mock.assert();
+        let import_result: ImportResult = serde_json::from_slice(&out).unwrap();
+        assert_eq!(import_result.imported_count, 2);
+        assert_eq!(import_result.errors, vec!["row 3, Date: invalid date".to_string()]);
+    }
+
+    #[test]
+    fn convert_date_format_should_reject_invalid_date() {
+        assert!(convert_date_format("29.02.", 2025).is_err());
+        assert!(convert_date_format("29.02.", 2024).is_ok());
+    }
+}
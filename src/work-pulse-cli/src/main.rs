@@ -1,15 +1,26 @@
+mod activities;
 mod activity_service;
+mod categories;
 mod category_mapper;
 mod category_service;
+mod changelog;
+mod config;
 mod csv_export;
 mod csv_import;
 
 use anyhow::Result;
+use chrono::Datelike;
 use clap::{Parser, Subcommand};
 
+use crate::{config::CliConfig, csv_import::OutputFormat};
+
 #[derive(Parser)]
 #[command(name = "Work-Pulse CLI", version = "1.0", author = "Walter Stocker <wrstocke@googlemail.com>", about = "A CLI tool for interacting with work-pulse.")]
 struct Cli {
+    /// The base URL of the work-pulse service (defaults to http://localhost:8080).
+    #[arg(long, global = true)]
+    server_url: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -21,6 +32,26 @@ enum Commands {
         /// The path to the CSV file to import.
         #[arg(short, long)]
         file: String,
+
+        /// The year of the activities, as the CSV dates only contain day and month (defaults to the current year).
+        #[arg(short, long, default_value_t = current_year())]
+        year: u32,
+
+        /// Only print what would be created, without sending any requests to the service.
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+
+        /// The path to a TOML file with additional category mappings in a `[mappings]` table, taking precedence over the defaults.
+        #[arg(long)]
+        category_map_file: Option<String>,
+
+        /// The format of the printed output.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        output_format: OutputFormat,
+
+        /// Upload the whole CSV file to the service in a single request, instead of creating the activities one by one.
+        #[arg(long, default_value_t = false)]
+        upload_via_api: bool,
     },
 
     /// Export activities to a CSV file.
@@ -28,19 +59,99 @@ enum Commands {
         /// The path to the CSV file to export.
         #[arg(short, long)]
         file: String,
+
+        /// The optional first date of the range to export (YYYY-MM-DD).
+        #[arg(long)]
+        start_date: Option<String>,
+
+        /// The optional last date of the range to export (YYYY-MM-DD).
+        #[arg(long)]
+        end_date: Option<String>,
+    },
+
+    /// Generate a Markdown changelog from the activities of a date range.
+    GenerateChangelog {
+        /// The first date of the range (YYYY-MM-DD).
+        #[arg(long)]
+        start_date: String,
+
+        /// The last date of the range (YYYY-MM-DD).
+        #[arg(long)]
+        end_date: String,
+
+        /// The path to the Markdown file to write.
+        #[arg(short, long)]
+        output: String,
+    },
+
+    /// List the activities of an optional date range.
+    ListActivities {
+        /// The optional first date of the range (YYYY-MM-DD).
+        #[arg(long)]
+        start_date: Option<String>,
+
+        /// The optional last date of the range (YYYY-MM-DD).
+        #[arg(long)]
+        end_date: Option<String>,
+    },
+
+    /// Show a single activity.
+    GetActivity {
+        /// The ID of the activity.
+        id: String,
+    },
+
+    /// Delete a single activity.
+    DeleteActivity {
+        /// The ID of the activity.
+        id: String,
+    },
+
+    /// Import accounting categories from a CSV file with a `name` and optional `description` and `hourly_rate` columns.
+    ImportCategories {
+        /// The path to the CSV file to import.
+        #[arg(short, long)]
+        file: String,
     },
 }
 
+/// Returns the current year in local time.
+fn current_year() -> u32 {
+    chrono::Local::now().year() as u32
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    let config = CliConfig::load(cli.server_url);
+    let runtime = tokio::runtime::Runtime::new()?;
 
     match cli.command {
-        Commands::CsvImport { file } => {
-            csv_import::import(&file)?;
+        Commands::CsvImport { file, year, dry_run, category_map_file, output_format, upload_via_api } => {
+            csv_import::import(&file, year, dry_run, category_map_file.as_deref(), output_format, upload_via_api, &config)?;
+        }
+
+        Commands::CsvExport { file, start_date, end_date } => {
+            csv_export::export(&file, start_date.as_deref(), end_date.as_deref(), &config)?;
+        }
+
+        Commands::GenerateChangelog { start_date, end_date, output } => {
+            changelog::generate(&start_date, &end_date, &output, &config)?;
+        }
+
+        Commands::ListActivities { start_date, end_date } => {
+            runtime.block_on(activities::list(start_date.as_deref(), end_date.as_deref(), &config))?;
+        }
+
+        Commands::GetActivity { id } => {
+            activities::show(&id, &config)?;
+        }
+
+        Commands::DeleteActivity { id } => {
+            activities::delete(&id, &config)?;
         }
 
-        Commands::CsvExport { file } => {
-            csv_export::export(&file)?;
+        Commands::ImportCategories { file } => {
+            categories::import(&file, &config)?;
         }
     }
 
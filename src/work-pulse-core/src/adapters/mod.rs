@@ -1,4 +1,4 @@
-use std::io::Read;
+use std::io::{Read, Write};
 
 use async_trait::async_trait;
 use chrono::NaiveDate;
@@ -7,6 +7,8 @@ use thiserror::Error;
 use crate::entities::{
     accounting::{AccountingCategory, AccountingCategoryId},
     activity::{Activity, ActivityId},
+    category_summary::CategorySummary,
+    time_distribution::HourBucket,
 };
 
 /// Error type for the accounting categories list repository.
@@ -26,10 +28,17 @@ pub enum AccountingCategoriesListRepositoryError {
 pub trait AccountingCategoriesListRepository: Send + Sync {
     /// Retrieves a list of all accounting categories.
     ///
+    /// # Arguments
+    ///
+    /// - `include_archived`: Whether archived accounting categories should be included.
+    ///
     /// # Returns
     ///
-    /// A vector of `AccountingCategory` instances representing all available accounting categories.
-    async fn get_all(&self) -> Vec<AccountingCategory>;
+    /// `Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError>` containing all available accounting categories.
+    async fn get_all(
+        &self,
+        include_archived: bool,
+    ) -> Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError>;
 
     /// Retrieves a specific accounting category by its unique identifier.
     ///
@@ -39,15 +48,39 @@ pub trait AccountingCategoriesListRepository: Send + Sync {
     ///
     /// # Returns
     ///
-    /// An `Option<AccountingCategory>` that contains the requested category if found, or `None` if not found.
-    async fn get_by_id(&self, id: AccountingCategoryId) -> Option<AccountingCategory>;
+    /// `Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError>` containing the requested category if found, or `None` if not found.
+    async fn get_by_id(
+        &self,
+        id: AccountingCategoryId,
+    ) -> Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError>;
+
+    /// Retrieves a specific accounting category by its name. Names are compared case-sensitively.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: The exact name of the accounting category to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// `Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError>` containing the requested category if found, or `None` if not found.
+    async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError>;
 
     /// Adds a new accounting category to the repository.
     ///
     /// # Arguments
     ///
     /// - `category`: The `AccountingCategory` instance to be added to the repository.
-    async fn add(&mut self, category: AccountingCategory);
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), AccountingCategoriesListRepositoryError>` indicating success or failure of the add operation.
+    async fn add(
+        &mut self,
+        category: AccountingCategory,
+    ) -> Result<(), AccountingCategoriesListRepositoryError>;
 
     /// Updates an existing accounting category in the repository.
     ///
@@ -90,6 +123,22 @@ pub trait AccountingCategoriesListRepository: Send + Sync {
         &mut self,
         name: &str,
     ) -> Result<AccountingCategory, AccountingCategoriesListRepositoryError>;
+
+    /// Adds multiple accounting categories at once. Categories with the name of an existing
+    /// category, or of a category earlier in `categories`, are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// - `categories`: The `AccountingCategory` instances to be added to the repository.
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError>` containing the stored category for
+    /// each distinct name in `categories`, whether it was inserted or already existed.
+    async fn bulk_add(
+        &mut self,
+        categories: Vec<AccountingCategory>,
+    ) -> Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError>;
 }
 
 /// Error type for the activities list repository.
@@ -111,8 +160,22 @@ pub trait ActivitiesListRepository: Send + Sync {
     ///
     /// # Returns
     ///
-    /// A vector of `Activity` instances representing all activities in the repository.
-    async fn get_all(&self) -> Vec<Activity>;
+    /// A vector of `Activity` instances representing all activities in the repository, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    async fn get_all(&self) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
+
+    /// Retrieves a list of all activities in chronological order.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Activity` instances sorted ascending by date and start time, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    /// Activities with the same date and start time keep the order of `get_all`.
+    async fn get_all_sorted(&self) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let mut activities = self.get_all().await?;
+        activities.sort_by_key(|activity| (*activity.date(), *activity.start_time()));
+        Ok(activities)
+    }
 
     /// Retrieves a list of activities for a specific date.
     ///
@@ -121,8 +184,12 @@ pub trait ActivitiesListRepository: Send + Sync {
     /// - `date`: The date for which to retrieve activities.
     ///
     /// # Returns
-    /// A vector of `Activity` instances representing all activities for the specified date.
-    async fn get_by_date(&self, date: NaiveDate) -> Vec<Activity>;
+    /// A vector of `Activity` instances representing all activities for the specified date, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    async fn get_by_date(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
 
     /// Retrieves a list of activities within a specified date range.
     ///
@@ -132,15 +199,330 @@ pub trait ActivitiesListRepository: Send + Sync {
     /// - `end`: The end date of the range (inclusive).
     ///
     /// # Returns
-    /// A vector of `Activity` instances representing all activities within the specified date range.
-    async fn get_by_date_range(&self, start: NaiveDate, end: NaiveDate) -> Vec<Activity>;
+    /// A vector of `Activity` instances representing all activities within the specified date range, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    async fn get_by_date_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
+
+    /// Retrieves a list of activities within a calendar month.
+    ///
+    /// # Arguments
+    ///
+    /// - `year`: The year of the month.
+    /// - `month`: The month (1-12).
+    ///
+    /// # Returns
+    /// A vector of `Activity` instances representing all activities within the specified month, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    /// An invalid month yields an empty vector.
+    async fn get_by_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
+
+    /// Retrieves a list of activities within an ISO 8601 calendar week.
+    ///
+    /// # Arguments
+    ///
+    /// - `year`: The ISO week-numbering year of the week.
+    /// - `week`: The ISO week number (1-53).
+    ///
+    /// # Returns
+    /// A vector of `Activity` instances representing all activities within the specified week, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    /// A week that does not exist in the year yields an empty vector.
+    async fn get_by_week_number(
+        &self,
+        year: i32,
+        week: u32,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
+
+    /// Retrieves a list of activities on or after a specified date.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: The start date of the range (inclusive).
+    ///
+    /// # Returns
+    /// A vector of `Activity` instances representing all activities on or after the specified date, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    async fn get_from_date(
+        &self,
+        start: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        self.get_by_date_range(start, NaiveDate::MAX).await
+    }
+
+    /// Retrieves a list of activities on or before a specified date.
+    ///
+    /// # Arguments
+    ///
+    /// - `end`: The end date of the range (inclusive).
+    ///
+    /// # Returns
+    /// A vector of `Activity` instances representing all activities on or before the specified date, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    async fn get_until_date(
+        &self,
+        end: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        self.get_by_date_range(NaiveDate::MIN, end).await
+    }
+
+    /// Retrieves a list of activities within an optionally bounded date range.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: The optional start date of the range (inclusive).
+    /// - `end`: The optional end date of the range (inclusive).
+    ///
+    /// # Returns
+    /// A vector of `Activity` instances within the range. Without any bounds all activities are returned.
+    /// An `ActivitiesListRepositoryError::DatabaseError` is returned if the activities could not be read.
+    async fn get_by_date_range_opt(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        match (start, end) {
+            (Some(start), Some(end)) => self.get_by_date_range(start, end).await,
+            (Some(start), None) => self.get_from_date(start).await,
+            (None, Some(end)) => self.get_until_date(end).await,
+            (None, None) => self.get_all().await,
+        }
+    }
+
+    /// Calculates the distribution of activity start times by hour of day within an optionally bounded date range.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: The optional start date of the range (inclusive).
+    /// - `end`: The optional end date of the range (inclusive).
+    ///
+    /// # Returns
+    /// A vector with one `HourBucket` for each of the 24 hours of the day, ordered by hour, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    async fn get_time_distribution(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<HourBucket>, ActivitiesListRepositoryError> {
+        Ok(HourBucket::from_activities(
+            &self.get_by_date_range_opt(start, end).await?,
+        ))
+    }
+
+    /// Summarizes the activities within a specified date range per accounting category.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: The start date of the range (inclusive).
+    /// - `end`: The end date of the range (inclusive).
+    ///
+    /// # Returns
+    /// A vector with one `CategorySummary` for each accounting category with activities in the range,
+    /// ordered by category ID, or an `ActivitiesListRepositoryError::DatabaseError` if the activities
+    /// could not be read.
+    async fn get_category_summary(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<CategorySummary>, ActivitiesListRepositoryError> {
+        Ok(CategorySummary::from_activities(
+            &self.get_by_date_range(start, end).await?,
+        ))
+    }
+
+    /// Retrieves a list of activities created by a specific user.
+    ///
+    /// # Arguments
+    ///
+    /// - `user_id`: The username or email of the user who created the activities.
+    ///
+    /// # Returns
+    /// A vector of `Activity` instances representing all activities created by the specified user, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    async fn get_by_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
+
+    /// Retrieves a list of activities originating from a specific import source.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: The name of the import source (e.g., "csv", "toggl", "manual").
+    ///
+    /// # Returns
+    /// A vector of `Activity` instances representing all activities from the specified source, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    async fn get_by_import_source(
+        &self,
+        source: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
+
+    /// Retrieves a list of activities booked on a specific accounting category.
+    ///
+    /// # Arguments
+    ///
+    /// - `category_id`: The unique identifier of the accounting category.
+    ///
+    /// # Returns
+    /// A vector of `Activity` instances representing all activities of the specified category, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    async fn get_by_accounting_category_id(
+        &self,
+        category_id: &AccountingCategoryId,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
+
+    /// Retrieves all activities carrying a specific tag. Tags are compared case-sensitively.
+    ///
+    /// # Arguments
+    ///
+    /// - `tag`: The tag to filter the activities by.
+    ///
+    /// # Returns
+    /// A vector of `Activity` instances representing all activities with the tag, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    async fn get_by_tag(&self, tag: &str) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
+
+    /// Searches for activities whose task contains a keyword, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// - `query`: The keyword to search for. An empty keyword matches all activities.
+    ///
+    /// # Returns
+    /// A vector of `Activity` instances representing all activities with a matching task, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    async fn search_by_task(
+        &self,
+        query: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
+
+    /// Retrieves an activity by its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: The unique identifier of the activity.
+    ///
+    /// # Returns
+    /// `Some(Activity)` if an activity with the given ID exists, otherwise `None`, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activity could not be read.
+    async fn get_by_id(
+        &self,
+        id: &ActivityId,
+    ) -> Result<Option<Activity>, ActivitiesListRepositoryError>;
+
+    /// Retrieves the activity currently in progress for an import source, i.e. the most recently
+    /// started activity from that source without an end time.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: The import source the activity was stored with.
+    ///
+    /// # Returns
+    /// `Some(Activity)` if an activity from the source without an end time exists, otherwise
+    /// `None`, or an `ActivitiesListRepositoryError::DatabaseError` if the activity could not be
+    /// read.
+    async fn get_current_in_progress(
+        &self,
+        source: &str,
+    ) -> Result<Option<Activity>, ActivitiesListRepositoryError>;
+
+    /// Retrieves a page of activities using keyset pagination.
+    ///
+    /// # Arguments
+    ///
+    /// - `after_id`: The ID of the last activity of the previous page, or `None` for the first page.
+    /// - `limit`: The maximum number of activities to return.
+    ///
+    /// # Returns
+    /// A vector of at most `limit` activities following `after_id` in a stable, repository-specific order,
+    /// or an `ActivitiesListRepositoryError::DatabaseError` if the activities could not be read.
+    /// Iterating through all pages yields every activity exactly once.
+    async fn get_page_after(
+        &self,
+        after_id: Option<ActivityId>,
+        limit: usize,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
+
+    /// Retrieves a page of activities using offset pagination.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset`: The number of activities to skip.
+    /// - `limit`: The maximum number of activities to return.
+    ///
+    /// # Returns
+    /// A vector of at most `limit` activities, sorted descending by date and start time, starting
+    /// after the first `offset` activities, or an `ActivitiesListRepositoryError::DatabaseError` if
+    /// the activities could not be read. Offsets beyond the last activity yield an empty vector.
+    async fn get_paginated(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError>;
+
+    /// Counts all activities in the repository.
+    ///
+    /// # Returns
+    /// The total number of activities, or an `ActivitiesListRepositoryError::DatabaseError` if the
+    /// activities could not be counted.
+    async fn count(&self) -> Result<usize, ActivitiesListRepositoryError>;
+
+    /// Counts the activities within a specified date range.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: The start date of the range (inclusive).
+    /// - `end`: The end date of the range (inclusive).
+    ///
+    /// # Returns
+    /// The number of activities within the specified date range, or an
+    /// `ActivitiesListRepositoryError::DatabaseError` if the activities could not be counted.
+    async fn count_by_date_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<usize, ActivitiesListRepositoryError>;
 
     /// Adds a new activity to the list.
     ///
     /// # Arguments
     ///
     /// - `activity`: The `Activity` instance to be added to the list.
-    async fn add(&mut self, activity: Activity);
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: If the activity was added.
+    /// - `Err(ActivitiesListRepositoryError::DatabaseError)`: If the activity could not be stored.
+    async fn add(&mut self, activity: Activity) -> Result<(), ActivitiesListRepositoryError>;
+
+    /// Adds several new activities to the list.
+    ///
+    /// # Arguments
+    ///
+    /// - `activities`: The `Activity` instances to be added to the list.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: If all activities were added.
+    /// - `Err(ActivitiesListRepositoryError::DatabaseError)`: If the activities could not be stored.
+    async fn add_range(
+        &mut self,
+        activities: Vec<Activity>,
+    ) -> Result<(), ActivitiesListRepositoryError> {
+        for activity in activities {
+            self.add(activity).await?;
+        }
+
+        Ok(())
+    }
 
     /// Updates an existing activity in the repository.
     ///
@@ -190,11 +572,46 @@ pub enum ActivitiesImporterError {
     #[error("Could not parse the activities from the source")]
     ParseError,
 
+    /// The source could not be read.
+    #[error("Could not read the activities from the source: {0}")]
+    IoError(String),
+
+    /// The accounting category of an activity could not be looked up or created.
+    #[error("Could not resolve the accounting category: {0}")]
+    CategoryResolutionError(String),
+
     #[error("Error while accessing the repository: {0}")]
     RepositoryError(String),
 
     #[error("No activities to import")]
     NoActivitiesToImport,
+
+    /// The calendar week stated in a row does not match the ISO week of its date.
+    #[error("Calendar week mismatch in row {row}: stated {stated}, computed {computed}")]
+    CalendarWeekMismatch { row: usize, stated: u8, computed: u8 },
+}
+
+/// A row of the source that could not be imported.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RowImportError {
+    /// The 1-based number of the row in the source, not counting a header row.
+    pub row_number: usize,
+
+    /// The name of the field that could not be parsed.
+    pub field: String,
+
+    /// A human-readable description of why the row could not be imported.
+    pub reason: String,
+}
+
+/// The outcome of an import, holding the imported activities and the rows that were skipped.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImportResult {
+    /// The activities that were imported successfully.
+    pub activities: Vec<Activity>,
+
+    /// The rows that could not be imported.
+    pub errors: Vec<RowImportError>,
 }
 
 #[async_trait]
@@ -207,12 +624,44 @@ pub trait ActivitiesImporter: Send + Sync {
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the import operation.
+    /// An `ImportResult` with the imported activities and the rows that could not be imported, or an
+    /// `ActivitiesImporterError` if the source could not be imported at all.
     async fn import<R>(
         &mut self,
         reader: R,
         year: u16,
-    ) -> Result<Vec<Activity>, ActivitiesImporterError>
+    ) -> Result<ImportResult, ActivitiesImporterError>
     where
         R: Read + Send;
 }
+
+/// Error type for the activities exporter.
+#[derive(Error, Clone, Debug, Eq, PartialEq)]
+pub enum ActivitiesExporterError {
+    #[error("Could not write the activities to the target: {0}")]
+    WriteError(String),
+
+    #[error("Error while accessing the repository: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait ActivitiesExporter: Send + Sync {
+    /// Exports activities to a target.
+    ///
+    /// # Arguments
+    ///
+    /// - `activities`: The activities to export, written in the given order.
+    /// - `writer`: A writer instance that receives the exported data.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the export operation.
+    async fn export<W>(
+        &self,
+        activities: &[Activity],
+        writer: W,
+    ) -> Result<(), ActivitiesExporterError>
+    where
+        W: Write + Send;
+}
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -11,8 +12,9 @@ pub enum AccountingCategoryIdError {
     NotAValidId(String),
 }
 
-/// The unique identifier for an accounting category.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// The unique identifier for an accounting category, serialized as a plain UUID string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct AccountingCategoryId(pub Uuid);
 
 impl AccountingCategoryId {
@@ -49,13 +51,23 @@ impl Display for AccountingCategoryId {
 }
 
 /// Represents a category for accounting.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AccountingCategory {
     /// The unique identifier for the accounting category.
     id: AccountingCategoryId,
 
     /// The name of the accounting category.
     name: String,
+
+    /// The hourly rate used to calculate the cost of activities in this category, if known.
+    hourly_rate: Option<f64>,
+
+    /// A short description of what belongs into this accounting category.
+    description: Option<String>,
+
+    /// Whether the accounting category is archived, i.e. no longer in use but kept for historical activities.
+    #[serde(default)]
+    archived: bool,
 }
 
 impl AccountingCategory {
@@ -68,6 +80,9 @@ impl AccountingCategory {
         Self {
             id: AccountingCategoryId::new(),
             name,
+            hourly_rate: None,
+            description: None,
+            archived: false,
         }
     }
 
@@ -78,7 +93,23 @@ impl AccountingCategory {
     /// - `id`: The unique identifier for the accounting category.
     /// - `name`: The name of the accounting category.
     pub fn with_id(id: AccountingCategoryId, name: String) -> Self {
-        Self { id, name }
+        Self {
+            id,
+            name,
+            hourly_rate: None,
+            description: None,
+            archived: false,
+        }
+    }
+
+    /// Sets the description of the accounting category.
+    ///
+    /// # Arguments
+    ///
+    /// - `description`: A short description of what belongs into this accounting category.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
     }
 
     /// Returns the unique identifier of the accounting category.
@@ -99,6 +130,48 @@ impl AccountingCategory {
     pub fn set_name(&mut self, name: String) {
         self.name = name;
     }
+
+    /// Returns the hourly rate of the accounting category, if known.
+    pub fn hourly_rate(&self) -> Option<f64> {
+        self.hourly_rate
+    }
+
+    /// Sets the hourly rate of the accounting category.
+    ///
+    /// # Arguments
+    ///
+    /// - `hourly_rate`: The new hourly rate, or `None` if the rate is unknown.
+    pub fn set_hourly_rate(&mut self, hourly_rate: Option<f64>) {
+        self.hourly_rate = hourly_rate;
+    }
+
+    /// Returns the description of the accounting category, if any.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Sets the description of the accounting category.
+    ///
+    /// # Arguments
+    ///
+    /// - `description`: The new description, or `None` to remove it.
+    pub fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+
+    /// Returns whether the accounting category is archived.
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    /// Sets whether the accounting category is archived.
+    ///
+    /// # Arguments
+    ///
+    /// - `archived`: `true` to archive the accounting category, `false` to unarchive it.
+    pub fn set_archived(&mut self, archived: bool) {
+        self.archived = archived;
+    }
 }
 
 #[cfg(test)]
@@ -132,6 +205,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn accounting_category_id_should_serialize_as_uuid_string() {
+        let id = AccountingCategoryId::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
+
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, "\"550e8400-e29b-41d4-a716-446655440000\"");
+        assert_eq!(
+            serde_json::from_str::<AccountingCategoryId>(&json).unwrap(),
+            id
+        );
+    }
+
+    #[test]
+    fn accounting_category_should_roundtrip_through_json() {
+        let mut category = AccountingCategory::new("Development".to_string())
+            .with_description("Writing code".to_string());
+        category.set_hourly_rate(Some(80.0));
+
+        let json = serde_json::to_value(&category).unwrap();
+
+        assert_eq!(json["id"], category.id().to_string());
+        assert_eq!(
+            serde_json::from_value::<AccountingCategory>(json).unwrap(),
+            category
+        );
+    }
+
     #[test]
     fn accounting_category_new_should_create_accounting_category_with_name() {
         let category_name = "Test Category";
@@ -159,4 +260,65 @@ mod tests {
 
         assert_eq!(category.name, new_name);
     }
+
+    #[test]
+    fn accounting_category_set_hourly_rate_should_update_hourly_rate() {
+        let mut category = AccountingCategory::new("Test Category".to_string());
+        assert_eq!(category.hourly_rate(), None);
+
+        category.set_hourly_rate(Some(80.0));
+        assert_eq!(category.hourly_rate(), Some(80.0));
+    }
+
+    #[test]
+    fn accounting_category_with_description_should_set_description() {
+        let category = AccountingCategory::new("Test Category".to_string())
+            .with_description("Everything related to testing".to_string());
+
+        assert_eq!(
+            category.description(),
+            Some("Everything related to testing")
+        );
+    }
+
+    #[test]
+    fn accounting_category_set_description_should_update_description() {
+        let mut category = AccountingCategory::new("Test Category".to_string());
+        assert_eq!(category.description(), None);
+
+        category.set_description(Some("Everything related to testing".to_string()));
+        assert_eq!(
+            category.description(),
+            Some("Everything related to testing")
+        );
+
+        category.set_description(None);
+        assert_eq!(category.description(), None);
+    }
+
+    #[test]
+    fn accounting_category_set_archived_should_archive_and_unarchive_category() {
+        let mut category = AccountingCategory::new("Test Category".to_string());
+        assert!(!category.is_archived());
+
+        category.set_archived(true);
+        assert!(category.is_archived());
+
+        category.set_archived(false);
+        assert!(!category.is_archived());
+    }
+
+    #[test]
+    fn accounting_category_should_deserialize_without_archived_flag() {
+        let json = serde_json::json!({
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "name": "Development",
+            "hourly_rate": null,
+            "description": null,
+        });
+
+        let category = serde_json::from_value::<AccountingCategory>(json).unwrap();
+
+        assert!(!category.is_archived());
+    }
 }
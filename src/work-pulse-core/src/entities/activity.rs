@@ -1,10 +1,13 @@
 use std::fmt::Display;
 
-use chrono::{Duration, NaiveDate, NaiveTime};
+use std::hash::{Hash, Hasher};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
-use super::accounting::AccountingCategoryId;
+use super::accounting::{AccountingCategory, AccountingCategoryId};
 
 /// Errors that can occur when working with `ActivityId`.
 #[derive(Error, Clone, Debug, Eq, PartialEq)]
@@ -14,8 +17,29 @@ pub enum ActivityIdError {
     NotAValidId(String),
 }
 
-/// The unique identifier for an activity.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Errors that can occur when modifying an `Activity`.
+#[derive(Error, Clone, Debug, Eq, PartialEq)]
+pub enum ActivityError {
+    /// The end time is not after the start time of the activity.
+    #[error("The end time {end} must be after the start time {start}")]
+    EndTimeBeforeStartTime { start: NaiveTime, end: NaiveTime },
+
+    /// The split time is not strictly between the start and end time of the activity.
+    #[error("The split time {split} must be after the start time {start} and before the end time")]
+    SplitTimeOutOfRange {
+        split: NaiveTime,
+        start: NaiveTime,
+        end: Option<NaiveTime>,
+    },
+
+    /// A required field was not set when building the activity.
+    #[error("The {0} of the activity is required")]
+    MissingField(&'static str),
+}
+
+/// The unique identifier for an activity, serialized as a plain UUID string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct ActivityId(pub Uuid);
 
 impl ActivityId {
@@ -52,7 +76,10 @@ impl Display for ActivityId {
 }
 
 /// Represents an activity that the user did during his working day.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// Two activities are equal if all their fields except the `created_at` and `updated_at`
+/// timestamps are equal, as these are only bookkeeping of the repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Activity {
     /// The unique identifier for the activity.
     id: ActivityId,
@@ -74,6 +101,56 @@ pub struct Activity {
 
     /// An optional comment for the activity.
     comment: Option<String>,
+
+    /// The user (username or email) who created the activity, if known.
+    created_by: Option<String>,
+
+    /// The origin of the activity record (e.g., "csv", "toggl", "manual"), if known.
+    import_source: Option<String>,
+
+    /// Free-form tags of the activity (e.g., "sprint-42", "backend").
+    #[serde(default)]
+    tags: Vec<String>,
+
+    /// When the activity was first stored, if it has been stored by a repository that tracks it.
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+
+    /// When the activity was last stored, if it has been stored by a repository that tracks it.
+    #[serde(default)]
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl PartialEq for Activity {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.date == other.date
+            && self.start_time == other.start_time
+            && self.end_time == other.end_time
+            && self.accounting_category_id == other.accounting_category_id
+            && self.task == other.task
+            && self.comment == other.comment
+            && self.created_by == other.created_by
+            && self.import_source == other.import_source
+            && self.tags == other.tags
+    }
+}
+
+impl Eq for Activity {}
+
+impl Hash for Activity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.date.hash(state);
+        self.start_time.hash(state);
+        self.end_time.hash(state);
+        self.accounting_category_id.hash(state);
+        self.task.hash(state);
+        self.comment.hash(state);
+        self.created_by.hash(state);
+        self.import_source.hash(state);
+        self.tags.hash(state);
+    }
 }
 
 impl Activity {
@@ -91,15 +168,13 @@ impl Activity {
         accounting_category_id: AccountingCategoryId,
         task: String,
     ) -> Self {
-        Self {
-            id: ActivityId::new(),
+        Self::with_id(
+            ActivityId::new(),
             date,
             start_time,
-            end_time: None,
             accounting_category_id,
             task,
-            comment: None,
-        }
+        )
     }
 
     /// Creates a new `Activity` with a specific ID.
@@ -126,9 +201,36 @@ impl Activity {
             accounting_category_id,
             task,
             comment: None,
+            created_by: None,
+            import_source: None,
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
         }
     }
 
+    /// Returns a builder for an `Activity`, to set the optional fields without a chain of setters.
+    pub fn builder() -> ActivityBuilder {
+        ActivityBuilder::new()
+    }
+
+    /// Sets the timestamps of when the activity was first and last stored. Only meant to be used
+    /// by repositories when loading stored activities.
+    ///
+    /// # Arguments
+    ///
+    /// - `created_at`: When the activity was first stored, if known.
+    /// - `updated_at`: When the activity was last stored, if known.
+    pub fn with_timestamps(
+        mut self,
+        created_at: Option<DateTime<Utc>>,
+        updated_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.created_at = created_at;
+        self.updated_at = updated_at;
+        self
+    }
+
     /// Returns the unique identifier for the activity.
     pub fn id(&self) -> &ActivityId {
         &self.id
@@ -149,13 +251,36 @@ impl Activity {
         self.end_time.as_ref()
     }
 
+    /// Returns `true` if the activity is still running, i.e. it has no end time yet.
+    pub fn is_ongoing(&self) -> bool {
+        self.end_time.is_none()
+    }
+
+    /// Returns `true` if the activity has an end time.
+    pub fn is_completed(&self) -> bool {
+        !self.is_ongoing()
+    }
+
     /// Sets the end time for the activity.
     ///
     /// # Arguments
     ///
     /// - `end_time`: The time when the activity ended, if applicable.
-    pub fn set_end_time(&mut self, end_time: Option<NaiveTime>) {
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: If the end time was set.
+    /// - `Err(ActivityError::EndTimeBeforeStartTime)`: If the end time is not strictly after the start time.
+    pub fn set_end_time(&mut self, end_time: Option<NaiveTime>) -> Result<(), ActivityError> {
+        if let Some(end) = end_time.filter(|end| *end <= self.start_time) {
+            return Err(ActivityError::EndTimeBeforeStartTime {
+                start: self.start_time,
+                end,
+            });
+        }
+
         self.end_time = end_time;
+        Ok(())
     }
 
     /// Returns the accounting category ID associated with the activity.
@@ -200,6 +325,74 @@ impl Activity {
         self.comment = comment;
     }
 
+    /// Returns the user who created the activity, if any.
+    pub fn created_by(&self) -> Option<&str> {
+        self.created_by.as_deref()
+    }
+
+    /// Sets the user who created the activity.
+    ///
+    /// # Arguments
+    ///
+    /// - `created_by`: An optional username or email of the creator.
+    pub fn set_created_by(&mut self, created_by: Option<String>) {
+        self.created_by = created_by;
+    }
+
+    /// Returns the source the activity was imported from, if any.
+    pub fn import_source(&self) -> Option<&str> {
+        self.import_source.as_deref()
+    }
+
+    /// Sets the source the activity was imported from.
+    ///
+    /// # Arguments
+    ///
+    /// - `import_source`: An optional name of the origin (e.g., "csv", "toggl", "manual").
+    pub fn set_import_source(&mut self, import_source: Option<String>) {
+        self.import_source = import_source;
+    }
+
+    /// Returns the tags of the activity.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Sets the tags of the activity, replacing all existing tags.
+    ///
+    /// # Arguments
+    ///
+    /// - `tags`: The new tags of the activity.
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        self.tags = tags;
+    }
+
+    /// Adds a tag to the activity, unless the activity already carries it.
+    ///
+    /// # Arguments
+    ///
+    /// - `tag`: The tag to add.
+    pub fn add_tag(&mut self, tag: &str) {
+        if !self.tags.iter().any(|t| t == tag) {
+            self.tags.push(tag.to_string());
+        }
+    }
+
+    /// Returns when the activity was first stored, if known.
+    pub fn created_at(&self) -> Option<&DateTime<Utc>> {
+        self.created_at.as_ref()
+    }
+
+    /// Returns when the activity was last stored, if known.
+    pub fn updated_at(&self) -> Option<&DateTime<Utc>> {
+        self.updated_at.as_ref()
+    }
+
+    /// Returns the ISO 8601 calendar week number (1-53) of the activity date.
+    pub fn week_number(&self) -> u32 {
+        self.date.iso_week().week()
+    }
+
     /// Calculates the duration of the activity.
     /// If the end time is not set, the duration is considered to be zero.
     ///
@@ -213,12 +406,259 @@ impl Activity {
             Duration::zero()
         }
     }
+
+    /// Calculates the cost of the activity based on the hourly rate of its accounting category.
+    ///
+    /// # Arguments
+    ///
+    /// - `category`: The accounting category of the activity.
+    ///
+    /// # Returns
+    ///
+    /// The cost of the activity, or `None` if the hourly rate of the category is unknown.
+    pub fn cost(&self, category: &AccountingCategory) -> Option<f64> {
+        category
+            .hourly_rate()
+            .map(|hourly_rate| self.duration().num_minutes() as f64 / 60.0 * hourly_rate)
+    }
+
+    /// Checks whether the time window of this activity overlaps with another activity.
+    /// Activities without an end time are treated as open-ended until the end of the day.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: The activity to compare with.
+    ///
+    /// # Returns
+    ///
+    /// `true` if both activities are on the same date and their `[start_time, end_time)`
+    /// intervals overlap, `false` otherwise.
+    pub fn overlaps_with(&self, other: &Activity) -> bool {
+        if self.date != other.date {
+            return false;
+        }
+
+        let starts_before_other_ends = other.end_time.is_none_or(|end| self.start_time < end);
+        let other_starts_before_self_ends = self.end_time.is_none_or(|end| other.start_time < end);
+
+        starts_before_other_ends && other_starts_before_self_ends
+    }
+
+    /// Splits the activity at the given time into two activities with new IDs.
+    /// Both activities keep the date, accounting category, task and all other details of this activity.
+    ///
+    /// # Arguments
+    ///
+    /// - `split_time`: The time at which the first activity ends and the second one starts.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((Activity, Activity))`: The activities covering `[start_time, split_time)` and `[split_time, end_time)`.
+    /// - `Err(ActivityError::SplitTimeOutOfRange)`: If the activity has no end time or `split_time` is not strictly between the start and end time.
+    pub fn split_at_time(
+        &self,
+        split_time: NaiveTime,
+    ) -> Result<(Activity, Activity), ActivityError> {
+        let end_time = match self.end_time {
+            Some(end) if self.start_time < split_time && split_time < end => end,
+            _ => {
+                return Err(ActivityError::SplitTimeOutOfRange {
+                    split: split_time,
+                    start: self.start_time,
+                    end: self.end_time,
+                });
+            }
+        };
+
+        let first = Activity {
+            id: ActivityId::new(),
+            end_time: Some(split_time),
+            created_at: None,
+            updated_at: None,
+            ..self.clone()
+        };
+        let second = Activity {
+            id: ActivityId::new(),
+            start_time: split_time,
+            end_time: Some(end_time),
+            created_at: None,
+            updated_at: None,
+            ..self.clone()
+        };
+
+        Ok((first, second))
+    }
+}
+
+/// A builder for an `Activity`, created with `Activity::builder()`.
+///
+/// The date, start time and accounting category ID are required. The other fields default to a
+/// random ID, an empty task and no other details.
+#[derive(Debug, Clone)]
+pub struct ActivityBuilder {
+    /// The unique identifier for the activity.
+    id: ActivityId,
+
+    /// The date when the activity was performed.
+    date: Option<NaiveDate>,
+
+    /// The time when the activity started.
+    start_time: Option<NaiveTime>,
+
+    /// The time when the activity ended, if applicable.
+    end_time: Option<NaiveTime>,
+
+    /// The accounting category ID associated with the activity.
+    accounting_category_id: Option<AccountingCategoryId>,
+
+    /// The task itself.
+    task: String,
+
+    /// An optional comment (notes) for the activity.
+    comment: Option<String>,
+
+    /// The user who created the activity, if known.
+    created_by: Option<String>,
+
+    /// The origin of the activity record, if known.
+    import_source: Option<String>,
+
+    /// Free-form tags of the activity.
+    tags: Vec<String>,
+}
+
+impl ActivityBuilder {
+    /// Creates a new `ActivityBuilder` with the default values and no required fields set.
+    pub fn new() -> Self {
+        Self {
+            id: ActivityId::new(),
+            date: None,
+            start_time: None,
+            end_time: None,
+            accounting_category_id: None,
+            task: String::new(),
+            comment: None,
+            created_by: None,
+            import_source: None,
+            tags: Vec::new(),
+        }
+    }
+
+    /// Sets the unique identifier for the activity, e.g. when loading a stored activity.
+    pub fn id(mut self, id: ActivityId) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the date when the activity was performed.
+    pub fn date(mut self, date: NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the time when the activity started.
+    pub fn start_time(mut self, start_time: NaiveTime) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Sets the time when the activity ended, if applicable.
+    pub fn end_time(mut self, end_time: Option<NaiveTime>) -> Self {
+        self.end_time = end_time;
+        self
+    }
+
+    /// Sets the accounting category ID associated with the activity.
+    pub fn accounting_category_id(mut self, accounting_category_id: AccountingCategoryId) -> Self {
+        self.accounting_category_id = Some(accounting_category_id);
+        self
+    }
+
+    /// Sets the task associated with the activity.
+    pub fn task(mut self, task: String) -> Self {
+        self.task = task;
+        self
+    }
+
+    /// Sets the comment (notes) of the activity.
+    pub fn comment(mut self, comment: Option<String>) -> Self {
+        self.comment = comment;
+        self
+    }
+
+    /// Sets the user (username or email) who created the activity.
+    pub fn created_by(mut self, created_by: Option<String>) -> Self {
+        self.created_by = created_by;
+        self
+    }
+
+    /// Sets the source the activity was imported from (e.g., "csv", "toggl", "manual").
+    pub fn import_source(mut self, import_source: Option<String>) -> Self {
+        self.import_source = import_source;
+        self
+    }
+
+    /// Sets the tags of the activity.
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Builds the `Activity`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Activity)`: The activity with the values of the builder.
+    /// - `Err(ActivityError::MissingField)`: If the date, start time or accounting category ID is not set.
+    /// - `Err(ActivityError::EndTimeBeforeStartTime)`: If the end time is not strictly after the start time.
+    pub fn build(self) -> Result<Activity, ActivityError> {
+        let date = self.date.ok_or(ActivityError::MissingField("date"))?;
+        let start_time = self
+            .start_time
+            .ok_or(ActivityError::MissingField("start_time"))?;
+        let accounting_category_id = self
+            .accounting_category_id
+            .ok_or(ActivityError::MissingField("accounting_category_id"))?;
+
+        let mut activity =
+            Activity::with_id(self.id, date, start_time, accounting_category_id, self.task);
+        activity.set_end_time(self.end_time)?;
+        activity.set_comment(self.comment);
+        activity.set_created_by(self.created_by);
+        activity.set_import_source(self.import_source);
+        activity.set_tags(self.tags);
+
+        Ok(activity)
+    }
+}
+
+impl Default for ActivityBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn activity_at(day: u32, start: (u32, u32), end: Option<(u32, u32)>) -> Activity {
+        let mut activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+            NaiveTime::from_hms_opt(start.0, start.1, 0).expect("Valid activity start time"),
+            AccountingCategoryId::new(),
+            "Test Task".to_string(),
+        );
+        if let Some((hour, minute)) = end {
+            let end_time =
+                NaiveTime::from_hms_opt(hour, minute, 0).expect("Valid activity end time");
+            activity
+                .set_end_time(Some(end_time))
+                .expect("End time after start time");
+        }
+        activity
+    }
+
     #[test]
     fn activity_id_new_should_create_activity_with_id() {
         let id = ActivityId::new();
@@ -245,6 +685,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn activity_id_should_serialize_as_uuid_string() {
+        let id = ActivityId::parse_str("550E8400-E29B-41D4-A716-446655440000").unwrap();
+
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, "\"550e8400-e29b-41d4-a716-446655440000\"");
+        assert_eq!(serde_json::from_str::<ActivityId>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn activity_should_roundtrip_through_json() {
+        let mut activity = activity_at(1, (9, 0), Some((10, 30)));
+        activity.set_comment(Some("Pairing".to_string()));
+
+        let json = serde_json::to_value(&activity).unwrap();
+
+        assert_eq!(json["id"], activity.id().to_string());
+        assert_eq!(
+            json["accounting_category_id"],
+            activity.accounting_category_id().to_string()
+        );
+        assert_eq!(serde_json::from_value::<Activity>(json).unwrap(), activity);
+    }
+
     #[test]
     fn activity_new_should_create_activity_with_random_id() {
         let date = NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date");
@@ -303,10 +768,58 @@ mod tests {
         assert_eq!(activity.duration(), Duration::zero());
 
         // Set the end_time and check the duration
-        activity.set_end_time(Some(end_time));
+        activity
+            .set_end_time(Some(end_time))
+            .expect("End time after start time");
         assert_eq!(activity.duration(), Duration::minutes(90));
     }
 
+    #[test]
+    fn set_end_time_should_reject_end_time_not_after_start_time() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date");
+        let start_time = NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time");
+        let end_time = NaiveTime::from_hms_opt(8, 30, 0).expect("Valid activity end time");
+
+        let accounting_category_id = AccountingCategoryId::new();
+        let task = "Test Task".to_string();
+
+        let mut activity = Activity::new(date, start_time, accounting_category_id, task);
+
+        assert_eq!(
+            activity.set_end_time(Some(end_time)),
+            Err(ActivityError::EndTimeBeforeStartTime {
+                start: start_time,
+                end: end_time
+            })
+        );
+        assert_eq!(
+            activity.set_end_time(Some(start_time)),
+            Err(ActivityError::EndTimeBeforeStartTime {
+                start: start_time,
+                end: start_time
+            })
+        );
+        assert_eq!(activity.end_time(), None);
+    }
+
+    #[test]
+    fn set_end_time_should_allow_removing_end_time() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date");
+        let start_time = NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time");
+        let end_time = NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity end time");
+
+        let accounting_category_id = AccountingCategoryId::new();
+        let task = "Test Task".to_string();
+
+        let mut activity = Activity::new(date, start_time, accounting_category_id, task);
+        activity
+            .set_end_time(Some(end_time))
+            .expect("End time after start time");
+
+        assert_eq!(activity.set_end_time(None), Ok(()));
+        assert_eq!(activity.end_time(), None);
+    }
+
     #[test]
     fn set_comment_should_store_and_retrieve_comment() {
         let date = NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date");
@@ -324,6 +837,107 @@ mod tests {
         assert_eq!(activity.comment(), None);
     }
 
+    #[test]
+    fn set_created_by_should_store_and_retrieve_creator() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date");
+        let start_time = NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time");
+        let accounting_category_id = AccountingCategoryId::new();
+        let task = "Test Task".to_string();
+
+        let mut activity = Activity::new(date, start_time, accounting_category_id, task);
+        assert_eq!(activity.created_by(), None);
+
+        activity.set_created_by(Some("jdoe".to_string()));
+        assert_eq!(activity.created_by(), Some("jdoe"));
+    }
+
+    #[test]
+    fn set_import_source_should_store_and_retrieve_source() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date");
+        let start_time = NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time");
+        let accounting_category_id = AccountingCategoryId::new();
+        let task = "Test Task".to_string();
+
+        let mut activity = Activity::new(date, start_time, accounting_category_id, task);
+        assert_eq!(activity.import_source(), None);
+
+        activity.set_import_source(Some("csv".to_string()));
+        assert_eq!(activity.import_source(), Some("csv"));
+    }
+
+    #[test]
+    fn set_tags_should_replace_tags() {
+        let mut activity = activity_at(1, (9, 0), None);
+        assert!(activity.tags().is_empty());
+
+        activity.set_tags(vec!["sprint-42".to_string(), "backend".to_string()]);
+        assert_eq!(activity.tags(), ["sprint-42", "backend"]);
+
+        activity.set_tags(vec!["frontend".to_string()]);
+        assert_eq!(activity.tags(), ["frontend"]);
+    }
+
+    #[test]
+    fn add_tag_should_append_tag_only_once() {
+        let mut activity = activity_at(1, (9, 0), None);
+
+        activity.add_tag("sprint-42");
+        activity.add_tag("backend");
+        activity.add_tag("sprint-42");
+
+        assert_eq!(activity.tags(), ["sprint-42", "backend"]);
+    }
+
+    #[test]
+    fn eq_should_ignore_timestamps() {
+        let activity = activity_at(2, (9, 0), Some((10, 0)));
+        let stored = activity
+            .clone()
+            .with_timestamps(Some(Utc::now()), Some(Utc::now()));
+
+        assert_eq!(stored, activity);
+        assert_eq!(activity.created_at(), None);
+        assert!(stored.created_at().is_some());
+        assert!(stored.updated_at().is_some());
+    }
+
+    #[test]
+    fn week_number_should_return_iso_week_of_date() {
+        assert_eq!(activity_at(13, (9, 0), None).week_number(), 41);
+
+        let new_year = Activity::new(
+            NaiveDate::from_ymd_opt(2021, 1, 1).expect("Valid activity date"),
+            NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+            AccountingCategoryId::new(),
+            "Test Task".to_string(),
+        );
+        assert_eq!(new_year.week_number(), 53);
+    }
+
+    #[test]
+    fn cost_should_calculate_cost_from_hourly_rate() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date");
+        let start_time = NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time");
+        let end_time = NaiveTime::from_hms_opt(10, 30, 0).expect("Valid activity end time");
+        let mut category = AccountingCategory::new("Test Category".to_string());
+
+        let mut activity = Activity::new(
+            date,
+            start_time,
+            category.id().clone(),
+            "Test Task".to_string(),
+        );
+        activity
+            .set_end_time(Some(end_time))
+            .expect("End time after start time");
+
+        // Without an hourly rate, the cost is unknown
+        assert_eq!(activity.cost(&category), None);
+
+        category.set_hourly_rate(Some(80.0));
+        assert_eq!(activity.cost(&category), Some(120.0));
+    }
+
     #[test]
     fn duration_should_return_zero_for_ongoing_activity() {
         let date = NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date");
@@ -336,4 +950,325 @@ mod tests {
         // Since end_time is None, duration should be zero
         assert_eq!(activity.duration(), Duration::zero());
     }
+
+    #[test]
+    fn is_ongoing_should_be_true_without_end_time() {
+        let activity = activity_at(1, (9, 0), None);
+
+        assert!(activity.is_ongoing());
+        assert!(!activity.is_completed());
+    }
+
+    #[test]
+    fn is_completed_should_be_true_with_end_time() {
+        let activity = activity_at(1, (9, 0), Some((10, 0)));
+
+        assert!(activity.is_completed());
+        assert!(!activity.is_ongoing());
+    }
+
+    #[test]
+    fn is_ongoing_should_be_true_after_removing_end_time() {
+        let mut activity = activity_at(1, (9, 0), Some((10, 0)));
+        activity.set_end_time(None).unwrap();
+
+        assert!(activity.is_ongoing());
+    }
+
+    #[test]
+    fn overlaps_with_should_detect_full_overlap() {
+        let outer = activity_at(1, (9, 0), Some((12, 0)));
+        let inner = activity_at(1, (10, 0), Some((11, 0)));
+
+        assert!(outer.overlaps_with(&inner));
+        assert!(inner.overlaps_with(&outer));
+    }
+
+    #[test]
+    fn overlaps_with_should_detect_partial_overlap() {
+        let first = activity_at(1, (9, 0), Some((10, 30)));
+        let second = activity_at(1, (10, 0), Some((11, 0)));
+
+        assert!(first.overlaps_with(&second));
+        assert!(second.overlaps_with(&first));
+    }
+
+    #[test]
+    fn overlaps_with_should_not_detect_touching_boundaries() {
+        let first = activity_at(1, (9, 0), Some((10, 0)));
+        let second = activity_at(1, (10, 0), Some((11, 0)));
+
+        assert!(!first.overlaps_with(&second));
+        assert!(!second.overlaps_with(&first));
+    }
+
+    #[test]
+    fn overlaps_with_should_not_detect_activities_on_different_dates() {
+        let first = activity_at(1, (9, 0), Some((12, 0)));
+        let second = activity_at(2, (10, 0), Some((11, 0)));
+
+        assert!(!first.overlaps_with(&second));
+    }
+
+    #[test]
+    fn overlaps_with_should_treat_missing_end_time_as_open_ended() {
+        let open_ended = activity_at(1, (9, 0), None);
+        let later = activity_at(1, (15, 0), Some((16, 0)));
+        let earlier = activity_at(1, (7, 0), Some((8, 0)));
+
+        assert!(open_ended.overlaps_with(&later));
+        assert!(later.overlaps_with(&open_ended));
+        assert!(!open_ended.overlaps_with(&earlier));
+    }
+
+    #[test]
+    fn overlaps_with_should_detect_overlap_when_both_are_open_ended() {
+        let first = activity_at(1, (9, 0), None);
+        let second = activity_at(1, (13, 0), None);
+
+        assert!(first.overlaps_with(&second));
+    }
+
+    #[test]
+    fn split_at_time_should_divide_activity_into_two_segments() {
+        let activity = activity_at(1, (9, 0), Some((13, 0)));
+        let split_time = NaiveTime::from_hms_opt(11, 30, 0).unwrap();
+
+        let (first, second) = activity.split_at_time(split_time).unwrap();
+
+        assert_eq!(first.start_time(), activity.start_time());
+        assert_eq!(first.end_time(), Some(&split_time));
+        assert_eq!(second.start_time(), &split_time);
+        assert_eq!(second.end_time(), activity.end_time());
+        assert_eq!(first.duration() + second.duration(), activity.duration());
+
+        for part in [&first, &second] {
+            assert_ne!(part.id(), activity.id());
+            assert_eq!(part.date(), activity.date());
+            assert_eq!(
+                part.accounting_category_id(),
+                activity.accounting_category_id()
+            );
+            assert_eq!(part.task(), activity.task());
+        }
+        assert_ne!(first.id(), second.id());
+    }
+
+    #[test]
+    fn split_at_time_should_fail_at_boundaries() {
+        let activity = activity_at(1, (9, 0), Some((13, 0)));
+
+        for split_time in [
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(13, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(14, 0, 0).unwrap(),
+        ] {
+            assert_eq!(
+                activity.split_at_time(split_time),
+                Err(ActivityError::SplitTimeOutOfRange {
+                    split: split_time,
+                    start: *activity.start_time(),
+                    end: activity.end_time().copied(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn split_at_time_should_fail_without_end_time() {
+        let activity = activity_at(1, (9, 0), None);
+        let split_time = NaiveTime::from_hms_opt(10, 0, 0).unwrap();
+
+        assert_eq!(
+            activity.split_at_time(split_time),
+            Err(ActivityError::SplitTimeOutOfRange {
+                split: split_time,
+                start: *activity.start_time(),
+                end: None,
+            })
+        );
+    }
+
+    #[test]
+    fn split_at_time_should_fail_when_end_is_before_start() {
+        let mut activity = activity_at(1, (13, 0), None);
+        activity.end_time = Some(NaiveTime::from_hms_opt(9, 0, 0).unwrap());
+
+        assert!(
+            activity
+                .split_at_time(NaiveTime::from_hms_opt(11, 0, 0).unwrap())
+                .is_err()
+        );
+    }
+
+    mod properties {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        fn time() -> impl Strategy<Value = NaiveTime> {
+            (0u32..86_400).prop_map(|seconds| {
+                NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0).expect("Valid time")
+            })
+        }
+
+        fn activity_from(start_time: NaiveTime, end_time: Option<NaiveTime>) -> Activity {
+            let mut activity = Activity::new(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                start_time,
+                AccountingCategoryId::new(),
+                "Test Task".to_string(),
+            );
+            activity
+                .set_end_time(end_time)
+                .expect("End time after start time");
+            activity
+        }
+
+        proptest! {
+            #[test]
+            fn duration_should_be_positive_and_exact_for_completed_activity(
+                start_time in time(),
+                end_time in time(),
+            ) {
+                prop_assume!(start_time < end_time);
+
+                let activity = activity_from(start_time, Some(end_time));
+
+                prop_assert!(activity.duration() > Duration::zero());
+                prop_assert_eq!(activity.duration(), end_time - start_time);
+            }
+
+            #[test]
+            fn duration_should_be_zero_for_ongoing_activity(start_time in time()) {
+                prop_assert_eq!(activity_from(start_time, None).duration(), Duration::zero());
+            }
+
+            #[test]
+            fn split_at_time_should_preserve_total_duration(
+                mut times in prop::array::uniform3(time()),
+            ) {
+                times.sort();
+                let [start_time, split_time, end_time] = times;
+                prop_assume!(start_time < split_time && split_time < end_time);
+
+                let activity = activity_from(start_time, Some(end_time));
+                let (first, second) = activity.split_at_time(split_time).unwrap();
+
+                prop_assert_eq!(first.duration() + second.duration(), activity.duration());
+            }
+
+            #[test]
+            fn activity_id_should_roundtrip_through_string(value in any::<u128>()) {
+                let id = ActivityId(Uuid::from_u128(value));
+
+                prop_assert_eq!(ActivityId::parse_str(&id.to_string()), Ok(id));
+            }
+        }
+    }
+
+    /// Returns a builder with the required date, start time and accounting category ID set.
+    fn builder_with_required_fields() -> ActivityBuilder {
+        Activity::builder()
+            .date(NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"))
+            .start_time(NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"))
+            .accounting_category_id(AccountingCategoryId::new())
+    }
+
+    #[test]
+    fn builder_should_use_default_values() {
+        let activity = builder_with_required_fields().build().unwrap();
+
+        assert_eq!(activity.end_time(), None);
+        assert_eq!(activity.task(), "");
+        assert_eq!(activity.comment(), None);
+        assert_eq!(activity.created_by(), None);
+        assert_eq!(activity.import_source(), None);
+        assert!(activity.tags().is_empty());
+        assert_ne!(
+            builder_with_required_fields().build().unwrap().id(),
+            activity.id()
+        );
+    }
+
+    #[test]
+    fn builder_should_reject_missing_required_fields() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date");
+        let start_time = NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time");
+
+        assert_eq!(
+            Activity::builder()
+                .start_time(start_time)
+                .accounting_category_id(AccountingCategoryId::new())
+                .build(),
+            Err(ActivityError::MissingField("date"))
+        );
+        assert_eq!(
+            Activity::builder()
+                .date(date)
+                .accounting_category_id(AccountingCategoryId::new())
+                .build(),
+            Err(ActivityError::MissingField("start_time"))
+        );
+        assert_eq!(
+            Activity::builder()
+                .date(date)
+                .start_time(start_time)
+                .build(),
+            Err(ActivityError::MissingField("accounting_category_id"))
+        );
+    }
+
+    #[test]
+    fn builder_should_chain_all_setters() {
+        let id = ActivityId::new();
+        let date = NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date");
+        let start_time = NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time");
+        let end_time = NaiveTime::from_hms_opt(10, 30, 0).expect("Valid activity end time");
+        let accounting_category_id = AccountingCategoryId::new();
+
+        let activity = Activity::builder()
+            .id(id.clone())
+            .date(date)
+            .start_time(start_time)
+            .end_time(Some(end_time))
+            .accounting_category_id(accounting_category_id.clone())
+            .task("Code Review".to_string())
+            .comment(Some("Pairing".to_string()))
+            .created_by(Some("alice".to_string()))
+            .import_source(Some("csv".to_string()))
+            .tags(vec!["backend".to_string()])
+            .build()
+            .unwrap();
+
+        let mut expected = Activity::with_id(
+            id,
+            date,
+            start_time,
+            accounting_category_id,
+            "Code Review".to_string(),
+        );
+        expected.set_end_time(Some(end_time)).unwrap();
+        expected.set_comment(Some("Pairing".to_string()));
+        expected.set_created_by(Some("alice".to_string()));
+        expected.set_import_source(Some("csv".to_string()));
+        expected.set_tags(vec!["backend".to_string()]);
+        assert_eq!(activity, expected);
+    }
+
+    #[test]
+    fn builder_should_reject_end_time_before_start_time() {
+        let start = NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity start time");
+        let end = NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity end time");
+
+        let result = builder_with_required_fields()
+            .start_time(start)
+            .end_time(Some(end))
+            .build();
+
+        assert_eq!(
+            result,
+            Err(ActivityError::EndTimeBeforeStartTime { start, end })
+        );
+    }
 }
@@ -0,0 +1,133 @@
+use chrono::Duration;
+
+use crate::entities::{accounting::AccountingCategoryId, activity::Activity};
+
+/// Aggregated statistics of the activities of a specific accounting category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategorySummary {
+    /// The accounting category of the activities.
+    category_id: AccountingCategoryId,
+
+    /// The number of activities of the category.
+    activity_count: usize,
+
+    /// The total duration of the activities. Activities without an end time do not contribute to it.
+    total_duration: Duration,
+}
+
+impl CategorySummary {
+    /// Creates a new `CategorySummary`.
+    ///
+    /// # Arguments
+    ///
+    /// - `category_id`: The accounting category of the activities.
+    /// - `activity_count`: The number of activities of the category.
+    /// - `total_duration`: The total duration of the activities.
+    pub fn new(
+        category_id: AccountingCategoryId,
+        activity_count: usize,
+        total_duration: Duration,
+    ) -> Self {
+        Self {
+            category_id,
+            activity_count,
+            total_duration,
+        }
+    }
+
+    /// Groups activities by their accounting category.
+    ///
+    /// # Arguments
+    ///
+    /// - `activities`: The activities to group.
+    ///
+    /// # Returns
+    ///
+    /// - A vector with one summary for each accounting category of the activities, ordered by category ID.
+    pub fn from_activities(activities: &[Activity]) -> Vec<Self> {
+        let mut summaries: Vec<Self> = Vec::new();
+
+        for activity in activities {
+            match summaries
+                .iter_mut()
+                .find(|summary| summary.category_id == *activity.accounting_category_id())
+            {
+                Some(summary) => {
+                    summary.activity_count += 1;
+                    summary.total_duration += activity.duration();
+                }
+                None => summaries.push(Self::new(
+                    activity.accounting_category_id().clone(),
+                    1,
+                    activity.duration(),
+                )),
+            }
+        }
+
+        summaries.sort_by_key(|summary| summary.category_id.0);
+        summaries
+    }
+
+    /// Returns the accounting category of the activities.
+    pub fn category_id(&self) -> &AccountingCategoryId {
+        &self.category_id
+    }
+
+    /// Returns the number of activities of the category.
+    pub fn activity_count(&self) -> usize {
+        self.activity_count
+    }
+
+    /// Returns the total duration of the activities.
+    pub fn total_duration(&self) -> Duration {
+        self.total_duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime};
+
+    use super::*;
+
+    fn activity(category_id: &AccountingCategoryId, start: u32, end: Option<u32>) -> Activity {
+        let mut activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date"),
+            NaiveTime::from_hms_opt(start, 0, 0).expect("Valid activity start time"),
+            category_id.clone(),
+            "Test Task".to_string(),
+        );
+        activity
+            .set_end_time(
+                end.map(|end| NaiveTime::from_hms_opt(end, 0, 0).expect("Valid activity end time")),
+            )
+            .expect("End time after start time");
+        activity
+    }
+
+    #[test]
+    fn from_activities_should_be_empty_without_activities() {
+        assert!(CategorySummary::from_activities(&[]).is_empty());
+    }
+
+    #[test]
+    fn from_activities_should_group_by_category() {
+        let category1 = AccountingCategoryId::new();
+        let category2 = AccountingCategoryId::new();
+        let activities = vec![
+            activity(&category1, 9, Some(10)),
+            activity(&category2, 10, Some(12)),
+            activity(&category1, 13, Some(15)),
+            activity(&category1, 16, None),
+        ];
+
+        let summaries = CategorySummary::from_activities(&activities);
+
+        let mut expected = vec![
+            CategorySummary::new(category1, 3, Duration::hours(3)),
+            CategorySummary::new(category2, 1, Duration::hours(2)),
+        ];
+        expected.sort_by_key(|summary| summary.category_id().0);
+        assert_eq!(summaries, expected);
+    }
+}
@@ -1,2 +1,4 @@
 pub mod activity;
 pub mod accounting;
+pub mod category_summary;
+pub mod time_distribution;
@@ -0,0 +1,137 @@
+use chrono::Timelike;
+
+use crate::entities::activity::Activity;
+
+/// The number of hours in a day, i.e. the number of buckets in a time distribution.
+pub const HOURS_PER_DAY: u32 = 24;
+
+/// Aggregated statistics of the activities starting in a specific hour of the day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HourBucket {
+    /// The hour of the day (0-23) in which the activities started.
+    hour: u32,
+
+    /// The number of activities that started in this hour.
+    count: usize,
+
+    /// The average duration of the activities in seconds.
+    avg_duration_seconds: i64,
+}
+
+impl HourBucket {
+    /// Creates a new `HourBucket`.
+    ///
+    /// # Arguments
+    ///
+    /// - `hour`: The hour of the day (0-23).
+    /// - `count`: The number of activities that started in this hour.
+    /// - `avg_duration_seconds`: The average duration of the activities in seconds.
+    pub fn new(hour: u32, count: usize, avg_duration_seconds: i64) -> Self {
+        Self {
+            hour,
+            count,
+            avg_duration_seconds,
+        }
+    }
+
+    /// Groups activities by the hour of their start time.
+    ///
+    /// # Arguments
+    ///
+    /// - `activities`: The activities to group.
+    ///
+    /// # Returns
+    ///
+    /// - A vector with one bucket for each of the 24 hours of the day, ordered by hour.
+    pub fn from_activities(activities: &[Activity]) -> Vec<Self> {
+        (0..HOURS_PER_DAY)
+            .map(|hour| {
+                let durations: Vec<i64> = activities
+                    .iter()
+                    .filter(|activity| activity.start_time().hour() == hour)
+                    .map(|activity| activity.duration().num_seconds())
+                    .collect();
+
+                let count = durations.len();
+                let avg_duration_seconds = if count == 0 {
+                    0
+                } else {
+                    durations.iter().sum::<i64>() / count as i64
+                };
+
+                Self::new(hour, count, avg_duration_seconds)
+            })
+            .collect()
+    }
+
+    /// Returns the hour of the day (0-23).
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Returns the number of activities that started in this hour.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the average duration of the activities in seconds.
+    pub fn avg_duration_seconds(&self) -> i64 {
+        self.avg_duration_seconds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime};
+
+    use super::*;
+    use crate::entities::accounting::AccountingCategoryId;
+
+    fn activity(start: (u32, u32), end: (u32, u32)) -> Activity {
+        let mut activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date"),
+            NaiveTime::from_hms_opt(start.0, start.1, 0).expect("Valid activity start time"),
+            AccountingCategoryId::new(),
+            "Test Task".to_string(),
+        );
+        activity
+            .set_end_time(Some(
+                NaiveTime::from_hms_opt(end.0, end.1, 0).expect("Valid activity end time"),
+            ))
+            .expect("End time after start time");
+        activity
+    }
+
+    #[test]
+    fn from_activities_should_return_a_bucket_for_each_hour() {
+        let buckets = HourBucket::from_activities(&[]);
+
+        assert_eq!(buckets.len(), 24);
+        assert!(
+            buckets
+                .iter()
+                .enumerate()
+                .all(|(i, b)| b.hour() == i as u32)
+        );
+        assert!(
+            buckets
+                .iter()
+                .all(|b| b.count() == 0 && b.avg_duration_seconds() == 0)
+        );
+    }
+
+    #[test]
+    fn from_activities_should_group_by_start_hour() {
+        let activities = vec![
+            activity((9, 0), (10, 0)),
+            activity((9, 30), (9, 45)),
+            activity((14, 0), (16, 0)),
+        ];
+
+        let buckets = HourBucket::from_activities(&activities);
+
+        assert_eq!(buckets[9], HourBucket::new(9, 2, 2250));
+        assert_eq!(buckets[14], HourBucket::new(14, 1, 7200));
+        assert_eq!(buckets[10], HourBucket::new(10, 0, 0));
+    }
+}
@@ -0,0 +1,250 @@
+use std::{collections::HashMap, io::Write, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::Datelike;
+use csv::Writer;
+use serde::Serialize;
+use tokio::sync::Mutex;
+
+use crate::{
+    adapters::{AccountingCategoriesListRepository, ActivitiesExporter, ActivitiesExporterError},
+    entities::activity::Activity,
+};
+
+/// An exporter for activities to CSV files, using the same column layout as the
+/// `CsvActivitiesImporter` so that exported files can be imported again.
+pub struct CsvActivitiesExporter {
+    /// The repository used to resolve the names of the accounting categories.
+    accounting_categories_list_repository: Arc<Mutex<dyn AccountingCategoriesListRepository>>,
+}
+
+impl CsvActivitiesExporter {
+    /// Creates a new `CsvActivitiesExporter`.
+    ///
+    /// # Arguments
+    ///
+    /// - `accounting_categories_list_repository`: An `Arc<Mutex<dyn AccountingCategoriesListRepository>>` to resolve accounting category names.
+    pub fn new(
+        accounting_categories_list_repository: Arc<Mutex<dyn AccountingCategoriesListRepository>>,
+    ) -> Self {
+        Self {
+            accounting_categories_list_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl ActivitiesExporter for CsvActivitiesExporter {
+    /// Exports activities as CSV to a writer.
+    ///
+    /// # Arguments
+    ///
+    /// - `activities`: The activities to export, written in the given order.
+    /// - `writer`: A writer that receives the CSV data.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the export is successful.
+    /// - `Err(ActivitiesExporterError)` if there is an error during export.
+    #[tracing::instrument(skip(self, activities, writer), fields(activities = activities.len()))]
+    async fn export<W>(
+        &self,
+        activities: &[Activity],
+        writer: W,
+    ) -> Result<(), ActivitiesExporterError>
+    where
+        W: Write + Send,
+    {
+        let category_names: HashMap<_, _> = self
+            .accounting_categories_list_repository
+            .lock()
+            .await
+            .get_all(true)
+            .await
+            .map_err(|err| ActivitiesExporterError::RepositoryError(err.to_string()))?
+            .into_iter()
+            .map(|category| (category.id().clone(), category.name().to_string()))
+            .collect();
+
+        let mut csv_writer = Writer::from_writer(writer);
+
+        for activity in activities {
+            let pam_category = category_names
+                .get(activity.accounting_category_id())
+                .cloned()
+                .unwrap_or_default();
+
+            csv_writer
+                .serialize(ActivityTableRecord::from_entity(activity, pam_category))
+                .map_err(|err| ActivitiesExporterError::WriteError(err.to_string()))?;
+        }
+
+        csv_writer
+            .flush()
+            .map_err(|err| ActivitiesExporterError::WriteError(err.to_string()))?;
+
+        tracing::info!("CSV export complete");
+
+        Ok(())
+    }
+}
+
+/// A record representing a row in the activity CSV file.
+/// The fields correspond to the columns in the CSV file.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize)]
+struct ActivityTableRecord {
+    #[serde(rename = "CW")]
+    pub cw: u32,
+
+    #[serde(rename = "Date")]
+    pub date: String,
+
+    #[serde(rename = "Check In")]
+    pub check_in: String,
+
+    #[serde(rename = "Check Out")]
+    pub check_out: String,
+
+    #[serde(rename = "PAM Category")]
+    pub pam_category: String,
+
+    #[serde(rename = "Topic")]
+    pub task: String,
+
+    #[serde(rename = "Comment")]
+    pub comment: String,
+}
+
+impl ActivityTableRecord {
+    /// Converts an activity into a CSV record. Dates are written as "dd.mm." and times as
+    /// "HH:MM", matching the format expected by the `CsvActivitiesImporter`.
+    ///
+    /// # Arguments
+    ///
+    /// - `activity`: The activity to convert.
+    /// - `pam_category`: The name of the accounting category of the activity.
+    fn from_entity(activity: &Activity, pam_category: String) -> Self {
+        Self {
+            cw: activity.date().iso_week().week(),
+            date: activity.date().format("%d.%m.").to_string(),
+            check_in: activity.start_time().format("%H:%M").to_string(),
+            check_out: activity
+                .end_time()
+                .map(|end_time| end_time.format("%H:%M").to_string())
+                .unwrap_or_default(),
+            pam_category,
+            task: activity.task().to_string(),
+            comment: activity.comment().unwrap_or_default().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime};
+
+    use crate::{
+        adapters::ActivitiesImporter,
+        entities::accounting::AccountingCategory,
+        infra::{
+            importers::csv_activities_importer::CsvActivitiesImporter,
+            repositories::in_memory::accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+        },
+    };
+
+    use super::*;
+
+    async fn repository_with_category(
+        name: &str,
+    ) -> (
+        Arc<Mutex<InMemoryAccountingCategoriesListRepository>>,
+        AccountingCategory,
+    ) {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let category = repository
+            .lock()
+            .await
+            .get_or_create_by_name(name)
+            .await
+            .unwrap();
+
+        (repository, category)
+    }
+
+    #[tokio::test]
+    async fn export_should_write_activities_as_csv() {
+        let (repository, category) = repository_with_category("Development").await;
+
+        let mut activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 3, 15).unwrap(),
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            category.id().clone(),
+            "Coding".to_string(),
+        );
+        activity
+            .set_end_time(Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()))
+            .unwrap();
+        activity.set_comment(Some("Worked on project X".to_string()));
+
+        let mut output = Vec::new();
+        CsvActivitiesExporter::new(repository)
+            .export(&[activity], &mut output)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\
+CW,Date,Check In,Check Out,PAM Category,Topic,Comment
+11,15.03.,09:00,17:00,Development,Coding,Worked on project X
+"
+        );
+    }
+
+    #[tokio::test]
+    async fn export_should_leave_missing_values_empty() {
+        let (repository, _) = repository_with_category("Development").await;
+
+        let activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 3, 15).unwrap(),
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            crate::entities::accounting::AccountingCategoryId::new(),
+            "Coding".to_string(),
+        );
+
+        let mut output = Vec::new();
+        CsvActivitiesExporter::new(repository)
+            .export(&[activity], &mut output)
+            .await
+            .unwrap();
+
+        assert!(
+            String::from_utf8(output)
+                .unwrap()
+                .ends_with("11,15.03.,09:00,,,Coding,\n")
+        );
+    }
+
+    #[tokio::test]
+    async fn exported_csv_should_be_importable() {
+        let csv_data = "\
+CW,Date,Check In,Check Out,PAM Category,Topic,Comment
+11,15.03.,09:00,17:00,Development,Coding,Worked on project X
+11,16.03.,10:00,18:00,Meeting,Team Meeting,
+";
+        let (repository, _) = repository_with_category("Development").await;
+        let activities = CsvActivitiesImporter::new(repository.clone())
+            .import(csv_data.as_bytes(), 2023)
+            .await
+            .unwrap()
+            .activities;
+
+        let mut output = Vec::new();
+        CsvActivitiesExporter::new(repository)
+            .export(&activities, &mut output)
+            .await
+            .unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), csv_data);
+    }
+}
@@ -0,0 +1,199 @@
+use std::{collections::HashMap, fmt::Write};
+
+use chrono::Duration;
+
+use crate::{entities::accounting::AccountingCategoryId, use_cases::weekly_report::WeeklyReport};
+
+/// Renders a weekly report as a self-contained, printable HTML page.
+///
+/// The page lists the activities of each day of the week followed by the total duration per
+/// accounting category. All styles are inlined, so the page does not load any external resources.
+///
+/// # Arguments
+///
+/// - `report`: The weekly report to render.
+/// - `category_names`: The names of the accounting categories, keyed by their ID. Categories
+///   without a name are rendered with their ID.
+///
+/// # Returns
+///
+/// The HTML page as a string.
+pub fn render_weekly_report(
+    report: &WeeklyReport,
+    category_names: &HashMap<AccountingCategoryId, String>,
+) -> String {
+    let category_name = |id: &AccountingCategoryId| {
+        category_names
+            .get(id)
+            .cloned()
+            .unwrap_or_else(|| id.to_string())
+    };
+
+    let mut html = String::new();
+    let title = format!(
+        "Weekly Report {} - {}",
+        report.week_start(),
+        report.week_end()
+    );
+
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    let _ = writeln!(html, "<title>{}</title>", escape(&title));
+    html.push_str(STYLE);
+    html.push_str("</head>\n<body>\n");
+    let _ = writeln!(html, "<h1>{}</h1>", escape(&title));
+    let _ = writeln!(
+        html,
+        "<p>Total duration: {}</p>",
+        format_duration(report.total_duration())
+    );
+
+    for (date, activities) in report.activities_per_day() {
+        if activities.is_empty() {
+            continue;
+        }
+
+        let _ = writeln!(html, "<h2>{}</h2>", date.format("%A, %Y-%m-%d"));
+        html.push_str("<table>\n<tr><th>Start</th><th>End</th><th>Duration</th><th>Category</th><th>Task</th></tr>\n");
+        for activity in activities {
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                activity.start_time().format("%H:%M"),
+                activity
+                    .end_time()
+                    .map(|end_time| end_time.format("%H:%M").to_string())
+                    .unwrap_or_default(),
+                format_duration(activity.duration()),
+                escape(&category_name(activity.accounting_category_id())),
+                escape(activity.task()),
+            );
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str(
+        "<h2>Duration per category</h2>\n<table>\n<tr><th>Category</th><th>Duration</th></tr>\n",
+    );
+    for (category_id, duration) in report.duration_per_category() {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td></tr>",
+            escape(&category_name(category_id)),
+            format_duration(*duration),
+        );
+    }
+    html.push_str("</table>\n</body>\n</html>\n");
+
+    html
+}
+
+/// The inlined styles of the report page.
+const STYLE: &str = "<style>
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 1em; }
+th, td { border: 1px solid #ccc; padding: 0.25em 0.5em; text-align: left; }
+th { background: #eee; }
+</style>
+";
+
+/// Formats a duration as hours and minutes (H:MM).
+fn format_duration(duration: Duration) -> String {
+    let minutes = duration.num_minutes();
+    format!("{}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// Escapes the characters with a special meaning in HTML.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{NaiveDate, NaiveTime};
+    use tokio::sync::Mutex;
+
+    use crate::{
+        infra::repositories::in_memory::{
+            accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+            activities_list::InMemoryActivitiesListRepository,
+        },
+        use_cases::activities_list::ActivitiesList,
+    };
+
+    use super::*;
+
+    /// Creates a weekly report starting on 2025-10-12 with the given activities, each recorded on
+    /// `(day, start_hour, end_hour)` of October 2025.
+    async fn report_with(
+        activities: &[(u32, u32, u32, &AccountingCategoryId, &str)],
+    ) -> WeeklyReport {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+        for (day, start_hour, end_hour, category_id, task) in activities {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2025, 10, *day).unwrap(),
+                    NaiveTime::from_hms_opt(*start_hour, 0, 0).unwrap(),
+                    Some(NaiveTime::from_hms_opt(*end_hour, 0, 0).unwrap()),
+                    (*category_id).clone(),
+                    task.to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        WeeklyReport::new(
+            NaiveDate::from_ymd_opt(2025, 10, 12).unwrap(),
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn render_weekly_report_should_contain_week_start_and_tasks() {
+        let category_id = AccountingCategoryId::new();
+        let report = report_with(&[
+            (13, 9, 11, &category_id, "Code Review"),
+            (14, 13, 14, &category_id, "Sprint Planning"),
+        ])
+        .await;
+        let category_names = HashMap::from([(category_id, "Development".to_string())]);
+
+        let html = render_weekly_report(&report, &category_names);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("2025-10-12"));
+        assert!(html.contains("<td>Code Review</td>"));
+        assert!(html.contains("<td>Sprint Planning</td>"));
+        assert!(html.contains("<td>Development</td><td>3:00</td>"));
+        assert!(!html.contains("<link"));
+    }
+
+    #[tokio::test]
+    async fn render_weekly_report_should_escape_task_names() {
+        let category_id = AccountingCategoryId::new();
+        let report = report_with(&[(13, 9, 10, &category_id, "Fix <script> & co")]).await;
+
+        let html = render_weekly_report(&report, &HashMap::new());
+
+        assert!(html.contains("<td>Fix &lt;script&gt; &amp; co</td>"));
+        assert!(html.contains(&format!("<td>{}</td>", category_id)));
+    }
+}
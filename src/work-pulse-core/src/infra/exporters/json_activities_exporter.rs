@@ -0,0 +1,130 @@
+use std::io::Write;
+
+use async_trait::async_trait;
+
+use crate::{
+    adapters::{ActivitiesExporter, ActivitiesExporterError},
+    entities::activity::Activity,
+};
+
+/// An exporter for activities to JSON, writing all activities as a single JSON array.
+#[derive(Default)]
+pub struct JsonActivitiesExporter;
+
+impl JsonActivitiesExporter {
+    /// Creates a new `JsonActivitiesExporter`.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl ActivitiesExporter for JsonActivitiesExporter {
+    /// Exports activities as a JSON array to a writer.
+    ///
+    /// # Arguments
+    ///
+    /// - `activities`: The activities to export, written in the given order.
+    /// - `writer`: A writer that receives the JSON data.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the export is successful.
+    /// - `Err(ActivitiesExporterError)` if there is an error during export.
+    #[tracing::instrument(skip(self, activities, writer), fields(activities = activities.len()))]
+    async fn export<W>(
+        &self,
+        activities: &[Activity],
+        mut writer: W,
+    ) -> Result<(), ActivitiesExporterError>
+    where
+        W: Write + Send,
+    {
+        serde_json::to_writer(&mut writer, activities)
+            .map_err(|err| ActivitiesExporterError::WriteError(err.to_string()))?;
+        writer
+            .flush()
+            .map_err(|err| ActivitiesExporterError::WriteError(err.to_string()))?;
+
+        tracing::info!("JSON export complete");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use chrono::{NaiveDate, NaiveTime};
+
+    use crate::entities::accounting::AccountingCategoryId;
+
+    use super::*;
+
+    /// A writer that fails on every write.
+    struct FailingWriter;
+
+    impl Write for FailingWriter {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk full"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn activity(task: &str, end_time: Option<NaiveTime>) -> Activity {
+        let mut activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 3, 15).unwrap(),
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            AccountingCategoryId::new(),
+            task.to_string(),
+        );
+        activity.set_end_time(end_time).unwrap();
+        activity
+    }
+
+    #[tokio::test]
+    async fn export_should_write_activities_as_json_array() {
+        let mut completed = activity("Coding", NaiveTime::from_hms_opt(17, 0, 0));
+        completed.set_comment(Some("Worked on project X".to_string()));
+        completed.set_tags(vec!["backend".to_string()]);
+        let activities = vec![completed, activity("Review", None)];
+
+        let mut output = Vec::new();
+        JsonActivitiesExporter::new()
+            .export(&activities, &mut output)
+            .await
+            .unwrap();
+
+        let exported: Vec<Activity> = serde_json::from_slice(&output).unwrap();
+        assert_eq!(exported, activities);
+        assert!(String::from_utf8(output).unwrap().starts_with('['));
+    }
+
+    #[tokio::test]
+    async fn export_should_write_empty_array_without_activities() {
+        let mut output = Vec::new();
+
+        JsonActivitiesExporter::new()
+            .export(&[], &mut output)
+            .await
+            .unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "[]");
+    }
+
+    #[tokio::test]
+    async fn export_should_fail_with_write_error_when_writer_fails() {
+        let result = JsonActivitiesExporter::new()
+            .export(&[activity("Coding", None)], FailingWriter)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ActivitiesExporterError::WriteError(message)) if message.contains("disk full")
+        ));
+    }
+}
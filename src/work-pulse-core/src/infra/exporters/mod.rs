@@ -0,0 +1,3 @@
+pub mod csv_activities_exporter;
+pub mod html_report_exporter;
+pub mod json_activities_exporter;
@@ -1,20 +1,36 @@
-use std::{io::Read, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    io::Read,
+    sync::Arc,
+};
 
 use async_trait::async_trait;
-use chrono::{NaiveDate, NaiveTime};
-use csv::Reader;
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use csv::{Reader, StringRecord};
 use serde::Deserialize;
 use tokio::sync::Mutex;
 
 use crate::{
-    adapters::{AccountingCategoriesListRepository, ActivitiesImporter, ActivitiesImporterError},
-    entities::activity::Activity,
+    adapters::{
+        AccountingCategoriesListRepository, ActivitiesImporter, ActivitiesImporterError,
+        ImportResult, RowImportError,
+    },
+    entities::{
+        accounting::{AccountingCategory, AccountingCategoryId},
+        activity::Activity,
+    },
 };
 
 /// An importer for activities from CSV files.
 pub struct CsvActivitiesImporter {
     /// The repository for managing accounting categories.
     accounting_categories_list_repository: Arc<Mutex<dyn AccountingCategoriesListRepository>>,
+
+    /// The import source recorded on every imported activity.
+    import_source: String,
+
+    /// Whether the stated calendar week of each row is checked against its date.
+    strict_mode: bool,
 }
 
 impl CsvActivitiesImporter {
@@ -28,7 +44,101 @@ impl CsvActivitiesImporter {
     ) -> Self {
         Self {
             accounting_categories_list_repository,
+            import_source: "csv".to_string(),
+            strict_mode: false,
+        }
+    }
+
+    /// Creates a new `CsvActivitiesImporter` in strict mode, which fails the import with
+    /// `ActivitiesImporterError::CalendarWeekMismatch` if the CW column of a row does not match
+    /// the ISO week of its date.
+    ///
+    /// # Arguments
+    ///
+    /// - `accounting_categories_list_repository`: An `Arc<Mutex<dyn AccountingCategoriesListRepository>>` to manage accounting categories.
+    pub fn new_strict(
+        accounting_categories_list_repository: Arc<Mutex<dyn AccountingCategoriesListRepository>>,
+    ) -> Self {
+        Self {
+            strict_mode: true,
+            ..Self::new(accounting_categories_list_repository)
+        }
+    }
+
+    /// Overrides the import source recorded on the imported activities (defaults to "csv").
+    ///
+    /// # Arguments
+    ///
+    /// - `import_source`: The name of the origin of the CSV data (e.g., "toggl").
+    pub fn with_import_source(mut self, import_source: impl Into<String>) -> Self {
+        self.import_source = import_source.into();
+        self
+    }
+}
+
+impl CsvActivitiesImporter {
+    /// Looks up the IDs of the accounting categories with the given names. Missing categories
+    /// are created with a single call to the repository.
+    ///
+    /// # Arguments
+    ///
+    /// - `names`: The names of the accounting categories, possibly containing duplicates.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(HashMap)` mapping each name to the ID of its accounting category.
+    /// - `Err(ActivitiesImporterError::CategoryResolutionError)` if the categories could not be read or the missing ones could not be created.
+    async fn resolve_accounting_categories<'a>(
+        &self,
+        names: impl Iterator<Item = &'a str>,
+    ) -> Result<HashMap<String, AccountingCategoryId>, ActivitiesImporterError> {
+        let mut accounting_categories_list_repository =
+            self.accounting_categories_list_repository.lock().await;
+
+        let mut category_ids: HashMap<String, AccountingCategoryId> =
+            accounting_categories_list_repository
+                .get_all(true)
+                .await
+                .map_err(|err| ActivitiesImporterError::CategoryResolutionError(err.to_string()))?
+                .into_iter()
+                .map(|category| (category.name().to_string(), category.id().clone()))
+                .collect();
+
+        let mut seen = HashSet::new();
+        let missing: Vec<AccountingCategory> = names
+            .filter(|name| !category_ids.contains_key(*name) && seen.insert(*name))
+            .map(|name| AccountingCategory::new(name.to_string()))
+            .collect();
+        if missing.is_empty() {
+            return Ok(category_ids);
         }
+
+        let missing_names = missing
+            .iter()
+            .map(AccountingCategory::name)
+            .collect::<Vec<_>>()
+            .join("', '");
+        let created = accounting_categories_list_repository
+            .bulk_add(missing)
+            .await
+            .map_err(|err| {
+                ActivitiesImporterError::CategoryResolutionError(format!(
+                    "Failed to create accounting categories '{}': {}",
+                    missing_names, err
+                ))
+            })?;
+        tracing::debug!(
+            count = created.len(),
+            "Created missing accounting categories"
+        );
+
+        category_ids.extend(
+            created
+                .into_iter()
+                .map(|category| (category.name().to_string(), category.id().clone())),
+        );
+
+        Ok(category_ids)
     }
 }
 
@@ -36,6 +146,9 @@ impl CsvActivitiesImporter {
 impl ActivitiesImporter for CsvActivitiesImporter {
     /// Imports activities from a CSV reader for a specific year.
     ///
+    /// Rows that cannot be parsed are skipped and reported in the result, the remaining rows are
+    /// imported nevertheless.
+    ///
     /// # Arguments
     ///
     /// - `reader`: A reader that provides the CSV data.
@@ -43,76 +156,187 @@ impl ActivitiesImporter for CsvActivitiesImporter {
     ///
     /// # Returns
     ///
-    /// - `Ok(Vec<Activity>)` if the import is successful.
-    /// - `Err(ActivitiesImporterError)` if there is an error during import.
+    /// - `Ok(ImportResult)` with the imported activities and the skipped rows.
+    /// - `Err(ActivitiesImporterError)` if the CSV header cannot be read or the accounting categories cannot be accessed.
+    #[tracing::instrument(skip(self, reader), fields(year = year))]
     async fn import<R>(
         &mut self,
         reader: R,
         year: u16,
-    ) -> Result<Vec<Activity>, ActivitiesImporterError>
+    ) -> Result<ImportResult, ActivitiesImporterError>
     where
         R: Read + Send,
     {
         let mut csv_reader = Reader::from_reader(reader);
+        let headers = csv_reader.headers().map_err(import_error)?.clone();
         let mut records = Vec::new();
-
-        for result in csv_reader.deserialize() {
-            let record: ActivityTableRecord =
-                result.map_err(|_| ActivitiesImporterError::ParseError)?;
-            records.push(record);
+        let mut errors = Vec::new();
+
+        for (i, result) in csv_reader.deserialize::<ActivityTableRecord>().enumerate() {
+            let row_number = i + 1;
+            match result {
+                Ok(record) => {
+                    tracing::debug!(row = row_number, date = ?record.date, "Parsed CSV row");
+                    records.push((row_number, record));
+                }
+                Err(err) if err.is_io_error() => return Err(import_error(err)),
+                Err(err) => {
+                    tracing::warn!(row = row_number, error = %err, "Skipping unparsable CSV row");
+                    errors.push(RowImportError {
+                        row_number,
+                        field: error_field(&err, &headers),
+                        reason: err.to_string(),
+                    });
+                }
+            }
         }
 
-        let mut activities = Vec::new();
-        let mut accounting_categories_list_repository =
-            self.accounting_categories_list_repository.lock().await;
-        let mut accounting_categories_cache = accounting_categories_list_repository
-            .get_all()
-            .await;
+        tracing::info!(
+            parsed = records.len(),
+            failed = errors.len(),
+            "CSV parsing complete"
+        );
+
+        let mut valid_records = Vec::new();
+        for (row_number, activity_record) in records {
+            let row_error = |field: &str, reason: String| RowImportError {
+                row_number,
+                field: field.to_string(),
+                reason,
+            };
+
+            let Some(date) =
+                ActivityTableRecord::convert_date_format(&activity_record.date, &year.to_string())
+                    .ok()
+                    .and_then(|date| date.parse::<NaiveDate>().ok())
+            else {
+                errors.push(row_error(
+                    "Date",
+                    format!("Invalid date '{}', expected dd.mm.", activity_record.date),
+                ));
+                continue;
+            };
+            if self.strict_mode {
+                let computed = date.iso_week().week() as u8;
+                if activity_record.cw != computed {
+                    tracing::warn!(
+                        row = row_number,
+                        stated = activity_record.cw,
+                        computed,
+                        "Calendar week mismatch"
+                    );
+                    return Err(ActivitiesImporterError::CalendarWeekMismatch {
+                        row: row_number,
+                        stated: activity_record.cw,
+                        computed,
+                    });
+                }
+            }
+            let Ok(start_time) = activity_record.check_in.parse::<NaiveTime>() else {
+                errors.push(row_error(
+                    "Check In",
+                    format!(
+                        "Invalid time '{}', expected HH:MM",
+                        activity_record.check_in
+                    ),
+                ));
+                continue;
+            };
+            let Ok(end_time) = activity_record.check_out.parse::<NaiveTime>() else {
+                errors.push(row_error(
+                    "Check Out",
+                    format!(
+                        "Invalid time '{}', expected HH:MM",
+                        activity_record.check_out
+                    ),
+                ));
+                continue;
+            };
 
-        for activity_record in records {
-            let date =
-                ActivityTableRecord::convert_date_format(&activity_record.date, &year.to_string())?;
+            valid_records.push((row_number, date, start_time, end_time, activity_record));
+        }
 
-            // Caching existing categories to avoid multiple DB calls.
-            let accounting_category = match accounting_categories_cache
-                .iter()
-                .find(|cat| cat.name() == activity_record.pam_category)
-            {
-                Some(cat) => cat.clone(),
-                None => {
-                    let cat = accounting_categories_list_repository
-                        .get_or_create_by_name(&activity_record.pam_category)
-                        .await
-                        .map_err(|_| ActivitiesImporterError::RepositoryError("Failed to get or create accounting category".to_string()))?;
-
-                    accounting_categories_cache.push(cat.clone());
-
-                    cat
-                }            
+        let category_ids = self
+            .resolve_accounting_categories(
+                valid_records
+                    .iter()
+                    .map(|(.., record)| record.pam_category.as_str()),
+            )
+            .await?;
+
+        let mut activities = Vec::new();
+        for (row_number, date, start_time, end_time, activity_record) in valid_records {
+            let Some(category_id) = category_ids.get(&activity_record.pam_category) else {
+                return Err(ActivitiesImporterError::CategoryResolutionError(format!(
+                    "Accounting category '{}' was not stored",
+                    activity_record.pam_category
+                )));
             };
 
-            let mut activity = Activity::new(
-                date.parse()
-                    .map_err(|_| ActivitiesImporterError::ParseError)?,
-                activity_record
-                    .check_in
-                    .parse::<NaiveTime>()
-                    .map_err(|_| ActivitiesImporterError::ParseError)?,
-                accounting_category.id().clone(),
-                activity_record.task,
-            );
-
-            let end_time = activity_record
-                .check_out
-                .parse::<NaiveTime>()
-                .map_err(|_| ActivitiesImporterError::ParseError)?;
-            activity.set_end_time(Some(end_time));
-            activity.set_comment(Some(activity_record.comment).filter(|s| !s.is_empty()));
-
-            activities.push(activity);
+            let activity = Activity::builder()
+                .date(date)
+                .start_time(start_time)
+                .end_time(Some(end_time))
+                .accounting_category_id(category_id.clone())
+                .task(activity_record.task)
+                .comment(Some(activity_record.comment).filter(|s| !s.is_empty()))
+                .import_source(Some(self.import_source.clone()))
+                .build();
+
+            match activity {
+                Ok(activity) => activities.push(activity),
+                Err(err) => errors.push(RowImportError {
+                    row_number,
+                    field: "Check Out".to_string(),
+                    reason: err.to_string(),
+                }),
+            }
         }
 
-        Ok(activities)
+        tracing::info!(
+            activities = activities.len(),
+            skipped = errors.len(),
+            "Activities resolved from CSV"
+        );
+
+        errors.sort_by_key(|error| error.row_number);
+
+        Ok(ImportResult { activities, errors })
+    }
+}
+
+/// Converts a CSV error that aborts the whole import into an `ActivitiesImporterError`.
+///
+/// # Arguments
+///
+/// - `err`: The error raised while reading the CSV data.
+///
+/// # Returns
+///
+/// - `ActivitiesImporterError::IoError` if the underlying reader failed.
+/// - `ActivitiesImporterError::ParseError` otherwise.
+fn import_error(err: csv::Error) -> ActivitiesImporterError {
+    if err.is_io_error() {
+        ActivitiesImporterError::IoError(err.to_string())
+    } else {
+        ActivitiesImporterError::ParseError
+    }
+}
+
+/// Returns the name of the column a CSV error relates to, or "row" if it relates to the row as a whole.
+///
+/// # Arguments
+///
+/// - `err`: The error raised while reading the row.
+/// - `headers`: The header row of the CSV data.
+fn error_field(err: &csv::Error, headers: &StringRecord) -> String {
+    match err.kind() {
+        csv::ErrorKind::Deserialize { err, .. } => err
+            .field()
+            .and_then(|index| headers.get(index as usize))
+            .unwrap_or("row")
+            .to_string(),
+        _ => "row".to_string(),
     }
 }
 
@@ -169,10 +393,90 @@ impl ActivityTableRecord {
 
 #[cfg(test)]
 mod tests {
-    use crate::infra::repositories::in_memory::accounting_categories_list::InMemoryAccountingCategoriesListRepository;
+    use std::io;
+
+    use crate::{
+        adapters::AccountingCategoriesListRepositoryError,
+        infra::repositories::in_memory::accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+    };
 
     use super::*;
 
+    /// A reader that fails on every read.
+    struct FailingReader;
+
+    impl Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("connection reset"))
+        }
+    }
+
+    /// A repository without categories that fails to create any.
+    struct FailingAccountingCategoriesListRepository;
+
+    #[async_trait]
+    impl AccountingCategoriesListRepository for FailingAccountingCategoriesListRepository {
+        async fn get_all(
+            &self,
+            _include_archived: bool,
+        ) -> Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_by_id(
+            &self,
+            _id: AccountingCategoryId,
+        ) -> Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+            Ok(None)
+        }
+
+        async fn find_by_name(
+            &self,
+            _name: &str,
+        ) -> Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+            Ok(None)
+        }
+
+        async fn add(
+            &mut self,
+            _category: AccountingCategory,
+        ) -> Result<(), AccountingCategoriesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn update(
+            &mut self,
+            _category: AccountingCategory,
+        ) -> Result<(), AccountingCategoriesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn delete(
+            &mut self,
+            _id: AccountingCategoryId,
+        ) -> Result<(), AccountingCategoriesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_or_create_by_name(
+            &mut self,
+            _name: &str,
+        ) -> Result<AccountingCategory, AccountingCategoriesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn bulk_add(
+            &mut self,
+            _categories: Vec<AccountingCategory>,
+        ) -> Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+            Err(database_error())
+        }
+    }
+
+    fn database_error() -> AccountingCategoriesListRepositoryError {
+        AccountingCategoriesListRepositoryError::DatabaseError("connection lost".to_string())
+    }
+
     #[test]
     fn convert_date_format_should_convert_valid_date() {
         let date = "15.03.";
@@ -206,7 +510,9 @@ CW,Date,Check In,Check Out,PAM Category,Topic,Comment
             Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
         let mut importer = CsvActivitiesImporter::new(accounting_repo);
 
-        let activities = importer.import(reader, 2023).await.unwrap();
+        let result = importer.import(reader, 2023).await.unwrap();
+        assert!(result.errors.is_empty());
+        let activities = result.activities;
         assert_eq!(activities.len(), 2);
 
         assert_eq!(activities[0].date().to_string(), "2023-03-15");
@@ -214,6 +520,8 @@ CW,Date,Check In,Check Out,PAM Category,Topic,Comment
         assert_eq!(activities[0].end_time().unwrap().to_string(), "17:00:00");
         assert_eq!(activities[0].task(), "Coding");
         assert_eq!(activities[0].comment(), Some("Worked on project X"));
+        assert_eq!(activities[0].import_source(), Some("csv"));
+        assert!(activities[0].tags().is_empty());
 
         assert_eq!(activities[1].date().to_string(), "2023-03-16");
         assert_eq!(activities[1].start_time().to_string(), "10:00:00");
@@ -223,7 +531,124 @@ CW,Date,Check In,Check Out,PAM Category,Topic,Comment
     }
 
     #[tokio::test]
-    async fn import_should_fail_with_invalid_csv() {
+    async fn import_should_create_each_missing_category_once() {
+        let csv_data = "\
+CW,Date,Check In,Check Out,PAM Category,Topic,Comment
+11,15.03.,09:00,12:00,Development,Coding,
+11,15.03.,13:00,14:00,Meeting,Daily,
+11,16.03.,09:00,12:00,Development,Coding,
+11,16.03.,13:00,14:00,Training,Workshop,
+";
+        let accounting_repo =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let meeting = AccountingCategory::new("Meeting".to_string());
+        accounting_repo
+            .lock()
+            .await
+            .add(meeting.clone())
+            .await
+            .unwrap();
+        let mut importer = CsvActivitiesImporter::new(accounting_repo.clone());
+
+        let activities = importer
+            .import(csv_data.as_bytes(), 2023)
+            .await
+            .unwrap()
+            .activities;
+
+        let categories = accounting_repo.lock().await.get_all(true).await.unwrap();
+        assert_eq!(
+            categories.iter().map(|c| c.name()).collect::<Vec<_>>(),
+            vec!["Meeting", "Development", "Training"]
+        );
+        assert_eq!(activities.len(), 4);
+        assert_eq!(
+            activities[0].accounting_category_id(),
+            activities[2].accounting_category_id()
+        );
+        assert_eq!(activities[1].accounting_category_id(), meeting.id());
+    }
+
+    #[tokio::test]
+    async fn import_should_record_custom_import_source() {
+        let csv_data = "\
+CW,Date,Check In,Check Out,PAM Category,Topic,Comment
+11,15.03.,09:00,17:00,Development,Coding,Worked on project X
+";
+
+        let reader = csv_data.as_bytes();
+        let accounting_repo =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut importer = CsvActivitiesImporter::new(accounting_repo).with_import_source("toggl");
+
+        let activities = importer.import(reader, 2023).await.unwrap().activities;
+        assert_eq!(activities.len(), 1);
+        assert_eq!(activities[0].import_source(), Some("toggl"));
+    }
+
+    #[tokio::test]
+    async fn import_should_accept_matching_calendar_week_in_strict_mode() {
+        let csv_data = "\
+CW,Date,Check In,Check Out,PAM Category,Topic,Comment
+11,15.03.,09:00,17:00,Development,Coding,
+1,02.01.,09:00,17:00,Development,Coding,
+";
+        let accounting_repo =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut importer = CsvActivitiesImporter::new_strict(accounting_repo);
+
+        let result = importer.import(csv_data.as_bytes(), 2023).await.unwrap();
+        assert!(result.errors.is_empty());
+        assert_eq!(result.activities.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn import_should_fail_with_calendar_week_mismatch_in_strict_mode() {
+        let csv_data = "\
+CW,Date,Check In,Check Out,PAM Category,Topic,Comment
+11,15.03.,09:00,17:00,Development,Coding,
+12,16.03.,09:00,17:00,Development,Coding,
+";
+        let accounting_repo =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut importer = CsvActivitiesImporter::new_strict(accounting_repo.clone());
+
+        let result = importer.import(csv_data.as_bytes(), 2023).await;
+        assert_eq!(
+            result.unwrap_err(),
+            ActivitiesImporterError::CalendarWeekMismatch {
+                row: 2,
+                stated: 12,
+                computed: 11,
+            }
+        );
+        assert!(
+            accounting_repo
+                .lock()
+                .await
+                .get_all(true)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn import_should_ignore_calendar_week_mismatch_by_default() {
+        let csv_data = "\
+CW,Date,Check In,Check Out,PAM Category,Topic,Comment
+12,16.03.,09:00,17:00,Development,Coding,
+";
+        let accounting_repo =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut importer = CsvActivitiesImporter::new(accounting_repo);
+
+        let result = importer.import(csv_data.as_bytes(), 2023).await.unwrap();
+        assert_eq!(result.activities.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn import_should_report_invalid_date() {
         let csv_data = "\
 CW,Date,Check In,Check Out,PAM Category,Topic,Comment
 11,invalid-date,09:00,17:00,Development,Coding,Worked on project X
@@ -233,8 +658,78 @@ CW,Date,Check In,Check Out,PAM Category,Topic,Comment
             Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
         let mut importer = CsvActivitiesImporter::new(accounting_repo);
 
-        let result = importer.import(reader, 2023).await;
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), ActivitiesImporterError::ParseError);
+        let result = importer.import(reader, 2023).await.unwrap();
+        assert!(result.activities.is_empty());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].row_number, 1);
+        assert_eq!(result.errors[0].field, "Date");
+    }
+
+    #[tokio::test]
+    async fn import_should_skip_invalid_rows_and_import_valid_ones() {
+        let csv_data = "\
+CW,Date,Check In,Check Out,PAM Category,Topic,Comment
+11,15.03.,09:00,12:00,Development,Coding,
+11,15.03.,nine,12:00,Development,Coding,
+11,31.02.,09:00,12:00,Development,Coding,
+abc,15.03.,13:00,14:00,Development,Coding,
+11,16.03.,13:00,12:00,Development,Coding,
+11,16.03.,14:00,17:00,Meeting,Team Meeting,
+";
+        let reader = csv_data.as_bytes();
+        let accounting_repo =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut importer = CsvActivitiesImporter::new(accounting_repo);
+
+        let result = importer.import(reader, 2023).await.unwrap();
+
+        assert_eq!(
+            result
+                .activities
+                .iter()
+                .map(|activity| activity.date().to_string())
+                .collect::<Vec<_>>(),
+            vec!["2023-03-15", "2023-03-16"]
+        );
+        assert_eq!(
+            result
+                .errors
+                .iter()
+                .map(|error| (error.row_number, error.field.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(2, "Check In"), (3, "Date"), (4, "CW"), (5, "Check Out")]
+        );
+    }
+
+    #[tokio::test]
+    async fn import_should_fail_with_io_error_when_reader_fails() {
+        let accounting_repo =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut importer = CsvActivitiesImporter::new(accounting_repo);
+
+        let result = importer.import(FailingReader, 2023).await;
+
+        assert!(matches!(
+            result,
+            Err(ActivitiesImporterError::IoError(message)) if message.contains("connection reset")
+        ));
+    }
+
+    #[tokio::test]
+    async fn import_should_fail_with_category_resolution_error_when_category_cannot_be_created() {
+        let csv_data = "\
+CW,Date,Check In,Check Out,PAM Category,Topic,Comment
+11,15.03.,09:00,17:00,Development,Coding,
+";
+        let mut importer = CsvActivitiesImporter::new(Arc::new(Mutex::new(
+            FailingAccountingCategoriesListRepository,
+        )));
+
+        let result = importer.import(csv_data.as_bytes(), 2023).await;
+
+        assert!(matches!(
+            result,
+            Err(ActivitiesImporterError::CategoryResolutionError(message)) if message.contains("Development")
+        ));
     }
 }
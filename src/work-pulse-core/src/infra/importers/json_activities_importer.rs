@@ -0,0 +1,299 @@
+use std::{io::Read, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveTime};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{
+    adapters::{
+        AccountingCategoriesListRepository, ActivitiesImporter, ActivitiesImporterError,
+        ImportResult,
+    },
+    entities::activity::Activity,
+};
+
+/// An importer for activities from a JSON array.
+pub struct JsonActivitiesImporter {
+    /// The repository for managing accounting categories.
+    accounting_categories_list_repository: Arc<Mutex<dyn AccountingCategoriesListRepository>>,
+
+    /// The import source recorded on every imported activity.
+    import_source: String,
+}
+
+impl JsonActivitiesImporter {
+    /// Creates a new `JsonActivitiesImporter`.
+    ///
+    /// # Arguments
+    ///
+    /// - `accounting_categories_list_repository`: An `Arc<Mutex<dyn AccountingCategoriesListRepository>>` to manage accounting categories.
+    pub fn new(
+        accounting_categories_list_repository: Arc<Mutex<dyn AccountingCategoriesListRepository>>,
+    ) -> Self {
+        Self {
+            accounting_categories_list_repository,
+            import_source: "json".to_string(),
+        }
+    }
+
+    /// Overrides the import source recorded on the imported activities (defaults to "json").
+    ///
+    /// # Arguments
+    ///
+    /// - `import_source`: The name of the origin of the JSON data (e.g., "toggl").
+    pub fn with_import_source(mut self, import_source: impl Into<String>) -> Self {
+        self.import_source = import_source.into();
+        self
+    }
+}
+
+#[async_trait]
+impl ActivitiesImporter for JsonActivitiesImporter {
+    /// Imports activities from a JSON reader.
+    ///
+    /// # Arguments
+    ///
+    /// - `reader`: A reader that provides the JSON data.
+    /// - `_year`: Not used, as the JSON records contain complete ISO 8601 dates.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(ImportResult)` with all activities if the import is successful. An invalid record fails
+    ///   the whole import, so no rows are ever reported as skipped.
+    /// - `Err(ActivitiesImporterError)` if there is an error during import.
+    #[tracing::instrument(skip(self, reader))]
+    async fn import<R>(
+        &mut self,
+        reader: R,
+        _year: u16,
+    ) -> Result<ImportResult, ActivitiesImporterError>
+    where
+        R: Read + Send,
+    {
+        let records: Vec<ActivityJsonRecord> = serde_json::from_reader(reader).map_err(|err| {
+            if err.is_io() {
+                ActivitiesImporterError::IoError(err.to_string())
+            } else {
+                ActivitiesImporterError::ParseError
+            }
+        })?;
+
+        tracing::info!(parsed = records.len(), "JSON parsing complete");
+
+        let mut activities = Vec::new();
+        let mut accounting_categories_list_repository =
+            self.accounting_categories_list_repository.lock().await;
+        let mut accounting_categories_cache = accounting_categories_list_repository
+            .get_all(true)
+            .await
+            .map_err(|err| ActivitiesImporterError::CategoryResolutionError(err.to_string()))?;
+
+        for activity_record in records {
+            // Caching existing categories to avoid multiple DB calls.
+            let accounting_category = match accounting_categories_cache
+                .iter()
+                .find(|cat| cat.name() == activity_record.pam_category)
+            {
+                Some(cat) => cat.clone(),
+                None => {
+                    let cat = accounting_categories_list_repository
+                        .get_or_create_by_name(&activity_record.pam_category)
+                        .await
+                        .map_err(|err| {
+                            ActivitiesImporterError::CategoryResolutionError(format!(
+                                "Failed to get or create accounting category '{}': {}",
+                                activity_record.pam_category, err
+                            ))
+                        })?;
+
+                    accounting_categories_cache.push(cat.clone());
+
+                    cat
+                }
+            };
+
+            let date = NaiveDate::parse_from_str(&activity_record.date, "%Y-%m-%d")
+                .map_err(|_| ActivitiesImporterError::ParseError)?;
+            let start_time = parse_time(&activity_record.start_time)?;
+            let end_time = activity_record
+                .end_time
+                .as_deref()
+                .map(parse_time)
+                .transpose()?;
+
+            let activity = Activity::builder()
+                .date(date)
+                .start_time(start_time)
+                .end_time(end_time)
+                .accounting_category_id(accounting_category.id().clone())
+                .task(activity_record.task)
+                .comment(activity_record.comment.filter(|s| !s.is_empty()))
+                .import_source(Some(self.import_source.clone()))
+                .build()
+                .map_err(|_| ActivitiesImporterError::ParseError)?;
+
+            activities.push(activity);
+        }
+
+        tracing::info!(
+            activities = activities.len(),
+            "Activities resolved from JSON"
+        );
+
+        Ok(ImportResult {
+            activities,
+            errors: Vec::new(),
+        })
+    }
+}
+
+/// A record representing an element of the activities JSON array.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize)]
+struct ActivityJsonRecord {
+    /// The date of the activity in ISO 8601 format (YYYY-MM-DD).
+    pub date: String,
+
+    /// The start time of the activity (HH:MM or HH:MM:SS).
+    pub start_time: String,
+
+    /// The optional end time of the activity (HH:MM or HH:MM:SS).
+    #[serde(default)]
+    pub end_time: Option<String>,
+
+    /// The name of the accounting category.
+    pub pam_category: String,
+
+    /// The task of the activity.
+    pub task: String,
+
+    /// An optional comment.
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+/// Parses a time in either "HH:MM" or "HH:MM:SS" format.
+///
+/// # Arguments
+///
+/// - `value`: The time string to parse.
+///
+/// # Returns
+///
+/// - `Ok(NaiveTime)` if the time can be parsed.
+/// - `Err(ActivitiesImporterError::ParseError)` otherwise.
+fn parse_time(value: &str) -> Result<NaiveTime, ActivitiesImporterError> {
+    NaiveTime::parse_from_str(value, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+        .map_err(|_| ActivitiesImporterError::ParseError)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infra::repositories::in_memory::accounting_categories_list::InMemoryAccountingCategoriesListRepository;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn import_should_import_activities_from_json() {
+        let json_data = r#"[
+            {
+                "date": "2023-03-15",
+                "start_time": "09:00",
+                "end_time": "17:00",
+                "pam_category": "Development",
+                "task": "Coding"
+            },
+            {
+                "date": "2023-03-16",
+                "start_time": "10:00:00",
+                "pam_category": "Meeting",
+                "task": "Team Meeting"
+            }
+        ]"#;
+
+        let accounting_repo =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut importer = JsonActivitiesImporter::new(accounting_repo.clone());
+
+        let activities = importer
+            .import(json_data.as_bytes(), 2023)
+            .await
+            .unwrap()
+            .activities;
+        assert_eq!(activities.len(), 2);
+
+        assert_eq!(activities[0].date().to_string(), "2023-03-15");
+        assert_eq!(activities[0].start_time().to_string(), "09:00:00");
+        assert_eq!(activities[0].end_time().unwrap().to_string(), "17:00:00");
+        assert_eq!(activities[0].task(), "Coding");
+        assert_eq!(activities[0].import_source(), Some("json"));
+
+        assert_eq!(activities[1].date().to_string(), "2023-03-16");
+        assert_eq!(activities[1].start_time().to_string(), "10:00:00");
+        assert_eq!(activities[1].end_time(), None);
+        assert_eq!(activities[1].task(), "Team Meeting");
+
+        assert_eq!(
+            accounting_repo
+                .lock()
+                .await
+                .get_all(true)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn import_should_fail_with_malformed_json() {
+        let json_data = r#"[{"date": "2023-03-15", "start_time": "09:00""#;
+
+        let accounting_repo =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut importer = JsonActivitiesImporter::new(accounting_repo);
+
+        let result = importer.import(json_data.as_bytes(), 2023).await;
+        assert_eq!(result.unwrap_err(), ActivitiesImporterError::ParseError);
+    }
+
+    #[tokio::test]
+    async fn import_should_fail_with_invalid_date() {
+        let json_data = r#"[
+            {
+                "date": "15.03.2023",
+                "start_time": "09:00",
+                "pam_category": "Development",
+                "task": "Coding"
+            }
+        ]"#;
+
+        let accounting_repo =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut importer = JsonActivitiesImporter::new(accounting_repo);
+
+        let result = importer.import(json_data.as_bytes(), 2023).await;
+        assert_eq!(result.unwrap_err(), ActivitiesImporterError::ParseError);
+    }
+
+    #[tokio::test]
+    async fn import_should_fail_when_end_time_is_before_start_time() {
+        let json_data = r#"[
+            {
+                "date": "2023-03-15",
+                "start_time": "17:00",
+                "end_time": "09:00",
+                "pam_category": "Development",
+                "task": "Coding"
+            }
+        ]"#;
+
+        let accounting_repo =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut importer = JsonActivitiesImporter::new(accounting_repo);
+
+        let result = importer.import(json_data.as_bytes(), 2023).await;
+        assert_eq!(result.unwrap_err(), ActivitiesImporterError::ParseError);
+    }
+}
@@ -1 +1,2 @@
-pub mod csv_activities_importer;
\ No newline at end of file
+pub mod csv_activities_importer;
+pub mod json_activities_importer;
\ No newline at end of file
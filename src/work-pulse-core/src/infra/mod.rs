@@ -1,2 +1,3 @@
+pub mod exporters;
 pub mod importers;
 pub mod repositories;
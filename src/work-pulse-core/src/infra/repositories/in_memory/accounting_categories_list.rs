@@ -7,13 +7,22 @@ use crate::{
 };
 
 /// Represents a record for a `AccountingCategory`.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq)]
 struct AccountingCategoryRecord {
     /// The unique identifier for the record.
     id: Uuid,
 
     /// The name of the accounting category.
     name: String,
+
+    /// The hourly rate of the accounting category, if known.
+    hourly_rate: Option<f64>,
+
+    /// The description of the accounting category, if any.
+    description: Option<String>,
+
+    /// Whether the accounting category is archived.
+    archived: bool,
 }
 
 impl AccountingCategoryRecord {
@@ -26,17 +35,26 @@ impl AccountingCategoryRecord {
         Self {
             id: category.id().0,
             name: category.name().to_string(),
+            hourly_rate: category.hourly_rate(),
+            description: category.description().map(str::to_string),
+            archived: category.is_archived(),
         }
     }
 
     /// Converts a `AccountingCategoryRecord` to a `AccountingCategory` entity.
     fn to_entity(&self) -> AccountingCategory {
-        AccountingCategory::with_id(AccountingCategoryId(self.id), self.name.clone())
+        let mut category =
+            AccountingCategory::with_id(AccountingCategoryId(self.id), self.name.clone());
+        category.set_hourly_rate(self.hourly_rate);
+        category.set_description(self.description.clone());
+        category.set_archived(self.archived);
+
+        category
     }
 }
 
 /// In-memory implementation of a repository for accounting categories.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct InMemoryAccountingCategoriesListRepository {
     /// The list of accounting categories that are stored in memory.
     categories: Vec<AccountingCategoryRecord>,
@@ -49,27 +67,67 @@ impl InMemoryAccountingCategoriesListRepository {
             categories: Vec::new(),
         }
     }
+
+    /// Creates a new in-memory repository pre-populated with the given categories, e.g. as a test
+    /// fixture.
+    ///
+    /// # Arguments
+    ///
+    /// - `categories`: The categories to store, in insertion order.
+    pub fn from_categories(categories: Vec<AccountingCategory>) -> Self {
+        Self {
+            categories: categories
+                .into_iter()
+                .map(AccountingCategoryRecord::from_entity)
+                .collect(),
+        }
+    }
 }
 
 #[async_trait]
 impl AccountingCategoriesListRepository for InMemoryAccountingCategoriesListRepository {
-    async fn get_all(&self) -> Vec<AccountingCategory> {
-        self.categories
+    async fn get_all(
+        &self,
+        include_archived: bool,
+    ) -> Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        Ok(self
+            .categories
             .iter()
+            .filter(|record| include_archived || !record.archived)
             .map(|record| record.to_entity())
-            .collect()
+            .collect())
     }
 
-    async fn get_by_id(&self, id: AccountingCategoryId) -> Option<AccountingCategory> {
-        self.categories
+    async fn get_by_id(
+        &self,
+        id: AccountingCategoryId,
+    ) -> Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        Ok(self
+            .categories
             .iter()
             .find(|&record| record.id == id.0)
-            .map(|record| record.to_entity())
+            .map(|record| record.to_entity()))
     }
 
-    async fn add(&mut self, category: AccountingCategory) {
+    async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        Ok(self
+            .categories
+            .iter()
+            .find(|record| record.name == name)
+            .map(|record| record.to_entity()))
+    }
+
+    async fn add(
+        &mut self,
+        category: AccountingCategory,
+    ) -> Result<(), AccountingCategoriesListRepositoryError> {
         let record = AccountingCategoryRecord::from_entity(category);
         self.categories.push(record);
+
+        Ok(())
     }
 
     async fn update(
@@ -103,12 +161,191 @@ impl AccountingCategoriesListRepository for InMemoryAccountingCategoriesListRepo
         &mut self,
         name: &str,
     ) -> Result<AccountingCategory, AccountingCategoriesListRepositoryError> {
-        if let Some(record) = self.categories.iter().find(|r| r.name.eq(name)) {
-            Ok(record.to_entity())
+        if let Some(category) = self.find_by_name(name).await? {
+            Ok(category)
         } else {
             let new_category = AccountingCategory::new(name.to_string());
-            self.add(new_category.clone());
+            self.add(new_category.clone()).await?;
             Ok(new_category)
         }
     }
+
+    async fn bulk_add(
+        &mut self,
+        categories: Vec<AccountingCategory>,
+    ) -> Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        let mut stored: Vec<AccountingCategory> = Vec::new();
+        for category in categories {
+            if stored.iter().any(|s| s.name() == category.name()) {
+                continue;
+            }
+            match self.find_by_name(category.name()).await? {
+                Some(existing) => stored.push(existing),
+                None => {
+                    self.add(category.clone()).await?;
+                    stored.push(category);
+                }
+            }
+        }
+
+        Ok(stored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn get_or_create_by_name_should_store_new_category() {
+        let mut repository = InMemoryAccountingCategoriesListRepository::new();
+
+        let created = repository
+            .get_or_create_by_name("Development")
+            .await
+            .unwrap();
+        let existing = repository
+            .get_or_create_by_name("Development")
+            .await
+            .unwrap();
+
+        assert_eq!(existing, created);
+        assert_eq!(repository.get_all(true).await.unwrap(), vec![created]);
+    }
+
+    #[tokio::test]
+    async fn from_categories_should_store_categories_unchanged() {
+        let mut archived = AccountingCategory::new("Meetings".to_string());
+        archived.set_archived(true);
+        let categories = vec![AccountingCategory::new("Development".to_string()), archived];
+
+        let repository =
+            InMemoryAccountingCategoriesListRepository::from_categories(categories.clone());
+
+        assert_eq!(repository.get_all(true).await.unwrap(), categories);
+    }
+
+    #[tokio::test]
+    async fn bulk_add_should_be_idempotent() {
+        let mut repository = InMemoryAccountingCategoriesListRepository::new();
+        let new_categories = || {
+            ["Development", "Meetings", "Development"]
+                .map(|name| AccountingCategory::new(name.to_string()))
+                .to_vec()
+        };
+
+        let first = repository.bulk_add(new_categories()).await.unwrap();
+        let second = repository.bulk_add(new_categories()).await.unwrap();
+
+        assert_eq!(
+            first.iter().map(|c| c.name()).collect::<Vec<_>>(),
+            vec!["Development", "Meetings"]
+        );
+        assert_eq!(second, first);
+        assert_eq!(repository.get_all(true).await.unwrap(), first);
+    }
+
+    #[tokio::test]
+    async fn find_by_name_should_return_category_with_exact_name() {
+        let mut repository = InMemoryAccountingCategoriesListRepository::new();
+        let category = AccountingCategory::new("Development".to_string());
+        repository.add(category.clone()).await.unwrap();
+
+        assert_eq!(
+            repository.find_by_name("Development").await.unwrap(),
+            Some(category)
+        );
+    }
+
+    #[tokio::test]
+    async fn find_by_name_should_compare_case_sensitively() {
+        let mut repository = InMemoryAccountingCategoriesListRepository::new();
+        repository
+            .add(AccountingCategory::new("Development".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(repository.find_by_name("development").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn find_by_name_should_return_none_for_unknown_name() {
+        let mut repository = InMemoryAccountingCategoriesListRepository::new();
+        repository
+            .add(AccountingCategory::new("Development".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(repository.find_by_name("Meetings").await.unwrap(), None);
+        assert_eq!(repository.get_all(true).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn add_and_update_should_persist_description() {
+        let mut repository = InMemoryAccountingCategoriesListRepository::new();
+        let mut category = AccountingCategory::new("Development".to_string())
+            .with_description("Coding and code reviews".to_string());
+        repository.add(category.clone()).await.unwrap();
+
+        let stored = repository
+            .get_by_id(category.id().clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.description(), Some("Coding and code reviews"));
+
+        category.set_description(None);
+        repository.update(category.clone()).await.unwrap();
+
+        let stored = repository
+            .get_by_id(category.id().clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.description(), None);
+    }
+
+    #[tokio::test]
+    async fn update_should_persist_archived_flag() {
+        let mut repository = InMemoryAccountingCategoriesListRepository::new();
+        let mut category = AccountingCategory::new("Development".to_string());
+        repository.add(category.clone()).await.unwrap();
+
+        category.set_archived(true);
+        repository.update(category.clone()).await.unwrap();
+        let stored = repository
+            .get_by_id(category.id().clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.is_archived());
+
+        category.set_archived(false);
+        repository.update(category.clone()).await.unwrap();
+        let stored = repository
+            .get_by_id(category.id().clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!stored.is_archived());
+    }
+
+    #[tokio::test]
+    async fn get_all_should_exclude_archived_categories_unless_requested() {
+        let mut repository = InMemoryAccountingCategoriesListRepository::new();
+        let active = AccountingCategory::new("Development".to_string());
+        let mut archived = AccountingCategory::new("Legacy Project".to_string());
+        archived.set_archived(true);
+        repository.add(active.clone()).await.unwrap();
+        repository.add(archived.clone()).await.unwrap();
+
+        assert_eq!(
+            repository.get_all(false).await.unwrap(),
+            vec![active.clone()]
+        );
+        assert_eq!(
+            repository.get_all(true).await.unwrap(),
+            vec![active, archived]
+        );
+    }
 }
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use chrono::{NaiveDate, NaiveTime};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Utc};
 use uuid::Uuid;
 
 use crate::{
@@ -33,6 +33,21 @@ struct ActivityRecord {
 
     /// An optional comment for the activity.
     comment: Option<String>,
+
+    /// The user who created the activity, if known.
+    created_by: Option<String>,
+
+    /// The origin of the activity record, if known.
+    import_source: Option<String>,
+
+    /// The free-form tags of the activity.
+    tags: Vec<String>,
+
+    /// When the record was inserted.
+    created_at: DateTime<Utc>,
+
+    /// When the record was last modified.
+    updated_at: DateTime<Utc>,
 }
 
 impl ActivityRecord {
@@ -41,7 +56,13 @@ impl ActivityRecord {
     /// # Arguments
     ///
     /// - `activity`: The `Activity` entity to convert.
-    fn from_entity(activity: Activity) -> Self {
+    /// - `created_at`: When the record was inserted.
+    /// - `updated_at`: When the record was last modified.
+    fn from_entity(
+        activity: Activity,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
         ActivityRecord {
             id: activity.id().0,
             date: activity.date().clone(),
@@ -50,22 +71,37 @@ impl ActivityRecord {
             accounting_category_id: activity.accounting_category_id().clone(),
             task: activity.task().to_string(),
             comment: activity.comment().map(str::to_owned),
+            created_by: activity.created_by().map(str::to_owned),
+            import_source: activity.import_source().map(str::to_owned),
+            tags: activity.tags().to_vec(),
+            created_at,
+            updated_at,
         }
     }
 
     /// Converts a `ActivityRecord` to an `Activity` entity.
-    fn to_entity(&self) -> Activity {
-        let mut activity = Activity::with_id(
-            ActivityId(self.id),
-            self.date,
-            self.start_time,
-            self.accounting_category_id.clone(),
-            self.task.clone(),
-        );
-        activity.set_end_time(self.end_time);
-        activity.set_comment(self.comment.clone());
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Activity)`: The activity stored in the record.
+    /// - `Err(ActivitiesListRepositoryError::DatabaseError)`: If the stored end time is not after the start time.
+    fn to_entity(&self) -> Result<Activity, ActivitiesListRepositoryError> {
+        let activity = Activity::builder()
+            .id(ActivityId(self.id))
+            .date(self.date)
+            .start_time(self.start_time)
+            .end_time(self.end_time)
+            .accounting_category_id(self.accounting_category_id.clone())
+            .task(self.task.clone())
+            .comment(self.comment.clone())
+            .created_by(self.created_by.clone())
+            .import_source(self.import_source.clone())
+            .tags(self.tags.clone())
+            .build()
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?
+            .with_timestamps(Some(self.created_at), Some(self.updated_at));
 
-        activity
+        Ok(activity)
     }
 }
 
@@ -83,18 +119,61 @@ impl InMemoryActivitiesListRepository {
             activities: Vec::new(),
         }
     }
+
+    /// Creates a new in-memory repository pre-populated with the given activities, e.g. as a test
+    /// fixture. Timestamps of the activities are kept, missing ones are set to now.
+    ///
+    /// # Arguments
+    ///
+    /// - `activities`: The activities to store, in insertion order.
+    pub fn from_activities(activities: Vec<Activity>) -> Self {
+        let now = Utc::now();
+        InMemoryActivitiesListRepository {
+            activities: activities
+                .into_iter()
+                .map(|activity| {
+                    let created_at = activity.created_at().copied().unwrap_or(now);
+                    let updated_at = activity.updated_at().copied().unwrap_or(created_at);
+                    ActivityRecord::from_entity(activity, created_at, updated_at)
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns all activities sorted ascending by date and start time.
+    /// Activities with the same date and start time keep their insertion order.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: The sorted activities.
+    /// - `Err(ActivitiesListRepositoryError::DatabaseError)`: If a stored activity could not be loaded.
+    pub fn get_sorted_by_date(&self) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let mut records = self.activities.iter().collect::<Vec<_>>();
+        records.sort_by_key(|record| (record.date, record.start_time));
+        records
+            .into_iter()
+            .map(|record| record.to_entity())
+            .collect()
+    }
 }
 
 #[async_trait]
 impl ActivitiesListRepository for InMemoryActivitiesListRepository {
-    async fn get_all(&self) -> Vec<Activity> {
+    async fn get_all(&self) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
         self.activities
             .iter()
             .map(|record| record.to_entity())
             .collect()
     }
 
-    async fn get_by_date(&self, date: NaiveDate) -> Vec<Activity> {
+    async fn get_all_sorted(&self) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        self.get_sorted_by_date()
+    }
+
+    async fn get_by_date(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
         self.activities
             .iter()
             .filter(|record| record.date == date)
@@ -102,7 +181,11 @@ impl ActivitiesListRepository for InMemoryActivitiesListRepository {
             .collect()
     }
 
-    async fn get_by_date_range(&self, start: NaiveDate, end: NaiveDate) -> Vec<Activity> {
+    async fn get_by_date_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
         self.activities
             .iter()
             .filter(|record| record.date >= start && record.date <= end)
@@ -110,14 +193,171 @@ impl ActivitiesListRepository for InMemoryActivitiesListRepository {
             .collect()
     }
 
-    async fn add(&mut self, activity: Activity) {
-        let record = ActivityRecord::from_entity(activity);
+    async fn get_by_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        self.activities
+            .iter()
+            .filter(|record| record.date.year() == year && record.date.month() == month)
+            .map(|record| record.to_entity())
+            .collect()
+    }
+
+    async fn get_by_week_number(
+        &self,
+        year: i32,
+        week: u32,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        self.activities
+            .iter()
+            .filter(|record| {
+                let iso_week = record.date.iso_week();
+                iso_week.year() == year && iso_week.week() == week
+            })
+            .map(|record| record.to_entity())
+            .collect()
+    }
+
+    async fn get_by_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        self.activities
+            .iter()
+            .filter(|record| record.created_by.as_deref() == Some(user_id))
+            .map(|record| record.to_entity())
+            .collect()
+    }
+
+    async fn get_by_import_source(
+        &self,
+        source: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        self.activities
+            .iter()
+            .filter(|record| record.import_source.as_deref() == Some(source))
+            .map(|record| record.to_entity())
+            .collect()
+    }
+
+    async fn get_by_tag(&self, tag: &str) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        self.activities
+            .iter()
+            .filter(|record| record.tags.iter().any(|t| t == tag))
+            .map(|record| record.to_entity())
+            .collect()
+    }
+
+    async fn search_by_task(
+        &self,
+        query: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let query = query.to_lowercase();
+
+        self.activities
+            .iter()
+            .filter(|record| record.task.to_lowercase().contains(&query))
+            .map(|record| record.to_entity())
+            .collect()
+    }
+
+    async fn get_by_id(
+        &self,
+        id: &ActivityId,
+    ) -> Result<Option<Activity>, ActivitiesListRepositoryError> {
+        self.activities
+            .iter()
+            .find(|record| record.id == id.0)
+            .map(|record| record.to_entity())
+            .transpose()
+    }
+
+    async fn get_current_in_progress(
+        &self,
+        source: &str,
+    ) -> Result<Option<Activity>, ActivitiesListRepositoryError> {
+        self.activities
+            .iter()
+            .filter(|record| {
+                record.end_time.is_none() && record.import_source.as_deref() == Some(source)
+            })
+            .max_by_key(|record| (record.date, record.start_time))
+            .map(|record| record.to_entity())
+            .transpose()
+    }
+
+    async fn get_by_accounting_category_id(
+        &self,
+        category_id: &AccountingCategoryId,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        self.activities
+            .iter()
+            .filter(|record| record.accounting_category_id == *category_id)
+            .map(|record| record.to_entity())
+            .collect()
+    }
+
+    async fn get_page_after(
+        &self,
+        after_id: Option<ActivityId>,
+        limit: usize,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let start = match after_id {
+            Some(after_id) => match self.activities.iter().position(|r| r.id == after_id.0) {
+                Some(position) => position + 1,
+                None => return Ok(Vec::new()),
+            },
+            None => 0,
+        };
+
+        self.activities
+            .iter()
+            .skip(start)
+            .take(limit)
+            .map(|record| record.to_entity())
+            .collect()
+    }
+
+    async fn get_paginated(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let mut activities = self.get_sorted_by_date()?;
+        activities.reverse();
+
+        Ok(activities.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn count(&self) -> Result<usize, ActivitiesListRepositoryError> {
+        Ok(self.activities.len())
+    }
+
+    async fn count_by_date_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<usize, ActivitiesListRepositoryError> {
+        Ok(self
+            .activities
+            .iter()
+            .filter(|record| record.date >= start && record.date <= end)
+            .count())
+    }
+
+    async fn add(&mut self, activity: Activity) -> Result<(), ActivitiesListRepositoryError> {
+        let now = Utc::now();
+        let record = ActivityRecord::from_entity(activity, now, now);
         self.activities.push(record);
+
+        Ok(())
     }
 
     async fn update(&mut self, activity: Activity) -> Result<(), ActivitiesListRepositoryError> {
         if let Some(record) = self.activities.iter_mut().find(|r| r.id == activity.id().0) {
-            *record = ActivityRecord::from_entity(activity);
+            *record = ActivityRecord::from_entity(activity, record.created_at, Utc::now());
 
             Ok(())
         } else {
@@ -155,3 +395,550 @@ impl ActivitiesListRepository for InMemoryActivitiesListRepository {
         Ok(deleted_count)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn get_by_id_should_return_activity() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        let activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+            NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+            AccountingCategoryId::new(),
+            "Test Task".to_string(),
+        );
+        repository.add(activity.clone()).await.unwrap();
+
+        assert_eq!(
+            repository.get_by_id(activity.id()).await.unwrap(),
+            Some(activity)
+        );
+    }
+
+    fn activities_for_fixture() -> (Activity, Activity) {
+        (
+            Activity::new(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                AccountingCategoryId::new(),
+                "First Task".to_string(),
+            ),
+            Activity::new(
+                NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity start time"),
+                AccountingCategoryId::new(),
+                "Second Task".to_string(),
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn from_activities_should_store_activities_unchanged() {
+        let (activity1, activity2) = activities_for_fixture();
+
+        let repository = InMemoryActivitiesListRepository::from_activities(vec![
+            activity1.clone(),
+            activity2.clone(),
+        ]);
+
+        assert_eq!(
+            repository.get_all().await.unwrap(),
+            vec![activity1, activity2]
+        );
+    }
+
+    #[tokio::test]
+    async fn from_activities_should_support_update_and_delete() {
+        let (mut activity1, activity2) = activities_for_fixture();
+        let mut repository = InMemoryActivitiesListRepository::from_activities(vec![
+            activity1.clone(),
+            activity2.clone(),
+        ]);
+
+        activity1.set_task("Updated Task".to_string());
+        repository.update(activity1.clone()).await.unwrap();
+        repository.delete(activity2.id().clone()).await.unwrap();
+
+        assert_eq!(repository.get_all().await.unwrap(), vec![activity1]);
+    }
+
+    #[tokio::test]
+    async fn add_and_update_should_set_timestamps() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        let mut activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+            NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+            AccountingCategoryId::new(),
+            "Test Task".to_string(),
+        );
+        let before_add = Utc::now();
+        repository.add(activity.clone()).await.unwrap();
+
+        let added = repository.get_by_id(activity.id()).await.unwrap().unwrap();
+        let created_at = *added.created_at().expect("Timestamp set on insert");
+        assert!(created_at >= before_add);
+        assert_eq!(added.updated_at(), Some(&created_at));
+
+        activity.set_task("Updated Task".to_string());
+        repository.update(activity.clone()).await.unwrap();
+
+        let updated = repository.get_by_id(activity.id()).await.unwrap().unwrap();
+        assert_eq!(updated, activity);
+        assert_eq!(updated.created_at(), Some(&created_at));
+        assert!(*updated.updated_at().expect("Timestamp set on update") >= created_at);
+    }
+
+    #[tokio::test]
+    async fn get_current_in_progress_should_return_latest_activity_without_end_time() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        for (day, hour, end_hour, task, source) in [
+            (2, 9, None, "Forgotten", Some("tracker")),
+            (3, 8, None, "Current", Some("tracker")),
+            (3, 10, Some(11), "Completed", Some("tracker")),
+            (3, 9, None, "Imported", None),
+        ] {
+            let mut activity = Activity::new(
+                NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(hour, 0, 0).expect("Valid activity start time"),
+                AccountingCategoryId::new(),
+                task.to_string(),
+            );
+            activity
+                .set_end_time(end_hour.map(|hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap()))
+                .expect("Valid activity end time");
+            activity.set_import_source(source.map(str::to_string));
+            repository.add(activity).await.unwrap();
+        }
+
+        let activity = repository.get_current_in_progress("tracker").await.unwrap();
+
+        assert_eq!(activity.as_ref().map(Activity::task), Some("Current"));
+    }
+
+    #[tokio::test]
+    async fn get_current_in_progress_should_return_none_without_open_activities() {
+        let repository = InMemoryActivitiesListRepository::new();
+
+        assert_eq!(
+            repository.get_current_in_progress("tracker").await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn get_by_id_should_return_none_for_unknown_id() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        repository
+            .add(Activity::new(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                AccountingCategoryId::new(),
+                "Test Task".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            repository.get_by_id(&ActivityId::new()).await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn get_by_accounting_category_id_should_only_return_activities_of_category() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        let category_id = AccountingCategoryId::new();
+        let activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+            NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+            category_id.clone(),
+            "Billable Task".to_string(),
+        );
+        repository.add(activity.clone()).await.unwrap();
+        repository
+            .add(Activity::new(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity start time"),
+                AccountingCategoryId::new(),
+                "Other Task".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            repository
+                .get_by_accounting_category_id(&category_id)
+                .await
+                .unwrap(),
+            vec![activity]
+        );
+        assert!(
+            repository
+                .get_by_accounting_category_id(&AccountingCategoryId::new())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    /// Creates a repository with one activity for each of the given tasks.
+    async fn repository_with_tasks(tasks: &[&str]) -> InMemoryActivitiesListRepository {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        for task in tasks {
+            repository
+                .add(Activity::new(
+                    NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                    AccountingCategoryId::new(),
+                    task.to_string(),
+                ))
+                .await
+                .unwrap();
+        }
+        repository
+    }
+
+    fn tasks(activities: &[Activity]) -> Vec<&str> {
+        activities.iter().map(Activity::task).collect()
+    }
+
+    #[tokio::test]
+    async fn get_all_sorted_should_order_by_date_and_start_time() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        for (day, hour, task) in [(2, 9, "Third"), (1, 14, "Second"), (1, 8, "First")] {
+            repository
+                .add(Activity::new(
+                    NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(hour, 0, 0).expect("Valid activity start time"),
+                    AccountingCategoryId::new(),
+                    task.to_string(),
+                ))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            tasks(&repository.get_all_sorted().await.unwrap()),
+            vec!["First", "Second", "Third"]
+        );
+    }
+
+    /// Creates a repository with the activities "Task 1" to "Task 5", one per day of October
+    /// 2023, recorded out of date order.
+    async fn repository_with_five_days() -> InMemoryActivitiesListRepository {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        for day in [3, 1, 5, 2, 4] {
+            repository
+                .add(Activity::new(
+                    NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                    AccountingCategoryId::new(),
+                    format!("Task {}", day),
+                ))
+                .await
+                .unwrap();
+        }
+        repository
+    }
+
+    #[tokio::test]
+    async fn get_paginated_should_return_first_page_newest_first() {
+        let repository = repository_with_five_days().await;
+
+        assert_eq!(
+            tasks(&repository.get_paginated(0, 2).await.unwrap()),
+            vec!["Task 5", "Task 4"]
+        );
+    }
+
+    #[tokio::test]
+    async fn get_paginated_should_return_middle_page() {
+        let repository = repository_with_five_days().await;
+
+        assert_eq!(
+            tasks(&repository.get_paginated(2, 2).await.unwrap()),
+            vec!["Task 3", "Task 2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn get_paginated_should_return_partial_last_page() {
+        let repository = repository_with_five_days().await;
+
+        assert_eq!(
+            tasks(&repository.get_paginated(4, 2).await.unwrap()),
+            vec!["Task 1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn get_paginated_should_return_empty_page_beyond_end() {
+        let repository = repository_with_five_days().await;
+
+        assert!(repository.get_paginated(6, 2).await.unwrap().is_empty());
+        assert_eq!(repository.count().await.unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn get_sorted_by_date_should_keep_insertion_order_for_equal_start() {
+        let repository = repository_with_tasks(&["A", "B", "C", "D"]).await;
+
+        assert_eq!(
+            tasks(&repository.get_sorted_by_date().unwrap()),
+            vec!["A", "B", "C", "D"]
+        );
+    }
+
+    #[tokio::test]
+    async fn search_by_task_should_find_exact_match() {
+        let repository = repository_with_tasks(&["Code Review", "Planning"]).await;
+
+        let activities = repository.search_by_task("Code Review").await.unwrap();
+
+        assert_eq!(tasks(&activities), vec!["Code Review"]);
+    }
+
+    #[tokio::test]
+    async fn search_by_task_should_find_partial_matches() {
+        let repository =
+            repository_with_tasks(&["Code Review", "Review Meeting", "Planning"]).await;
+
+        let activities = repository.search_by_task("Review").await.unwrap();
+
+        assert_eq!(tasks(&activities), vec!["Code Review", "Review Meeting"]);
+    }
+
+    #[tokio::test]
+    async fn search_by_task_should_ignore_case() {
+        let repository = repository_with_tasks(&["Code Review", "Planning"]).await;
+
+        let activities = repository.search_by_task("cODE rEVIEW").await.unwrap();
+
+        assert_eq!(tasks(&activities), vec!["Code Review"]);
+    }
+
+    #[tokio::test]
+    async fn search_by_task_should_return_all_activities_for_empty_query() {
+        let repository = repository_with_tasks(&["Code Review", "Planning"]).await;
+
+        let activities = repository.search_by_task("").await.unwrap();
+
+        assert_eq!(tasks(&activities), vec!["Code Review", "Planning"]);
+    }
+
+    #[tokio::test]
+    async fn get_by_tag_should_return_activities_with_tag() {
+        let mut repository = repository_with_tasks(&[]).await;
+        for (task, tags) in [
+            ("Code Review", vec!["sprint-42", "backend"]),
+            ("Planning", vec!["sprint-42"]),
+            ("Support", vec![]),
+        ] {
+            let mut activity = Activity::new(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                AccountingCategoryId::new(),
+                task.to_string(),
+            );
+            activity.set_tags(tags.into_iter().map(str::to_string).collect());
+            repository.add(activity).await.unwrap();
+        }
+
+        assert_eq!(
+            tasks(&repository.get_by_tag("sprint-42").await.unwrap()),
+            vec!["Code Review", "Planning"]
+        );
+        assert_eq!(
+            tasks(&repository.get_by_tag("backend").await.unwrap()),
+            vec!["Code Review"]
+        );
+        assert!(repository.get_by_tag("Backend").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_page_after_should_iterate_through_all_activities() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        for hour in 8..15 {
+            repository
+                .add(Activity::new(
+                    NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(hour, 0, 0).expect("Valid activity start time"),
+                    AccountingCategoryId::new(),
+                    "Test Task".to_string(),
+                ))
+                .await
+                .unwrap();
+        }
+
+        let mut activities = Vec::new();
+        let mut after_id = None;
+        loop {
+            let page = repository.get_page_after(after_id, 3).await.unwrap();
+            assert!(page.len() <= 3);
+            if page.is_empty() {
+                break;
+            }
+            after_id = page.last().map(|activity| activity.id().clone());
+            activities.extend(page);
+        }
+
+        assert_eq!(activities, repository.get_all().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn get_page_after_should_return_empty_page_for_unknown_id() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        repository
+            .add(Activity::new(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                AccountingCategoryId::new(),
+                "Test Task".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        assert!(
+            repository
+                .get_page_after(Some(ActivityId::new()), 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_all_should_remove_all_activities() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        for hour in [9, 10] {
+            repository
+                .add(Activity::new(
+                    NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(hour, 0, 0).expect("Valid activity start time"),
+                    AccountingCategoryId::new(),
+                    "Test Task".to_string(),
+                ))
+                .await
+                .unwrap();
+        }
+        assert_eq!(repository.count().await.unwrap(), 2);
+
+        repository.delete_all().await.unwrap();
+
+        assert!(repository.get_all().await.unwrap().is_empty());
+        assert_eq!(repository.count().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn count_by_date_range_should_only_count_activities_within_range() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        for day in 1..=5 {
+            repository
+                .add(Activity::new(
+                    NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                    AccountingCategoryId::new(),
+                    "Test Task".to_string(),
+                ))
+                .await
+                .unwrap();
+        }
+
+        let start = NaiveDate::from_ymd_opt(2023, 10, 2).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 10, 4).unwrap();
+
+        assert_eq!(repository.count().await.unwrap(), 5);
+        assert_eq!(repository.count_by_date_range(start, end).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn get_by_month_should_include_first_and_last_day_of_month() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        for (year, month, day) in [
+            (2023, 11, 30),
+            (2023, 12, 1),
+            (2023, 12, 31),
+            (2024, 1, 1),
+            (2024, 12, 15),
+        ] {
+            repository
+                .add(Activity::new(
+                    NaiveDate::from_ymd_opt(year, month, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                    AccountingCategoryId::new(),
+                    "Test Task".to_string(),
+                ))
+                .await
+                .unwrap();
+        }
+
+        let december: Vec<NaiveDate> = repository
+            .get_by_month(2023, 12)
+            .await
+            .unwrap()
+            .iter()
+            .map(|activity| *activity.date())
+            .collect();
+        assert_eq!(
+            december,
+            vec![
+                NaiveDate::from_ymd_opt(2023, 12, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            ]
+        );
+
+        let january = repository.get_by_month(2024, 1).await.unwrap();
+        assert_eq!(january.len(), 1);
+        assert_eq!(
+            *january[0].date(),
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+        );
+
+        assert!(repository.get_by_month(2023, 13).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_by_week_number_should_use_iso_week_year() {
+        let mut repository = InMemoryActivitiesListRepository::new();
+        for (year, month, day) in [(2024, 12, 29), (2024, 12, 30), (2025, 1, 5), (2025, 1, 6)] {
+            repository
+                .add(Activity::new(
+                    NaiveDate::from_ymd_opt(year, month, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                    AccountingCategoryId::new(),
+                    "Test Task".to_string(),
+                ))
+                .await
+                .unwrap();
+        }
+
+        let first_week: Vec<NaiveDate> = repository
+            .get_by_week_number(2025, 1)
+            .await
+            .unwrap()
+            .iter()
+            .map(|activity| *activity.date())
+            .collect();
+        assert_eq!(
+            first_week,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 12, 30).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 1, 5).unwrap(),
+            ]
+        );
+
+        assert_eq!(
+            repository.get_by_week_number(2024, 52).await.unwrap().len(),
+            1
+        );
+        assert!(
+            repository
+                .get_by_week_number(2024, 1)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -1,2 +1,3 @@
 pub mod in_memory;
 pub mod postgres;
+pub mod sqlite;
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use sqlx::Row;
+use sqlx::{Row, postgres::PgRow};
 use uuid::Uuid;
 
 use crate::{
@@ -19,52 +19,104 @@ impl PsqlAccountingCategoriesListRepository {
     }
 }
 
+/// Converts a row of the `accounting_categories` table into an `AccountingCategory` entity.
+///
+/// # Arguments
+///
+/// - `row`: The database row to convert.
+fn accounting_category_from_row(row: &PgRow) -> AccountingCategory {
+    let id: Uuid = row.get("id");
+    let name: String = row.get("name");
+    let hourly_rate: Option<f64> = row.get("hourly_rate");
+    let description: Option<String> = row.get("description");
+    let archived: bool = row.get("archived");
+
+    let mut category = AccountingCategory::with_id(AccountingCategoryId(id), name);
+    category.set_hourly_rate(hourly_rate);
+    category.set_description(description);
+    category.set_archived(archived);
+
+    category
+}
+
 #[async_trait]
 impl AccountingCategoriesListRepository for PsqlAccountingCategoriesListRepository {
-    async fn get_all(&self) -> Vec<AccountingCategory> {
-        let rows = sqlx::query("SELECT id, name FROM accounting_categories")
-            .fetch_all(self.psql_connection.pool())
-            .await
-            .unwrap();
-
-        rows.into_iter()
-            .map(|row| {
-                let id: Uuid = row.get("id");
-                let name: String = row.get("name");
-                AccountingCategory::with_id(AccountingCategoryId(id), name)
-            })
-            .collect()
+    async fn get_all(
+        &self,
+        include_archived: bool,
+    ) -> Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        let rows = sqlx::query(
+            "SELECT id, name, hourly_rate, description, archived FROM accounting_categories \
+             WHERE $1 OR NOT archived",
+        )
+        .bind(include_archived)
+        .fetch_all(self.psql_connection.pool())
+        .await
+        .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.iter().map(accounting_category_from_row).collect())
     }
 
-    async fn get_by_id(&self, id: AccountingCategoryId) -> Option<AccountingCategory> {
-        let row = sqlx::query("SELECT id, name FROM accounting_categories WHERE id = $1")
-            .bind(id.0)
-            .fetch_optional(self.psql_connection.pool())
-            .await
-            .unwrap();
+    async fn get_by_id(
+        &self,
+        id: AccountingCategoryId,
+    ) -> Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        let row = sqlx::query(
+            "SELECT id, name, hourly_rate, description, archived FROM accounting_categories WHERE id = $1",
+        )
+        .bind(id.0)
+        .fetch_optional(self.psql_connection.pool())
+        .await
+        .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
 
-        row.map(|row| {
-            let id: Uuid = row.get("id");
-            let name: String = row.get("name");
-            AccountingCategory::with_id(AccountingCategoryId(id), name)
-        })
+        Ok(row.as_ref().map(accounting_category_from_row))
     }
 
-    async fn add(&mut self, category: AccountingCategory) {
-        sqlx::query("INSERT INTO accounting_categories (id, name) VALUES ($1, $2)")
+    async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        let row = sqlx::query(
+            "SELECT id, name, hourly_rate, description, archived FROM accounting_categories WHERE name = $1",
+        )
+        .bind(name)
+        .fetch_optional(self.psql_connection.pool())
+        .await
+        .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.as_ref().map(accounting_category_from_row))
+    }
+
+    async fn add(
+        &mut self,
+        category: AccountingCategory,
+    ) -> Result<(), AccountingCategoriesListRepositoryError> {
+        sqlx::query(
+                "INSERT INTO accounting_categories (id, name, hourly_rate, description, archived) VALUES ($1, $2, $3, $4, $5)",
+            )
             .bind(category.id().0)
             .bind(category.name())
+            .bind(category.hourly_rate())
+            .bind(category.description())
+            .bind(category.is_archived())
             .execute(self.psql_connection.pool())
             .await
-            .unwrap();
+            .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
     }
 
     async fn update(
         &mut self,
         category: AccountingCategory,
     ) -> Result<(), AccountingCategoriesListRepositoryError> {
-        sqlx::query("UPDATE accounting_categories SET name = $1 WHERE id = $2")
+        sqlx::query(
+                "UPDATE accounting_categories SET name = $1, hourly_rate = $2, description = $3, archived = $4 WHERE id = $5",
+            )
             .bind(category.name())
+            .bind(category.hourly_rate())
+            .bind(category.description())
+            .bind(category.is_archived())
             .bind(category.id().0)
             .execute(self.psql_connection.pool())
             .await
@@ -90,20 +142,50 @@ impl AccountingCategoriesListRepository for PsqlAccountingCategoriesListReposito
         &mut self,
         name: &str,
     ) -> Result<AccountingCategory, AccountingCategoriesListRepositoryError> {
-        let row = sqlx::query("SELECT id, name FROM accounting_categories WHERE name = $1")
-            .bind(name)
-            .fetch_optional(self.psql_connection.pool())
-            .await
-            .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
-
-        if let Some(row) = row {
-            let id: Uuid = row.get("id");
-            let name: String = row.get("name");
-            Ok(AccountingCategory::with_id(AccountingCategoryId(id), name))
+        if let Some(category) = self.find_by_name(name).await? {
+            Ok(category)
         } else {
             let new_category = AccountingCategory::new(name.to_string());
-            self.add(new_category.clone()).await;
+            self.add(new_category.clone()).await?;
             Ok(new_category)
         }
     }
+
+    async fn bulk_add(
+        &mut self,
+        categories: Vec<AccountingCategory>,
+    ) -> Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        if categories.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut insert_builder = sqlx::QueryBuilder::new(
+            "INSERT INTO accounting_categories (id, name, hourly_rate, description, archived) ",
+        );
+        insert_builder.push_values(categories.iter(), |mut b, category| {
+            b.push_bind(category.id().0)
+                .push_bind(category.name())
+                .push_bind(category.hourly_rate())
+                .push_bind(category.description())
+                .push_bind(category.is_archived());
+        });
+        insert_builder.push(" ON CONFLICT (name) DO NOTHING");
+
+        insert_builder
+            .build()
+            .execute(self.psql_connection.pool())
+            .await
+            .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        let names: Vec<&str> = categories.iter().map(AccountingCategory::name).collect();
+        let rows = sqlx::query(
+            "SELECT id, name, hourly_rate, description, archived FROM accounting_categories WHERE name = ANY($1)",
+        )
+        .bind(names)
+        .fetch_all(self.psql_connection.pool())
+        .await
+        .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.iter().map(accounting_category_from_row).collect())
+    }
 }
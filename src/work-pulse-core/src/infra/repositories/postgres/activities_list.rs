@@ -1,6 +1,6 @@
 use async_trait::async_trait;
-use chrono::{NaiveDate, NaiveTime};
-use sqlx::Row;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use sqlx::{Row, postgres::PgRow};
 use uuid::Uuid;
 
 use crate::{
@@ -8,12 +8,13 @@ use crate::{
     entities::{
         accounting::AccountingCategoryId,
         activity::{Activity, ActivityId},
+        category_summary::CategorySummary,
+        time_distribution::{HOURS_PER_DAY, HourBucket},
     },
     infra::repositories::postgres::PsqlConnection,
+    utils::dates::iso_week_range,
 };
 
-// TODO: Improve error handling for repository access (add Result)
-
 #[derive(Clone)]
 pub struct PsqlActivitiesListRepository {
     psql_connection: PsqlConnection,
@@ -24,29 +25,12 @@ impl PsqlActivitiesListRepository {
         Self { psql_connection }
     }
 
-    async fn add_range(
-        &mut self,
-        activities: Vec<Activity>,
-    ) -> Result<(), ActivitiesListRepositoryError> {
-        if activities.is_empty() {
-            return Ok(());
-        }
-
-        const CHUNK_SIZE: usize = 100;
-
-        for chunk in activities.chunks(CHUNK_SIZE) {
-            self.add_batch(chunk.to_vec()).await?;
-        }
-
-        Ok(())
-    }
-
     async fn add_batch(
         &mut self,
         activities: Vec<Activity>,
     ) -> Result<(), ActivitiesListRepositoryError> {
         let mut query_builder = sqlx::QueryBuilder::new(
-            "INSERT INTO activities (id, date, start_time, end_time, category_id, task, comment) ",
+            "INSERT INTO activities (id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags) ",
         );
 
         query_builder.push_values(activities.iter(), |mut b, activity| {
@@ -56,7 +40,10 @@ impl PsqlActivitiesListRepository {
                 .push_bind(activity.end_time())
                 .push_bind(activity.accounting_category_id().0)
                 .push_bind(activity.task())
-                .push_bind(activity.comment());
+                .push_bind(activity.comment())
+                .push_bind(activity.created_by())
+                .push_bind(activity.import_source())
+                .push_bind(activity.tags());
         });
 
         let query = query_builder.build();
@@ -70,115 +57,398 @@ impl PsqlActivitiesListRepository {
     }
 }
 
+/// Converts a row of the `activities` table into an `Activity` entity.
+///
+/// # Arguments
+///
+/// - `row`: The database row to convert.
+///
+/// # Returns
+///
+/// - `Ok(Activity)`: The activity stored in the row.
+/// - `Err(ActivitiesListRepositoryError::DatabaseError)`: If the stored end time is not after the start time.
+fn activity_from_row(row: &PgRow) -> Result<Activity, ActivitiesListRepositoryError> {
+    let id: Uuid = row.get("id");
+    let date: NaiveDate = row.get("date");
+    let start_time: NaiveTime = row.get("start_time");
+    let end_time: Option<NaiveTime> = row.get("end_time");
+    let category_id: Uuid = row.get("category_id");
+    let task: String = row.get("task");
+    let comment: Option<String> = row.get("comment");
+    let created_by: Option<String> = row.get("created_by");
+    let import_source: Option<String> = row.get("import_source");
+    let tags: Vec<String> = row.get("tags");
+    let created_at: DateTime<Utc> = row.get("created_at");
+    let updated_at: DateTime<Utc> = row.get("updated_at");
+
+    let activity = Activity::builder()
+        .id(ActivityId(id))
+        .date(date)
+        .start_time(start_time)
+        .end_time(end_time)
+        .accounting_category_id(AccountingCategoryId(category_id))
+        .task(task)
+        .comment(comment)
+        .created_by(created_by)
+        .import_source(import_source)
+        .tags(tags)
+        .build()
+        .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?
+        .with_timestamps(Some(created_at), Some(updated_at));
+
+    Ok(activity)
+}
+
 #[async_trait]
 impl ActivitiesListRepository for PsqlActivitiesListRepository {
-    async fn get_all(&self) -> Vec<Activity> {
+    async fn get_all(&self) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
         let rows =
-            sqlx::query("SELECT id, date, start_time, end_time, category_id, task, comment FROM activities")
+            sqlx::query("SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities")
                 .fetch_all(self.psql_connection.pool())
                 .await
-                .unwrap();
+                .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
 
-        rows.into_iter()
-            .map(|row| {
-                let id: Uuid = row.get("id");
-                let date: NaiveDate = row.get("date");
-                let start_time: NaiveTime = row.get("start_time");
-                let end_time: Option<NaiveTime> = row.get("end_time");
-                let category_id: Uuid = row.get("category_id");
-                let task: String = row.get("task");
-                let comment: Option<String> = row.get("comment");
-
-                let mut activity = Activity::with_id(
-                    ActivityId(id),
-                    date,
-                    start_time,
-                    AccountingCategoryId(category_id),
-                    task,
-                );
-
-                activity.set_end_time(end_time);
-                activity.set_comment(comment);
-
-                activity
-            })
-            .collect()
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_all_sorted(&self) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows =
+            sqlx::query("SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities ORDER BY date ASC, start_time ASC")
+                .fetch_all(self.psql_connection.pool())
+                .await
+                .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
     }
 
-    async fn get_by_date(&self, date: NaiveDate) -> Vec<Activity> {
+    async fn get_by_date(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
         let rows = sqlx::query(
-                "SELECT id, date, start_time, end_time, category_id, task, comment FROM activities WHERE date = $1",
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE date = $1",
             )
             .bind(date)
             .fetch_all(self.psql_connection.pool())
             .await
-            .unwrap();
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
 
-        rows.into_iter()
-            .map(|row| {
-                let id: Uuid = row.get("id");
-                let date: NaiveDate = row.get("date");
-                let start_time: NaiveTime = row.get("start_time");
-                let end_time: Option<NaiveTime> = row.get("end_time");
-                let category_id: Uuid = row.get("category_id");
-                let task: String = row.get("task");
-                let comment: Option<String> = row.get("comment");
-
-                let mut activity = Activity::with_id(
-                    ActivityId(id),
-                    date,
-                    start_time,
-                    AccountingCategoryId(category_id),
-                    task,
-                );
-
-                activity.set_end_time(end_time);
-                activity.set_comment(comment);
-
-                activity
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_date_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE date BETWEEN $1 AND $2",
+            )
+            .bind(start)
+            .bind(end)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let Some(month_start) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            return Ok(Vec::new());
+        };
+
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE date_trunc('month', date) = date_trunc('month', $1::date)",
+            )
+            .bind(month_start)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_week_number(
+        &self,
+        year: i32,
+        week: u32,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let Some((week_start, week_end)) = iso_week_range(year, week) else {
+            return Ok(Vec::new());
+        };
+
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE date >= $1 AND date <= $2",
+            )
+            .bind(week_start)
+            .bind(week_end)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_from_date(
+        &self,
+        start: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE date >= $1",
+            )
+            .bind(start)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_until_date(
+        &self,
+        end: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE date <= $1",
+            )
+            .bind(end)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_time_distribution(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<HourBucket>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT EXTRACT(HOUR FROM start_time)::INT AS hour, COUNT(*) AS count, \
+                 AVG(EXTRACT(EPOCH FROM COALESCE(end_time - start_time, INTERVAL '0')))::BIGINT AS avg_duration_seconds \
+                 FROM activities WHERE ($1::DATE IS NULL OR date >= $1) AND ($2::DATE IS NULL OR date <= $2) \
+                 GROUP BY hour",
+            )
+            .bind(start)
+            .bind(end)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok((0..HOURS_PER_DAY)
+            .map(|hour| {
+                rows.iter()
+                    .find(|row| row.get::<i32, _>("hour") as u32 == hour)
+                    .map(|row| {
+                        HourBucket::new(
+                            hour,
+                            row.get::<i64, _>("count") as usize,
+                            row.get("avg_duration_seconds"),
+                        )
+                    })
+                    .unwrap_or_else(|| HourBucket::new(hour, 0, 0))
             })
-            .collect()
+            .collect())
     }
 
-    async fn get_by_date_range(&self, start: NaiveDate, end: NaiveDate) -> Vec<Activity> {
+    async fn get_category_summary(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<CategorySummary>, ActivitiesListRepositoryError> {
         let rows = sqlx::query(
-                "SELECT id, date, start_time, end_time, category_id, task, comment FROM activities WHERE date BETWEEN $1 AND $2",
+                "SELECT category_id, COUNT(*) AS activity_count, \
+                 COALESCE(EXTRACT(EPOCH FROM SUM(end_time - start_time)), 0)::BIGINT AS total_seconds \
+                 FROM activities WHERE date BETWEEN $1 AND $2 \
+                 GROUP BY category_id ORDER BY category_id",
             )
             .bind(start)
             .bind(end)
             .fetch_all(self.psql_connection.pool())
             .await
-            .unwrap();
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
 
-        rows.into_iter()
+        Ok(rows
+            .iter()
             .map(|row| {
-                let id: Uuid = row.get("id");
-                let date: NaiveDate = row.get("date");
-                let start_time: NaiveTime = row.get("start_time");
-                let end_time: Option<NaiveTime> = row.get("end_time");
-                let category_id: Uuid = row.get("category_id");
-                let task: String = row.get("task");
-                let comment: Option<String> = row.get("comment");
-
-                let mut activity = Activity::with_id(
-                    ActivityId(id),
-                    date,
-                    start_time,
-                    AccountingCategoryId(category_id),
-                    task,
-                );
-
-                activity.set_end_time(end_time);
-                activity.set_comment(comment);
-
-                activity
+                CategorySummary::new(
+                    AccountingCategoryId(row.get::<Uuid, _>("category_id")),
+                    row.get::<i64, _>("activity_count") as usize,
+                    Duration::seconds(row.get("total_seconds")),
+                )
             })
-            .collect()
+            .collect())
     }
 
-    async fn add(&mut self, activity: Activity) {
+    async fn get_by_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE created_by = $1",
+            )
+            .bind(user_id)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_import_source(
+        &self,
+        source: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE import_source = $1",
+            )
+            .bind(source)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_id(
+        &self,
+        id: &ActivityId,
+    ) -> Result<Option<Activity>, ActivitiesListRepositoryError> {
+        let row = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE id = $1",
+            )
+            .bind(id.0)
+            .fetch_optional(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        row.as_ref().map(activity_from_row).transpose()
+    }
+
+    async fn get_current_in_progress(
+        &self,
+        source: &str,
+    ) -> Result<Option<Activity>, ActivitiesListRepositoryError> {
+        let row = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE end_time IS NULL AND import_source = $1 ORDER BY date DESC, start_time DESC LIMIT 1",
+            )
+            .bind(source)
+            .fetch_optional(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        row.as_ref().map(activity_from_row).transpose()
+    }
+
+    async fn get_by_accounting_category_id(
+        &self,
+        category_id: &AccountingCategoryId,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE category_id = $1",
+            )
+            .bind(category_id.0)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_tag(&self, tag: &str) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE $1 = ANY(tags)",
+            )
+            .bind(tag)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn search_by_task(
+        &self,
+        query: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE task ILIKE '%' || $1 || '%'",
+            )
+            .bind(query)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_page_after(
+        &self,
+        after_id: Option<ActivityId>,
+        limit: usize,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE ($1::uuid IS NULL OR id > $1) ORDER BY id LIMIT $2",
+            )
+            .bind(after_id.map(|id| id.0))
+            .bind(limit as i64)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_paginated(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities ORDER BY date DESC, start_time DESC LIMIT $1 OFFSET $2",
+            )
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn count(&self) -> Result<usize, ActivitiesListRepositoryError> {
+        let row = sqlx::query("SELECT COUNT(*) FROM activities")
+            .fetch_one(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.get::<i64, _>(0) as usize)
+    }
+
+    async fn count_by_date_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<usize, ActivitiesListRepositoryError> {
+        let row = sqlx::query("SELECT COUNT(*) FROM activities WHERE date BETWEEN $1 AND $2")
+            .bind(start)
+            .bind(end)
+            .fetch_one(self.psql_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.get::<i64, _>(0) as usize)
+    }
+
+    async fn add(&mut self, activity: Activity) -> Result<(), ActivitiesListRepositoryError> {
         sqlx::query(
-                "INSERT INTO activities (id, date, start_time, end_time, category_id, task, comment) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+                "INSERT INTO activities (id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
             )
             .bind(activity.id().0)
             .bind(activity.date())
@@ -187,14 +457,36 @@ impl ActivitiesListRepository for PsqlActivitiesListRepository {
             .bind(activity.accounting_category_id().0)
             .bind(activity.task())
             .bind(activity.comment())
+            .bind(activity.created_by())
+            .bind(activity.import_source())
+            .bind(activity.tags())
             .execute(self.psql_connection.pool())
             .await
-            .unwrap();
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn add_range(
+        &mut self,
+        activities: Vec<Activity>,
+    ) -> Result<(), ActivitiesListRepositoryError> {
+        if activities.is_empty() {
+            return Ok(());
+        }
+
+        const CHUNK_SIZE: usize = 100;
+
+        for chunk in activities.chunks(CHUNK_SIZE) {
+            self.add_batch(chunk.to_vec()).await?;
+        }
+
+        Ok(())
     }
 
     async fn update(&mut self, activity: Activity) -> Result<(), ActivitiesListRepositoryError> {
         sqlx::query(
-                "UPDATE activities SET date = $1, start_time = $2, end_time = $3, category_id = $4, task = $5, comment = $6 WHERE id = $7",
+                "UPDATE activities SET date = $1, start_time = $2, end_time = $3, category_id = $4, task = $5, comment = $6, created_by = $7, import_source = $8, tags = $9, updated_at = NOW() WHERE id = $10",
             )
             .bind(activity.date())
             .bind(activity.start_time())
@@ -202,6 +494,9 @@ impl ActivitiesListRepository for PsqlActivitiesListRepository {
             .bind(activity.accounting_category_id().0)
             .bind(activity.task())
             .bind(activity.comment())
+            .bind(activity.created_by())
+            .bind(activity.import_source())
+            .bind(activity.tags())
             .bind(activity.id().0)
             .execute(self.psql_connection.pool())
             .await
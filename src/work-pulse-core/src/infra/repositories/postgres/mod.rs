@@ -1,7 +1,9 @@
 pub mod accounting_categories_list;
 pub mod activities_list;
 
-use sqlx::PgPool;
+use std::time::Duration;
+
+use sqlx::{PgPool, postgres::PgPoolOptions};
 
 /// A wrapper around a PostgreSQL connection pool.
 #[derive(Clone)]
@@ -24,9 +26,100 @@ impl PsqlConnection {
     /// # Arguments
     ///
     /// - `database_url`: The database URL to connect to.
-    pub async fn with_database_url(database_url: &str) -> Self {
-        let pool = PgPool::connect(database_url).await.unwrap();
-        Self::new(pool)
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(PsqlConnection)` if a connection could be established.
+    /// - `Err(sqlx::Error)` if the database is not reachable.
+    pub async fn with_database_url(database_url: &str) -> Result<Self, sqlx::Error> {
+        let pool = PgPool::connect(database_url).await?;
+        Ok(Self::new(pool))
+    }
+
+    /// Creates a new `PsqlConnection` instance with the given database URL and pool size.
+    ///
+    /// # Arguments
+    ///
+    /// - `database_url`: The database URL to connect to.
+    /// - `max_connections`: The maximum number of connections the pool keeps open.
+    /// - `min_connections`: The number of idle connections the pool keeps open at least.
+    /// - `acquire_timeout_secs`: The number of seconds to wait for a free connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(PsqlConnection)` if a connection could be established.
+    /// - `Err(sqlx::Error)` if the database is not reachable.
+    pub async fn with_config(
+        database_url: &str,
+        max_connections: u32,
+        min_connections: u32,
+        acquire_timeout_secs: u64,
+    ) -> Result<Self, sqlx::Error> {
+        let pool = Self::pool_options(max_connections, min_connections, acquire_timeout_secs)
+            .connect(database_url)
+            .await?;
+        Ok(Self::new(pool))
+    }
+
+    /// Creates the options for a connection pool of the given size.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_connections`: The maximum number of connections the pool keeps open.
+    /// - `min_connections`: The number of idle connections the pool keeps open at least, capped
+    ///   at `max_connections`.
+    /// - `acquire_timeout_secs`: The number of seconds to wait for a free connection.
+    pub fn pool_options(
+        max_connections: u32,
+        min_connections: u32,
+        acquire_timeout_secs: u64,
+    ) -> PgPoolOptions {
+        PgPoolOptions::new()
+            .max_connections(max_connections)
+            .min_connections(min_connections.min(max_connections))
+            .acquire_timeout(Duration::from_secs(acquire_timeout_secs))
+    }
+
+    /// Creates a new `PsqlConnection` instance, retrying the connection if the database is not reachable yet.
+    ///
+    /// # Arguments
+    ///
+    /// - `database_url`: The database URL to connect to.
+    /// - `pool_options`: The options of the connection pool, see `pool_options`.
+    /// - `retries`: The number of retries after the first failed connection attempt.
+    /// - `retry_interval`: The time to wait between two connection attempts.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(PsqlConnection)` if a connection could be established.
+    /// - `Err(sqlx::Error)` with the last connection error if all attempts failed.
+    pub async fn connect_with_retry(
+        database_url: &str,
+        pool_options: PgPoolOptions,
+        retries: u32,
+        retry_interval: Duration,
+    ) -> Result<Self, sqlx::Error> {
+        let mut attempt = 0;
+
+        loop {
+            match pool_options.clone().connect(database_url).await {
+                Ok(pool) => return Ok(Self::new(pool)),
+                Err(err) if attempt < retries => {
+                    attempt += 1;
+
+                    tracing::warn!(
+                        attempt,
+                        retries,
+                        error = %err,
+                        "Failed to connect to the database, retrying in {:?}",
+                        retry_interval
+                    );
+
+                    tokio::time::sleep(retry_interval).await;
+                }
+                Err(err) => return Err(err),
+            }
+        }
     }
 
     /// Creates a new `PsqlConnection` instance with a lazy connection pool.
@@ -48,7 +141,38 @@ impl PsqlConnection {
     }
 
     /// Pings the database to check connectivity.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the database answered a `SELECT 1`.
+    /// - `Err(sqlx::Error)` if the database is not reachable.
     pub async fn ping(&self) -> Result<(), sqlx::Error> {
         sqlx::query("SELECT 1").fetch_one(&self.pool).await.map(|_| ())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn pool_options_should_configure_pool_size_and_timeout() {
+        let pool = PsqlConnection::pool_options(25, 3, 45)
+            .connect_lazy("postgres://workpulse@localhost:5432/workpulse")
+            .unwrap();
+
+        let psql_connection = PsqlConnection::new(pool);
+
+        let options = psql_connection.pool().options();
+        assert_eq!(options.get_max_connections(), 25);
+        assert_eq!(options.get_min_connections(), 3);
+        assert_eq!(options.get_acquire_timeout(), Duration::from_secs(45));
+    }
+
+    #[tokio::test]
+    async fn pool_options_should_cap_min_connections_at_max_connections() {
+        let options = PsqlConnection::pool_options(2, 5, 30);
+
+        assert_eq!(options.get_min_connections(), 2);
+    }
+}
@@ -0,0 +1,317 @@
+use async_trait::async_trait;
+use sqlx::{Row, sqlite::SqliteRow};
+use uuid::Uuid;
+
+use crate::{
+    adapters::{AccountingCategoriesListRepository, AccountingCategoriesListRepositoryError},
+    entities::accounting::{AccountingCategory, AccountingCategoryId},
+    infra::repositories::sqlite::SqliteConnection,
+};
+
+#[derive(Clone)]
+pub struct SqliteAccountingCategoriesListRepository {
+    sqlite_connection: SqliteConnection,
+}
+
+impl SqliteAccountingCategoriesListRepository {
+    pub fn new(sqlite_connection: SqliteConnection) -> Self {
+        Self { sqlite_connection }
+    }
+}
+
+/// Converts a row of the `accounting_categories` table into an `AccountingCategory` entity.
+///
+/// # Arguments
+///
+/// - `row`: The database row to convert.
+fn accounting_category_from_row(row: &SqliteRow) -> AccountingCategory {
+    let id: Uuid = row.get("id");
+    let name: String = row.get("name");
+    let hourly_rate: Option<f64> = row.get("hourly_rate");
+    let description: Option<String> = row.get("description");
+    let archived: bool = row.get("archived");
+
+    let mut category = AccountingCategory::with_id(AccountingCategoryId(id), name);
+    category.set_hourly_rate(hourly_rate);
+    category.set_description(description);
+    category.set_archived(archived);
+
+    category
+}
+
+#[async_trait]
+impl AccountingCategoriesListRepository for SqliteAccountingCategoriesListRepository {
+    async fn get_all(
+        &self,
+        include_archived: bool,
+    ) -> Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        let rows = sqlx::query(
+            "SELECT id, name, hourly_rate, description, archived FROM accounting_categories \
+             WHERE ?1 OR NOT archived",
+        )
+        .bind(include_archived)
+        .fetch_all(self.sqlite_connection.pool())
+        .await
+        .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.iter().map(accounting_category_from_row).collect())
+    }
+
+    async fn get_by_id(
+        &self,
+        id: AccountingCategoryId,
+    ) -> Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        let row = sqlx::query(
+            "SELECT id, name, hourly_rate, description, archived FROM accounting_categories WHERE id = ?1",
+        )
+        .bind(id.0)
+        .fetch_optional(self.sqlite_connection.pool())
+        .await
+        .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.as_ref().map(accounting_category_from_row))
+    }
+
+    async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        let row = sqlx::query(
+            "SELECT id, name, hourly_rate, description, archived FROM accounting_categories WHERE name = ?1",
+        )
+        .bind(name)
+        .fetch_optional(self.sqlite_connection.pool())
+        .await
+        .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.as_ref().map(accounting_category_from_row))
+    }
+
+    async fn add(
+        &mut self,
+        category: AccountingCategory,
+    ) -> Result<(), AccountingCategoriesListRepositoryError> {
+        sqlx::query(
+                "INSERT INTO accounting_categories (id, name, hourly_rate, description, archived) VALUES (?1, ?2, ?3, ?4, ?5)",
+            )
+            .bind(category.id().0)
+            .bind(category.name())
+            .bind(category.hourly_rate())
+            .bind(category.description())
+            .bind(category.is_archived())
+            .execute(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn update(
+        &mut self,
+        category: AccountingCategory,
+    ) -> Result<(), AccountingCategoriesListRepositoryError> {
+        sqlx::query(
+                "UPDATE accounting_categories SET name = ?1, hourly_rate = ?2, description = ?3, archived = ?4 WHERE id = ?5",
+            )
+            .bind(category.name())
+            .bind(category.hourly_rate())
+            .bind(category.description())
+            .bind(category.is_archived())
+            .bind(category.id().0)
+            .execute(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(
+        &mut self,
+        id: AccountingCategoryId,
+    ) -> Result<(), AccountingCategoriesListRepositoryError> {
+        sqlx::query("DELETE FROM accounting_categories WHERE id = ?1")
+            .bind(id.0)
+            .execute(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn get_or_create_by_name(
+        &mut self,
+        name: &str,
+    ) -> Result<AccountingCategory, AccountingCategoriesListRepositoryError> {
+        if let Some(category) = self.find_by_name(name).await? {
+            Ok(category)
+        } else {
+            let new_category = AccountingCategory::new(name.to_string());
+            self.add(new_category.clone()).await?;
+            Ok(new_category)
+        }
+    }
+
+    async fn bulk_add(
+        &mut self,
+        categories: Vec<AccountingCategory>,
+    ) -> Result<Vec<AccountingCategory>, AccountingCategoriesListRepositoryError> {
+        if categories.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut insert_builder = sqlx::QueryBuilder::new(
+            "INSERT INTO accounting_categories (id, name, hourly_rate, description, archived) ",
+        );
+        insert_builder.push_values(categories.iter(), |mut b, category| {
+            b.push_bind(category.id().0)
+                .push_bind(category.name())
+                .push_bind(category.hourly_rate())
+                .push_bind(category.description())
+                .push_bind(category.is_archived());
+        });
+        insert_builder.push(" ON CONFLICT (name) DO NOTHING");
+
+        insert_builder
+            .build()
+            .execute(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut select_builder = sqlx::QueryBuilder::new(
+            "SELECT id, name, hourly_rate, description, archived FROM accounting_categories WHERE name IN (",
+        );
+        let mut separated = select_builder.separated(", ");
+        for category in &categories {
+            separated.push_bind(category.name());
+        }
+        separated.push_unseparated(")");
+
+        let rows = select_builder
+            .build()
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| AccountingCategoriesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.iter().map(accounting_category_from_row).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn repository() -> SqliteAccountingCategoriesListRepository {
+        let sqlite_connection = SqliteConnection::with_database_url("sqlite::memory:")
+            .await
+            .unwrap();
+
+        SqliteAccountingCategoriesListRepository::new(sqlite_connection)
+    }
+
+    #[tokio::test]
+    async fn new_database_should_contain_default_categories() {
+        let repository = repository().await;
+
+        let categories = repository.get_all(true).await.unwrap();
+
+        assert_eq!(categories.len(), 9);
+        assert!(
+            repository
+                .find_by_name("Development")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn bulk_add_should_be_idempotent() {
+        let mut repository = repository().await;
+        let development = repository
+            .find_by_name("Development")
+            .await
+            .unwrap()
+            .unwrap();
+        let new_categories = || {
+            ["Consulting", "Development", "Consulting"]
+                .map(|name| AccountingCategory::new(name.to_string()))
+                .to_vec()
+        };
+
+        let mut first = repository.bulk_add(new_categories()).await.unwrap();
+        let mut second = repository.bulk_add(new_categories()).await.unwrap();
+        first.sort_by(|a, b| a.name().cmp(b.name()));
+        second.sort_by(|a, b| a.name().cmp(b.name()));
+
+        assert_eq!(
+            first.iter().map(|c| c.name()).collect::<Vec<_>>(),
+            vec!["Consulting", "Development"]
+        );
+        assert_eq!(first[1], development);
+        assert_eq!(second, first);
+        assert_eq!(repository.get_all(true).await.unwrap().len(), 10);
+    }
+
+    #[tokio::test]
+    async fn bulk_add_should_accept_empty_list() {
+        let mut repository = repository().await;
+
+        assert_eq!(repository.bulk_add(Vec::new()).await, Ok(Vec::new()));
+        assert_eq!(repository.get_all(true).await.unwrap().len(), 9);
+    }
+
+    #[tokio::test]
+    async fn add_should_store_all_fields() {
+        let mut repository = repository().await;
+        let mut category = AccountingCategory::new("Consulting".to_string());
+        category.set_hourly_rate(Some(120.0));
+        category.set_description(Some("Customer projects".to_string()));
+        repository.add(category.clone()).await.unwrap();
+
+        assert_eq!(
+            repository.get_by_id(category.id().clone()).await.unwrap(),
+            Some(category)
+        );
+    }
+
+    #[tokio::test]
+    async fn get_all_should_exclude_archived_categories() {
+        let mut repository = repository().await;
+        let mut category = repository.find_by_name("Planning").await.unwrap().unwrap();
+        category.set_archived(true);
+        repository.update(category.clone()).await.unwrap();
+
+        assert!(!repository.get_all(false).await.unwrap().contains(&category));
+        assert!(repository.get_all(true).await.unwrap().contains(&category));
+    }
+
+    #[tokio::test]
+    async fn get_or_create_by_name_should_store_new_category() {
+        let mut repository = repository().await;
+
+        let created = repository
+            .get_or_create_by_name("Consulting")
+            .await
+            .unwrap();
+        let existing = repository
+            .get_or_create_by_name("Consulting")
+            .await
+            .unwrap();
+
+        assert_eq!(existing, created);
+        assert_eq!(repository.get_all(true).await.unwrap().len(), 10);
+    }
+
+    #[tokio::test]
+    async fn delete_should_remove_category() {
+        let mut repository = repository().await;
+        let category = repository.find_by_name("Support").await.unwrap().unwrap();
+
+        repository.delete(category.id().clone()).await.unwrap();
+
+        assert_eq!(
+            repository.get_by_id(category.id().clone()).await.unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,885 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use sqlx::{
+    Row, Sqlite,
+    query::Query,
+    sqlite::{SqliteArguments, SqliteRow},
+};
+use uuid::Uuid;
+
+use crate::{
+    adapters::{ActivitiesListRepository, ActivitiesListRepositoryError},
+    entities::{
+        accounting::AccountingCategoryId,
+        activity::{Activity, ActivityId},
+        category_summary::CategorySummary,
+        time_distribution::{HOURS_PER_DAY, HourBucket},
+    },
+    infra::repositories::sqlite::SqliteConnection,
+    utils::dates::iso_week_range,
+};
+
+#[derive(Clone)]
+pub struct SqliteActivitiesListRepository {
+    sqlite_connection: SqliteConnection,
+}
+
+impl SqliteActivitiesListRepository {
+    pub fn new(sqlite_connection: SqliteConnection) -> Self {
+        Self { sqlite_connection }
+    }
+}
+
+/// Builds the query inserting an activity into the `activities` table.
+///
+/// # Arguments
+///
+/// - `activity`: The activity to insert.
+fn insert_query(activity: &Activity) -> Query<'_, Sqlite, SqliteArguments<'_>> {
+    sqlx::query(
+            "INSERT INTO activities (id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        )
+        .bind(activity.id().0)
+        .bind(activity.date())
+        .bind(activity.start_time())
+        .bind(activity.end_time())
+        .bind(activity.accounting_category_id().0)
+        .bind(activity.task())
+        .bind(activity.comment())
+        .bind(activity.created_by())
+        .bind(activity.import_source())
+        .bind(tags_to_json(activity.tags()))
+}
+
+/// Serializes the tags of an activity into the JSON array stored in the `tags` column.
+///
+/// # Arguments
+///
+/// - `tags`: The tags to serialize.
+fn tags_to_json(tags: &[String]) -> String {
+    serde_json::to_string(tags).expect("Tags can always be serialized")
+}
+
+/// Converts a row of the `activities` table into an `Activity` entity.
+///
+/// # Arguments
+///
+/// - `row`: The database row to convert.
+///
+/// # Returns
+///
+/// - `Ok(Activity)`: The activity stored in the row.
+/// - `Err(ActivitiesListRepositoryError::DatabaseError)`: If the stored tags are not a JSON array or the
+///   stored end time is not after the start time.
+fn activity_from_row(row: &SqliteRow) -> Result<Activity, ActivitiesListRepositoryError> {
+    let id: Uuid = row.get("id");
+    let date: NaiveDate = row.get("date");
+    let start_time: NaiveTime = row.get("start_time");
+    let end_time: Option<NaiveTime> = row.get("end_time");
+    let category_id: Uuid = row.get("category_id");
+    let task: String = row.get("task");
+    let comment: Option<String> = row.get("comment");
+    let created_by: Option<String> = row.get("created_by");
+    let import_source: Option<String> = row.get("import_source");
+    let tags: String = row.get("tags");
+    let created_at: Option<DateTime<Utc>> = row.get("created_at");
+    let updated_at: Option<DateTime<Utc>> = row.get("updated_at");
+    let tags: Vec<String> = serde_json::from_str(&tags).map_err(|e| {
+        ActivitiesListRepositoryError::DatabaseError(format!(
+            "Stored tags are not a JSON array: {e}"
+        ))
+    })?;
+
+    let activity = Activity::builder()
+        .id(ActivityId(id))
+        .date(date)
+        .start_time(start_time)
+        .end_time(end_time)
+        .accounting_category_id(AccountingCategoryId(category_id))
+        .task(task)
+        .comment(comment)
+        .created_by(created_by)
+        .import_source(import_source)
+        .tags(tags)
+        .build()
+        .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?
+        .with_timestamps(created_at, updated_at);
+
+    Ok(activity)
+}
+
+#[async_trait]
+impl ActivitiesListRepository for SqliteActivitiesListRepository {
+    async fn get_all(&self) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows =
+            sqlx::query("SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities")
+                .fetch_all(self.sqlite_connection.pool())
+                .await
+                .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_all_sorted(&self) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows =
+            sqlx::query("SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities ORDER BY date ASC, start_time ASC")
+                .fetch_all(self.sqlite_connection.pool())
+                .await
+                .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_date(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE date = ?1",
+            )
+            .bind(date)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_date_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE date BETWEEN ?1 AND ?2",
+            )
+            .bind(start)
+            .bind(end)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let Some(month_start) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            return Ok(Vec::new());
+        };
+
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE strftime('%Y-%m', date) = strftime('%Y-%m', ?1)",
+            )
+            .bind(month_start)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_week_number(
+        &self,
+        year: i32,
+        week: u32,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let Some((week_start, week_end)) = iso_week_range(year, week) else {
+            return Ok(Vec::new());
+        };
+
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE date >= ?1 AND date <= ?2",
+            )
+            .bind(week_start)
+            .bind(week_end)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_from_date(
+        &self,
+        start: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE date >= ?1",
+            )
+            .bind(start)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_until_date(
+        &self,
+        end: NaiveDate,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE date <= ?1",
+            )
+            .bind(end)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_time_distribution(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<HourBucket>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT CAST(strftime('%H', start_time) AS INTEGER) AS hour, COUNT(*) AS count, \
+                 CAST(ROUND(AVG(COALESCE(strftime('%s', end_time) - strftime('%s', start_time), 0))) AS INTEGER) AS avg_duration_seconds \
+                 FROM activities WHERE (?1 IS NULL OR date >= ?1) AND (?2 IS NULL OR date <= ?2) \
+                 GROUP BY hour",
+            )
+            .bind(start)
+            .bind(end)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok((0..HOURS_PER_DAY)
+            .map(|hour| {
+                rows.iter()
+                    .find(|row| row.get::<i64, _>("hour") as u32 == hour)
+                    .map(|row| {
+                        HourBucket::new(
+                            hour,
+                            row.get::<i64, _>("count") as usize,
+                            row.get("avg_duration_seconds"),
+                        )
+                    })
+                    .unwrap_or_else(|| HourBucket::new(hour, 0, 0))
+            })
+            .collect())
+    }
+
+    async fn get_category_summary(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<CategorySummary>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT category_id, COUNT(*) AS activity_count, \
+                 CAST(COALESCE(SUM(strftime('%s', end_time) - strftime('%s', start_time)), 0) AS INTEGER) AS total_seconds \
+                 FROM activities WHERE date BETWEEN ?1 AND ?2 \
+                 GROUP BY category_id ORDER BY category_id",
+            )
+            .bind(start)
+            .bind(end)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                CategorySummary::new(
+                    AccountingCategoryId(row.get::<Uuid, _>("category_id")),
+                    row.get::<i64, _>("activity_count") as usize,
+                    Duration::seconds(row.get("total_seconds")),
+                )
+            })
+            .collect())
+    }
+
+    async fn get_by_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE created_by = ?1",
+            )
+            .bind(user_id)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_import_source(
+        &self,
+        source: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE import_source = ?1",
+            )
+            .bind(source)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_id(
+        &self,
+        id: &ActivityId,
+    ) -> Result<Option<Activity>, ActivitiesListRepositoryError> {
+        let row = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE id = ?1",
+            )
+            .bind(id.0)
+            .fetch_optional(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        row.as_ref().map(activity_from_row).transpose()
+    }
+
+    async fn get_current_in_progress(
+        &self,
+        source: &str,
+    ) -> Result<Option<Activity>, ActivitiesListRepositoryError> {
+        let row = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE end_time IS NULL AND import_source = ?1 ORDER BY date DESC, start_time DESC LIMIT 1",
+            )
+            .bind(source)
+            .fetch_optional(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        row.as_ref().map(activity_from_row).transpose()
+    }
+
+    async fn get_by_accounting_category_id(
+        &self,
+        category_id: &AccountingCategoryId,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE category_id = ?1",
+            )
+            .bind(category_id.0)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_by_tag(&self, tag: &str) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE EXISTS (SELECT 1 FROM json_each(activities.tags) WHERE json_each.value = ?1)",
+            )
+            .bind(tag)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn search_by_task(
+        &self,
+        query: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        // LIKE is case-insensitive for ASCII characters in SQLite.
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE task LIKE '%' || ?1 || '%'",
+            )
+            .bind(query)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_page_after(
+        &self,
+        after_id: Option<ActivityId>,
+        limit: usize,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities WHERE (?1 IS NULL OR id > ?1) ORDER BY id LIMIT ?2",
+            )
+            .bind(after_id.map(|id| id.0))
+            .bind(limit as i64)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn get_paginated(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+        let rows = sqlx::query(
+                "SELECT id, date, start_time, end_time, category_id, task, comment, created_by, import_source, tags, created_at, updated_at FROM activities ORDER BY date DESC, start_time DESC LIMIT ?1 OFFSET ?2",
+            )
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(activity_from_row).collect()
+    }
+
+    async fn count(&self) -> Result<usize, ActivitiesListRepositoryError> {
+        let row = sqlx::query("SELECT COUNT(*) FROM activities")
+            .fetch_one(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.get::<i64, _>(0) as usize)
+    }
+
+    async fn count_by_date_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<usize, ActivitiesListRepositoryError> {
+        let row = sqlx::query("SELECT COUNT(*) FROM activities WHERE date BETWEEN ?1 AND ?2")
+            .bind(start)
+            .bind(end)
+            .fetch_one(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.get::<i64, _>(0) as usize)
+    }
+
+    async fn add(&mut self, activity: Activity) -> Result<(), ActivitiesListRepositoryError> {
+        insert_query(&activity)
+            .execute(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn add_range(
+        &mut self,
+        activities: Vec<Activity>,
+    ) -> Result<(), ActivitiesListRepositoryError> {
+        let mut transaction = self
+            .sqlite_connection
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        for activity in &activities {
+            insert_query(activity)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        transaction
+            .commit()
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))
+    }
+
+    async fn update(&mut self, activity: Activity) -> Result<(), ActivitiesListRepositoryError> {
+        sqlx::query(
+                "UPDATE activities SET date = ?1, start_time = ?2, end_time = ?3, category_id = ?4, task = ?5, comment = ?6, created_by = ?7, import_source = ?8, tags = ?9, updated_at = CURRENT_TIMESTAMP WHERE id = ?10",
+            )
+            .bind(activity.date())
+            .bind(activity.start_time())
+            .bind(activity.end_time())
+            .bind(activity.accounting_category_id().0)
+            .bind(activity.task())
+            .bind(activity.comment())
+            .bind(activity.created_by())
+            .bind(activity.import_source())
+            .bind(tags_to_json(activity.tags()))
+            .bind(activity.id().0)
+            .execute(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&mut self, id: ActivityId) -> Result<(), ActivitiesListRepositoryError> {
+        sqlx::query("DELETE FROM activities WHERE id = ?1")
+            .bind(id.0)
+            .execute(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete_all(&mut self) -> Result<(), ActivitiesListRepositoryError> {
+        sqlx::query("DELETE FROM activities")
+            .execute(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete_by_date_range(
+        &mut self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<usize, ActivitiesListRepositoryError> {
+        let result = sqlx::query("DELETE FROM activities WHERE date BETWEEN ?1 AND ?2")
+            .bind(start)
+            .bind(end)
+            .execute(self.sqlite_connection.pool())
+            .await
+            .map_err(|e| ActivitiesListRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        adapters::AccountingCategoriesListRepository,
+        infra::repositories::sqlite::accounting_categories_list::SqliteAccountingCategoriesListRepository,
+    };
+
+    /// Creates a repository on a fresh in-memory database together with the ID of one of the
+    /// default accounting categories, which the activities must reference.
+    async fn repository() -> (SqliteActivitiesListRepository, AccountingCategoryId) {
+        let sqlite_connection = SqliteConnection::with_database_url("sqlite::memory:")
+            .await
+            .unwrap();
+        let category = SqliteAccountingCategoriesListRepository::new(sqlite_connection.clone())
+            .find_by_name("Development")
+            .await
+            .unwrap()
+            .unwrap();
+
+        (
+            SqliteActivitiesListRepository::new(sqlite_connection),
+            category.id().clone(),
+        )
+    }
+
+    fn activity(
+        category_id: &AccountingCategoryId,
+        day: u32,
+        start_hour: u32,
+        end_hour: Option<u32>,
+        task: &str,
+    ) -> Activity {
+        let mut activity = Activity::new(
+            NaiveDate::from_ymd_opt(2025, 1, day).unwrap(),
+            NaiveTime::from_hms_opt(start_hour, 0, 0).unwrap(),
+            category_id.clone(),
+            task.to_string(),
+        );
+        activity
+            .set_end_time(end_hour.map(|hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap()))
+            .unwrap();
+        activity
+    }
+
+    #[tokio::test]
+    async fn add_should_store_all_fields() {
+        let (mut repository, category_id) = repository().await;
+        let mut activity = activity(&category_id, 15, 9, Some(12), "Coding");
+        activity.set_comment(Some("Feature work".to_string()));
+        activity.set_created_by(Some("jdoe".to_string()));
+        activity.set_import_source(Some("csv".to_string()));
+        activity.set_tags(vec!["backend".to_string(), "urgent".to_string()]);
+
+        repository.add(activity.clone()).await.unwrap();
+
+        assert_eq!(
+            repository.get_by_id(activity.id()).await.unwrap(),
+            Some(activity)
+        );
+    }
+
+    #[tokio::test]
+    async fn get_all_should_fail_for_malformed_stored_tags() {
+        let (mut repository, category_id) = repository().await;
+        repository
+            .add(activity(&category_id, 15, 9, Some(12), "Coding"))
+            .await
+            .unwrap();
+        sqlx::query("UPDATE activities SET tags = 'backend'")
+            .execute(repository.sqlite_connection.pool())
+            .await
+            .unwrap();
+
+        let result = repository.get_all().await;
+
+        assert!(matches!(
+            result,
+            Err(ActivitiesListRepositoryError::DatabaseError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_by_id_should_fail_for_stored_end_time_before_start_time() {
+        let (mut repository, category_id) = repository().await;
+        let stored = activity(&category_id, 15, 9, Some(12), "Coding");
+        repository.add(stored.clone()).await.unwrap();
+        sqlx::query("UPDATE activities SET end_time = ?1")
+            .bind(NaiveTime::from_hms_opt(8, 0, 0).expect("Valid end time"))
+            .execute(repository.sqlite_connection.pool())
+            .await
+            .unwrap();
+
+        let result = repository.get_by_id(stored.id()).await;
+
+        assert!(matches!(
+            result,
+            Err(ActivitiesListRepositoryError::DatabaseError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn add_should_fail_for_unknown_category() {
+        let (mut repository, _) = repository().await;
+
+        let result = repository
+            .add(activity(
+                &AccountingCategoryId::new(),
+                15,
+                9,
+                None,
+                "Coding",
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ActivitiesListRepositoryError::DatabaseError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_by_date_range_should_only_return_activities_in_range() {
+        let (mut repository, category_id) = repository().await;
+        repository
+            .add_range(vec![
+                activity(&category_id, 14, 9, None, "Task 1"),
+                activity(&category_id, 15, 9, None, "Task 2"),
+                activity(&category_id, 16, 9, None, "Task 3"),
+                activity(&category_id, 17, 9, None, "Task 4"),
+            ])
+            .await
+            .unwrap();
+
+        let activities = repository
+            .get_by_date_range(
+                NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 1, 16).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let mut tasks = activities.iter().map(|a| a.task()).collect::<Vec<_>>();
+        tasks.sort();
+        assert_eq!(tasks, vec!["Task 2", "Task 3"]);
+        assert_eq!(repository.count().await.unwrap(), 4);
+        assert_eq!(repository.get_by_month(2025, 1).await.unwrap().len(), 4);
+        assert!(repository.get_by_month(2025, 2).await.unwrap().is_empty());
+        assert_eq!(
+            repository.get_by_week_number(2025, 3).await.unwrap().len(),
+            4
+        );
+        assert!(
+            repository
+                .get_by_week_number(2025, 4)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_all_sorted_should_order_by_date_and_start_time() {
+        let (mut repository, category_id) = repository().await;
+        repository
+            .add_range(vec![
+                activity(&category_id, 16, 9, None, "Task 3"),
+                activity(&category_id, 15, 14, None, "Task 2"),
+                activity(&category_id, 15, 8, None, "Task 1"),
+            ])
+            .await
+            .unwrap();
+
+        let activities = repository.get_all_sorted().await.unwrap();
+
+        assert_eq!(
+            activities.iter().map(|a| a.task()).collect::<Vec<_>>(),
+            vec!["Task 1", "Task 2", "Task 3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn get_current_in_progress_should_return_latest_activity_without_end_time() {
+        let (mut repository, category_id) = repository().await;
+        assert_eq!(
+            repository.get_current_in_progress("tracker").await.unwrap(),
+            None
+        );
+
+        let mut activities = vec![
+            activity(&category_id, 15, 9, None, "Forgotten"),
+            activity(&category_id, 16, 8, None, "Current"),
+            activity(&category_id, 16, 10, Some(11), "Completed"),
+        ];
+        for activity in &mut activities {
+            activity.set_import_source(Some("tracker".to_string()));
+        }
+        activities.push(activity(&category_id, 16, 9, None, "Imported"));
+        repository.add_range(activities).await.unwrap();
+
+        let activity = repository.get_current_in_progress("tracker").await.unwrap();
+
+        assert_eq!(activity.as_ref().map(|a| a.task()), Some("Current"));
+    }
+
+    #[tokio::test]
+    async fn get_paginated_should_return_newest_activities_first() {
+        let (mut repository, category_id) = repository().await;
+        repository
+            .add_range(vec![
+                activity(&category_id, 15, 8, None, "Task 1"),
+                activity(&category_id, 16, 9, None, "Task 3"),
+                activity(&category_id, 15, 14, None, "Task 2"),
+            ])
+            .await
+            .unwrap();
+
+        let tasks = |activities: Vec<Activity>| {
+            activities
+                .iter()
+                .map(|a| a.task().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            tasks(repository.get_paginated(0, 2).await.unwrap()),
+            vec!["Task 3", "Task 2"]
+        );
+        assert_eq!(
+            tasks(repository.get_paginated(2, 2).await.unwrap()),
+            vec!["Task 1"]
+        );
+        assert!(repository.get_paginated(4, 2).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_by_tag_should_match_whole_tags() {
+        let (mut repository, category_id) = repository().await;
+        let mut tagged = activity(&category_id, 15, 9, None, "Tagged");
+        tagged.set_tags(vec!["backend".to_string()]);
+        let mut other = activity(&category_id, 15, 10, None, "Other");
+        other.set_tags(vec!["backend-ops".to_string()]);
+        repository.add_range(vec![tagged, other]).await.unwrap();
+
+        let activities = repository.get_by_tag("backend").await.unwrap();
+
+        assert_eq!(
+            activities.iter().map(|a| a.task()).collect::<Vec<_>>(),
+            vec!["Tagged"]
+        );
+    }
+
+    #[tokio::test]
+    async fn get_time_distribution_should_average_durations_by_hour() {
+        let (mut repository, category_id) = repository().await;
+        repository
+            .add_range(vec![
+                activity(&category_id, 15, 9, Some(10), "Task 1"),
+                activity(&category_id, 16, 9, Some(12), "Task 2"),
+                activity(&category_id, 16, 14, None, "Task 3"),
+            ])
+            .await
+            .unwrap();
+
+        let distribution = repository.get_time_distribution(None, None).await.unwrap();
+
+        assert_eq!(distribution.len(), HOURS_PER_DAY as usize);
+        assert_eq!(distribution[9], HourBucket::new(9, 2, 7200));
+        assert_eq!(distribution[14], HourBucket::new(14, 1, 0));
+        assert_eq!(distribution[10], HourBucket::new(10, 0, 0));
+    }
+
+    #[tokio::test]
+    async fn get_category_summary_should_sum_activities_in_range() {
+        let (mut repository, category_id) = repository().await;
+        repository
+            .add_range(vec![
+                activity(&category_id, 15, 9, Some(10), "Task 1"),
+                activity(&category_id, 16, 9, Some(12), "Task 2"),
+                activity(&category_id, 16, 14, None, "Task 3"),
+                activity(&category_id, 17, 9, Some(10), "Task 4"),
+            ])
+            .await
+            .unwrap();
+
+        let summary = repository
+            .get_category_summary(
+                NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 1, 16).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            summary,
+            vec![CategorySummary::new(category_id, 3, Duration::hours(4))]
+        );
+    }
+
+    #[tokio::test]
+    async fn update_and_delete_should_change_stored_activities() {
+        let (mut repository, category_id) = repository().await;
+        let mut activity = activity(&category_id, 15, 9, None, "Coding");
+        repository.add(activity.clone()).await.unwrap();
+
+        activity
+            .set_end_time(NaiveTime::from_hms_opt(11, 0, 0))
+            .unwrap();
+        repository.update(activity.clone()).await.unwrap();
+        assert_eq!(
+            repository.get_by_id(activity.id()).await.unwrap(),
+            Some(activity.clone())
+        );
+
+        repository.delete(activity.id().clone()).await.unwrap();
+        assert_eq!(repository.get_by_id(activity.id()).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn add_and_update_should_set_timestamps() {
+        let (mut repository, category_id) = repository().await;
+        let mut activity = activity(&category_id, 15, 9, None, "Coding");
+        repository.add(activity.clone()).await.unwrap();
+
+        let added = repository.get_by_id(activity.id()).await.unwrap().unwrap();
+        let created_at = *added.created_at().expect("Timestamp set on insert");
+        assert_eq!(added.updated_at(), Some(&created_at));
+
+        activity.set_task("Code Review".to_string());
+        repository.update(activity.clone()).await.unwrap();
+
+        let updated = repository.get_by_id(activity.id()).await.unwrap().unwrap();
+        assert_eq!(updated, activity);
+        assert_eq!(updated.created_at(), Some(&created_at));
+        assert!(*updated.updated_at().expect("Timestamp set on update") >= created_at);
+    }
+}
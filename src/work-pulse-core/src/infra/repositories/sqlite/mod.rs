@@ -0,0 +1,158 @@
+pub mod accounting_categories_list;
+pub mod activities_list;
+
+use std::{path::Path, str::FromStr};
+
+use sqlx::{
+    Row, SqlitePool,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+};
+
+/// The SQLite schema, in the dbmate migration format used for the PostgreSQL schema.
+const SCHEMA_MIGRATION: &str =
+    include_str!("../../../../../../db/sqlite/migrations/20261015000001_create_schema.sql");
+
+/// The schema version stored in `PRAGMA user_version` once the schema migration has been applied.
+const SCHEMA_VERSION: i64 = 1;
+
+/// A wrapper around a SQLite connection pool.
+#[derive(Clone)]
+pub struct SqliteConnection {
+    pool: SqlitePool,
+}
+
+impl SqliteConnection {
+    /// Creates a new `SqliteConnection` instance.
+    ///
+    /// The schema is expected to exist already, see `migrate`.
+    ///
+    /// # Arguments
+    ///
+    /// - `pool`: A SQLite connection pool.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Creates a new `SqliteConnection` instance with the given database URL and applies the
+    /// schema migration if necessary.
+    ///
+    /// # Arguments
+    ///
+    /// - `database_url`: The database URL to connect to, e.g. `sqlite://workpulse.db` or
+    ///   `sqlite::memory:`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(SqliteConnection)` if the database could be opened and migrated.
+    /// - `Err(sqlx::Error)` otherwise.
+    pub async fn with_database_url(database_url: &str) -> Result<Self, sqlx::Error> {
+        Self::connect(SqliteConnectOptions::from_str(database_url)?).await
+    }
+
+    /// Opens the SQLite database file at the given path, creating it if it does not exist, and
+    /// applies the schema migration if necessary.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: The path of the database file.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(SqliteConnection)` if the database could be opened and migrated.
+    /// - `Err(sqlx::Error)` otherwise.
+    pub async fn with_path(path: impl AsRef<Path>) -> Result<Self, sqlx::Error> {
+        Self::connect(SqliteConnectOptions::new().filename(path)).await
+    }
+
+    async fn connect(options: SqliteConnectOptions) -> Result<Self, sqlx::Error> {
+        // A single connection that is never closed keeps `sqlite::memory:` databases alive and
+        // serializes the writes, which SQLite does not run concurrently anyway.
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(options.create_if_missing(true).foreign_keys(true))
+            .await?;
+
+        let connection = Self::new(pool);
+        connection.migrate().await?;
+
+        Ok(connection)
+    }
+
+    /// Creates the schema if the database does not have it yet.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the schema is up to date.
+    /// - `Err(sqlx::Error)` if the schema could not be created.
+    pub async fn migrate(&self) -> Result<(), sqlx::Error> {
+        let version: i64 = sqlx::query("PRAGMA user_version")
+            .fetch_one(&self.pool)
+            .await?
+            .get(0);
+        if version >= SCHEMA_VERSION {
+            return Ok(());
+        }
+
+        let up = SCHEMA_MIGRATION
+            .split("-- migrate:down")
+            .next()
+            .unwrap_or_default();
+
+        let mut transaction = self.pool.begin().await?;
+        sqlx::raw_sql(up).execute(&mut *transaction).await?;
+        sqlx::raw_sql(&format!("PRAGMA user_version = {}", SCHEMA_VERSION))
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await
+    }
+
+    /// Returns a reference to the underlying SQLite connection pool.
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    /// Pings the database to check connectivity.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the database answered a `SELECT 1`.
+    /// - `Err(sqlx::Error)` if the database is not reachable.
+    pub async fn ping(&self) -> Result<(), sqlx::Error> {
+        sqlx::query("SELECT 1")
+            .fetch_one(&self.pool)
+            .await
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn with_database_url_should_create_schema() {
+        let connection = SqliteConnection::with_database_url("sqlite::memory:")
+            .await
+            .unwrap();
+
+        let count: i64 = sqlx::query("SELECT COUNT(*) FROM activities")
+            .fetch_one(connection.pool())
+            .await
+            .unwrap()
+            .get(0);
+
+        assert_eq!(count, 0);
+        assert!(connection.ping().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn migrate_should_be_idempotent() {
+        let connection = SqliteConnection::with_database_url("sqlite::memory:")
+            .await
+            .unwrap();
+
+        assert!(connection.migrate().await.is_ok());
+    }
+}
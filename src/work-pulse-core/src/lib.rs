@@ -1,4 +1,5 @@
 pub mod adapters;
 pub mod entities;
 pub mod infra;
-pub mod use_cases;
\ No newline at end of file
+pub mod use_cases;
+pub mod utils;
\ No newline at end of file
@@ -1,10 +1,14 @@
-use std::sync::Arc;
+use std::{collections::HashSet, io::Read, sync::Arc};
 
+use serde::Deserialize;
 use thiserror::Error;
 use tokio::sync::Mutex;
 
 use crate::{
-    adapters::AccountingCategoriesListRepository,
+    adapters::{
+        AccountingCategoriesListRepository, AccountingCategoriesListRepositoryError,
+        ActivitiesListRepository, ActivitiesListRepositoryError,
+    },
     entities::accounting::{AccountingCategory, AccountingCategoryId},
 };
 
@@ -18,6 +22,46 @@ pub enum AccountingCategoriesListError {
     /// An accounting category with the ID does not exists.
     #[error("Accounting category with the ID `{0}` does not exists.")]
     NotFound(AccountingCategoryId),
+
+    /// An accounting category cannot be merged into itself.
+    #[error("Accounting category with the ID `{0}` cannot be merged into itself.")]
+    MergeIntoItself(AccountingCategoryId),
+
+    /// The activities of an accounting category could not be reassigned.
+    #[error("Activities could not be reassigned: {0}")]
+    ActivitiesRepositoryError(#[from] ActivitiesListRepositoryError),
+
+    /// The accounting categories could not be read or stored.
+    #[error("Accounting categories could not be read or stored: {0}")]
+    RepositoryError(#[from] AccountingCategoriesListRepositoryError),
+}
+
+/// Represents an error that can occur while importing accounting categories from a CSV file.
+#[derive(Error, Clone, Debug, Eq, PartialEq)]
+pub enum CsvImportError {
+    /// A row of the CSV file could not be parsed.
+    #[error("Could not parse row {row}: {reason}")]
+    ParseError { row: usize, reason: String },
+
+    /// A row of the CSV file has an empty category name.
+    #[error("Row {0} has no category name")]
+    MissingName(usize),
+
+    /// The imported accounting categories could not be stored.
+    #[error("Could not store the accounting categories: {0}")]
+    RepositoryError(String),
+}
+
+/// A row of a CSV file with accounting categories.
+#[derive(Debug, Deserialize)]
+struct AccountingCategoryRecord {
+    name: String,
+
+    #[serde(default)]
+    description: Option<String>,
+
+    #[serde(default)]
+    hourly_rate: Option<f64>,
 }
 
 /// Represents a list of all accounting categories.
@@ -45,20 +89,22 @@ impl<R: AccountingCategoriesListRepository> AccountingCategoriesList<R> {
     /// # Returns
     ///
     /// - `Ok(AccountingCategory)`: If the category was successfully created.
-    /// - `Err(AccountingCategoriesListError)`: If a category with the same name already exists.
+    /// - `Err(AccountingCategoriesListError::AccountingCategoryAlreadyExists)`: If a category with the same name already exists.
+    /// - `Err(AccountingCategoriesListError::RepositoryError)`: If the categories could not be read or stored.
+    #[tracing::instrument(skip(self))]
     pub async fn create(
         &mut self,
         category_name: &str,
     ) -> Result<AccountingCategory, AccountingCategoriesListError> {
+        tracing::debug!("Creating accounting category");
         let mut repository = self.repository.lock().await;
 
         // Check if a category with the same name already exists.
         if repository
-            .get_all()
-            .await
+            .get_all(true)
+            .await?
             .iter()
-            .find(|category| category.name() == category_name)
-            .is_some()
+            .any(|category| category.name() == category_name)
         {
             return Err(
                 AccountingCategoriesListError::AccountingCategoryAlreadyExists(
@@ -68,19 +114,118 @@ impl<R: AccountingCategoriesListRepository> AccountingCategoriesList<R> {
         }
 
         let accounting_category = AccountingCategory::new(category_name.to_string());
-        repository.add(accounting_category.clone()).await;
+        repository.add(accounting_category.clone()).await?;
 
+        tracing::info!(category_id = %accounting_category.id(), "Accounting category created");
         Ok(accounting_category)
     }
 
+    /// Imports accounting categories from a CSV file with a `name` column and the optional
+    /// columns `description` and `hourly_rate`.
+    ///
+    /// Rows with the name of an existing category, or of a previous row, are skipped, so that
+    /// importing the same file twice does not create duplicates. Nothing is stored if any row is
+    /// malformed.
+    ///
+    /// # Arguments
+    ///
+    /// - `reader`: The source of the CSV data.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<AccountingCategory>)`: The newly created accounting categories.
+    /// - `Err(CsvImportError)`: If a row could not be parsed or the categories could not be stored.
+    #[tracing::instrument(skip(self, reader))]
+    pub async fn import_from_csv<T: Read>(
+        &mut self,
+        reader: T,
+    ) -> Result<Vec<AccountingCategory>, CsvImportError> {
+        tracing::debug!("Importing accounting categories from CSV");
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+
+        let mut records = Vec::new();
+        for (i, result) in csv_reader
+            .deserialize::<AccountingCategoryRecord>()
+            .enumerate()
+        {
+            let row = i + 1;
+            let record = result.map_err(|err| CsvImportError::ParseError {
+                row,
+                reason: err.to_string(),
+            })?;
+            if record.name.is_empty() {
+                return Err(CsvImportError::MissingName(row));
+            }
+            records.push(record);
+        }
+
+        let mut repository = self.repository.lock().await;
+        let mut known_names: HashSet<String> = repository
+            .get_all(true)
+            .await
+            .map_err(|err| CsvImportError::RepositoryError(err.to_string()))?
+            .into_iter()
+            .map(|category| category.name().to_string())
+            .collect();
+
+        let new_categories: Vec<AccountingCategory> = records
+            .into_iter()
+            .filter(|record| known_names.insert(record.name.clone()))
+            .map(|record| {
+                let mut category = AccountingCategory::new(record.name);
+                category.set_description(record.description);
+                category.set_hourly_rate(record.hourly_rate);
+                category
+            })
+            .collect();
+
+        let created = repository
+            .bulk_add(new_categories)
+            .await
+            .map_err(|err| CsvImportError::RepositoryError(err.to_string()))?;
+
+        tracing::info!(count = created.len(), "Accounting categories imported");
+        Ok(created)
+    }
+
     /// Returns the list of accounting categories.
     ///
+    /// # Arguments
+    ///
+    /// - `include_archived`: Whether archived accounting categories should be included.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<AccountingCategory>)`: A vector containing all (non-archived) accounting categories.
+    /// - `Err(AccountingCategoriesListError::RepositoryError)`: If the categories could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn categories(
+        &self,
+        include_archived: bool,
+    ) -> Result<Vec<AccountingCategory>, AccountingCategoriesListError> {
+        let repository = self.repository.lock().await;
+        Ok(repository.get_all(include_archived).await?)
+    }
+
+    /// Returns the accounting category with the given name.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: The exact, case-sensitive name of the accounting category.
+    ///
     /// # Returns
     ///
-    /// - `Vec<AccountingCategory>`: A vector containing all accounting categories.
-    pub async fn categories(&self) -> Vec<AccountingCategory> {
+    /// - `Ok(Option<AccountingCategory>)`: The accounting category, or `None` if there is none with the name.
+    /// - `Err(AccountingCategoriesListError::RepositoryError)`: If the categories could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<AccountingCategory>, AccountingCategoriesListError> {
         let repository = self.repository.lock().await;
-        repository.get_all().await
+        Ok(repository.find_by_name(name).await?)
     }
 
     /// Updates an existing accounting category in the list.
@@ -93,17 +238,71 @@ impl<R: AccountingCategoriesListRepository> AccountingCategoriesList<R> {
     ///
     /// - `Ok(())`: If the category was successfully updated.
     /// - `Err(AccountingCategoriesListError)`: If the category with the specified ID does not exist.
+    #[tracing::instrument(skip(self, category), fields(category_id = %category.id()))]
     pub async fn update(
         &mut self,
         category: AccountingCategory,
     ) -> Result<(), AccountingCategoriesListError> {
+        tracing::debug!("Updating accounting category");
         let mut repository = self.repository.lock().await;
         let category_id = category.id().clone();
 
         repository
             .update(category)
             .await
-            .map_err(|_| AccountingCategoriesListError::NotFound(category_id))
+            .map_err(|_| AccountingCategoriesListError::NotFound(category_id))?;
+
+        tracing::info!("Accounting category updated");
+        Ok(())
+    }
+
+    /// Renames an existing accounting category.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: The unique identifier of the accounting category to rename.
+    /// - `new_name`: The new name of the accounting category.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(AccountingCategory)`: The renamed accounting category.
+    /// - `Err(AccountingCategoriesListError::AccountingCategoryAlreadyExists)`: If another category already has the name.
+    /// - `Err(AccountingCategoriesListError::NotFound)`: If the category with the specified ID does not exist.
+    /// - `Err(AccountingCategoriesListError::RepositoryError)`: If the categories could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn rename(
+        &mut self,
+        id: AccountingCategoryId,
+        new_name: &str,
+    ) -> Result<AccountingCategory, AccountingCategoriesListError> {
+        tracing::debug!("Renaming accounting category");
+        let mut repository = self.repository.lock().await;
+
+        if repository
+            .find_by_name(new_name)
+            .await?
+            .is_some_and(|existing| *existing.id() != id)
+        {
+            return Err(
+                AccountingCategoriesListError::AccountingCategoryAlreadyExists(
+                    new_name.to_string(),
+                ),
+            );
+        }
+
+        let mut category = repository
+            .get_by_id(id.clone())
+            .await?
+            .ok_or_else(|| AccountingCategoriesListError::NotFound(id.clone()))?;
+        category.set_name(new_name.to_string());
+
+        repository
+            .update(category.clone())
+            .await
+            .map_err(|_| AccountingCategoriesListError::NotFound(id))?;
+
+        tracing::info!("Accounting category renamed");
+        Ok(category)
     }
 
     /// Deletes an accounting category from the list.
@@ -116,22 +315,88 @@ impl<R: AccountingCategoriesListRepository> AccountingCategoriesList<R> {
     ///
     /// - `Ok(())`: If the category was successfully deleted.
     /// - `Err(AccountingCategoriesListError)`: If the category with the specified ID does not exist.
+    #[tracing::instrument(skip(self))]
     pub async fn delete(
         &mut self,
         id: AccountingCategoryId,
     ) -> Result<(), AccountingCategoriesListError> {
+        tracing::debug!("Deleting accounting category");
         let mut repository = self.repository.lock().await;
 
         repository
             .delete(id.clone())
             .await
-            .map_err(|_| AccountingCategoriesListError::NotFound(id))
+            .map_err(|_| AccountingCategoriesListError::NotFound(id))?;
+
+        tracing::info!("Accounting category deleted");
+        Ok(())
+    }
+
+    /// Merges an accounting category into another one. All activities of the merged category are
+    /// reassigned to the surviving category, then the merged category is deleted.
+    ///
+    /// # Arguments
+    ///
+    /// - `from_id`: The unique identifier of the accounting category to merge and delete.
+    /// - `into_id`: The unique identifier of the accounting category that receives the activities.
+    /// - `activities_repo`: The repository holding the activities to reassign.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(usize)`: The number of reassigned activities.
+    /// - `Err(AccountingCategoriesListError::MergeIntoItself)`: If both IDs are the same.
+    /// - `Err(AccountingCategoriesListError::NotFound)`: If one of the categories does not exist.
+    /// - `Err(AccountingCategoriesListError::RepositoryError)`: If the categories could not be read.
+    /// - `Err(AccountingCategoriesListError::ActivitiesRepositoryError)`: If the activities could not be read or updated.
+    #[tracing::instrument(skip(self, activities_repo))]
+    pub async fn merge(
+        &mut self,
+        from_id: AccountingCategoryId,
+        into_id: AccountingCategoryId,
+        activities_repo: &mut impl ActivitiesListRepository,
+    ) -> Result<usize, AccountingCategoriesListError> {
+        tracing::debug!("Merging accounting categories");
+        if from_id == into_id {
+            return Err(AccountingCategoriesListError::MergeIntoItself(from_id));
+        }
+
+        let mut repository = self.repository.lock().await;
+        for id in [&from_id, &into_id] {
+            if repository.get_by_id(id.clone()).await?.is_none() {
+                return Err(AccountingCategoriesListError::NotFound(id.clone()));
+            }
+        }
+
+        let activities = activities_repo
+            .get_by_accounting_category_id(&from_id)
+            .await?;
+        let reassigned = activities.len();
+        for mut activity in activities {
+            activity.set_accounting_category_id(into_id.clone());
+            activities_repo.update(activity).await?;
+        }
+
+        repository
+            .delete(from_id.clone())
+            .await
+            .map_err(|_| AccountingCategoriesListError::NotFound(from_id))?;
+
+        tracing::info!(reassigned, "Accounting categories merged");
+        Ok(reassigned)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::infra::repositories::in_memory::accounting_categories_list::InMemoryAccountingCategoriesListRepository;
+    use chrono::{NaiveDate, NaiveTime};
+
+    use crate::{
+        entities::activity::Activity,
+        infra::repositories::in_memory::{
+            accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+            activities_list::InMemoryActivitiesListRepository,
+        },
+    };
 
     use super::*;
 
@@ -143,8 +408,11 @@ mod tests {
         let category_name = "Test Category";
         categories_list.create(category_name).await.unwrap();
 
-        assert_eq!(categories_list.categories().await.len(), 1);
-        assert_eq!(categories_list.categories().await[0].name(), category_name);
+        assert_eq!(categories_list.categories(false).await.unwrap().len(), 1);
+        assert_eq!(
+            categories_list.categories(false).await.unwrap()[0].name(),
+            category_name
+        );
     }
 
     #[tokio::test]
@@ -165,12 +433,100 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn import_from_csv_should_create_categories_with_optional_fields() {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut categories_list = AccountingCategoriesList::new(repository);
+        let csv_data = "\
+name,description,hourly_rate
+Development,Product development,120.5
+Meetings,,
+";
+
+        let created = categories_list
+            .import_from_csv(csv_data.as_bytes())
+            .await
+            .unwrap();
+
+        assert_eq!(created.len(), 2);
+        assert_eq!(created[0].name(), "Development");
+        assert_eq!(created[0].description(), Some("Product development"));
+        assert_eq!(created[0].hourly_rate(), Some(120.5));
+        assert_eq!(created[1].name(), "Meetings");
+        assert_eq!(created[1].description(), None);
+        assert_eq!(created[1].hourly_rate(), None);
+    }
+
+    #[tokio::test]
+    async fn import_from_csv_should_be_idempotent() {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut categories_list = AccountingCategoriesList::new(repository);
+        categories_list.create("Meetings").await.unwrap();
+        let csv_data = "\
+name
+Development
+Meetings
+Development
+";
+
+        let first = categories_list
+            .import_from_csv(csv_data.as_bytes())
+            .await
+            .unwrap();
+        let second = categories_list
+            .import_from_csv(csv_data.as_bytes())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            first.iter().map(|c| c.name()).collect::<Vec<_>>(),
+            vec!["Development"]
+        );
+        assert!(second.is_empty());
+        assert_eq!(
+            categories_list
+                .categories(true)
+                .await
+                .unwrap()
+                .iter()
+                .map(|c| c.name().to_string())
+                .collect::<Vec<_>>(),
+            vec!["Meetings", "Development"]
+        );
+    }
+
+    #[tokio::test]
+    async fn import_from_csv_should_fail_on_malformed_csv_without_storing_categories() {
+        for (csv_data, expected) in [
+            (
+                "name,hourly_rate\nDevelopment,120\nMeetings,expensive\n",
+                "Could not parse row 2",
+            ),
+            (
+                "name,description\nDevelopment,Coding,extra\n",
+                "Could not parse row 1",
+            ),
+            ("description\nCoding\n", "Could not parse row 1"),
+            ("name\nDevelopment\n  \n", "Row 2 has no category name"),
+        ] {
+            let repository =
+                Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+            let mut categories_list = AccountingCategoriesList::new(repository);
+
+            let result = categories_list.import_from_csv(csv_data.as_bytes()).await;
+
+            let err = result.unwrap_err().to_string();
+            assert!(err.starts_with(expected), "{}", err);
+            assert!(categories_list.categories(true).await.unwrap().is_empty());
+        }
+    }
+
     #[tokio::test]
     async fn accounting_categories_list_should_return_empty_when_no_categories() {
         let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
         let categories_list = AccountingCategoriesList::new(repository);
 
-        assert!(categories_list.categories().await.is_empty());
+        assert!(categories_list.categories(false).await.unwrap().is_empty());
     }
 
     #[tokio::test]
@@ -181,7 +537,7 @@ mod tests {
         categories_list.create("Category 1").await.unwrap();
         categories_list.create("Category 2").await.unwrap();
 
-        let categories = categories_list.categories().await;
+        let categories = categories_list.categories(false).await.unwrap();
         assert_eq!(categories.len(), 2);
         assert_eq!(categories[0].name(), "Category 1");
         assert_eq!(categories[1].name(), "Category 2");
@@ -200,7 +556,7 @@ mod tests {
 
         categories_list.update(category).await.unwrap();
 
-        let categories = categories_list.categories().await;
+        let categories = categories_list.categories(false).await.unwrap();
         let actual_name = categories.first().map(|c| c.name()).unwrap();
         assert_eq!(actual_name, updated_name);
     }
@@ -224,6 +580,75 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn accounting_categories_list_rename_should_fail_when_other_category_has_name() {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut categories_list = AccountingCategoriesList::new(repository);
+
+        let category_a = categories_list.create("Category A").await.unwrap();
+        categories_list.create("Category B").await.unwrap();
+
+        let result = categories_list
+            .rename(category_a.id().clone(), "Category B")
+            .await;
+        assert_eq!(
+            result.unwrap_err(),
+            AccountingCategoriesListError::AccountingCategoryAlreadyExists(
+                "Category B".to_string()
+            )
+        );
+        assert_eq!(
+            categories_list.find_by_name("Category A").await.unwrap(),
+            Some(category_a)
+        );
+    }
+
+    #[tokio::test]
+    async fn accounting_categories_list_rename_should_allow_keeping_own_name() {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut categories_list = AccountingCategoriesList::new(repository);
+
+        let category_a = categories_list.create("Category A").await.unwrap();
+
+        let renamed = categories_list
+            .rename(category_a.id().clone(), "Category A")
+            .await
+            .unwrap();
+        assert_eq!(renamed, category_a);
+    }
+
+    #[tokio::test]
+    async fn accounting_categories_list_rename_should_change_name() {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut categories_list = AccountingCategoriesList::new(repository);
+
+        let category_a = categories_list.create("Category A").await.unwrap();
+
+        let renamed = categories_list
+            .rename(category_a.id().clone(), "Category C")
+            .await
+            .unwrap();
+        assert_eq!(renamed.id(), category_a.id());
+        assert_eq!(renamed.name(), "Category C");
+        assert_eq!(
+            categories_list.categories(true).await.unwrap(),
+            vec![renamed]
+        );
+    }
+
+    #[tokio::test]
+    async fn accounting_categories_list_rename_should_fail_when_category_not_found() {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut categories_list = AccountingCategoriesList::new(repository);
+
+        let id = AccountingCategoryId::new();
+        let result = categories_list.rename(id.clone(), "Category A").await;
+        assert_eq!(
+            result.unwrap_err(),
+            AccountingCategoriesListError::NotFound(id)
+        );
+    }
+
     #[tokio::test]
     async fn accounting_categories_list_delete_should_remove_existing_category() {
         let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
@@ -234,7 +659,7 @@ mod tests {
 
         categories_list.delete(category.id().clone()).await.unwrap();
 
-        assert!(categories_list.categories().await.is_empty());
+        assert!(categories_list.categories(false).await.unwrap().is_empty());
     }
 
     #[tokio::test]
@@ -251,4 +676,157 @@ mod tests {
             AccountingCategoriesListError::NotFound(non_existent_id)
         );
     }
+
+    #[tokio::test]
+    async fn accounting_categories_list_should_only_return_archived_categories_when_requested() {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut categories_list = AccountingCategoriesList::new(repository);
+
+        categories_list.create("Category 1").await.unwrap();
+        let mut archived = categories_list.create("Category 2").await.unwrap();
+        archived.set_archived(true);
+        categories_list.update(archived.clone()).await.unwrap();
+
+        let categories = categories_list.categories(false).await.unwrap();
+        assert_eq!(categories.len(), 1);
+        assert_eq!(categories[0].name(), "Category 1");
+
+        let categories = categories_list.categories(true).await.unwrap();
+        assert_eq!(categories.len(), 2);
+        assert!(categories[1].is_archived());
+
+        archived.set_archived(false);
+        categories_list.update(archived).await.unwrap();
+        assert_eq!(categories_list.categories(false).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn accounting_categories_list_create_should_fail_when_archived_category_exists() {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut categories_list = AccountingCategoriesList::new(repository);
+
+        let mut category = categories_list.create("Test Category").await.unwrap();
+        category.set_archived(true);
+        categories_list.update(category).await.unwrap();
+
+        assert!(categories_list.create("Test Category").await.is_err());
+    }
+
+    /// Creates an activities repository with one activity for each of the given categories.
+    async fn activities_repository_with(
+        category_ids: &[&AccountingCategoryId],
+    ) -> InMemoryActivitiesListRepository {
+        let mut activities_repository = InMemoryActivitiesListRepository::new();
+        for category_id in category_ids {
+            activities_repository
+                .add(Activity::new(
+                    NaiveDate::from_ymd_opt(2025, 10, 13).unwrap(),
+                    NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                    (*category_id).clone(),
+                    "Task".to_string(),
+                ))
+                .await
+                .unwrap();
+        }
+        activities_repository
+    }
+
+    #[tokio::test]
+    async fn accounting_categories_list_merge_should_reassign_activities_and_delete_category() {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut categories_list = AccountingCategoriesList::new(repository);
+
+        let meeting = categories_list.create("Meeting").await.unwrap();
+        let meetings = categories_list.create("Meetings").await.unwrap();
+        let mut activities_repository =
+            activities_repository_with(&[meeting.id(), meeting.id(), meetings.id()]).await;
+
+        let reassigned = categories_list
+            .merge(
+                meeting.id().clone(),
+                meetings.id().clone(),
+                &mut activities_repository,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(reassigned, 2);
+        assert_eq!(
+            categories_list.categories(true).await.unwrap(),
+            vec![meetings.clone()]
+        );
+        assert!(
+            activities_repository
+                .get_by_accounting_category_id(meeting.id())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            activities_repository
+                .get_by_accounting_category_id(meetings.id())
+                .await
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn accounting_categories_list_merge_should_fail_for_unknown_category() {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut categories_list = AccountingCategoriesList::new(repository);
+
+        let meeting = categories_list.create("Meeting").await.unwrap();
+        let unknown_id = AccountingCategoryId::new();
+        let mut activities_repository = activities_repository_with(&[meeting.id()]).await;
+
+        let result = categories_list
+            .merge(
+                meeting.id().clone(),
+                unknown_id.clone(),
+                &mut activities_repository,
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(AccountingCategoriesListError::NotFound(unknown_id))
+        );
+        assert_eq!(
+            categories_list.categories(true).await.unwrap(),
+            vec![meeting.clone()]
+        );
+        assert_eq!(
+            activities_repository
+                .get_by_accounting_category_id(meeting.id())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn accounting_categories_list_merge_should_fail_into_itself() {
+        let repository = Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let mut categories_list = AccountingCategoriesList::new(repository);
+
+        let meeting = categories_list.create("Meeting").await.unwrap();
+
+        let result = categories_list
+            .merge(
+                meeting.id().clone(),
+                meeting.id().clone(),
+                &mut InMemoryActivitiesListRepository::new(),
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(AccountingCategoriesListError::MergeIntoItself(
+                meeting.id().clone()
+            ))
+        );
+    }
 }
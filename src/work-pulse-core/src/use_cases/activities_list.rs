@@ -1,14 +1,18 @@
 use std::{io::Read, sync::Arc, time::Instant, usize};
 
-use chrono::{NaiveDate, NaiveTime};
+use chrono::{Duration, NaiveDate, NaiveTime};
 use thiserror::Error;
 use tokio::sync::Mutex;
 
 use crate::{
-    adapters::{ActivitiesImporter, ActivitiesImporterError, ActivitiesListRepository},
+    adapters::{
+        ActivitiesImporter, ActivitiesImporterError, ActivitiesListRepository, ImportResult,
+    },
     entities::{
         accounting::AccountingCategoryId,
-        activity::{Activity, ActivityId},
+        activity::{Activity, ActivityError, ActivityId},
+        category_summary::CategorySummary,
+        time_distribution::HourBucket,
     },
 };
 
@@ -25,6 +29,36 @@ pub enum ReplaceMode {
     ImportDateRange,
 }
 
+/// Specifies which fields two activities must share to be considered duplicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateCriteria {
+    /// The same date, start time, accounting category and task.
+    StartTimeCategoryAndTask,
+
+    /// The same date, start time, end time, accounting category and task.
+    Exact,
+}
+
+impl DuplicateCriteria {
+    /// Returns whether the two activities are duplicates according to these criteria.
+    ///
+    /// # Arguments
+    ///
+    /// - `existing`: The activity already in the list.
+    /// - `candidate`: The activity to be recorded.
+    pub fn is_duplicate(&self, existing: &Activity, candidate: &Activity) -> bool {
+        let same_start = existing.date() == candidate.date()
+            && existing.start_time() == candidate.start_time()
+            && existing.accounting_category_id() == candidate.accounting_category_id()
+            && existing.task() == candidate.task();
+
+        match self {
+            DuplicateCriteria::StartTimeCategoryAndTask => same_start,
+            DuplicateCriteria::Exact => same_start && existing.end_time() == candidate.end_time(),
+        }
+    }
+}
+
 /// Represents an error that can occur while managing the list of activities.
 #[derive(Error, Clone, Debug, Eq, PartialEq)]
 pub enum ActivitiesListError {
@@ -35,6 +69,14 @@ pub enum ActivitiesListError {
     /// A technical error occurred.
     #[error("A technical error occurred: {0}")]
     TechnicalError(String),
+
+    /// The activity is not valid.
+    #[error("The activity is not valid: {0}")]
+    InvalidActivity(#[from] ActivityError),
+
+    /// The activity duplicates the existing activity with the ID.
+    #[error("The activity duplicates the activity with the ID `{0}`.")]
+    Duplicate(ActivityId),
 }
 
 /// Represents a list of activities.
@@ -64,10 +106,16 @@ impl<R: ActivitiesListRepository> ActivitiesList<R> {
     /// - `end_time`: The time when the activity ended, if applicable.
     /// - `accounting_category_id`: The accounting category ID associated with the activity.
     /// - `task`: The task associated with the activity.
+    /// - `comment`: An optional comment for the activity.
+    /// - `created_by`: The user who created the activity, if known.
     ///
     /// # Returns
     ///
-    /// - `Activity`: The created activity.
+    /// - `Ok(Activity)`: The created activity.
+    /// - `Err(ActivitiesListError::InvalidActivity)`: If the end time is not after the start time.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activity could not be stored.
+    #[tracing::instrument(skip(self, task, comment))]
+    #[allow(clippy::too_many_arguments)]
     pub async fn record(
         &mut self,
         date: NaiveDate,
@@ -76,22 +124,425 @@ impl<R: ActivitiesListRepository> ActivitiesList<R> {
         accounting_category_id: AccountingCategoryId,
         task: String,
         comment: Option<String>,
-    ) -> Activity {
+        created_by: Option<String>,
+    ) -> Result<Activity, ActivitiesListError> {
+        tracing::debug!("Recording activity");
+        let mut repo = self.repository.lock().await;
+
+        let activity = Activity::builder()
+            .date(date)
+            .start_time(start_time)
+            .end_time(end_time)
+            .accounting_category_id(accounting_category_id)
+            .task(task)
+            .comment(comment)
+            .created_by(created_by)
+            .build()?;
+
+        repo.add(activity.clone())
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))?;
+
+        tracing::info!(activity_id = %activity.id(), "Activity recorded");
+        Ok(activity)
+    }
+
+    /// Adds an already validated activity to the list.
+    ///
+    /// # Arguments
+    ///
+    /// - `activity`: The activity to add.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Activity)`: The added activity.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activity could not be stored.
+    #[tracing::instrument(skip(self, activity), fields(activity_id = %activity.id()))]
+    pub async fn record_activity(
+        &mut self,
+        activity: Activity,
+    ) -> Result<Activity, ActivitiesListError> {
+        tracing::debug!("Recording activity");
+        let mut repo = self.repository.lock().await;
+
+        repo.add(activity.clone())
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))?;
+
+        tracing::info!(activity_id = %activity.id(), "Activity recorded");
+        Ok(activity)
+    }
+
+    /// Adds an activity to the list unless it duplicates an activity already recorded on the
+    /// same date.
+    ///
+    /// # Arguments
+    ///
+    /// - `date`: The date when the activity was performed.
+    /// - `start_time`: The time when the activity started.
+    /// - `end_time`: The time when the activity ended, if applicable.
+    /// - `accounting_category_id`: The accounting category ID associated with the activity.
+    /// - `task`: The task associated with the activity.
+    /// - `comment`: An optional comment for the activity.
+    /// - `created_by`: The user who created the activity, if known.
+    /// - `criteria`: The fields two activities must share to be considered duplicates.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Activity)`: The created activity.
+    /// - `Err(ActivitiesListError::InvalidActivity)`: If the end time is not after the start time.
+    /// - `Err(ActivitiesListError::Duplicate)`: If the activity duplicates an existing activity.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read or stored.
+    #[tracing::instrument(skip(self, task, comment))]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn record_unique(
+        &mut self,
+        date: NaiveDate,
+        start_time: NaiveTime,
+        end_time: Option<NaiveTime>,
+        accounting_category_id: AccountingCategoryId,
+        task: String,
+        comment: Option<String>,
+        created_by: Option<String>,
+        criteria: DuplicateCriteria,
+    ) -> Result<Activity, ActivitiesListError> {
+        tracing::debug!("Recording activity");
         let mut repo = self.repository.lock().await;
 
-        let mut activity = Activity::new(date, start_time, accounting_category_id, task);
-        activity.set_end_time(end_time);
-        activity.set_comment(comment);
+        let activity = Activity::builder()
+            .date(date)
+            .start_time(start_time)
+            .end_time(end_time)
+            .accounting_category_id(accounting_category_id)
+            .task(task)
+            .comment(comment)
+            .created_by(created_by)
+            .build()?;
+
+        if let Some(existing) = repo
+            .get_by_date(date)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))?
+            .into_iter()
+            .find(|existing| criteria.is_duplicate(existing, &activity))
+        {
+            return Err(ActivitiesListError::Duplicate(existing.id().clone()));
+        }
 
-        repo.add(activity.clone()).await;
+        repo.add(activity.clone())
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))?;
 
-        activity
+        tracing::info!(activity_id = %activity.id(), "Activity recorded");
+        Ok(activity)
+    }
+
+    /// Adds several already validated activities to the list at once.
+    ///
+    /// # Arguments
+    ///
+    /// - `activities`: The activities to add.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: The added activities.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be stored.
+    #[tracing::instrument(skip(self, activities), fields(count = activities.len()))]
+    pub async fn record_all(
+        &mut self,
+        activities: Vec<Activity>,
+    ) -> Result<Vec<Activity>, ActivitiesListError> {
+        tracing::debug!("Recording activities");
+        let mut repo = self.repository.lock().await;
+
+        repo.add_range(activities.clone())
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))?;
+
+        tracing::info!(count = activities.len(), "Activities recorded");
+        Ok(activities)
     }
 
     /// Returns the list of activities.
-    pub async fn activities(&self) -> Vec<Activity> {
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: All activities of the list.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn activities(&self) -> Result<Vec<Activity>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_all()
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the list of activities within an optionally bounded date range.
+    ///
+    /// # Arguments
+    ///
+    /// - `start_date`: The optional start date of the range (inclusive).
+    /// - `end_date`: The optional end date of the range (inclusive).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: The activities within the range.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn activities_in_date_range(
+        &self,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+    ) -> Result<Vec<Activity>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_by_date_range_opt(start_date, end_date)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the list of activities within a calendar month.
+    ///
+    /// # Arguments
+    ///
+    /// - `year`: The year of the month.
+    /// - `month`: The month (1-12).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: The activities within the month.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn activities_for_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<Activity>, ActivitiesListError> {
         let repo = self.repository.lock().await;
-        repo.get_all().await
+        repo.get_by_month(year, month)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the list of activities within an ISO 8601 calendar week.
+    ///
+    /// # Arguments
+    ///
+    /// - `year`: The ISO week-numbering year of the week.
+    /// - `week`: The ISO week number (1-53).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: The activities within the week.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn activities_for_week_number(
+        &self,
+        year: i32,
+        week: u32,
+    ) -> Result<Vec<Activity>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_by_week_number(year, week)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the distribution of activity start times by hour of day.
+    ///
+    /// # Arguments
+    ///
+    /// - `start_date`: The optional start date of the range (inclusive).
+    /// - `end_date`: The optional end date of the range (inclusive).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<HourBucket>)`: One bucket for each of the 24 hours of the day, ordered by hour.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn time_distribution(
+        &self,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+    ) -> Result<Vec<HourBucket>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_time_distribution(start_date, end_date)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the number and total duration of the activities within a date range per accounting category.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: The start date of the range (inclusive).
+    /// - `end`: The end date of the range (inclusive).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<CategorySummary>)`: One summary for each accounting category with activities in the range.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn category_summary(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<CategorySummary>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_category_summary(start, end)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the total duration of all activities recorded on a date.
+    ///
+    /// Activities without an end time do not contribute to the total.
+    ///
+    /// # Arguments
+    ///
+    /// - `date`: The date to sum the activity durations for.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Duration)`: The total duration of the activities on the date.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn total_duration_for_date(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Duration, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_by_date(date)
+            .await
+            .map(|activities| Self::total_duration(&activities))
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the total duration of all activities recorded within a date range.
+    ///
+    /// Activities without an end time do not contribute to the total.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: The start date of the range (inclusive).
+    /// - `end`: The end date of the range (inclusive).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Duration)`: The total duration of the activities within the range.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn total_duration_for_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Duration, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_by_date_range(start, end)
+            .await
+            .map(|activities| Self::total_duration(&activities))
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    fn total_duration(activities: &[Activity]) -> Duration {
+        activities.iter().map(|activity| activity.duration()).sum()
+    }
+
+    /// Returns the list of activities created by a specific user.
+    ///
+    /// # Arguments
+    ///
+    /// - `user_id`: The username or email of the user who created the activities.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: The activities created by the user.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn activities_by_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_by_user(user_id)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the list of activities originating from a specific import source.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: The name of the import source (e.g., "csv", "toggl", "manual").
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: The activities from the import source.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn activities_by_import_source(
+        &self,
+        source: &str,
+    ) -> Result<Vec<Activity>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_by_import_source(source)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the list of activities booked on a specific accounting category.
+    ///
+    /// # Arguments
+    ///
+    /// - `category_id`: The unique identifier of the accounting category.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: The activities of the accounting category.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn activities_by_category(
+        &self,
+        category_id: &AccountingCategoryId,
+    ) -> Result<Vec<Activity>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_by_accounting_category_id(category_id)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the list of activities carrying a specific tag.
+    ///
+    /// # Arguments
+    ///
+    /// - `tag`: The tag to filter the activities by.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: The activities with the tag.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn activities_by_tag(&self, tag: &str) -> Result<Vec<Activity>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_by_tag(tag)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the list of activities whose task contains a keyword, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// - `query`: The keyword to search for. An empty keyword matches all activities.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: The activities with a matching task.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self, query))]
+    pub async fn search(&self, query: &str) -> Result<Vec<Activity>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.search_by_task(query)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
     }
 
     /// Retrieves an activity by its ID.
@@ -102,16 +553,78 @@ impl<R: ActivitiesListRepository> ActivitiesList<R> {
     ///
     /// # Returns
     ///
-    /// - `Some(Activity)`: If the activity was found.
-    /// - `None`: If the activity with the specified ID does not exist.
-    pub async fn get_by_id(&self, activity_id: &ActivityId) -> Option<Activity> {
+    /// - `Ok(Some(Activity))`: If the activity was found.
+    /// - `Ok(None)`: If the activity with the specified ID does not exist.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activity could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn get_by_id(
+        &self,
+        activity_id: &ActivityId,
+    ) -> Result<Option<Activity>, ActivitiesListError> {
         let repo = self.repository.lock().await;
+        repo.get_by_id(activity_id)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
 
-        repo.get_all()
+    /// Returns a page of activities following the given activity, using keyset pagination.
+    ///
+    /// # Arguments
+    ///
+    /// - `after_id`: The ID of the last activity of the previous page, or `None` for the first page.
+    /// - `limit`: The maximum number of activities to return.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: At most `limit` activities.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn activities_page_after(
+        &self,
+        after_id: Option<ActivityId>,
+        limit: usize,
+    ) -> Result<Vec<Activity>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_page_after(after_id, limit)
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns a page of activities, newest first, using offset pagination.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset`: The number of activities to skip.
+    /// - `limit`: The maximum number of activities to return.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Activity>)`: At most `limit` activities.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be read.
+    #[tracing::instrument(skip(self))]
+    pub async fn activities_paginated(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<Activity>, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.get_paginated(offset, limit)
             .await
-            .iter()
-            .find(|activity| activity.id() == activity_id)
-            .cloned()
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
+    }
+
+    /// Returns the total number of activities.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(usize)`: The number of activities.
+    /// - `Err(ActivitiesListError::TechnicalError)`: If the activities could not be counted.
+    #[tracing::instrument(skip(self))]
+    pub async fn count(&self) -> Result<usize, ActivitiesListError> {
+        let repo = self.repository.lock().await;
+        repo.count()
+            .await
+            .map_err(|err| ActivitiesListError::TechnicalError(err.to_string()))
     }
 
     /// Updates an existing activity in the list.
@@ -124,13 +637,18 @@ impl<R: ActivitiesListRepository> ActivitiesList<R> {
     ///
     /// - `Ok(())`: If the activity was successfully updated.
     /// - `Err(ActivitiesListError)`: If the activity with the specified ID does not exist.
+    #[tracing::instrument(skip(self, activity), fields(activity_id = %activity.id()))]
     pub async fn update(&mut self, activity: Activity) -> Result<(), ActivitiesListError> {
+        tracing::debug!("Updating activity");
         let mut repo = self.repository.lock().await;
 
         let activity_id = activity.id().clone();
         repo.update(activity)
             .await
-            .map_err(|_| ActivitiesListError::NotFound(activity_id))
+            .map_err(|_| ActivitiesListError::NotFound(activity_id))?;
+
+        tracing::info!("Activity updated");
+        Ok(())
     }
 
     /// Deletes an activity from the list.
@@ -143,12 +661,17 @@ impl<R: ActivitiesListRepository> ActivitiesList<R> {
     ///
     /// - `Ok(())`: If the activity was successfully deleted.
     /// - `Err(ActivitiesListError)`: If the activity with the specified ID does
+    #[tracing::instrument(skip(self))]
     pub async fn delete(&mut self, activity_id: ActivityId) -> Result<(), ActivitiesListError> {
+        tracing::debug!("Deleting activity");
         let mut repo = self.repository.lock().await;
 
         repo.delete(activity_id.clone())
             .await
-            .map_err(|_| ActivitiesListError::NotFound(activity_id))
+            .map_err(|_| ActivitiesListError::NotFound(activity_id))?;
+
+        tracing::info!("Activity deleted");
+        Ok(())
     }
 
     /// Deletes all activities within a specified date range.
@@ -162,16 +685,22 @@ impl<R: ActivitiesListRepository> ActivitiesList<R> {
     /// 
     /// - `Ok(usize)`: The number of activities deleted.
     /// - `Err(ActivitiesListError)`: If a technical error occurred during deletion.
+    #[tracing::instrument(skip(self))]
     pub async fn delete_by_date_range(
         &mut self,
         start_date: NaiveDate,
         end_date: NaiveDate,
     ) -> Result<usize, ActivitiesListError> {
+        tracing::debug!("Deleting activities in date range");
         let mut repo = self.repository.lock().await;
 
-        repo.delete_by_date_range(start_date, end_date)
+        let deleted = repo
+            .delete_by_date_range(start_date, end_date)
             .await
-            .map_err(|e| ActivitiesListError::TechnicalError(e.to_string()))
+            .map_err(|e| ActivitiesListError::TechnicalError(e.to_string()))?;
+
+        tracing::info!(count = deleted, "Activities in date range deleted");
+        Ok(deleted)
     }
 
     /// Imports activities from an external source using the provided importer.
@@ -182,27 +711,36 @@ impl<R: ActivitiesListRepository> ActivitiesList<R> {
     /// - `reader`: The reader from which to import activities.
     /// - `year`: The year to associate with the imported activities.
     /// - `replace_existing`: The mode specifying how to handle existing activities during the import.
+    /// - `transform`: An optional transformation applied to each imported activity before it is inserted.
     ///
     /// # Returns
     ///
-    /// - `Ok(())`: If the import was successful.
+    /// - `Ok(ImportResult)`: The inserted activities and the rows of the source that were skipped.
     /// - `Err(ActivitiesImporterError)`: If an error occurred during the import process.
+    #[tracing::instrument(skip(self, importer, reader, transform))]
     pub async fn import<I: ActivitiesImporter, D: Read + Send>(
         &mut self,
         importer: &mut I,
         reader: D,
         year: u16,
         replace_existing: ReplaceMode,
-    ) -> Result<(), ActivitiesImporterError> {
+        transform: Option<Box<dyn Fn(Activity) -> Activity + Send>>,
+    ) -> Result<ImportResult, ActivitiesImporterError> {
+        tracing::debug!("Importing activities");
         let mut repo = self.repository.lock().await;
 
         let import_start = Instant::now();
-        let activities = importer.import(reader, year).await?;
+        let ImportResult { activities, errors } = importer.import(reader, year).await?;
+        let activities: Vec<Activity> = match &transform {
+            Some(transform) => activities.into_iter().map(transform).collect(),
+            None => activities,
+        };
         let import_duration = import_start.elapsed();
 
         tracing::info!(
             duration_ms = import_duration.as_millis(),
             count = activities.len(),
+            skipped = errors.len(),
             "Activities imported from source"
         );
 
@@ -244,8 +782,10 @@ impl<R: ActivitiesListRepository> ActivitiesList<R> {
             }
         };
 
-        for activity in activities {
-            repo.add(activity).await;
+        for activity in &activities {
+            repo.add(activity.clone())
+                .await
+                .map_err(|e| ActivitiesImporterError::RepositoryError(e.to_string()))?;
         }
 
         let db_duration = db_start.elapsed();
@@ -254,7 +794,7 @@ impl<R: ActivitiesListRepository> ActivitiesList<R> {
             "Activities saved to database"
         );
 
-        Ok(())
+        Ok(ImportResult { activities, errors })
     }
 }
 
@@ -262,12 +802,62 @@ impl<R: ActivitiesListRepository> ActivitiesList<R> {
 mod tests {
     use super::*;
     use crate::{
+        adapters::{ActivitiesListRepositoryError, RowImportError},
         entities::accounting::AccountingCategoryId,
         infra::repositories::in_memory::activities_list::InMemoryActivitiesListRepository,
     };
     use async_trait::async_trait;
     use chrono::{NaiveDate, NaiveTime};
 
+    /// A writer collecting the formatted tracing output in memory.
+    #[derive(Clone, Default)]
+    struct CapturedOutput(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedOutput {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn record_should_emit_span_events() {
+        let output = CapturedOutput::default();
+        let writer = output.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+        activities_list
+            .record(
+                NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+                NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                None,
+                AccountingCategoryId::new(),
+                "Confidential task".to_string(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("record{"), "{}", output);
+        assert!(output.contains("close"), "{}", output);
+        assert!(output.contains("Activity recorded"), "{}", output);
+        assert!(!output.contains("Confidential task"), "{}", output);
+    }
+
     #[tokio::test]
     async fn record_should_add_activity_with_end_time() {
         let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
@@ -281,10 +871,12 @@ mod tests {
                 AccountingCategoryId::new(),
                 "Test Task".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
-        let activities = activities_list.activities().await;
+        let activities = activities_list.activities().await.unwrap();
         assert_eq!(activities.len(), 1);
         assert_eq!(activities[0], activity);
     }
@@ -292,61 +884,559 @@ mod tests {
     #[tokio::test]
     async fn record_should_add_activity_without_end_time() {
         let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
-        let mut activities_list = ActivitiesList::new(repository);
+        let mut activities_list = ActivitiesList::new(repository);
+
+        let activity = activities_list
+            .record(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                None,
+                AccountingCategoryId::new(),
+                "Test Task".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+
+        let activities = activities_list.activities().await.unwrap();
+        assert_eq!(activities.len(), 1);
+        assert_eq!(activities[0], activity);
+    }
+
+    async fn record_unique(
+        activities_list: &mut ActivitiesList<InMemoryActivitiesListRepository>,
+        end_hour: u32,
+        accounting_category_id: &AccountingCategoryId,
+        task: &str,
+        criteria: DuplicateCriteria,
+    ) -> Result<Activity, ActivitiesListError> {
+        activities_list
+            .record_unique(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                Some(NaiveTime::from_hms_opt(end_hour, 0, 0).expect("Valid activity end time")),
+                accounting_category_id.clone(),
+                task.to_string(),
+                None,
+                None,
+                criteria,
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn record_activity_should_add_activity_with_tags() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        let mut activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+            NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+            AccountingCategoryId::new(),
+            "Test Task".to_string(),
+        );
+        activity.set_tags(vec!["sprint-42".to_string()]);
+
+        let recorded = activities_list
+            .record_activity(activity.clone())
+            .await
+            .expect("Activity stored");
+
+        assert_eq!(recorded, activity);
+        assert_eq!(activities_list.activities().await.unwrap(), vec![activity]);
+    }
+
+    #[tokio::test]
+    async fn record_all_should_add_all_activities() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        let activities: Vec<Activity> = (1..=3)
+            .map(|day| {
+                Activity::new(
+                    NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                    AccountingCategoryId::new(),
+                    format!("Task {}", day),
+                )
+            })
+            .collect();
+
+        let recorded = activities_list
+            .record_all(activities.clone())
+            .await
+            .expect("Activities stored");
+
+        assert_eq!(recorded, activities);
+        assert_eq!(activities_list.activities().await.unwrap(), activities);
+    }
+
+    #[tokio::test]
+    async fn record_unique_should_reject_duplicate() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+        let category_id = AccountingCategoryId::new();
+        let criteria = DuplicateCriteria::StartTimeCategoryAndTask;
+
+        let existing = record_unique(&mut activities_list, 10, &category_id, "Task", criteria)
+            .await
+            .expect("Valid activity");
+
+        let result = record_unique(&mut activities_list, 11, &category_id, "Task", criteria).await;
+
+        assert_eq!(
+            result,
+            Err(ActivitiesListError::Duplicate(existing.id().clone()))
+        );
+        assert_eq!(activities_list.count().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn record_unique_should_add_activity_that_is_no_duplicate() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+        let category_id = AccountingCategoryId::new();
+        let criteria = DuplicateCriteria::StartTimeCategoryAndTask;
+
+        record_unique(&mut activities_list, 10, &category_id, "Task", criteria)
+            .await
+            .expect("Valid activity");
+        record_unique(
+            &mut activities_list,
+            10,
+            &category_id,
+            "Other Task",
+            criteria,
+        )
+        .await
+        .expect("Different task");
+        record_unique(
+            &mut activities_list,
+            10,
+            &AccountingCategoryId::new(),
+            "Task",
+            criteria,
+        )
+        .await
+        .expect("Different accounting category");
+
+        assert_eq!(activities_list.count().await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn record_unique_should_compare_end_time_for_exact_criteria() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+        let category_id = AccountingCategoryId::new();
+        let criteria = DuplicateCriteria::Exact;
+
+        record_unique(&mut activities_list, 10, &category_id, "Task", criteria)
+            .await
+            .expect("Valid activity");
+        record_unique(&mut activities_list, 11, &category_id, "Task", criteria)
+            .await
+            .expect("Different end time");
+
+        let result = record_unique(&mut activities_list, 11, &category_id, "Task", criteria).await;
+
+        assert!(matches!(result, Err(ActivitiesListError::Duplicate(_))));
+        assert_eq!(activities_list.count().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn activities_should_return_empty_when_no_activities() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let activities_list = ActivitiesList::new(repository);
+
+        assert!(activities_list.activities().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn activities_should_return_all_activities() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        activities_list
+            .record(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                Some(NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity end time")),
+                AccountingCategoryId::new(),
+                "Task 1".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+
+        activities_list
+            .record(
+                NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(11, 0, 0).expect("Valid activity start time"),
+                None,
+                AccountingCategoryId::new(),
+                "Task 2".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+
+        let activities = activities_list.activities().await.unwrap();
+        assert_eq!(activities.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn activities_in_date_range_should_support_open_bounds() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        for (day, task) in [(1, "Task 1"), (15, "Task 2"), (31, "Task 3")] {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                    None,
+                    AccountingCategoryId::new(),
+                    task.to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        let mid_month = NaiveDate::from_ymd_opt(2023, 10, 15);
+
+        let from = activities_list
+            .activities_in_date_range(mid_month, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            from.iter().map(|a| a.task()).collect::<Vec<_>>(),
+            vec!["Task 2", "Task 3"]
+        );
+
+        let until = activities_list
+            .activities_in_date_range(None, mid_month)
+            .await
+            .unwrap();
+        assert_eq!(
+            until.iter().map(|a| a.task()).collect::<Vec<_>>(),
+            vec!["Task 1", "Task 2"]
+        );
+
+        let all = activities_list
+            .activities_in_date_range(None, None)
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn activities_for_month_should_not_cross_year_boundary() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        for (date, task) in [
+            (NaiveDate::from_ymd_opt(2023, 12, 1), "Task 1"),
+            (NaiveDate::from_ymd_opt(2023, 12, 31), "Task 2"),
+            (NaiveDate::from_ymd_opt(2024, 1, 1), "Task 3"),
+            (NaiveDate::from_ymd_opt(2024, 12, 1), "Task 4"),
+        ] {
+            activities_list
+                .record(
+                    date.expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                    None,
+                    AccountingCategoryId::new(),
+                    task.to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        let december = activities_list
+            .activities_for_month(2023, 12)
+            .await
+            .unwrap();
+        assert_eq!(
+            december.iter().map(|a| a.task()).collect::<Vec<_>>(),
+            vec!["Task 1", "Task 2"]
+        );
+
+        let january = activities_list.activities_for_month(2024, 1).await.unwrap();
+        assert_eq!(
+            january.iter().map(|a| a.task()).collect::<Vec<_>>(),
+            vec!["Task 3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn time_distribution_should_only_count_activities_in_date_range() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        for (day, hour) in [(1, 9), (2, 9), (3, 14)] {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(hour, 0, 0).expect("Valid activity start time"),
+                    Some(NaiveTime::from_hms_opt(hour + 1, 0, 0).expect("Valid activity end time")),
+                    AccountingCategoryId::new(),
+                    "Task".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        let distribution = activities_list
+            .time_distribution(NaiveDate::from_ymd_opt(2023, 10, 2), None)
+            .await
+            .unwrap();
+
+        assert_eq!(distribution.len(), 24);
+        assert_eq!(distribution[9], HourBucket::new(9, 1, 3600));
+        assert_eq!(distribution[14], HourBucket::new(14, 1, 3600));
+    }
+
+    #[tokio::test]
+    async fn category_summary_should_only_include_activities_in_date_range() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+        let category_id = AccountingCategoryId::new();
+
+        for (day, hours) in [(1, 1), (2, 2), (3, 3)] {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                    Some(
+                        NaiveTime::from_hms_opt(9 + hours, 0, 0).expect("Valid activity end time"),
+                    ),
+                    category_id.clone(),
+                    "Task".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        let summary = activities_list
+            .category_summary(
+                NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 10, 3).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            summary,
+            vec![CategorySummary::new(category_id, 2, Duration::hours(5))]
+        );
+    }
+
+    #[tokio::test]
+    async fn total_duration_for_date_should_be_zero_without_activities() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let activities_list = ActivitiesList::new(repository);
+
+        let total = activities_list
+            .total_duration_for_date(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(total, Duration::zero());
+    }
+
+    #[tokio::test]
+    async fn total_duration_for_date_should_sum_activities_of_date() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        for (day, start, end) in [
+            (15, (9, 0), (12, 0)),
+            (15, (13, 0), (15, 30)),
+            (16, (9, 0), (17, 0)),
+        ] {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2025, 1, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(start.0, start.1, 0)
+                        .expect("Valid activity start time"),
+                    Some(
+                        NaiveTime::from_hms_opt(end.0, end.1, 0).expect("Valid activity end time"),
+                    ),
+                    AccountingCategoryId::new(),
+                    "Task".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        let total = activities_list
+            .total_duration_for_date(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(total, Duration::minutes(5 * 60 + 30));
+    }
+
+    #[tokio::test]
+    async fn total_duration_should_ignore_activities_without_end_time() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        for (day, end) in [(15, Some(11)), (15, None), (16, Some(10))] {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2025, 1, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                    end.map(|hour| NaiveTime::from_hms_opt(hour, 0, 0).expect("Valid end time")),
+                    AccountingCategoryId::new(),
+                    "Task".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        let day = activities_list
+            .total_duration_for_date(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(day, Duration::hours(2));
+
+        let range = activities_list
+            .total_duration_for_range(
+                NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 1, 16).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(range, Duration::hours(3));
+    }
+
+    #[tokio::test]
+    async fn activities_by_user_should_return_only_activities_of_user() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        let activity = activities_list
+            .record(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                None,
+                AccountingCategoryId::new(),
+                "Task of jdoe".to_string(),
+                None,
+                Some("jdoe".to_string()),
+            )
+            .await
+            .expect("Valid activity");
+
+        activities_list
+            .record(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity start time"),
+                None,
+                AccountingCategoryId::new(),
+                "Anonymous Task".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+
+        let activities = activities_list.activities_by_user("jdoe").await.unwrap();
+        assert_eq!(activities, vec![activity]);
+    }
+
+    #[tokio::test]
+    async fn activities_by_import_source_should_return_only_activities_of_source() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
 
-        let activity = activities_list
+        let mut imported_activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+            NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+            AccountingCategoryId::new(),
+            "Imported Task".to_string(),
+        );
+        imported_activity.set_import_source(Some("csv".to_string()));
+        repository
+            .lock()
+            .await
+            .add(imported_activity.clone())
+            .await
+            .unwrap();
+
+        activities_list
             .record(
                 NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
-                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity start time"),
                 None,
                 AccountingCategoryId::new(),
-                "Test Task".to_string(),
+                "Manual Task".to_string(),
+                None,
                 None,
             )
-            .await;
-
-        let activities = activities_list.activities().await;
-        assert_eq!(activities.len(), 1);
-        assert_eq!(activities[0], activity);
-    }
-
-    #[tokio::test]
-    async fn activities_should_return_empty_when_no_activities() {
-        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
-        let activities_list = ActivitiesList::new(repository);
+            .await
+            .expect("Valid activity");
 
-        assert!(activities_list.activities().await.is_empty());
+        let activities = activities_list
+            .activities_by_import_source("csv")
+            .await
+            .unwrap();
+        assert_eq!(activities, vec![imported_activity]);
     }
 
     #[tokio::test]
-    async fn activities_should_return_all_activities() {
+    async fn activities_by_category_should_return_only_activities_of_category() {
         let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
         let mut activities_list = ActivitiesList::new(repository);
+        let category_id = AccountingCategoryId::new();
 
-        activities_list
+        let activity = activities_list
             .record(
                 NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
                 NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
-                Some(NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity end time")),
-                AccountingCategoryId::new(),
-                "Task 1".to_string(),
+                None,
+                category_id.clone(),
+                "Task in category".to_string(),
+                None,
                 None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         activities_list
             .record(
-                NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date"),
-                NaiveTime::from_hms_opt(11, 0, 0).expect("Valid activity start time"),
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity start time"),
                 None,
                 AccountingCategoryId::new(),
-                "Task 2".to_string(),
+                "Task in other category".to_string(),
+                None,
                 None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
-        let activities = activities_list.activities().await;
-        assert_eq!(activities.len(), 2);
+        let activities = activities_list
+            .activities_by_category(&category_id)
+            .await
+            .unwrap();
+        assert_eq!(activities, vec![activity]);
     }
 
     #[tokio::test]
@@ -362,10 +1452,16 @@ mod tests {
                 AccountingCategoryId::new(),
                 "Test Task".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
-        let retrieved_activity = activities_list.get_by_id(activity.id()).await.unwrap();
+        let retrieved_activity = activities_list
+            .get_by_id(activity.id())
+            .await
+            .unwrap()
+            .unwrap();
         assert_eq!(retrieved_activity, activity);
     }
 
@@ -375,11 +1471,71 @@ mod tests {
         let activities_list = ActivitiesList::new(repository);
 
         let non_existent_id = ActivityId::new();
-        let result = activities_list.get_by_id(&non_existent_id).await;
+        let result = activities_list.get_by_id(&non_existent_id).await.unwrap();
 
         assert!(result.is_none());
     }
 
+    #[tokio::test]
+    async fn activities_list_activities_page_after_should_return_following_activities() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        let mut recorded = Vec::new();
+        for hour in 9..12 {
+            let activity = activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(hour, 0, 0).expect("Valid activity start time"),
+                    None,
+                    AccountingCategoryId::new(),
+                    "Test Task".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+            recorded.push(activity);
+        }
+
+        let first_page = activities_list
+            .activities_page_after(None, 2)
+            .await
+            .unwrap();
+        assert_eq!(first_page, recorded[..2]);
+
+        let second_page = activities_list
+            .activities_page_after(Some(recorded[1].id().clone()), 2)
+            .await
+            .unwrap();
+        assert_eq!(second_page, recorded[2..]);
+    }
+
+    #[tokio::test]
+    async fn activities_list_count_should_return_number_of_activities() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        assert_eq!(activities_list.count().await.unwrap(), 0);
+
+        for hour in 9..12 {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(hour, 0, 0).expect("Valid activity start time"),
+                    None,
+                    AccountingCategoryId::new(),
+                    "Test Task".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        assert_eq!(activities_list.count().await.unwrap(), 3);
+    }
+
     #[tokio::test]
     async fn activities_list_update_should_modify_existing_activity() {
         let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
@@ -393,13 +1549,19 @@ mod tests {
                 AccountingCategoryId::new(),
                 "Test Task".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         activity.set_task("Updated Task".to_string());
         activities_list.update(activity.clone()).await.unwrap();
 
-        let updated_activity = activities_list.get_by_id(activity.id()).await.unwrap();
+        let updated_activity = activities_list
+            .get_by_id(activity.id())
+            .await
+            .unwrap()
+            .unwrap();
         assert_eq!(updated_activity.task(), "Updated Task");
     }
 
@@ -438,12 +1600,14 @@ mod tests {
                 AccountingCategoryId::new(),
                 "Test Task".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         activities_list.delete(activity.id().clone()).await.unwrap();
 
-        assert!(activities_list.activities().await.is_empty());
+        assert!(activities_list.activities().await.unwrap().is_empty());
     }
 
     #[tokio::test]
@@ -475,8 +1639,10 @@ mod tests {
                 AccountingCategoryId::new(),
                 "Before Range".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         activities_list
             .record(
@@ -486,8 +1652,10 @@ mod tests {
                 AccountingCategoryId::new(),
                 "In Range 1".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         activities_list
             .record(
@@ -497,8 +1665,10 @@ mod tests {
                 AccountingCategoryId::new(),
                 "In Range 2".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         activities_list
             .record(
@@ -508,8 +1678,10 @@ mod tests {
                 AccountingCategoryId::new(),
                 "After Range".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         // Delete activities in October 2023
         let deleted_count = activities_list
@@ -522,7 +1694,7 @@ mod tests {
 
         assert_eq!(deleted_count, 2);
 
-        let remaining_activities = activities_list.activities().await;
+        let remaining_activities = activities_list.activities().await.unwrap();
         assert_eq!(remaining_activities.len(), 2);
         assert_eq!(remaining_activities[0].task(), "Before Range");
         assert_eq!(remaining_activities[1].task(), "After Range");
@@ -538,7 +1710,7 @@ mod tests {
                 &mut self,
                 _reader: R,
                 year: u16,
-            ) -> Result<Vec<Activity>, ActivitiesImporterError> {
+            ) -> Result<ImportResult, ActivitiesImporterError> {
                 let activity1 = Activity::with_id(
                     ActivityId::new(),
                     NaiveDate::from_ymd_opt(year as i32, 10, 1).expect("Valid activity date"),
@@ -555,7 +1727,10 @@ mod tests {
                     "Imported Task 2".to_string(),
                 );
 
-                Ok(vec![activity1, activity2])
+                Ok(ImportResult {
+                    activities: vec![activity1, activity2],
+                    errors: Vec::new(),
+                })
             }
         }
 
@@ -565,16 +1740,118 @@ mod tests {
         let mut importer = MockImporter;
         let data = b"mock data";
         activities_list
-            .import(&mut importer, &data[..], 2023, ReplaceMode::All)
+            .import(&mut importer, &data[..], 2023, ReplaceMode::All, None)
             .await
             .unwrap();
 
-        let activities = activities_list.activities().await;
+        let activities = activities_list.activities().await.unwrap();
         assert_eq!(activities.len(), 2);
         assert_eq!(activities[0].task(), "Imported Task 1");
         assert_eq!(activities[1].task(), "Imported Task 2");
     }
 
+    #[tokio::test]
+    async fn activities_list_import_should_return_skipped_rows() {
+        struct MockImporter;
+
+        #[async_trait]
+        impl ActivitiesImporter for MockImporter {
+            async fn import<R: Read + Send>(
+                &mut self,
+                _reader: R,
+                year: u16,
+            ) -> Result<ImportResult, ActivitiesImporterError> {
+                Ok(ImportResult {
+                    activities: vec![Activity::new(
+                        NaiveDate::from_ymd_opt(year as i32, 10, 1).expect("Valid activity date"),
+                        NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                        AccountingCategoryId::new(),
+                        "Imported Task".to_string(),
+                    )],
+                    errors: vec![RowImportError {
+                        row_number: 2,
+                        field: "Date".to_string(),
+                        reason: "Invalid date".to_string(),
+                    }],
+                })
+            }
+        }
+
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        let mut importer = MockImporter;
+        let data = b"mock data";
+        let result = activities_list
+            .import(&mut importer, &data[..], 2023, ReplaceMode::None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.activities.len(), 1);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].row_number, 2);
+        assert_eq!(activities_list.count().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn activities_list_import_should_apply_transform() {
+        struct MockImporter;
+
+        #[async_trait]
+        impl ActivitiesImporter for MockImporter {
+            async fn import<R: Read + Send>(
+                &mut self,
+                _reader: R,
+                year: u16,
+            ) -> Result<ImportResult, ActivitiesImporterError> {
+                let activity1 = Activity::with_id(
+                    ActivityId::new(),
+                    NaiveDate::from_ymd_opt(year as i32, 10, 1).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                    AccountingCategoryId::new(),
+                    "Imported Task 1".to_string(),
+                );
+
+                let activity2 = Activity::with_id(
+                    ActivityId::new(),
+                    NaiveDate::from_ymd_opt(year as i32, 10, 2).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity start time"),
+                    AccountingCategoryId::new(),
+                    "Imported Task 2".to_string(),
+                );
+
+                Ok(ImportResult {
+                    activities: vec![activity1, activity2],
+                    errors: Vec::new(),
+                })
+            }
+        }
+
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        let mut importer = MockImporter;
+        let data = b"mock data";
+        activities_list
+            .import(
+                &mut importer,
+                &data[..],
+                2023,
+                ReplaceMode::All,
+                Some(Box::new(|mut activity: Activity| {
+                    activity.set_task(activity.task().to_uppercase());
+                    activity
+                })),
+            )
+            .await
+            .unwrap();
+
+        let activities = activities_list.activities().await.unwrap();
+        assert_eq!(activities.len(), 2);
+        assert_eq!(activities[0].task(), "IMPORTED TASK 1");
+        assert_eq!(activities[1].task(), "IMPORTED TASK 2");
+    }
+
     #[tokio::test]
     async fn activities_list_import_should_delete_activities_before_import() {
         struct MockImporter;
@@ -585,7 +1862,7 @@ mod tests {
                 &mut self,
                 _reader: R,
                 year: u16,
-            ) -> Result<Vec<Activity>, ActivitiesImporterError> {
+            ) -> Result<ImportResult, ActivitiesImporterError> {
                 let activity = Activity::with_id(
                     ActivityId::new(),
                     NaiveDate::from_ymd_opt(year as i32, 10, 1).expect("Valid activity date"),
@@ -594,7 +1871,10 @@ mod tests {
                     "Imported Task".to_string(),
                 );
 
-                Ok(vec![activity])
+                Ok(ImportResult {
+                    activities: vec![activity],
+                    errors: Vec::new(),
+                })
             }
         }
 
@@ -610,17 +1890,19 @@ mod tests {
                 AccountingCategoryId::new(),
                 "Initial Task".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         let mut importer = MockImporter;
         let data = b"mock data";
         activities_list
-            .import(&mut importer, &data[..], 2023, ReplaceMode::All)
+            .import(&mut importer, &data[..], 2023, ReplaceMode::All, None)
             .await
             .unwrap();
 
-        let activities = activities_list.activities().await;
+        let activities = activities_list.activities().await.unwrap();
         assert_eq!(activities.len(), 1);
         assert_eq!(activities[0].task(), "Imported Task");
     }
@@ -635,7 +1917,7 @@ mod tests {
                 &mut self,
                 _reader: R,
                 year: u16,
-            ) -> Result<Vec<Activity>, ActivitiesImporterError> {
+            ) -> Result<ImportResult, ActivitiesImporterError> {
                 let activity1 = Activity::with_id(
                     ActivityId::new(),
                     NaiveDate::from_ymd_opt(year as i32, 10, 1).expect("Valid activity date"),
@@ -652,7 +1934,10 @@ mod tests {
                     "October Import 2".to_string(),
                 );
 
-                Ok(vec![activity1, activity2])
+                Ok(ImportResult {
+                    activities: vec![activity1, activity2],
+                    errors: Vec::new(),
+                })
             }
         }
 
@@ -668,8 +1953,10 @@ mod tests {
                 AccountingCategoryId::new(),
                 "September Task".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         activities_list
             .record(
@@ -679,8 +1966,10 @@ mod tests {
                 AccountingCategoryId::new(),
                 "Old October Task".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         // Import with date range replacement (delete only October activities)
         let mut importer = MockImporter;
@@ -691,11 +1980,12 @@ mod tests {
                 &data[..],
                 2023,
                 ReplaceMode::ImportDateRange,
+                None,
             )
             .await
             .unwrap();
 
-        let activities = activities_list.activities().await;
+        let activities = activities_list.activities().await.unwrap();
         assert_eq!(activities.len(), 3); // September + 2 new October imports
         
         // Check that September task is still there
@@ -708,4 +1998,260 @@ mod tests {
         // Check that old October task is gone
         assert!(!activities.iter().any(|a| a.task() == "Old October Task"));
     }    
+
+    /// A repository whose fallible operations always fail, as if the database was unreachable.
+    struct FailingActivitiesListRepository;
+
+    fn database_error() -> ActivitiesListRepositoryError {
+        ActivitiesListRepositoryError::DatabaseError("connection refused".to_string())
+    }
+
+    #[async_trait]
+    impl ActivitiesListRepository for FailingActivitiesListRepository {
+        async fn get_all(&self) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_by_date(
+            &self,
+            _date: NaiveDate,
+        ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_by_date_range(
+            &self,
+            _start: NaiveDate,
+            _end: NaiveDate,
+        ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_by_month(
+            &self,
+            _year: i32,
+            _month: u32,
+        ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_by_week_number(
+            &self,
+            _year: i32,
+            _week: u32,
+        ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_by_user(
+            &self,
+            _user_id: &str,
+        ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_by_import_source(
+            &self,
+            _source: &str,
+        ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_by_accounting_category_id(
+            &self,
+            _category_id: &AccountingCategoryId,
+        ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_by_tag(
+            &self,
+            _tag: &str,
+        ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn search_by_task(
+            &self,
+            _query: &str,
+        ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_by_id(
+            &self,
+            _id: &ActivityId,
+        ) -> Result<Option<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_current_in_progress(
+            &self,
+            _source: &str,
+        ) -> Result<Option<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_page_after(
+            &self,
+            _after_id: Option<ActivityId>,
+            _limit: usize,
+        ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn get_paginated(
+            &self,
+            _offset: usize,
+            _limit: usize,
+        ) -> Result<Vec<Activity>, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn count(&self) -> Result<usize, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn count_by_date_range(
+            &self,
+            _start: NaiveDate,
+            _end: NaiveDate,
+        ) -> Result<usize, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn add(&mut self, _activity: Activity) -> Result<(), ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn update(
+            &mut self,
+            _activity: Activity,
+        ) -> Result<(), ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn delete(&mut self, _id: ActivityId) -> Result<(), ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn delete_all(&mut self) -> Result<(), ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+
+        async fn delete_by_date_range(
+            &mut self,
+            _start: NaiveDate,
+            _end: NaiveDate,
+        ) -> Result<usize, ActivitiesListRepositoryError> {
+            Err(database_error())
+        }
+    }
+
+    #[tokio::test]
+    async fn record_should_fail_with_technical_error_if_repository_fails() {
+        let repository = Arc::new(Mutex::new(FailingActivitiesListRepository));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        let result = activities_list
+            .record(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                None,
+                AccountingCategoryId::new(),
+                "Test Task".to_string(),
+                None,
+                None,
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(ActivitiesListError::TechnicalError(
+                database_error().to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn record_unique_should_fail_with_technical_error_if_repository_fails() {
+        let repository = Arc::new(Mutex::new(FailingActivitiesListRepository));
+        let mut activities_list = ActivitiesList::new(repository);
+
+        let result = activities_list
+            .record_unique(
+                NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                None,
+                AccountingCategoryId::new(),
+                "Test Task".to_string(),
+                None,
+                None,
+                DuplicateCriteria::Exact,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn reading_activities_should_fail_with_technical_error_if_repository_fails() {
+        let repository = Arc::new(Mutex::new(FailingActivitiesListRepository));
+        let activities_list = ActivitiesList::new(repository);
+        let date = NaiveDate::from_ymd_opt(2023, 10, 1);
+
+        assert!(matches!(
+            activities_list.activities().await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+        assert!(matches!(
+            activities_list.activities_in_date_range(date, None).await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+        assert!(matches!(
+            activities_list.activities_in_date_range(None, date).await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+        assert!(matches!(
+            activities_list.time_distribution(None, None).await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+        assert!(matches!(
+            activities_list
+                .category_summary(date.unwrap(), date.unwrap())
+                .await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+        assert!(matches!(
+            activities_list.activities_for_month(2023, 10).await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+        assert!(matches!(
+            activities_list.activities_by_tag("work").await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+        assert!(matches!(
+            activities_list.search("Test").await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+        assert!(matches!(
+            activities_list.get_by_id(&ActivityId::new()).await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+        assert!(matches!(
+            activities_list.count().await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+        assert!(matches!(
+            activities_list.activities_page_after(None, 10).await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+        assert!(matches!(
+            activities_list.activities_paginated(0, 10).await,
+            Err(ActivitiesListError::TechnicalError(_))
+        ));
+    }
 }
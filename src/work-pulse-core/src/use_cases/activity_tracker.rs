@@ -0,0 +1,578 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike};
+use thiserror::Error;
+use tokio::{sync::Mutex, time::Instant};
+
+use crate::{
+    adapters::ActivitiesListRepository,
+    entities::{
+        accounting::AccountingCategoryId,
+        activity::{Activity, ActivityError},
+    },
+};
+
+/// Represents an error that can occur while tracking an activity.
+#[derive(Error, Clone, Debug, Eq, PartialEq)]
+pub enum TrackerError {
+    /// An activity is already being tracked.
+    #[error("An activity is already being tracked.")]
+    AlreadyRunning,
+
+    /// No activity is being tracked.
+    #[error("No activity is being tracked.")]
+    NotRunning,
+
+    /// The tracked activity is already paused.
+    #[error("The tracked activity is already paused.")]
+    AlreadyPaused,
+
+    /// The tracked activity is not paused.
+    #[error("The tracked activity is not paused.")]
+    NotPaused,
+
+    /// The tracked activity could not be completed.
+    #[error("The tracked activity is not valid: {0}")]
+    InvalidActivity(#[from] ActivityError),
+
+    /// A technical error occurred.
+    #[error("A technical error occurred: {0}")]
+    TechnicalError(String),
+}
+
+/// The import source of the activities started by the `ActivityTracker`.
+pub const TRACKER_IMPORT_SOURCE: &str = "tracker";
+
+/// Tracks the time spent on a single activity, which can be paused and resumed.
+///
+/// When the tracking is started, the activity is added to the list of activities without an end
+/// time and with the `TRACKER_IMPORT_SOURCE`, so that the tracking survives a restart of the
+/// process. When the tracking is stopped, the activity is completed with the tracked time,
+/// excluding pauses.
+pub struct ActivityTracker<R> {
+    /// The repository holding the list of activities.
+    repository: Arc<Mutex<R>>,
+
+    /// The tracked activity and the instant its tracking was started or last resumed.
+    current: Option<(Activity, Instant)>,
+
+    /// The time tracked before the tracking was last paused.
+    tracked_before_pause: Duration,
+
+    /// Whether the tracking is paused.
+    paused: bool,
+}
+
+impl<R: ActivitiesListRepository> ActivityTracker<R> {
+    /// Creates a new `ActivityTracker`, which continues tracking the activity in progress it
+    /// started before, if any. Activities without an end time that were not started by the
+    /// tracker are left alone.
+    ///
+    /// A restored activity is tracked from its start time up to now. Pauses taken before the
+    /// restart are not known and therefore included in the tracked time.
+    ///
+    /// # Arguments
+    ///
+    /// - `repository`: The repository the tracked activities are stored in.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(ActivityTracker)`: The new tracker.
+    /// - `Err(TrackerError::TechnicalError)`: If the activity in progress could not be read.
+    pub async fn new(repository: Arc<Mutex<R>>) -> Result<Self, TrackerError> {
+        Self::restored_at(repository, Local::now().naive_local()).await
+    }
+
+    /// Creates a new `ActivityTracker`, which continues tracking the activity in progress in the
+    /// repository as if it was tracked without pauses until `now`.
+    async fn restored_at(
+        repository: Arc<Mutex<R>>,
+        now: NaiveDateTime,
+    ) -> Result<Self, TrackerError> {
+        let in_progress = repository
+            .lock()
+            .await
+            .get_current_in_progress(TRACKER_IMPORT_SOURCE)
+            .await
+            .map_err(|err| TrackerError::TechnicalError(err.to_string()))?;
+
+        let tracked_before_pause = in_progress
+            .as_ref()
+            .and_then(|activity| {
+                (now - activity.date().and_time(*activity.start_time()))
+                    .to_std()
+                    .ok()
+            })
+            .unwrap_or(Duration::ZERO);
+
+        Ok(Self {
+            repository,
+            current: in_progress.map(|activity| (activity, Instant::now())),
+            tracked_before_pause,
+            paused: false,
+        })
+    }
+
+    /// Starts tracking a new activity at the current local time.
+    ///
+    /// # Arguments
+    ///
+    /// - `date`: The date when the activity is performed.
+    /// - `accounting_category_id`: The accounting category ID associated with the activity.
+    /// - `task`: The task associated with the activity.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: If the tracking was started.
+    /// - `Err(TrackerError::AlreadyRunning)`: If an activity is already being tracked.
+    /// - `Err(TrackerError::TechnicalError)`: If the activity could not be stored.
+    pub async fn start(
+        &mut self,
+        date: NaiveDate,
+        accounting_category_id: AccountingCategoryId,
+        task: String,
+    ) -> Result<(), TrackerError> {
+        let start_time = Local::now().time().with_nanosecond(0).unwrap_or_default();
+        self.start_at(date, start_time, accounting_category_id, task)
+            .await
+    }
+
+    /// Starts tracking a new activity with the given start time.
+    ///
+    /// # Arguments
+    ///
+    /// - `date`: The date when the activity is performed.
+    /// - `start_time`: The time when the activity started.
+    /// - `accounting_category_id`: The accounting category ID associated with the activity.
+    /// - `task`: The task associated with the activity.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: If the tracking was started.
+    /// - `Err(TrackerError::AlreadyRunning)`: If an activity is already being tracked.
+    /// - `Err(TrackerError::TechnicalError)`: If the activity could not be stored.
+    pub async fn start_at(
+        &mut self,
+        date: NaiveDate,
+        start_time: NaiveTime,
+        accounting_category_id: AccountingCategoryId,
+        task: String,
+    ) -> Result<(), TrackerError> {
+        if self.current.is_some() {
+            return Err(TrackerError::AlreadyRunning);
+        }
+
+        let mut activity = Activity::new(date, start_time, accounting_category_id, task);
+        activity.set_import_source(Some(TRACKER_IMPORT_SOURCE.to_string()));
+        let mut repo = self.repository.lock().await;
+        repo.add(activity.clone())
+            .await
+            .map_err(|err| TrackerError::TechnicalError(err.to_string()))?;
+
+        self.current = Some((activity, Instant::now()));
+        self.tracked_before_pause = Duration::ZERO;
+        self.paused = false;
+
+        Ok(())
+    }
+
+    /// Stops tracking the activity and completes it in the list of activities. The end time of
+    /// the activity is its start time plus the tracked time, excluding pauses.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Activity)`: The completed activity.
+    /// - `Err(TrackerError::NotRunning)`: If no activity is being tracked.
+    /// - `Err(TrackerError::InvalidActivity)`: If less than a second was tracked or the activity
+    ///   would end after midnight. The tracking continues and the stored activity is left
+    ///   untouched.
+    /// - `Err(TrackerError::TechnicalError)`: If the activity could not be stored. The tracking
+    ///   continues.
+    pub async fn stop(&mut self) -> Result<Activity, TrackerError> {
+        let elapsed = self.elapsed().ok_or(TrackerError::NotRunning)?;
+        let mut activity = self.current().cloned().ok_or(TrackerError::NotRunning)?;
+
+        Self::complete(&mut activity, elapsed)?;
+        self.repository
+            .lock()
+            .await
+            .update(activity.clone())
+            .await
+            .map_err(|err| TrackerError::TechnicalError(err.to_string()))?;
+
+        self.current = None;
+
+        Ok(activity)
+    }
+
+    /// Sets the end time of the activity to its start time plus the tracked time.
+    fn complete(activity: &mut Activity, elapsed: Duration) -> Result<(), TrackerError> {
+        let tracked = TimeDelta::seconds(elapsed.as_secs() as i64);
+        let (end_time, wrapped_seconds) = activity.start_time().overflowing_add_signed(tracked);
+        if wrapped_seconds != 0 {
+            return Err(ActivityError::EndTimeBeforeStartTime {
+                start: *activity.start_time(),
+                end: end_time,
+            }
+            .into());
+        }
+        activity.set_end_time(Some(end_time))?;
+
+        Ok(())
+    }
+
+    /// Pauses the tracking of the activity.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: If the tracking was paused.
+    /// - `Err(TrackerError::NotRunning)`: If no activity is being tracked.
+    /// - `Err(TrackerError::AlreadyPaused)`: If the tracking is already paused.
+    pub fn pause(&mut self) -> Result<(), TrackerError> {
+        let (_, resumed_at) = self.current.as_ref().ok_or(TrackerError::NotRunning)?;
+        if self.paused {
+            return Err(TrackerError::AlreadyPaused);
+        }
+
+        self.tracked_before_pause += resumed_at.elapsed();
+        self.paused = true;
+
+        Ok(())
+    }
+
+    /// Resumes the paused tracking of the activity.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`: If the tracking was resumed.
+    /// - `Err(TrackerError::NotRunning)`: If no activity is being tracked.
+    /// - `Err(TrackerError::NotPaused)`: If the tracking is not paused.
+    pub fn resume(&mut self) -> Result<(), TrackerError> {
+        let (_, resumed_at) = self.current.as_mut().ok_or(TrackerError::NotRunning)?;
+        if !self.paused {
+            return Err(TrackerError::NotPaused);
+        }
+
+        *resumed_at = Instant::now();
+        self.paused = false;
+
+        Ok(())
+    }
+
+    /// Returns the time tracked for the activity so far, excluding pauses, or `None` if no
+    /// activity is being tracked.
+    pub fn elapsed(&self) -> Option<Duration> {
+        let (_, resumed_at) = self.current.as_ref()?;
+
+        if self.paused {
+            Some(self.tracked_before_pause)
+        } else {
+            Some(self.tracked_before_pause + resumed_at.elapsed())
+        }
+    }
+
+    /// Returns the tracked activity, if any.
+    pub fn current(&self) -> Option<&Activity> {
+        self.current.as_ref().map(|(activity, _)| activity)
+    }
+
+    /// Returns whether the tracking is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::repositories::in_memory::activities_list::InMemoryActivitiesListRepository;
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    async fn tracker() -> (
+        ActivityTracker<InMemoryActivitiesListRepository>,
+        Arc<Mutex<InMemoryActivitiesListRepository>>,
+    ) {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        (
+            ActivityTracker::new(repository.clone())
+                .await
+                .expect("Tracker created"),
+            repository,
+        )
+    }
+
+    async fn start(tracker: &mut ActivityTracker<InMemoryActivitiesListRepository>) {
+        tracker
+            .start_at(
+                NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                AccountingCategoryId::new(),
+                "Test Task".to_string(),
+            )
+            .await
+            .expect("Tracking started");
+    }
+
+    fn at(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 10, 2)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn new_tracker_should_not_be_running() {
+        let (tracker, _) = tracker().await;
+
+        assert_eq!(tracker.elapsed(), None);
+        assert_eq!(tracker.current(), None);
+        assert!(!tracker.is_paused());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn start_should_track_elapsed_time() {
+        let (mut tracker, _) = tracker().await;
+
+        start(&mut tracker).await;
+        tokio::time::advance(HOUR).await;
+
+        assert_eq!(tracker.elapsed(), Some(HOUR));
+        assert_eq!(tracker.current().unwrap().task(), "Test Task");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn start_should_fail_if_already_running() {
+        let (mut tracker, _) = tracker().await;
+        start(&mut tracker).await;
+
+        let result = tracker
+            .start(
+                NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(),
+                AccountingCategoryId::new(),
+                "Other Task".to_string(),
+            )
+            .await;
+
+        assert_eq!(result, Err(TrackerError::AlreadyRunning));
+        assert_eq!(tracker.current().unwrap().task(), "Test Task");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn start_should_store_activity_in_progress() {
+        let (mut tracker, repository) = tracker().await;
+
+        start(&mut tracker).await;
+
+        let repository = repository.lock().await;
+        assert_eq!(repository.count().await.unwrap(), 1);
+        assert_eq!(
+            repository
+                .get_current_in_progress(TRACKER_IMPORT_SOURCE)
+                .await
+                .unwrap()
+                .as_ref(),
+            tracker.current()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stop_should_add_completed_activity_to_repository() {
+        let (mut tracker, repository) = tracker().await;
+        start(&mut tracker).await;
+        tokio::time::advance(HOUR + Duration::from_millis(500)).await;
+
+        let activity = tracker.stop().await.expect("Tracking stopped");
+
+        assert_eq!(
+            activity.end_time(),
+            Some(&NaiveTime::from_hms_opt(10, 0, 0).unwrap())
+        );
+        assert_eq!(
+            repository.lock().await.get_all().await.unwrap(),
+            vec![activity]
+        );
+        assert_eq!(tracker.elapsed(), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stop_should_fail_if_not_running() {
+        let (mut tracker, repository) = tracker().await;
+
+        assert_eq!(tracker.stop().await, Err(TrackerError::NotRunning));
+        assert_eq!(repository.lock().await.count().await.unwrap(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stop_should_fail_if_less_than_a_second_was_tracked() {
+        let (mut tracker, repository) = tracker().await;
+        start(&mut tracker).await;
+
+        let started = tracker.current().cloned();
+
+        let result = tracker.stop().await;
+
+        assert!(matches!(result, Err(TrackerError::InvalidActivity(_))));
+        assert_eq!(tracker.current().cloned(), started);
+        assert_eq!(
+            repository.lock().await.get_all().await.unwrap(),
+            started.into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pause_should_stop_elapsed_time() {
+        let (mut tracker, _) = tracker().await;
+        start(&mut tracker).await;
+        tokio::time::advance(HOUR).await;
+
+        tracker.pause().expect("Tracking paused");
+        tokio::time::advance(HOUR).await;
+
+        assert!(tracker.is_paused());
+        assert_eq!(tracker.elapsed(), Some(HOUR));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pause_should_fail_if_not_running() {
+        let (mut tracker, _) = tracker().await;
+
+        assert_eq!(tracker.pause(), Err(TrackerError::NotRunning));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pause_should_fail_if_already_paused() {
+        let (mut tracker, _) = tracker().await;
+        start(&mut tracker).await;
+        tracker.pause().expect("Tracking paused");
+
+        assert_eq!(tracker.pause(), Err(TrackerError::AlreadyPaused));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn resume_should_continue_elapsed_time() {
+        let (mut tracker, _) = tracker().await;
+        start(&mut tracker).await;
+        tokio::time::advance(HOUR).await;
+        tracker.pause().expect("Tracking paused");
+        tokio::time::advance(HOUR).await;
+
+        tracker.resume().expect("Tracking resumed");
+        tokio::time::advance(HOUR).await;
+
+        assert!(!tracker.is_paused());
+        assert_eq!(tracker.elapsed(), Some(2 * HOUR));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn resume_should_fail_if_not_running() {
+        let (mut tracker, _) = tracker().await;
+
+        assert_eq!(tracker.resume(), Err(TrackerError::NotRunning));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn resume_should_fail_if_not_paused() {
+        let (mut tracker, _) = tracker().await;
+        start(&mut tracker).await;
+
+        assert_eq!(tracker.resume(), Err(TrackerError::NotPaused));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stop_should_exclude_pauses_from_the_activity() {
+        let (mut tracker, repository) = tracker().await;
+        start(&mut tracker).await;
+        tokio::time::advance(HOUR).await;
+        tracker.pause().expect("Tracking paused");
+        tokio::time::advance(HOUR).await;
+
+        let activity = tracker.stop().await.expect("Tracking stopped");
+
+        assert_eq!(activity.duration(), chrono::Duration::hours(1));
+        assert_eq!(repository.lock().await.count().await.unwrap(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn start_should_be_possible_after_stop() {
+        let (mut tracker, _) = tracker().await;
+        start(&mut tracker).await;
+        tokio::time::advance(HOUR).await;
+        tracker.stop().await.expect("Tracking stopped");
+
+        start(&mut tracker).await;
+
+        assert_eq!(tracker.elapsed(), Some(Duration::ZERO));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn new_tracker_should_resume_activity_in_progress_after_restart() {
+        let (mut tracker, repository) = tracker().await;
+        start(&mut tracker).await;
+        let started = tracker.current().cloned();
+        drop(tracker);
+
+        let mut tracker = ActivityTracker::restored_at(repository.clone(), at(10, 30))
+            .await
+            .expect("Tracker restored");
+
+        assert_eq!(tracker.current().cloned(), started);
+        assert_eq!(tracker.elapsed(), Some(HOUR + HOUR / 2));
+        assert!(!tracker.is_paused());
+
+        tokio::time::advance(HOUR / 2).await;
+        let activity = tracker.stop().await.expect("Tracking stopped");
+
+        assert_eq!(
+            activity.end_time(),
+            Some(&NaiveTime::from_hms_opt(11, 0, 0).unwrap())
+        );
+        let repository = repository.lock().await;
+        assert_eq!(repository.get_all().await.unwrap(), vec![activity]);
+        assert_eq!(
+            repository
+                .get_current_in_progress(TRACKER_IMPORT_SOURCE)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn new_tracker_should_not_resume_completed_activity() {
+        let (mut tracker, repository) = tracker().await;
+        start(&mut tracker).await;
+        tokio::time::advance(HOUR).await;
+        tracker.stop().await.expect("Tracking stopped");
+
+        let tracker = ActivityTracker::restored_at(repository, at(10, 30))
+            .await
+            .expect("Tracker restored");
+
+        assert_eq!(tracker.current(), None);
+        assert_eq!(tracker.elapsed(), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn new_tracker_should_not_resume_activity_it_did_not_start() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        repository
+            .lock()
+            .await
+            .add(Activity::new(
+                NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                AccountingCategoryId::new(),
+                "Imported Task".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let tracker = ActivityTracker::restored_at(repository.clone(), at(10, 30))
+            .await
+            .expect("Tracker restored");
+
+        assert_eq!(tracker.current(), None);
+        assert_eq!(repository.lock().await.count().await.unwrap(), 1);
+    }
+}
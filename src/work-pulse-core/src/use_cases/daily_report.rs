@@ -1,9 +1,16 @@
-use chrono::{Duration, NaiveDate};
+use chrono::{Duration, Local, NaiveDate, NaiveTime};
 
-use crate::{adapters::ActivitiesListRepository, entities::activity::Activity};
+use crate::{
+    adapters::{
+        AccountingCategoriesListRepository, ActivitiesListRepository, ActivitiesListRepositoryError,
+    },
+    entities::{accounting::AccountingCategory, activity::Activity},
+    use_cases::reports::cost::total_cost,
+    utils::dates,
+};
 
 /// Represents a daily report containing activities and total duration for a specific date.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DailyReport {
     /// The date of the report. Also represents the date of the activities used to generate the report.
     date: NaiveDate,
@@ -13,6 +20,9 @@ pub struct DailyReport {
 
     /// The total duration of all activities recorded for the report date.
     total_duration: Duration,
+
+    /// The total cost of all activities with a known hourly rate, if any.
+    total_cost: Option<f64>,
 }
 
 impl DailyReport {
@@ -22,16 +32,107 @@ impl DailyReport {
     ///
     /// - `date`: The date for which the report is generated.
     /// - `repository`: The repository used to fetch activities for the specified date.
-    pub async fn new(date: NaiveDate, repository: &dyn ActivitiesListRepository) -> Self {
-        let activities = repository.get_by_date(date).await;
+    /// - `categories_repository`: The repository used to look up the hourly rates of the accounting categories.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(DailyReport)`: The report for the date.
+    /// - `Err(ActivitiesListRepositoryError)`: If the activities or accounting categories could not be fetched.
+    #[tracing::instrument(skip(repository, categories_repository))]
+    pub async fn new(
+        date: NaiveDate,
+        repository: &dyn ActivitiesListRepository,
+        categories_repository: &dyn AccountingCategoriesListRepository,
+    ) -> Result<Self, ActivitiesListRepositoryError> {
+        tracing::debug!("Creating daily report");
+        let mut activities = repository.get_by_date(date).await?;
+        activities.sort_by_key(|activity| *activity.start_time());
 
         let total_duration = activities.iter().map(|activity| activity.duration()).sum();
+        let categories = categories_repository
+            .get_all(true)
+            .await
+            .map_err(|err| ActivitiesListRepositoryError::DatabaseError(err.to_string()))?;
+        let total_cost = total_cost(&activities, &categories);
 
-        DailyReport {
+        tracing::info!(count = activities.len(), "Daily report created");
+        Ok(DailyReport {
             date,
             activities,
             total_duration,
+            total_cost,
+        })
+    }
+
+    /// Creates a new `DailyReport` for the current local date.
+    ///
+    /// # Arguments
+    ///
+    /// - `repository`: The repository used to fetch the activities of today.
+    /// - `categories_repository`: The repository used to look up the hourly rates of the accounting categories.
+    pub async fn for_today(
+        repository: &dyn ActivitiesListRepository,
+        categories_repository: &dyn AccountingCategoriesListRepository,
+    ) -> Result<Self, ActivitiesListRepositoryError> {
+        Self::new(Local::now().date_naive(), repository, categories_repository).await
+    }
+
+    /// Creates one `DailyReport` per day of the specified date range using the provided repository.
+    ///
+    /// # Arguments
+    ///
+    /// - `start`: The first date of the range (inclusive).
+    /// - `end`: The last date of the range (inclusive).
+    /// - `repository`: The repository used to fetch the activities of the range.
+    /// - `categories_repository`: The repository used to look up the hourly rates of the accounting categories.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<DailyReport>)`: The reports ordered by date, empty if `end` is before `start`.
+    /// - `Err(ActivitiesListRepositoryError)`: If the activities or accounting categories could not be fetched.
+    #[tracing::instrument(skip(repository, categories_repository))]
+    pub async fn for_date_range(
+        start: NaiveDate,
+        end: NaiveDate,
+        repository: &dyn ActivitiesListRepository,
+        categories_repository: &dyn AccountingCategoriesListRepository,
+    ) -> Result<Vec<Self>, ActivitiesListRepositoryError> {
+        tracing::debug!("Creating daily reports for date range");
+        if end < start {
+            return Ok(Vec::new());
         }
+
+        let activities = repository.get_by_date_range(start, end).await?;
+        let categories = categories_repository
+            .get_all(true)
+            .await
+            .map_err(|err| ActivitiesListRepositoryError::DatabaseError(err.to_string()))?;
+
+        let reports: Vec<Self> = start
+            .iter_days()
+            .take_while(|date| *date <= end)
+            .map(|date| {
+                let mut activities: Vec<Activity> = activities
+                    .iter()
+                    .filter(|activity| *activity.date() == date)
+                    .cloned()
+                    .collect();
+                activities.sort_by_key(|activity| *activity.start_time());
+
+                let total_duration = activities.iter().map(|activity| activity.duration()).sum();
+                let total_cost = total_cost(&activities, &categories);
+
+                DailyReport {
+                    date,
+                    activities,
+                    total_duration,
+                    total_cost,
+                }
+            })
+            .collect();
+
+        tracing::info!(count = reports.len(), "Daily reports created");
+        Ok(reports)
     }
 
     /// Returns the date of the report.
@@ -39,7 +140,7 @@ impl DailyReport {
         self.date
     }
 
-    /// Returns the list of activities recorded for the report date.
+    /// Returns the list of activities recorded for the report date, ordered by start time.
     pub fn activities(&self) -> &[Activity] {
         &self.activities
     }
@@ -48,18 +149,94 @@ impl DailyReport {
     pub fn total_duration(&self) -> Duration {
         self.total_duration
     }
+
+    /// Returns the total cost of all activities with a known hourly rate, if any.
+    pub fn total_cost(&self) -> Option<f64> {
+        self.total_cost
+    }
+
+    /// Estimates the total cost of the activities of the report based on the given categories.
+    ///
+    /// Unlike `total_cost`, which uses the hourly rates known when the report was created, this
+    /// allows estimating the cost with other rates, e.g. to preview a change of the billing rates.
+    ///
+    /// # Arguments
+    ///
+    /// - `categories`: The accounting categories used to look up the hourly rates.
+    ///
+    /// # Returns
+    ///
+    /// - The sum of the costs of all activities with a known hourly rate, or `None` if no rate is known for any activity.
+    pub fn total_cost_estimate(&self, categories: &[AccountingCategory]) -> Option<f64> {
+        total_cost(&self.activities, categories)
+    }
+
+    /// Returns whether the report date is on a weekend.
+    pub fn is_weekend(&self) -> bool {
+        dates::is_weekend(self.date)
+    }
+
+    /// Returns the time windows between `work_start` and `work_end` not covered by any completed
+    /// activity.
+    ///
+    /// Activities without an end time are ignored, as it is unknown how much time they cover.
+    ///
+    /// # Arguments
+    ///
+    /// - `work_start`: The start of the work day.
+    /// - `work_end`: The end of the work day.
+    ///
+    /// # Returns
+    ///
+    /// - The uncovered time windows as `(start, end)` pairs, ordered by start time.
+    pub fn untracked_gaps(
+        &self,
+        work_start: NaiveTime,
+        work_end: NaiveTime,
+    ) -> Vec<(NaiveTime, NaiveTime)> {
+        let mut intervals: Vec<(NaiveTime, NaiveTime)> = self
+            .activities
+            .iter()
+            .filter_map(|activity| {
+                activity
+                    .end_time()
+                    .map(|end_time| (*activity.start_time(), *end_time))
+            })
+            .collect();
+        intervals.sort_by_key(|(start_time, _)| *start_time);
+
+        let mut gaps = Vec::new();
+        let mut covered_until = work_start;
+        for (start_time, end_time) in intervals {
+            if covered_until >= work_end {
+                break;
+            }
+            if start_time > covered_until {
+                gaps.push((covered_until, start_time.min(work_end)));
+            }
+            covered_until = covered_until.max(end_time);
+        }
+        if covered_until < work_end {
+            gaps.push((covered_until, work_end));
+        }
+
+        gaps
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
-    use chrono::NaiveTime;
+    use chrono::{Datelike, NaiveTime};
     use tokio::sync::Mutex;
 
     use crate::{
         entities::accounting::AccountingCategoryId,
-        infra::repositories::in_memory::activities_list::InMemoryActivitiesListRepository,
+        infra::repositories::in_memory::{
+            accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+            activities_list::InMemoryActivitiesListRepository,
+        },
         use_cases::activities_list::ActivitiesList,
     };
 
@@ -80,30 +257,258 @@ mod tests {
                 AccountingCategoryId::new(),
                 "Task 1".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         let activity2 = activities_list
             .record(
                 date,
-                NaiveTime::from_hms_opt(11, 0, 0).expect("Valid activity start time"),
-                Some(NaiveTime::from_hms_opt(12, 30, 0).expect("Valid activity end time")),
+                NaiveTime::from_hms_opt(7, 0, 0).expect("Valid activity start time"),
+                Some(NaiveTime::from_hms_opt(8, 30, 0).expect("Valid activity end time")),
                 AccountingCategoryId::new(),
                 "Task 2".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
-        let daily_report = DailyReport::new(date, &*repository.lock().await).await;
+        let daily_report = DailyReport::new(
+            date,
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(daily_report.date(), date);
         assert_eq!(
             daily_report.activities(),
-            &[activity1.clone(), activity2.clone()]
+            &[activity2.clone(), activity1.clone()]
         );
         assert_eq!(
             daily_report.total_duration(),
             Duration::minutes(90) + Duration::minutes(60)
         );
+        assert_eq!(daily_report.total_cost(), None);
+    }
+
+    #[tokio::test]
+    async fn for_date_range_should_create_one_report_per_day() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+        for (day, start_hour) in [(3, 9), (1, 10), (1, 8), (5, 9)] {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                    time(start_hour, 0),
+                    Some(time(start_hour + 1, 0)),
+                    AccountingCategoryId::new(),
+                    format!("Task {}-{}", day, start_hour),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+        let start = NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid start date");
+        let end = NaiveDate::from_ymd_opt(2023, 10, 4).expect("Valid end date");
+
+        let reports = DailyReport::for_date_range(
+            start,
+            end,
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            reports.iter().map(|r| r.date().day()).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(
+            reports[0]
+                .activities()
+                .iter()
+                .map(|a| a.task())
+                .collect::<Vec<_>>(),
+            vec!["Task 1-8", "Task 1-10"]
+        );
+        assert_eq!(reports[0].total_duration(), Duration::hours(2));
+        assert!(reports[1].activities().is_empty());
+        assert_eq!(reports[1].total_duration(), Duration::zero());
+        assert_eq!(reports[2].total_duration(), Duration::hours(1));
+        assert!(reports[3].activities().is_empty());
+    }
+
+    #[tokio::test]
+    async fn for_date_range_should_match_single_daily_report() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+        let date = NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date");
+        activities_list
+            .record(
+                date,
+                time(9, 0),
+                Some(time(10, 30)),
+                AccountingCategoryId::new(),
+                "Task".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+        let categories_repository = InMemoryAccountingCategoriesListRepository::new();
+        let repository = repository.lock().await;
+
+        let reports = DailyReport::for_date_range(date, date, &*repository, &categories_repository)
+            .await
+            .unwrap();
+
+        let daily_report = DailyReport::new(date, &*repository, &categories_repository)
+            .await
+            .unwrap();
+        assert_eq!(reports, vec![daily_report]);
+    }
+
+    #[tokio::test]
+    async fn for_date_range_should_be_empty_when_end_is_before_start() {
+        let reports = DailyReport::for_date_range(
+            NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid start date"),
+            NaiveDate::from_ymd_opt(2023, 10, 1).expect("Valid end date"),
+            &InMemoryActivitiesListRepository::new(),
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap();
+
+        assert!(reports.is_empty());
+    }
+
+    #[test]
+    fn total_cost_estimate_should_sum_costs_with_given_rates() {
+        let mut billable = AccountingCategory::new("Billable".to_string());
+        billable.set_hourly_rate(Some(80.0));
+        let internal = AccountingCategory::new("Internal".to_string());
+        let date = NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date");
+
+        let mut activities = Vec::new();
+        for (category, start_hour, end_hour) in [(&billable, 9, 11), (&internal, 11, 12)] {
+            let mut activity = Activity::new(
+                date,
+                time(start_hour, 0),
+                category.id().clone(),
+                "Task".to_string(),
+            );
+            activity
+                .set_end_time(Some(time(end_hour, 0)))
+                .expect("Valid activity end time");
+            activities.push(activity);
+        }
+        let report = DailyReport {
+            date,
+            activities,
+            total_duration: Duration::hours(3),
+            total_cost: None,
+        };
+
+        assert_eq!(
+            report.total_cost_estimate(&[billable.clone(), internal.clone()]),
+            Some(160.0)
+        );
+        assert_eq!(report.total_cost_estimate(&[internal]), None);
+    }
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).expect("Valid time")
+    }
+
+    /// Creates a report with one activity per `(start, end)` pair.
+    fn report_with_activities(times: &[(NaiveTime, Option<NaiveTime>)]) -> DailyReport {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date");
+        let activities = times
+            .iter()
+            .map(|(start_time, end_time)| {
+                let mut activity = Activity::new(
+                    date,
+                    *start_time,
+                    AccountingCategoryId::new(),
+                    "Task".to_string(),
+                );
+                activity
+                    .set_end_time(*end_time)
+                    .expect("Valid activity end time");
+                activity
+            })
+            .collect();
+
+        DailyReport {
+            date,
+            activities,
+            total_duration: Duration::zero(),
+            total_cost: None,
+        }
+    }
+
+    #[test]
+    fn untracked_gaps_should_be_empty_when_work_day_is_covered() {
+        let report = report_with_activities(&[
+            (time(13, 0), Some(time(17, 0))),
+            (time(9, 0), Some(time(13, 0))),
+        ]);
+
+        assert!(report.untracked_gaps(time(9, 0), time(17, 0)).is_empty());
+    }
+
+    #[test]
+    fn untracked_gaps_should_report_single_gap() {
+        let report = report_with_activities(&[
+            (time(9, 0), Some(time(12, 0))),
+            (time(13, 0), Some(time(17, 0))),
+        ]);
+
+        assert_eq!(
+            report.untracked_gaps(time(9, 0), time(17, 0)),
+            vec![(time(12, 0), time(13, 0))]
+        );
+    }
+
+    #[test]
+    fn untracked_gaps_should_report_multiple_gaps() {
+        let report = report_with_activities(&[
+            (time(14, 0), Some(time(16, 0))),
+            (time(10, 0), Some(time(11, 30))),
+            (time(11, 0), Some(time(12, 0))),
+            (time(12, 30), None),
+        ]);
+
+        assert_eq!(
+            report.untracked_gaps(time(9, 0), time(17, 0)),
+            vec![
+                (time(9, 0), time(10, 0)),
+                (time(12, 0), time(14, 0)),
+                (time(16, 0), time(17, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn untracked_gaps_should_be_empty_when_activity_spans_work_day() {
+        let report = report_with_activities(&[(time(8, 0), Some(time(18, 0)))]);
+
+        assert!(report.untracked_gaps(time(9, 0), time(17, 0)).is_empty());
+    }
+
+    #[test]
+    fn untracked_gaps_should_report_whole_work_day_without_completed_activities() {
+        let report = report_with_activities(&[(time(9, 0), None)]);
+
+        assert_eq!(
+            report.untracked_gaps(time(9, 0), time(17, 0)),
+            vec![(time(9, 0), time(17, 0))]
+        );
     }
 }
@@ -1,4 +1,8 @@
 pub mod activities_list;
 pub mod accounting_categories_list;
+pub mod activity_tracker;
 pub mod daily_report;
-pub mod weekly_report;
\ No newline at end of file
+pub mod monthly_report;
+pub mod reports;
+pub mod weekly_report;
+pub mod yearly_report;
\ No newline at end of file
@@ -0,0 +1,342 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+
+use crate::{
+    adapters::{
+        AccountingCategoriesListRepository, ActivitiesListRepository, ActivitiesListRepositoryError,
+    },
+    entities::{accounting::AccountingCategoryId, activity::Activity},
+    use_cases::reports::cost::total_cost,
+    utils::dates::count_working_days,
+};
+
+/// A report summarizing activities for a specific calendar month.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthlyReport {
+    /// The first day of the month.
+    month_start: NaiveDate,
+
+    /// The last day of the month.
+    month_end: NaiveDate,
+
+    /// The list of activities recorded during the month.
+    activities: Vec<Activity>,
+
+    /// The total duration of all activities recorded during the month.
+    total_duration: Duration,
+
+    /// The total cost of all activities with a known hourly rate, if any.
+    total_cost: Option<f64>,
+
+    /// A vector of tuples containing accounting category IDs and their corresponding total durations.
+    duration_per_category: Vec<(AccountingCategoryId, Duration)>,
+
+    /// A vector of tuples containing the start of each week within the month and the total duration of that week.
+    weekly_breakdown: Vec<(NaiveDate, Duration)>,
+
+    /// The number of weekdays (Monday to Friday) in the month.
+    total_working_days: u32,
+
+    /// The number of days with at least one completed activity.
+    days_with_activities: u32,
+}
+
+impl MonthlyReport {
+    /// Creates a new `MonthlyReport` for the given calendar month.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year of the month.
+    /// * `month` - The month (1-12).
+    /// * `repository` - A reference to an implementation of `ActivitiesListRepository` to fetch activities.
+    /// * `categories_repository` - A reference to an implementation of `AccountingCategoriesListRepository` to look up hourly rates.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(MonthlyReport))` - The report for the month.
+    /// * `Ok(None)` - If `year` and `month` do not denote a valid calendar month.
+    /// * `Err(ActivitiesListRepositoryError)` - If the activities or accounting categories could not be fetched.
+    pub async fn new(
+        year: i32,
+        month: u32,
+        repository: &dyn ActivitiesListRepository,
+        categories_repository: &dyn AccountingCategoriesListRepository,
+    ) -> Result<Option<Self>, ActivitiesListRepositoryError> {
+        let Some(month_start) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            return Ok(None);
+        };
+        let Some(next_month_start) = month_start.checked_add_months(Months::new(1)) else {
+            return Ok(None);
+        };
+        let month_end = next_month_start - Duration::days(1);
+        let activities = repository.get_by_date_range(month_start, month_end).await?;
+
+        let total_duration = activities.iter().map(|activity| activity.duration()).sum();
+        let categories = categories_repository
+            .get_all(true)
+            .await
+            .map_err(|err| ActivitiesListRepositoryError::DatabaseError(err.to_string()))?;
+        let total_cost = total_cost(&activities, &categories);
+
+        let mut category_durations = HashMap::new();
+        for activity in &activities {
+            *category_durations
+                .entry(activity.accounting_category_id().clone())
+                .or_insert(Duration::zero()) += activity.duration();
+        }
+        let duration_per_category = category_durations.into_iter().collect();
+
+        let weekly_breakdown =
+            Self::calculate_weekly_breakdown(&activities, month_start, month_end);
+
+        let days_with_activities = activities
+            .iter()
+            .filter(|activity| activity.is_completed())
+            .map(|activity| *activity.date())
+            .collect::<HashSet<_>>()
+            .len() as u32;
+
+        Ok(Some(MonthlyReport {
+            month_start,
+            month_end,
+            activities,
+            total_duration,
+            total_cost,
+            duration_per_category,
+            weekly_breakdown,
+            total_working_days: count_working_days(month_start, month_end),
+            days_with_activities,
+        }))
+    }
+
+    /// Sums up the durations of the activities per week. The first week starts on the first day
+    /// of the month, every following week starts on a Monday.
+    fn calculate_weekly_breakdown(
+        activities: &[Activity],
+        month_start: NaiveDate,
+        month_end: NaiveDate,
+    ) -> Vec<(NaiveDate, Duration)> {
+        let week_starts: Vec<NaiveDate> = month_start
+            .iter_days()
+            .take_while(|date| *date <= month_end)
+            .filter(|date| *date == month_start || date.weekday() == Weekday::Mon)
+            .collect();
+
+        week_starts
+            .iter()
+            .enumerate()
+            .map(|(i, week_start)| {
+                let next_week_start = week_starts
+                    .get(i + 1)
+                    .copied()
+                    .unwrap_or(month_end + Duration::days(1));
+
+                let duration = activities
+                    .iter()
+                    .filter(|a| *a.date() >= *week_start && *a.date() < next_week_start)
+                    .map(|a| a.duration())
+                    .sum();
+
+                (*week_start, duration)
+            })
+            .collect()
+    }
+
+    /// Returns the first day of the month.
+    pub fn month_start(&self) -> NaiveDate {
+        self.month_start
+    }
+
+    /// Returns the last day of the month.
+    pub fn month_end(&self) -> NaiveDate {
+        self.month_end
+    }
+
+    /// Returns a slice of activities included in the report.
+    pub fn activities(&self) -> &[Activity] {
+        &self.activities
+    }
+
+    /// Returns the total duration of all activities in the report.
+    pub fn total_duration(&self) -> Duration {
+        self.total_duration
+    }
+
+    /// Returns the total cost of all activities with a known hourly rate, if any.
+    pub fn total_cost(&self) -> Option<f64> {
+        self.total_cost
+    }
+
+    /// Returns a vector of tuples containing accounting category IDs and their corresponding total durations.
+    pub fn duration_per_category(&self) -> &[(AccountingCategoryId, Duration)] {
+        &self.duration_per_category
+    }
+
+    /// Returns a vector of tuples containing the start of each week within the month and the total duration of that week.
+    pub fn weekly_breakdown(&self) -> &[(NaiveDate, Duration)] {
+        &self.weekly_breakdown
+    }
+
+    /// Returns the number of weekdays (Monday to Friday) in the month.
+    pub fn total_working_days(&self) -> u32 {
+        self.total_working_days
+    }
+
+    /// Returns the number of days with at least one completed activity.
+    pub fn days_with_activities(&self) -> u32 {
+        self.days_with_activities
+    }
+
+    /// Returns the ratio of days with activities to working days in the month.
+    pub fn attendance_rate(&self) -> f64 {
+        if self.total_working_days == 0 {
+            0.0
+        } else {
+            self.days_with_activities as f64 / self.total_working_days as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::NaiveTime;
+    use tokio::sync::Mutex;
+
+    use crate::{
+        infra::repositories::in_memory::{
+            accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+            activities_list::InMemoryActivitiesListRepository,
+        },
+        use_cases::activities_list::ActivitiesList,
+    };
+
+    use super::*;
+
+    async fn record(
+        activities_list: &mut ActivitiesList<InMemoryActivitiesListRepository>,
+        date: NaiveDate,
+        hours: u32,
+        category_id: &AccountingCategoryId,
+    ) {
+        activities_list
+            .record(
+                date,
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                Some(NaiveTime::from_hms_opt(9 + hours, 0, 0).expect("Valid activity end time")),
+                category_id.clone(),
+                "Activity".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+    }
+
+    #[tokio::test]
+    async fn monthly_report_should_aggregate_activities_of_the_month() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+        let category1 = AccountingCategoryId::new();
+        let category2 = AccountingCategoryId::new();
+
+        let october = |day| NaiveDate::from_ymd_opt(2025, 10, day).unwrap();
+
+        // October 2025 starts on a Wednesday.
+        let september_30 = NaiveDate::from_ymd_opt(2025, 9, 30).unwrap();
+        record(&mut activities_list, september_30, 1, &category1).await;
+        record(&mut activities_list, october(1), 2, &category1).await;
+        record(&mut activities_list, october(6), 3, &category2).await;
+        record(&mut activities_list, october(31), 1, &category2).await;
+
+        let report = MonthlyReport::new(
+            2025,
+            10,
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap()
+        .expect("Valid month");
+
+        assert_eq!(report.month_start(), october(1));
+        assert_eq!(report.month_end(), october(31));
+        assert_eq!(report.activities().len(), 3);
+        assert_eq!(report.total_duration(), Duration::hours(6));
+
+        let duration_map: HashMap<_, _> = report.duration_per_category().iter().cloned().collect();
+        assert_eq!(duration_map.get(&category1), Some(&Duration::hours(2)));
+        assert_eq!(duration_map.get(&category2), Some(&Duration::hours(4)));
+
+        let weekly_breakdown = report.weekly_breakdown();
+        assert_eq!(weekly_breakdown.len(), 5);
+        assert_eq!(weekly_breakdown[0], (october(1), Duration::hours(2)));
+        assert_eq!(weekly_breakdown[1], (october(6), Duration::hours(3)));
+        assert_eq!(weekly_breakdown[4], (october(27), Duration::hours(1)));
+    }
+
+    #[tokio::test]
+    async fn monthly_report_should_calculate_attendance_rate() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+        let category = AccountingCategoryId::new();
+
+        let february = |day| NaiveDate::from_ymd_opt(2025, 2, day).unwrap();
+
+        // February 2025 has 20 working days.
+        for day in 3..=7 {
+            record(&mut activities_list, february(day), 1, &category).await;
+        }
+        record(&mut activities_list, february(3), 1, &category).await;
+
+        let report = MonthlyReport::new(
+            2025,
+            2,
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap()
+        .expect("Valid month");
+
+        assert_eq!(report.total_working_days(), 20);
+        assert_eq!(report.days_with_activities(), 5);
+        assert_eq!(report.attendance_rate(), 0.25);
+    }
+
+    #[tokio::test]
+    async fn monthly_report_should_handle_december() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+
+        let report = MonthlyReport::new(
+            2025,
+            12,
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap()
+        .expect("Valid month");
+
+        assert_eq!(report.month_end(), NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+        assert_eq!(report.total_duration(), Duration::zero());
+        assert_eq!(report.attendance_rate(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn monthly_report_should_return_none_for_invalid_month() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+
+        let report = MonthlyReport::new(
+            2025,
+            13,
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await;
+
+        assert_eq!(report, Ok(None));
+    }
+}
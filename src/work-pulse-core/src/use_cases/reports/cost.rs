@@ -0,0 +1,67 @@
+use crate::entities::{accounting::AccountingCategory, activity::Activity};
+
+/// Calculates the total cost of the given activities based on the hourly rates of their categories.
+///
+/// # Arguments
+///
+/// - `activities`: The activities to calculate the cost for.
+/// - `categories`: The accounting categories used to look up the hourly rates.
+///
+/// # Returns
+///
+/// The sum of the costs of all activities with a known hourly rate, or `None` if no rate is known for any activity.
+pub fn total_cost(activities: &[Activity], categories: &[AccountingCategory]) -> Option<f64> {
+    activities
+        .iter()
+        .filter_map(|activity| {
+            categories
+                .iter()
+                .find(|category| category.id() == activity.accounting_category_id())
+                .and_then(|category| activity.cost(category))
+        })
+        .reduce(|total, cost| total + cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime};
+
+    use super::*;
+
+    fn activity(category: &AccountingCategory, start_hour: u32, end_hour: u32) -> Activity {
+        let mut activity = Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date"),
+            NaiveTime::from_hms_opt(start_hour, 0, 0).expect("Valid activity start time"),
+            category.id().clone(),
+            "Task".to_string(),
+        );
+        activity
+            .set_end_time(NaiveTime::from_hms_opt(end_hour, 0, 0))
+            .expect("End time after start time");
+
+        activity
+    }
+
+    #[test]
+    fn total_cost_should_sum_costs_of_activities_with_known_rate() {
+        let mut billable = AccountingCategory::new("Billable".to_string());
+        billable.set_hourly_rate(Some(100.0));
+        let internal = AccountingCategory::new("Internal".to_string());
+
+        let activities = vec![
+            activity(&billable, 9, 11),
+            activity(&internal, 11, 12),
+            activity(&billable, 13, 14),
+        ];
+
+        assert_eq!(total_cost(&activities, &[billable, internal]), Some(300.0));
+    }
+
+    #[test]
+    fn total_cost_should_be_none_without_known_rates() {
+        let internal = AccountingCategory::new("Internal".to_string());
+        let activities = vec![activity(&internal, 9, 11)];
+
+        assert_eq!(total_cost(&activities, &[internal]), None);
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+
+use chrono::Datelike;
+
+use crate::use_cases::{daily_report::DailyReport, weekly_report::WeeklyReport};
+
+/// Creates a natural language summary for a daily report.
+///
+/// # Arguments
+///
+/// - `report`: The daily report to summarize.
+///
+/// # Returns
+///
+/// A summary like `"On 2023-10-01 you worked 2h 30m across 2 activities in 1 categories."`.
+pub fn daily_summary(report: &DailyReport) -> String {
+    let total_minutes = report.total_duration().num_minutes();
+    let categories = report
+        .activities()
+        .iter()
+        .map(|activity| activity.accounting_category_id())
+        .collect::<HashSet<_>>()
+        .len();
+
+    format!(
+        "On {} you worked {}h {}m across {} activities in {} categories.",
+        report.date(),
+        total_minutes / 60,
+        total_minutes % 60,
+        report.activities().len(),
+        categories
+    )
+}
+
+/// Creates a natural language summary for a weekly report.
+///
+/// # Arguments
+///
+/// - `report`: The weekly report to summarize.
+///
+/// # Returns
+///
+/// A summary like `"Week 40: 37.5h total across 5 active days."`.
+pub fn weekly_summary(report: &WeeklyReport) -> String {
+    let total_hours = report.total_duration().num_minutes() as f64 / 60.0;
+    let days_active = report
+        .activities()
+        .iter()
+        .map(|activity| activity.date())
+        .collect::<HashSet<_>>()
+        .len();
+
+    format!(
+        "Week {}: {:.1}h total across {} active days.",
+        report.week_start().iso_week().week(),
+        total_hours,
+        days_active
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{NaiveDate, NaiveTime};
+    use tokio::sync::Mutex;
+
+    use crate::{
+        entities::accounting::AccountingCategoryId,
+        infra::repositories::in_memory::{
+            accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+            activities_list::InMemoryActivitiesListRepository,
+        },
+        use_cases::activities_list::ActivitiesList,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn daily_summary_should_describe_worked_time_and_activities() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+
+        let date = NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date");
+        let category_id = AccountingCategoryId::new();
+
+        activities_list
+            .record(
+                date,
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                Some(NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity end time")),
+                category_id.clone(),
+                "Task 1".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+
+        activities_list
+            .record(
+                date,
+                NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity start time"),
+                Some(NaiveTime::from_hms_opt(11, 30, 0).expect("Valid activity end time")),
+                category_id,
+                "Task 2".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+
+        let report = DailyReport::new(
+            date,
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            daily_summary(&report),
+            "On 2023-10-02 you worked 2h 30m across 2 activities in 1 categories."
+        );
+    }
+
+    #[tokio::test]
+    async fn weekly_summary_should_describe_total_hours_and_active_days() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+
+        activities_list
+            .record(
+                NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date"), // Monday
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                Some(NaiveTime::from_hms_opt(17, 0, 0).expect("Valid activity end time")),
+                AccountingCategoryId::new(),
+                "Task 1".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+
+        activities_list
+            .record(
+                NaiveDate::from_ymd_opt(2023, 10, 4).expect("Valid activity date"), // Wednesday
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                Some(NaiveTime::from_hms_opt(9, 30, 0).expect("Valid activity end time")),
+                AccountingCategoryId::new(),
+                "Task 2".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+
+        let report = WeeklyReport::new(
+            NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(),
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            weekly_summary(&report),
+            "Week 40: 8.5h total across 2 active days."
+        );
+    }
+}
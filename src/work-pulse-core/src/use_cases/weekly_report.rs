@@ -1,12 +1,25 @@
-use chrono::{Duration, NaiveDate};
+use std::collections::HashMap;
+
+use chrono::{Duration, Local, NaiveDate};
 
 use crate::{
-    adapters::ActivitiesListRepository,
-    entities::{accounting::AccountingCategoryId, activity::Activity},
+    adapters::{
+        AccountingCategoriesListRepository, AccountingCategoriesListRepositoryError,
+        ActivitiesListRepository, ActivitiesListRepositoryError,
+    },
+    entities::{
+        accounting::{AccountingCategory, AccountingCategoryId},
+        activity::Activity,
+    },
+    use_cases::reports::cost::total_cost,
+    utils::dates::{is_weekend, start_of_week},
 };
 
+/// A day of the week, whether the day is on a weekend and the durations per accounting category of that day.
+pub type DailyCategoryDurations = (NaiveDate, bool, Vec<(AccountingCategoryId, Duration)>);
+
 /// A report summarizing activities for a specific week.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct WeeklyReport {
     /// The starting date of the week (typically a Monday).
     week_start: NaiveDate,
@@ -14,17 +27,20 @@ pub struct WeeklyReport {
     /// The ending date of the week (typically a Sunday).
     week_end: NaiveDate,
 
-    /// The list of activities recorded during the week.
+    /// The list of activities recorded during the week, ordered by date and start time.
     activities: Vec<Activity>,
 
     /// The total duration of all activities recorded during the week.
     total_duration: Duration,
 
+    /// The total cost of all activities with a known hourly rate, if any.
+    total_cost: Option<f64>,
+
     /// A vector of tuples containing accounting category IDs and their corresponding total durations.
     duration_per_category: Vec<(AccountingCategoryId, Duration)>,
 
-    /// A vector of tuples containing each day of the week and a vector of tuples with accounting category IDs and their corresponding total durations for that day.
-    daily_durations_per_category: Vec<(NaiveDate, Vec<(AccountingCategoryId, Duration)>)>,
+    /// A vector of tuples containing each day of the week, whether the day is on a weekend and a vector of tuples with accounting category IDs and their corresponding total durations for that day.
+    daily_durations_per_category: Vec<DailyCategoryDurations>,
 }
 
 impl WeeklyReport {
@@ -34,11 +50,29 @@ impl WeeklyReport {
     ///
     /// * `week_start` - The starting date of the week (should be a Monday).
     /// * `repository` - A reference to an implementation of `ActivitiesListRepository` to fetch activities.
-    pub async fn new(week_start: NaiveDate, repository: &dyn ActivitiesListRepository) -> Self {
-        let week_end = week_start + Duration::days(7);
-        let activities = repository.get_by_date_range(week_start, week_end).await;
+    /// * `categories_repository` - A reference to an implementation of `AccountingCategoriesListRepository` to look up hourly rates.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(WeeklyReport)` - The report for the week.
+    /// * `Err(ActivitiesListRepositoryError)` - If the activities or accounting categories could not be fetched.
+    #[tracing::instrument(skip(repository, categories_repository))]
+    pub async fn new(
+        week_start: NaiveDate,
+        repository: &dyn ActivitiesListRepository,
+        categories_repository: &dyn AccountingCategoriesListRepository,
+    ) -> Result<Self, ActivitiesListRepositoryError> {
+        tracing::debug!("Creating weekly report");
+        let week_end = week_start + Duration::days(6);
+        let mut activities = repository.get_by_date_range(week_start, week_end).await?;
+        activities.sort_by_key(|activity| (*activity.date(), *activity.start_time()));
 
         let total_duration = activities.iter().map(|activity| activity.duration()).sum();
+        let categories = categories_repository
+            .get_all(true)
+            .await
+            .map_err(|err| ActivitiesListRepositoryError::DatabaseError(err.to_string()))?;
+        let total_cost = total_cost(&activities, &categories);
 
         let mut duration_per_category = Vec::new();
         let mut category_durations = std::collections::HashMap::new();
@@ -58,20 +92,51 @@ impl WeeklyReport {
         let daily_durations_per_category =
             Self::calculate_daily_durations_per_category(&activities, week_start);
 
-        WeeklyReport {
+        tracing::info!(count = activities.len(), "Weekly report created");
+        Ok(WeeklyReport {
             week_start,
             week_end,
             activities,
             total_duration,
+            total_cost,
             daily_durations_per_category,
             duration_per_category,
-        }
+        })
+    }
+
+    /// Creates a new `WeeklyReport` for the week containing `date`, starting on the Monday on or
+    /// before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - A date within the week of the report.
+    /// * `repository` - A reference to an implementation of `ActivitiesListRepository` to fetch activities.
+    /// * `categories_repository` - A reference to an implementation of `AccountingCategoriesListRepository` to look up hourly rates.
+    pub async fn for_week_of(
+        date: NaiveDate,
+        repository: &dyn ActivitiesListRepository,
+        categories_repository: &dyn AccountingCategoriesListRepository,
+    ) -> Result<Self, ActivitiesListRepositoryError> {
+        Self::new(start_of_week(date), repository, categories_repository).await
+    }
+
+    /// Creates a new `WeeklyReport` for the current week, based on the local date.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - A reference to an implementation of `ActivitiesListRepository` to fetch activities.
+    /// * `categories_repository` - A reference to an implementation of `AccountingCategoriesListRepository` to look up hourly rates.
+    pub async fn for_current_week(
+        repository: &dyn ActivitiesListRepository,
+        categories_repository: &dyn AccountingCategoriesListRepository,
+    ) -> Result<Self, ActivitiesListRepositoryError> {
+        Self::for_week_of(Local::now().date_naive(), repository, categories_repository).await
     }
 
     fn calculate_daily_durations_per_category(
         activities: &[Activity],
         week_start: NaiveDate,
-    ) -> Vec<(NaiveDate, Vec<(AccountingCategoryId, Duration)>)> {
+    ) -> Vec<DailyCategoryDurations> {
         let mut daily_durations = Vec::new();
 
         // iterate through each day of the week
@@ -90,7 +155,11 @@ impl WeeklyReport {
             }
 
             // Convert the daily category durations into a vector and add it to the daily durations
-            daily_durations.push((current_date, daily_category_durations.into_iter().collect()));
+            daily_durations.push((
+                current_date,
+                is_weekend(current_date),
+                daily_category_durations.into_iter().collect(),
+            ));
         }
 
         daily_durations
@@ -106,44 +175,324 @@ impl WeeklyReport {
         self.week_end
     }
 
-    /// Returns a slice of activities included in the report.
+    /// Returns a slice of activities included in the report, ordered by date and start time.
     pub fn activities(&self) -> &[Activity] {
         &self.activities
     }
 
+    /// Returns the activities of the report on the given date, ordered by start time.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date to return the activities for.
+    pub fn activities_for_day(&self, date: NaiveDate) -> &[Activity] {
+        let start = self
+            .activities
+            .partition_point(|activity| *activity.date() < date);
+        let end = self
+            .activities
+            .partition_point(|activity| *activity.date() <= date);
+
+        &self.activities[start..end]
+    }
+
+    /// Returns each day of the week together with the activities of the report on that day.
+    pub fn activities_per_day(&self) -> Vec<(NaiveDate, &[Activity])> {
+        (0..7)
+            .map(|day_offset| {
+                let date = self.week_start + Duration::days(day_offset);
+                (date, self.activities_for_day(date))
+            })
+            .collect()
+    }
+
     /// Returns the total duration of all activities in the report.
     pub fn total_duration(&self) -> Duration {
         self.total_duration
     }
 
+    /// Returns the total cost of all activities with a known hourly rate, if any.
+    pub fn total_cost(&self) -> Option<f64> {
+        self.total_cost
+    }
+
+    /// Estimates the total cost of the activities of the report based on the given categories.
+    ///
+    /// Unlike `total_cost`, which uses the hourly rates known when the report was created, this
+    /// allows estimating the cost with other rates, e.g. to preview a change of the billing rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `categories` - The accounting categories used to look up the hourly rates.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the costs of all activities with a known hourly rate, or `None` if no rate is known for any activity.
+    pub fn total_cost_estimate(&self, categories: &[AccountingCategory]) -> Option<f64> {
+        total_cost(&self.activities, categories)
+    }
+
     /// Returns a vector of tuples containing accounting category IDs and their corresponding total durations.
     pub fn duration_per_category(&self) -> &[(AccountingCategoryId, Duration)] {
         &self.duration_per_category
     }
 
-    /// Returns a vector of tuples containing each day of the week and a vector of tuples with accounting category IDs and their corresponding total durations for that day.
-    pub fn daily_durations_per_category(
+    /// Returns a vector of tuples containing each day of the week, whether the day is on a weekend and a vector of tuples with accounting category IDs and their corresponding total durations for that day.
+    pub fn daily_durations_per_category(&self) -> &[DailyCategoryDurations] {
+        &self.daily_durations_per_category
+    }
+
+    /// Returns the signed difference between the total duration of the week and the `target`.
+    ///
+    /// A positive duration means the target was exceeded, a negative duration means the week fell short of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The duration that should be worked during the week (e.g. 40 hours).
+    pub fn overtime(&self, target: Duration) -> Duration {
+        self.total_duration - target
+    }
+
+    /// Returns the signed difference between the duration worked on each day of the week and the `daily_target`.
+    ///
+    /// Weekend days are expected to be free, so their target is zero and any work on them counts as overtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `daily_target` - The duration that should be worked on each working day (e.g. 8 hours).
+    pub fn overtime_per_day(&self, daily_target: Duration) -> Vec<(NaiveDate, Duration)> {
+        self.daily_durations_per_category
+            .iter()
+            .map(|(date, is_weekend, durations)| {
+                let worked: Duration = durations.iter().map(|(_, duration)| *duration).sum();
+                let target = if *is_weekend {
+                    Duration::zero()
+                } else {
+                    daily_target
+                };
+                (*date, worked - target)
+            })
+            .collect()
+    }
+
+    /// Compares the report to the report of another week, typically the previous one.
+    ///
+    /// All deltas are signed and calculated as the durations of this report minus the durations of `other`,
+    /// so a positive delta means more time was spent in this week.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The report to compare against.
+    pub fn compare(&self, other: &WeeklyReport) -> WeeklyComparison {
+        let other_durations: HashMap<&AccountingCategoryId, Duration> = other
+            .duration_per_category
+            .iter()
+            .map(|(category_id, duration)| (category_id, *duration))
+            .collect();
+        let own_durations: HashMap<&AccountingCategoryId, Duration> = self
+            .duration_per_category
+            .iter()
+            .map(|(category_id, duration)| (category_id, *duration))
+            .collect();
+
+        let categories_gained: Vec<AccountingCategoryId> = self
+            .duration_per_category
+            .iter()
+            .filter(|(category_id, _)| !other_durations.contains_key(category_id))
+            .map(|(category_id, _)| category_id.clone())
+            .collect();
+        let categories_lost: Vec<AccountingCategoryId> = other
+            .duration_per_category
+            .iter()
+            .filter(|(category_id, _)| !own_durations.contains_key(category_id))
+            .map(|(category_id, _)| category_id.clone())
+            .collect();
+
+        let duration_delta_per_category = self
+            .duration_per_category
+            .iter()
+            .map(|(category_id, duration)| {
+                let other_duration = other_durations
+                    .get(category_id)
+                    .copied()
+                    .unwrap_or_else(Duration::zero);
+                (category_id.clone(), *duration - other_duration)
+            })
+            .chain(
+                other
+                    .duration_per_category
+                    .iter()
+                    .filter(|(category_id, _)| !own_durations.contains_key(category_id))
+                    .map(|(category_id, duration)| (category_id.clone(), -*duration)),
+            )
+            .collect();
+
+        WeeklyComparison {
+            total_duration_delta: self.total_duration - other.total_duration,
+            categories_gained,
+            categories_lost,
+            duration_delta_per_category,
+        }
+    }
+
+    /// Resolves the accounting category IDs of the report to their names.
+    ///
+    /// Categories that are unknown to the repository are keyed by their ID instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `categories_repository` - A reference to an implementation of `AccountingCategoriesListRepository` to look up the category names.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(WeeklyReportWithNames)` - The durations of the report keyed by category name.
+    /// * `Err(AccountingCategoriesListRepositoryError)` - If the categories could not be fetched.
+    pub async fn resolve_names(
         &self,
-    ) -> &[(NaiveDate, Vec<(AccountingCategoryId, Duration)>)] {
+        categories_repository: &dyn AccountingCategoriesListRepository,
+    ) -> Result<WeeklyReportWithNames, AccountingCategoriesListRepositoryError> {
+        let categories = categories_repository.get_all(true).await?;
+        let category_names: HashMap<AccountingCategoryId, String> = self
+            .duration_per_category
+            .iter()
+            .map(|(category_id, _)| {
+                let name = categories
+                    .iter()
+                    .find(|category| category.id() == category_id)
+                    .map_or_else(|| category_id.to_string(), |c| c.name().to_string());
+                (category_id.clone(), name)
+            })
+            .collect();
+
+        let sum_by_name = |durations: &[(AccountingCategoryId, Duration)]| {
+            let mut durations_by_name = HashMap::new();
+            for (category_id, duration) in durations {
+                *durations_by_name
+                    .entry(category_names[category_id].clone())
+                    .or_insert(Duration::zero()) += *duration;
+            }
+            durations_by_name
+        };
+
+        let duration_per_category = sum_by_name(&self.duration_per_category);
+        let daily_durations_per_category = self
+            .daily_durations_per_category
+            .iter()
+            .map(|(date, _, durations)| (*date, sum_by_name(durations)))
+            .collect();
+
+        Ok(WeeklyReportWithNames {
+            category_names,
+            duration_per_category,
+            daily_durations_per_category,
+        })
+    }
+}
+
+/// The durations of a `WeeklyReport` keyed by accounting category name instead of ID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeeklyReportWithNames {
+    /// A map of the accounting category IDs of the report to their names.
+    category_names: HashMap<AccountingCategoryId, String>,
+
+    /// A map of accounting category names to their total durations.
+    duration_per_category: HashMap<String, Duration>,
+
+    /// A vector of tuples containing each day of the week and a map of accounting category names to their total durations for that day.
+    daily_durations_per_category: Vec<(NaiveDate, HashMap<String, Duration>)>,
+}
+
+impl WeeklyReportWithNames {
+    /// Returns a map of the accounting category IDs of the report to their names.
+    pub fn category_names(&self) -> &HashMap<AccountingCategoryId, String> {
+        &self.category_names
+    }
+
+    /// Returns a map of accounting category names to their total durations.
+    pub fn duration_per_category(&self) -> &HashMap<String, Duration> {
+        &self.duration_per_category
+    }
+
+    /// Returns a vector of tuples containing each day of the week and a map of accounting category names to their total durations for that day.
+    pub fn daily_durations_per_category(&self) -> &[(NaiveDate, HashMap<String, Duration>)] {
         &self.daily_durations_per_category
     }
 }
 
+/// The differences between two weekly reports, as calculated by `WeeklyReport::compare`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeeklyComparison {
+    /// The signed difference between the total durations of the compared weeks.
+    total_duration_delta: Duration,
+
+    /// The accounting categories with activities in the compared week only.
+    categories_gained: Vec<AccountingCategoryId>,
+
+    /// The accounting categories with activities in the week compared against only.
+    categories_lost: Vec<AccountingCategoryId>,
+
+    /// A vector of tuples containing the accounting category IDs of both weeks and the signed differences of their total durations.
+    duration_delta_per_category: Vec<(AccountingCategoryId, Duration)>,
+}
+
+impl WeeklyComparison {
+    /// Returns the signed difference between the total durations of the compared weeks.
+    pub fn total_duration_delta(&self) -> Duration {
+        self.total_duration_delta
+    }
+
+    /// Returns the accounting categories with activities in the compared week only.
+    pub fn categories_gained(&self) -> &[AccountingCategoryId] {
+        &self.categories_gained
+    }
+
+    /// Returns the accounting categories with activities in the week compared against only.
+    pub fn categories_lost(&self) -> &[AccountingCategoryId] {
+        &self.categories_lost
+    }
+
+    /// Returns a vector of tuples containing the accounting category IDs of both weeks and the signed differences of their total durations.
+    pub fn duration_delta_per_category(&self) -> &[(AccountingCategoryId, Duration)] {
+        &self.duration_delta_per_category
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
-    use chrono::NaiveTime;
+    use chrono::{Datelike, NaiveTime};
     use tokio::sync::Mutex;
 
     use crate::{
-        entities::accounting::AccountingCategoryId,
-        infra::repositories::in_memory::activities_list::InMemoryActivitiesListRepository,
+        entities::accounting::{AccountingCategory, AccountingCategoryId},
+        infra::repositories::in_memory::{
+            accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+            activities_list::InMemoryActivitiesListRepository,
+        },
         use_cases::activities_list::ActivitiesList,
     };
 
     use super::*;
 
+    #[tokio::test]
+    async fn for_week_of_should_start_report_on_preceding_monday() {
+        let repository = InMemoryActivitiesListRepository::new();
+
+        let report = WeeklyReport::for_week_of(
+            NaiveDate::from_ymd_opt(2023, 10, 5).unwrap(), // Thursday
+            &repository,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            report.week_start(),
+            NaiveDate::from_ymd_opt(2023, 10, 2).unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn weekly_report_should_aggregate_activities_and_calculate_total_duration() {
         let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
@@ -157,8 +506,10 @@ mod tests {
                 AccountingCategoryId::new(),
                 "Activity 1".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         let _activity2 = activities_list
             .record(
@@ -168,8 +519,10 @@ mod tests {
                 AccountingCategoryId::new(),
                 "Activity 2".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         let _activity3 = activities_list
             .record(
@@ -179,14 +532,18 @@ mod tests {
                 AccountingCategoryId::new(),
                 "Activity 3".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         let report = WeeklyReport::new(
             NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(), // Start of the week (Monday)
             &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
         )
-        .await;
+        .await
+        .unwrap();
 
         assert_eq!(
             report.week_start(),
@@ -194,7 +551,7 @@ mod tests {
         );
         assert_eq!(
             report.week_end(),
-            NaiveDate::from_ymd_opt(2023, 10, 9).unwrap()
+            NaiveDate::from_ymd_opt(2023, 10, 8).unwrap()
         );
         assert_eq!(report.activities().len(), 3);
         assert_eq!(
@@ -203,6 +560,67 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn activities_per_day_should_group_activities_by_date() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+
+        for (day, hour, task) in [
+            (4, 13, "Wednesday afternoon"),
+            (2, 9, "Monday"),
+            (4, 9, "Wednesday morning"),
+            (6, 14, "Friday"),
+        ] {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(hour, 0, 0).expect("Valid activity start time"),
+                    None,
+                    AccountingCategoryId::new(),
+                    task.to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        let report = WeeklyReport::new(
+            NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(), // Start of the week (Monday)
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap();
+
+        let tasks = |activities: &[Activity]| {
+            activities
+                .iter()
+                .map(|activity| activity.task().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            tasks(report.activities_for_day(NaiveDate::from_ymd_opt(2023, 10, 4).unwrap())),
+            vec!["Wednesday morning", "Wednesday afternoon"]
+        );
+        assert!(
+            report
+                .activities_for_day(NaiveDate::from_ymd_opt(2023, 10, 3).unwrap())
+                .is_empty()
+        );
+
+        let per_day = report
+            .activities_per_day()
+            .into_iter()
+            .map(|(date, activities)| (date.day(), activities.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            per_day,
+            vec![(2, 1), (3, 0), (4, 2), (5, 0), (6, 1), (7, 0), (8, 0)]
+        );
+    }
+
     #[tokio::test]
     async fn weekly_report_with_no_activities_should_have_zero_duration() {
         let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
@@ -210,8 +628,10 @@ mod tests {
         let report = WeeklyReport::new(
             NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(), // Start of the week (Monday)
             &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
         )
-        .await;
+        .await
+        .unwrap();
 
         assert_eq!(
             report.week_start(),
@@ -219,12 +639,36 @@ mod tests {
         );
         assert_eq!(
             report.week_end(),
-            NaiveDate::from_ymd_opt(2023, 10, 9).unwrap()
+            NaiveDate::from_ymd_opt(2023, 10, 8).unwrap()
         );
         assert_eq!(report.activities().len(), 0);
         assert_eq!(report.total_duration(), Duration::zero());
     }
 
+    #[tokio::test]
+    async fn weekly_report_should_flag_weekend_days() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+
+        let report = WeeklyReport::new(
+            NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(), // Start of the week (Monday)
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap();
+
+        let weekend_flags: Vec<bool> = report
+            .daily_durations_per_category()
+            .iter()
+            .map(|(_, is_weekend, _)| *is_weekend)
+            .collect();
+
+        assert_eq!(
+            weekend_flags,
+            vec![false, false, false, false, false, true, true]
+        );
+    }
+
     #[tokio::test]
     async fn weekly_report_should_calculate_duration_per_category() {
         let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
@@ -241,8 +685,10 @@ mod tests {
                 category1.clone(),
                 "Activity 1".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         let _activity2 = activities_list
             .record(
@@ -252,8 +698,10 @@ mod tests {
                 category2.clone(),
                 "Activity 2".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         let _activity3 = activities_list
             .record(
@@ -263,14 +711,18 @@ mod tests {
                 category2.clone(),
                 "Activity 3".to_string(),
                 None,
+                None,
             )
-            .await;
+            .await
+            .expect("Valid activity");
 
         let report = WeeklyReport::new(
             NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(), // Start of the week (Monday)
             &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
         )
-        .await;
+        .await
+        .unwrap();
 
         let mut duration_map = std::collections::HashMap::new();
         for (category_id, duration) in report.duration_per_category() {
@@ -283,4 +735,389 @@ mod tests {
             Some(&(Duration::hours(2) + Duration::minutes(45)))
         );
     }
+
+    async fn report_with_daily_hours(hours: &[(u32, u32)]) -> WeeklyReport {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+
+        for (day, hours) in hours {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, *day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(8, 0, 0).expect("Valid activity start time"),
+                    Some(
+                        NaiveTime::from_hms_opt(8 + hours, 0, 0).expect("Valid activity end time"),
+                    ),
+                    AccountingCategoryId::new(),
+                    "Activity".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        WeeklyReport::new(
+            NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(), // Start of the week (Monday)
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn overtime_should_be_zero_when_target_is_met_exactly() {
+        let report = report_with_daily_hours(&[(2, 8), (3, 8), (4, 8), (5, 8), (6, 8)]).await;
+
+        assert_eq!(report.overtime(Duration::hours(40)), Duration::zero());
+        assert!(
+            report
+                .overtime_per_day(Duration::hours(8))
+                .iter()
+                .all(|(_, overtime)| *overtime == Duration::zero())
+        );
+    }
+
+    #[tokio::test]
+    async fn overtime_should_be_positive_when_target_is_exceeded() {
+        let report =
+            report_with_daily_hours(&[(2, 10), (3, 8), (4, 8), (5, 8), (6, 8), (7, 2)]).await;
+
+        assert_eq!(report.overtime(Duration::hours(40)), Duration::hours(4));
+
+        let overtime_per_day = report.overtime_per_day(Duration::hours(8));
+        assert_eq!(
+            overtime_per_day[0],
+            (
+                NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(),
+                Duration::hours(2)
+            )
+        );
+        assert_eq!(
+            overtime_per_day[5],
+            (
+                NaiveDate::from_ymd_opt(2023, 10, 7).unwrap(),
+                Duration::hours(2)
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn overtime_should_be_negative_when_target_is_missed() {
+        let report = report_with_daily_hours(&[(2, 8), (3, 6), (4, 8), (5, 8)]).await;
+
+        assert_eq!(report.overtime(Duration::hours(40)), Duration::hours(-10));
+
+        let overtime_per_day: Vec<Duration> = report
+            .overtime_per_day(Duration::hours(8))
+            .into_iter()
+            .map(|(_, overtime)| overtime)
+            .collect();
+        assert_eq!(
+            overtime_per_day,
+            vec![
+                Duration::zero(),
+                Duration::hours(-2),
+                Duration::zero(),
+                Duration::zero(),
+                Duration::hours(-8),
+                Duration::zero(),
+                Duration::zero(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn total_cost_estimate_should_match_total_cost_with_stored_rates() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+        let mut categories_repository = InMemoryAccountingCategoriesListRepository::new();
+
+        let mut development = AccountingCategory::new("Development".to_string());
+        development.set_hourly_rate(Some(100.0));
+        let meetings = AccountingCategory::new("Meetings".to_string());
+        categories_repository
+            .add(development.clone())
+            .await
+            .unwrap();
+        categories_repository.add(meetings.clone()).await.unwrap();
+
+        for (day, start, end, category_id) in [
+            (2, 9, 11, development.id()),
+            (3, 9, 10, meetings.id()),
+            (4, 13, 14, development.id()),
+        ] {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(start, 0, 0).expect("Valid activity start time"),
+                    Some(NaiveTime::from_hms_opt(end, 0, 0).expect("Valid activity end time")),
+                    category_id.clone(),
+                    "Activity".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        let report = WeeklyReport::new(
+            NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(),
+            &*repository.lock().await,
+            &categories_repository,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.total_cost(), Some(300.0));
+        assert_eq!(
+            report.total_cost_estimate(&categories_repository.get_all(true).await.unwrap()),
+            Some(300.0)
+        );
+
+        development.set_hourly_rate(Some(120.0));
+        assert_eq!(
+            report.total_cost_estimate(&[development, meetings]),
+            Some(360.0)
+        );
+    }
+
+    #[tokio::test]
+    async fn resolve_names_should_key_durations_by_category_name() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+        let mut categories_repository = InMemoryAccountingCategoriesListRepository::new();
+
+        let development = AccountingCategory::new("Development".to_string());
+        let meetings = AccountingCategory::new("Meetings".to_string());
+        categories_repository
+            .add(development.clone())
+            .await
+            .unwrap();
+        categories_repository.add(meetings.clone()).await.unwrap();
+
+        for (day, start, end, category_id) in [
+            (2, 9, 11, development.id()),
+            (2, 11, 12, meetings.id()),
+            (3, 9, 10, development.id()),
+        ] {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, day).expect("Valid activity date"),
+                    NaiveTime::from_hms_opt(start, 0, 0).expect("Valid activity start time"),
+                    Some(NaiveTime::from_hms_opt(end, 0, 0).expect("Valid activity end time")),
+                    category_id.clone(),
+                    "Activity".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        let report = WeeklyReport::new(
+            NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(),
+            &*repository.lock().await,
+            &categories_repository,
+        )
+        .await
+        .unwrap();
+        let with_names = report.resolve_names(&categories_repository).await.unwrap();
+
+        assert_eq!(
+            with_names.category_names().get(development.id()),
+            Some(&"Development".to_string())
+        );
+        assert_eq!(
+            with_names.duration_per_category().get("Development"),
+            Some(&Duration::hours(3))
+        );
+        assert_eq!(
+            with_names.duration_per_category().get("Meetings"),
+            Some(&Duration::hours(1))
+        );
+
+        let (monday, monday_durations) = &with_names.daily_durations_per_category()[0];
+        assert_eq!(*monday, NaiveDate::from_ymd_opt(2023, 10, 2).unwrap());
+        assert_eq!(
+            monday_durations.get("Development"),
+            Some(&Duration::hours(2))
+        );
+        assert_eq!(monday_durations.get("Meetings"), Some(&Duration::hours(1)));
+        assert!(with_names.daily_durations_per_category()[2].1.is_empty());
+    }
+
+    #[tokio::test]
+    async fn resolve_names_should_fall_back_to_id_for_unknown_category() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+        let categories_repository = InMemoryAccountingCategoriesListRepository::new();
+        let unknown_category = AccountingCategoryId::new();
+
+        activities_list
+            .record(
+                NaiveDate::from_ymd_opt(2023, 10, 2).expect("Valid activity date"),
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                Some(NaiveTime::from_hms_opt(10, 0, 0).expect("Valid activity end time")),
+                unknown_category.clone(),
+                "Activity".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+
+        let report = WeeklyReport::new(
+            NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(),
+            &*repository.lock().await,
+            &categories_repository,
+        )
+        .await
+        .unwrap();
+        let with_names = report.resolve_names(&categories_repository).await.unwrap();
+
+        assert_eq!(
+            with_names
+                .duration_per_category()
+                .get(&unknown_category.to_string()),
+            Some(&Duration::hours(1))
+        );
+    }
+
+    /// Creates a report for the week starting on `week_start` with an activity on the Monday for each
+    /// of the given `(category, hours)` pairs.
+    async fn report_with_category_hours(
+        week_start: NaiveDate,
+        hours: &[(AccountingCategoryId, u32)],
+    ) -> WeeklyReport {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+
+        let mut start_time = NaiveTime::from_hms_opt(8, 0, 0).expect("Valid activity start time");
+        for (category_id, hours) in hours {
+            let end_time = start_time + Duration::hours(i64::from(*hours));
+            activities_list
+                .record(
+                    week_start,
+                    start_time,
+                    Some(end_time),
+                    category_id.clone(),
+                    "Activity".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+            start_time = end_time;
+        }
+
+        WeeklyReport::new(
+            week_start,
+            &*repository.lock().await,
+            &InMemoryAccountingCategoriesListRepository::new(),
+        )
+        .await
+        .unwrap()
+    }
+
+    fn previous_week_start() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, 10, 2).unwrap()
+    }
+
+    fn week_start() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, 10, 9).unwrap()
+    }
+
+    #[tokio::test]
+    async fn compare_should_have_zero_deltas_for_identical_weeks() {
+        let hours = [
+            (AccountingCategoryId::new(), 3),
+            (AccountingCategoryId::new(), 2),
+        ];
+        let previous = report_with_category_hours(previous_week_start(), &hours).await;
+        let current = report_with_category_hours(week_start(), &hours).await;
+
+        let comparison = current.compare(&previous);
+
+        assert_eq!(comparison.total_duration_delta(), Duration::zero());
+        assert!(comparison.categories_gained().is_empty());
+        assert!(comparison.categories_lost().is_empty());
+        assert_eq!(comparison.duration_delta_per_category().len(), 2);
+        assert!(
+            comparison
+                .duration_delta_per_category()
+                .iter()
+                .all(|(_, delta)| *delta == Duration::zero())
+        );
+    }
+
+    #[tokio::test]
+    async fn compare_should_have_positive_delta_for_growing_category() {
+        let category = AccountingCategoryId::new();
+        let previous =
+            report_with_category_hours(previous_week_start(), &[(category.clone(), 2)]).await;
+        let current = report_with_category_hours(week_start(), &[(category.clone(), 5)]).await;
+
+        let comparison = current.compare(&previous);
+
+        assert_eq!(comparison.total_duration_delta(), Duration::hours(3));
+        assert!(comparison.categories_gained().is_empty());
+        assert!(comparison.categories_lost().is_empty());
+        assert_eq!(
+            comparison.duration_delta_per_category(),
+            &[(category, Duration::hours(3))]
+        );
+    }
+
+    #[tokio::test]
+    async fn compare_should_have_negative_delta_for_shrinking_category() {
+        let category = AccountingCategoryId::new();
+        let previous =
+            report_with_category_hours(previous_week_start(), &[(category.clone(), 5)]).await;
+        let current = report_with_category_hours(week_start(), &[(category.clone(), 2)]).await;
+
+        let comparison = current.compare(&previous);
+
+        assert_eq!(comparison.total_duration_delta(), Duration::hours(-3));
+        assert_eq!(
+            comparison.duration_delta_per_category(),
+            &[(category, Duration::hours(-3))]
+        );
+    }
+
+    #[tokio::test]
+    async fn compare_should_list_gained_and_lost_categories() {
+        let kept = AccountingCategoryId::new();
+        let removed = AccountingCategoryId::new();
+        let added = AccountingCategoryId::new();
+        let previous = report_with_category_hours(
+            previous_week_start(),
+            &[(kept.clone(), 2), (removed.clone(), 4)],
+        )
+        .await;
+        let current =
+            report_with_category_hours(week_start(), &[(kept.clone(), 2), (added.clone(), 1)])
+                .await;
+
+        let comparison = current.compare(&previous);
+
+        assert_eq!(comparison.total_duration_delta(), Duration::hours(-3));
+        assert_eq!(comparison.categories_gained(), std::slice::from_ref(&added));
+        assert_eq!(comparison.categories_lost(), std::slice::from_ref(&removed));
+
+        let deltas: HashMap<AccountingCategoryId, Duration> = comparison
+            .duration_delta_per_category()
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(
+            deltas,
+            HashMap::from([
+                (kept, Duration::zero()),
+                (added, Duration::hours(1)),
+                (removed, Duration::hours(-4)),
+            ])
+        );
+    }
 }
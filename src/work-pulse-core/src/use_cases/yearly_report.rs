@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+use crate::{
+    adapters::{ActivitiesListRepository, ActivitiesListRepositoryError},
+    entities::accounting::AccountingCategoryId,
+};
+
+/// A report summarizing activities for a whole calendar year.
+#[derive(Debug, Clone, PartialEq)]
+pub struct YearlyReport {
+    /// The reported year.
+    year: i32,
+
+    /// The total duration of all activities recorded during the year.
+    total_duration: Duration,
+
+    /// A vector of tuples containing accounting category IDs and their corresponding total durations.
+    duration_per_category: Vec<(AccountingCategoryId, Duration)>,
+
+    /// A vector of tuples containing each month (1-12) of the year and the total duration of that month.
+    monthly_breakdown: Vec<(u32, Duration)>,
+}
+
+impl YearlyReport {
+    /// Creates a new `YearlyReport` for the given calendar year.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year to report on.
+    /// * `repository` - A reference to an implementation of `ActivitiesListRepository` to fetch activities.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(YearlyReport))` - The report for the year.
+    /// * `Ok(None)` - If `year` is outside the range of supported dates.
+    /// * `Err(ActivitiesListRepositoryError)` - If the activities could not be fetched.
+    #[tracing::instrument(skip(repository))]
+    pub async fn new(
+        year: i32,
+        repository: &dyn ActivitiesListRepository,
+    ) -> Result<Option<Self>, ActivitiesListRepositoryError> {
+        tracing::debug!("Creating yearly report");
+        let (Some(year_start), Some(year_end)) = (
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            NaiveDate::from_ymd_opt(year, 12, 31),
+        ) else {
+            return Ok(None);
+        };
+        let activities = repository.get_by_date_range(year_start, year_end).await?;
+
+        let total_duration = activities.iter().map(|activity| activity.duration()).sum();
+
+        let mut category_durations = HashMap::new();
+        let mut monthly_breakdown: Vec<(u32, Duration)> =
+            (1..=12).map(|month| (month, Duration::zero())).collect();
+        for activity in &activities {
+            *category_durations
+                .entry(activity.accounting_category_id().clone())
+                .or_insert(Duration::zero()) += activity.duration();
+            monthly_breakdown[activity.date().month0() as usize].1 += activity.duration();
+        }
+        let duration_per_category = category_durations.into_iter().collect();
+
+        tracing::info!(count = activities.len(), "Yearly report created");
+        Ok(Some(YearlyReport {
+            year,
+            total_duration,
+            duration_per_category,
+            monthly_breakdown,
+        }))
+    }
+
+    /// Returns the reported year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the total duration of all activities in the report.
+    pub fn total_duration(&self) -> Duration {
+        self.total_duration
+    }
+
+    /// Returns a vector of tuples containing accounting category IDs and their corresponding total durations.
+    pub fn duration_per_category(&self) -> &[(AccountingCategoryId, Duration)] {
+        &self.duration_per_category
+    }
+
+    /// Returns a vector of tuples containing each month (1-12) of the year and the total duration of that month.
+    pub fn monthly_breakdown(&self) -> &[(u32, Duration)] {
+        &self.monthly_breakdown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::NaiveTime;
+    use tokio::sync::Mutex;
+
+    use crate::{
+        infra::repositories::in_memory::activities_list::InMemoryActivitiesListRepository,
+        use_cases::activities_list::ActivitiesList,
+    };
+
+    use super::*;
+
+    async fn record(
+        activities_list: &mut ActivitiesList<InMemoryActivitiesListRepository>,
+        date: NaiveDate,
+        hours: u32,
+        category_id: &AccountingCategoryId,
+    ) {
+        activities_list
+            .record(
+                date,
+                NaiveTime::from_hms_opt(9, 0, 0).expect("Valid activity start time"),
+                Some(NaiveTime::from_hms_opt(9 + hours, 0, 0).expect("Valid activity end time")),
+                category_id.clone(),
+                "Activity".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+    }
+
+    #[tokio::test]
+    async fn yearly_report_should_aggregate_activities_of_the_year() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let mut activities_list = ActivitiesList::new(repository.clone());
+        let category1 = AccountingCategoryId::new();
+        let category2 = AccountingCategoryId::new();
+
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+        record(&mut activities_list, date(2024, 12, 31), 1, &category1).await;
+        record(&mut activities_list, date(2025, 1, 1), 2, &category1).await;
+        record(&mut activities_list, date(2025, 1, 15), 1, &category2).await;
+        record(&mut activities_list, date(2025, 6, 30), 3, &category2).await;
+        record(&mut activities_list, date(2025, 12, 31), 4, &category1).await;
+        record(&mut activities_list, date(2026, 1, 1), 1, &category2).await;
+
+        let report = YearlyReport::new(2025, &*repository.lock().await)
+            .await
+            .unwrap()
+            .expect("Valid year");
+
+        assert_eq!(report.year(), 2025);
+        assert_eq!(report.total_duration(), Duration::hours(10));
+
+        let duration_map: HashMap<_, _> = report.duration_per_category().iter().cloned().collect();
+        assert_eq!(duration_map.len(), 2);
+        assert_eq!(duration_map.get(&category1), Some(&Duration::hours(6)));
+        assert_eq!(duration_map.get(&category2), Some(&Duration::hours(4)));
+
+        let monthly_breakdown = report.monthly_breakdown();
+        assert_eq!(monthly_breakdown.len(), 12);
+        assert_eq!(monthly_breakdown[0], (1, Duration::hours(3)));
+        assert_eq!(monthly_breakdown[1], (2, Duration::zero()));
+        assert_eq!(monthly_breakdown[5], (6, Duration::hours(3)));
+        assert_eq!(monthly_breakdown[11], (12, Duration::hours(4)));
+    }
+
+    #[tokio::test]
+    async fn yearly_report_should_be_empty_for_year_without_activities() {
+        let repository = Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+
+        let report = YearlyReport::new(2025, &*repository.lock().await)
+            .await
+            .unwrap()
+            .expect("Valid year");
+
+        assert_eq!(report.year(), 2025);
+        assert_eq!(report.total_duration(), Duration::zero());
+        assert!(report.duration_per_category().is_empty());
+        assert_eq!(report.monthly_breakdown().len(), 12);
+        assert!(
+            report
+                .monthly_breakdown()
+                .iter()
+                .all(|(_, duration)| *duration == Duration::zero())
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+/// Checks whether a date falls on a weekend (Saturday or Sunday).
+///
+/// # Arguments
+///
+/// - `date`: The date to check.
+///
+/// # Returns
+///
+/// - `true` if the date is a Saturday or Sunday, `false` otherwise.
+pub fn is_weekend(date: NaiveDate) -> bool {
+    date.weekday() == Weekday::Sat || date.weekday() == Weekday::Sun
+}
+
+/// Counts the working days (Monday to Friday) between two dates.
+///
+/// # Arguments
+///
+/// - `start`: The first date of the period (inclusive).
+/// - `end`: The last date of the period (inclusive).
+///
+/// # Returns
+///
+/// - The number of weekdays in the period, or `0` if `end` is before `start`.
+pub fn count_working_days(start: NaiveDate, end: NaiveDate) -> u32 {
+    start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .filter(|date| !is_weekend(*date))
+        .count() as u32
+}
+
+/// Returns the Monday of the week a date falls in.
+///
+/// # Arguments
+///
+/// - `date`: A date within the week.
+///
+/// # Returns
+///
+/// - The date itself if it is a Monday, otherwise the preceding Monday.
+pub fn start_of_week(date: NaiveDate) -> NaiveDate {
+    date.week(Weekday::Mon).first_day()
+}
+
+/// Returns the first and last date of an ISO 8601 calendar week.
+///
+/// # Arguments
+///
+/// - `year`: The ISO week-numbering year, which can differ from the calendar year around new year.
+/// - `week`: The ISO week number (1-53).
+///
+/// # Returns
+///
+/// - `Some((monday, sunday))` of the week, or `None` if the year has no such week.
+pub fn iso_week_range(year: i32, week: u32) -> Option<(NaiveDate, NaiveDate)> {
+    let monday = NaiveDate::from_isoywd_opt(year, week, Weekday::Mon)?;
+    let sunday = NaiveDate::from_isoywd_opt(year, week, Weekday::Sun)?;
+    Some((monday, sunday))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        // 2025-10-13 is a Monday.
+        NaiveDate::from_ymd_opt(2025, 10, day).expect("Valid date")
+    }
+
+    #[test]
+    fn is_weekend_should_return_false_for_monday() {
+        assert!(!is_weekend(date(13)));
+    }
+
+    #[test]
+    fn is_weekend_should_return_false_for_tuesday() {
+        assert!(!is_weekend(date(14)));
+    }
+
+    #[test]
+    fn is_weekend_should_return_false_for_wednesday() {
+        assert!(!is_weekend(date(15)));
+    }
+
+    #[test]
+    fn is_weekend_should_return_false_for_thursday() {
+        assert!(!is_weekend(date(16)));
+    }
+
+    #[test]
+    fn is_weekend_should_return_false_for_friday() {
+        assert!(!is_weekend(date(17)));
+    }
+
+    #[test]
+    fn is_weekend_should_return_true_for_saturday() {
+        assert!(is_weekend(date(18)));
+    }
+
+    #[test]
+    fn is_weekend_should_return_true_for_sunday() {
+        assert!(is_weekend(date(19)));
+    }
+
+    #[test]
+    fn count_working_days_should_exclude_weekends() {
+        assert_eq!(count_working_days(date(13), date(19)), 5);
+        assert_eq!(count_working_days(date(13), date(26)), 10);
+    }
+
+    #[test]
+    fn count_working_days_should_count_single_day() {
+        assert_eq!(count_working_days(date(13), date(13)), 1);
+        assert_eq!(count_working_days(date(18), date(18)), 0);
+    }
+
+    #[test]
+    fn count_working_days_should_return_zero_if_end_is_before_start() {
+        assert_eq!(count_working_days(date(19), date(13)), 0);
+    }
+
+    #[test]
+    fn start_of_week_should_return_monday_of_the_week() {
+        for day in 13..=19 {
+            assert_eq!(start_of_week(date(day)), date(13));
+        }
+        assert_eq!(start_of_week(date(20)), date(20));
+    }
+
+    #[test]
+    fn start_of_week_should_cross_month_boundary() {
+        assert_eq!(
+            start_of_week(NaiveDate::from_ymd_opt(2025, 11, 2).expect("Valid date")),
+            date(27)
+        );
+    }
+
+    #[test]
+    fn iso_week_range_should_span_monday_to_sunday() {
+        assert_eq!(iso_week_range(2025, 42), Some((date(13), date(19))));
+        // The first ISO week of 2026 starts in 2025.
+        assert_eq!(
+            iso_week_range(2026, 1),
+            Some((
+                NaiveDate::from_ymd_opt(2025, 12, 29).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 1, 4).unwrap()
+            ))
+        );
+        assert_eq!(iso_week_range(2025, 53), None);
+    }
+}
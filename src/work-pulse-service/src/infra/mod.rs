@@ -0,0 +1,2 @@
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
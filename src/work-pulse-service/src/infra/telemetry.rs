@@ -0,0 +1,83 @@
+use std::sync::OnceLock;
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{
+    Resource,
+    trace::{SdkTracerProvider, Tracer},
+};
+
+/// The environment variable holding the endpoint of the OTLP collector, e.g. `http://localhost:4317`.
+pub const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+
+/// The tracer provider created by `init_tracer`, kept to flush pending spans on shutdown.
+static TRACER_PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
+/// Creates a tracer exporting spans via OTLP gRPC to the collector given by `OTEL_EXPORTER_OTLP_ENDPOINT`.
+///
+/// This runs before the tracing subscriber is initialized, so problems are reported on stderr.
+///
+/// # Arguments
+///
+/// - `service_name`: The name the spans are reported under, e.g. `work-pulse`.
+///
+/// # Returns
+///
+/// - `Some(Tracer)`: The tracer to pass to `tracing_opentelemetry::layer().with_tracer`.
+/// - `None`: If the endpoint is not set or the exporter could not be created.
+pub fn init_tracer(service_name: &str) -> Option<Tracer> {
+    let Some(endpoint) = std::env::var(OTLP_ENDPOINT_ENV)
+        .ok()
+        .filter(|endpoint| !endpoint.trim().is_empty())
+    else {
+        eprintln!(
+            "OpenTelemetry is enabled, but {} is not set. Spans are not exported.",
+            OTLP_ENDPOINT_ENV
+        );
+        return None;
+    };
+
+    let exporter = match SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint.as_str())
+        .build()
+    {
+        Ok(exporter) => exporter,
+        Err(err) => {
+            eprintln!(
+                "Failed to create the OTLP exporter for {}: {}. Spans are not exported.",
+                endpoint, err
+            );
+            return None;
+        }
+    };
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(service_name.to_string())
+                .build(),
+        )
+        .build();
+    let tracer = provider.tracer(service_name.to_string());
+
+    opentelemetry::global::set_tracer_provider(provider.clone());
+    let _ = TRACER_PROVIDER.set(provider);
+
+    Some(tracer)
+}
+
+/// Flushes the pending spans and shuts down the tracer provider created by `init_tracer`, if any.
+pub fn shutdown_tracer() {
+    let Some(provider) = TRACER_PROVIDER.get() else {
+        return;
+    };
+
+    if let Err(err) = provider.shutdown() {
+        tracing::warn!(
+            "Failed to shut down the OpenTelemetry tracer provider: {}",
+            err
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod middleware;
 pub mod services;
 
 pub mod prelude {
@@ -5,5 +6,7 @@ pub mod prelude {
     pub const ACCOUNTING_CATEGORIES_SERVICE_TAG: &str = "accounting-categories-service";
     pub const DAILY_REPORT_SERVICE_TAG: &str = "daily-report-service";
     pub const HEALTH_CHECK_SERVICE_TAG: &str = "health-check-service";
+    pub const MONTHLY_REPORT_SERVICE_TAG: &str = "monthly-report-service";
     pub const WEEKLY_REPORT_SERVICE_TAG: &str = "weekly-report-service";
+    pub const YEARLY_REPORT_SERVICE_TAG: &str = "yearly-report-service";
 }
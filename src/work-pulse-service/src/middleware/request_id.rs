@@ -0,0 +1,151 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use axum::{extract::Request, http::HeaderValue, response::Response};
+use tower::{Layer, Service};
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// The request and response header carrying the ID used to correlate the logs of a request.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// A `tower::Layer` that assigns an ID to every request.
+///
+/// The ID is taken from the `X-Request-ID` header of the request, or a new UUID v4 is generated if the
+/// header is absent. The request is handled within a `tracing` span carrying the ID as `request_id`
+/// field, and the ID is echoed back in the `X-Request-ID` header of the response.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RequestIdLayer;
+
+impl RequestIdLayer {
+    /// Creates a new `RequestIdLayer`.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for RequestIdLayer {
+    type Service = RequestIdService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestIdService { inner }
+    }
+}
+
+/// The service created by `RequestIdLayer`.
+#[derive(Clone, Debug)]
+pub struct RequestIdService<S> {
+    inner: S,
+}
+
+impl<S> Service<Request> for RequestIdService<S>
+where
+    S: Service<Request, Response = Response>,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request) -> Self::Future {
+        let request_id = request
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .filter(|value| !value.is_empty())
+            .cloned()
+            .unwrap_or_else(|| {
+                HeaderValue::from_str(&Uuid::new_v4().to_string())
+                    .expect("A UUID is a valid header value")
+            });
+        request
+            .headers_mut()
+            .insert(REQUEST_ID_HEADER, request_id.clone());
+
+        let span = tracing::info_span!(
+            "request",
+            request_id = %String::from_utf8_lossy(request_id.as_bytes())
+        );
+        let response = span.in_scope(|| self.inner.call(request));
+
+        Box::pin(
+            async move {
+                let mut response = response.await?;
+                response.headers_mut().insert(REQUEST_ID_HEADER, request_id);
+
+                Ok(response)
+            }
+            .instrument(span),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::{Router, body::Body, http::HeaderMap, routing::get};
+    use tower::ServiceExt;
+
+    fn test_router() -> Router {
+        Router::new()
+            .route(
+                "/",
+                get(|headers: HeaderMap| async move {
+                    headers
+                        .get(REQUEST_ID_HEADER)
+                        .and_then(|value| value.to_str().ok())
+                        .unwrap_or("<none>")
+                        .to_string()
+                }),
+            )
+            .layer(RequestIdLayer::new())
+    }
+
+    async fn send(request: Request) -> (Option<String>, String) {
+        let response = test_router().oneshot(request).await.unwrap();
+        let request_id = response
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .map(|value| value.to_str().unwrap().to_string());
+        let body = axum::body::to_bytes(response.into_body(), 1024)
+            .await
+            .unwrap();
+
+        (request_id, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn request_id_layer_should_generate_uuid_without_header() {
+        let request = axum::http::Request::builder()
+            .uri("/")
+            .body(Body::empty())
+            .unwrap();
+
+        let (request_id, seen_by_handler) = send(request).await;
+
+        let request_id = request_id.expect("Response should carry a request ID");
+        assert!(Uuid::parse_str(&request_id).is_ok());
+        assert_eq!(seen_by_handler, request_id);
+    }
+
+    #[tokio::test]
+    async fn request_id_layer_should_echo_header_value() {
+        let request = axum::http::Request::builder()
+            .uri("/")
+            .header(REQUEST_ID_HEADER, "client-request-42")
+            .body(Body::empty())
+            .unwrap();
+
+        let (request_id, seen_by_handler) = send(request).await;
+
+        assert_eq!(request_id.as_deref(), Some("client-request-42"));
+        assert_eq!(seen_by_handler, "client-request-42");
+    }
+}
@@ -0,0 +1,77 @@
+use axum::{extract::Request, middleware::Next, response::Response};
+
+/// The request header carrying the username or email of the calling user.
+pub const USER_ID_HEADER: &str = "x-user-id";
+
+/// The user that issued the current request, as provided by the `X-User-Id` header.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserId(pub Option<String>);
+
+/// Middleware that reads the optional `X-User-Id` header and stores it as a `UserId` request extension.
+///
+/// # Arguments
+///
+/// - `request`: The incoming request.
+/// - `next`: The next middleware or handler in the chain.
+pub async fn extract_user_id(mut request: Request, next: Next) -> Response {
+    let user_id = request
+        .headers()
+        .get(USER_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_owned);
+
+    request.extensions_mut().insert(UserId(user_id));
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::{Extension, Router, body::Body, routing::get};
+    use tower::ServiceExt;
+
+    fn test_router() -> Router {
+        Router::new()
+            .route(
+                "/",
+                get(|Extension(user_id): Extension<UserId>| async move {
+                    user_id.0.unwrap_or_else(|| "<none>".to_string())
+                }),
+            )
+            .layer(axum::middleware::from_fn(extract_user_id))
+    }
+
+    async fn send(request: Request) -> String {
+        let response = test_router().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), 1024)
+            .await
+            .unwrap();
+
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn extract_user_id_should_store_header_value() {
+        let request = axum::http::Request::builder()
+            .uri("/")
+            .header(USER_ID_HEADER, "jdoe")
+            .body(Body::empty())
+            .unwrap();
+
+        assert_eq!(send(request).await, "jdoe");
+    }
+
+    #[tokio::test]
+    async fn extract_user_id_should_store_none_without_header() {
+        let request = axum::http::Request::builder()
+            .uri("/")
+            .body(Body::empty())
+            .unwrap();
+
+        assert_eq!(send(request).await, "<none>");
+    }
+}
@@ -2,20 +2,47 @@ use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     response::IntoResponse,
 };
 use hyper::StatusCode;
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use utoipa_axum::{router::OpenApiRouter, routes};
 use work_pulse_core::{
-    adapters::AccountingCategoriesListRepository, entities::accounting::AccountingCategoryId,
-    use_cases::accounting_categories_list::AccountingCategoriesList,
+    adapters::{AccountingCategoriesListRepository, ActivitiesListRepository},
+    entities::accounting::AccountingCategoryId,
+    use_cases::{
+        accounting_categories_list::{
+            AccountingCategoriesList, AccountingCategoriesListError, CsvImportError,
+        },
+        activities_list::ActivitiesList,
+    },
+};
+
+use crate::{
+    prelude::ACCOUNTING_CATEGORIES_SERVICE_TAG,
+    services::{
+        activities_list_service::Activity,
+        error::{ServiceError, ValidationError},
+        pagination::PaginatedResponse,
+        params::parse_optional_date_param,
+    },
 };
 
-use crate::prelude::ACCOUNTING_CATEGORIES_SERVICE_TAG;
+/// Shared state for the accounting categories service.
+struct AccountingCategoriesServiceState<R, T>
+where
+    R: 'static + Send + Sync + AccountingCategoriesListRepository,
+    T: 'static + Send + Sync + ActivitiesListRepository,
+{
+    /// The accounting categories repository.
+    accounting_categories_repository: Arc<Mutex<R>>,
+
+    /// The activities list repository.
+    activities_list_repository: Arc<Mutex<T>>,
+}
 
 /// The Accounting Category.
 #[derive(Serialize, Deserialize, ToSchema, Clone)]
@@ -27,6 +54,19 @@ struct AccountingCategory {
     /// The name of the category.
     #[schema(example = "Current Version")]
     name: String,
+
+    /// The hourly rate used to calculate the cost of activities in this category.
+    #[schema(example = 120.0)]
+    hourly_rate: Option<f64>,
+
+    /// A short description of what belongs into this category.
+    #[schema(example = "Development and maintenance of the current product version")]
+    description: Option<String>,
+
+    /// Whether the category is archived, i.e. no longer in use but kept for historical activities.
+    #[serde(default)]
+    #[schema(example = false)]
+    archived: bool,
 }
 
 impl AccountingCategory {
@@ -43,6 +83,9 @@ impl AccountingCategory {
         Self {
             id: Some(entity.id().to_string()),
             name: entity.name().to_string(),
+            hourly_rate: entity.hourly_rate(),
+            description: entity.description().map(str::to_string),
+            archived: entity.is_archived(),
         }
     }
 }
@@ -51,15 +94,25 @@ impl AccountingCategory {
 ///
 /// # Arguments
 ///
-/// - `repository`: An `Arc<Mutex<AccountingCategoriesListRepository>>` instance for accessing the repository.
+/// - `accounting_categories_repository`: An `Arc<Mutex<AccountingCategoriesListRepository>>` instance for accessing the repository.
+/// - `activities_list_repository`: An `Arc<Mutex<ActivitiesListRepository>>` instance for accessing the activities of a category.
 ///
 /// # Returns
 ///
 /// - An `OpenApiRouter` configured with routes for managing accounting categories.
-pub fn router<R>(repository: Arc<Mutex<R>>) -> OpenApiRouter
+pub fn router<R, T>(
+    accounting_categories_repository: Arc<Mutex<R>>,
+    activities_list_repository: Arc<Mutex<T>>,
+) -> OpenApiRouter
 where
     R: 'static + Send + Sync + AccountingCategoriesListRepository,
+    T: 'static + Send + Sync + ActivitiesListRepository,
 {
+    let store = Arc::new(Mutex::new(AccountingCategoriesServiceState {
+        accounting_categories_repository,
+        activities_list_repository,
+    }));
+
     OpenApiRouter::new()
         .routes(routes!(
             list_accounting_categories,
@@ -67,25 +120,63 @@ where
         ))
         .routes(routes!(update_accounting_category))
         .routes(routes!(delete_accounting_category))
-        .with_state(repository)
+        .routes(routes!(list_category_activities))
+        .routes(routes!(merge_accounting_categories))
+        .routes(routes!(import_accounting_categories_csv))
+        .with_state(store)
 }
 
-/// Lists all accounting categories.
+/// Query parameters for listing accounting categories.
+#[derive(Deserialize, IntoParams)]
+struct ListAccountingCategoriesQuery {
+    /// The optional exact, case-sensitive name to search the accounting category by.
+    #[param(example = "Development")]
+    name: Option<String>,
+
+    /// Whether archived accounting categories should be included (defaults to `false`).
+    #[param(example = true)]
+    include_archived: Option<bool>,
+}
+
+/// Lists all accounting categories, or the one with the given name.
 #[utoipa::path(
     get,
     path = "",
     tag = ACCOUNTING_CATEGORIES_SERVICE_TAG,
+    params(
+        ListAccountingCategoriesQuery,
+    ),
     responses(
         (status = 200, description = "List all accounting categories successfully", body = [AccountingCategory])
     )
 )]
-async fn list_accounting_categories<R>(State(store): State<Arc<Mutex<R>>>) -> impl IntoResponse
+async fn list_accounting_categories<R, T>(
+    State(store): State<Arc<Mutex<AccountingCategoriesServiceState<R, T>>>>,
+    Query(query): Query<ListAccountingCategoriesQuery>,
+) -> impl IntoResponse
 where
     R: 'static + Send + Sync + AccountingCategoriesListRepository,
+    T: 'static + Send + Sync + ActivitiesListRepository,
 {
-    let accounting_categories_list = AccountingCategoriesList::new(store.clone());
+    let service_state = store.lock().await;
+    let accounting_categories_list =
+        AccountingCategoriesList::new(service_state.accounting_categories_repository.clone());
 
-    let categories_vec = accounting_categories_list.categories().await;
+    let categories_vec = match query.name {
+        Some(name) => accounting_categories_list
+            .find_by_name(&name)
+            .await
+            .map(|category| category.into_iter().collect()),
+        None => {
+            accounting_categories_list
+                .categories(query.include_archived.unwrap_or(false))
+                .await
+        }
+    };
+    let categories_vec = match categories_vec {
+        Ok(categories) => categories,
+        Err(err) => return ServiceError::DatabaseError(err.to_string()).into_response(),
+    };
 
     let categories: Vec<AccountingCategory> = categories_vec
         .iter()
@@ -103,28 +194,49 @@ where
     request_body = AccountingCategory,
     responses(
         (status = 201, description = "New accounting category successfully created", body = AccountingCategory),
-        (status = 500, description = "Internal server error", body = String)
+        (status = 500, description = "Internal server error", body = ValidationError)
     ),
 )]
-async fn create_accounting_category<R>(
-    State(store): State<Arc<Mutex<R>>>,
+async fn create_accounting_category<R, T>(
+    State(store): State<Arc<Mutex<AccountingCategoriesServiceState<R, T>>>>,
     Json(new_category): Json<AccountingCategory>,
 ) -> impl IntoResponse
 where
     R: 'static + Send + Sync + AccountingCategoriesListRepository,
+    T: 'static + Send + Sync + ActivitiesListRepository,
 {
-    let mut accounting_categories_list = AccountingCategoriesList::new(store.clone());
+    let service_state = store.lock().await;
+    let mut accounting_categories_list =
+        AccountingCategoriesList::new(service_state.accounting_categories_repository.clone());
 
     match accounting_categories_list
         .create(new_category.name.as_str())
         .await
     {
-        Ok(accounting_category) => (
-            StatusCode::CREATED,
-            Json(AccountingCategory::from_entity(&accounting_category)),
-        )
-            .into_response(),
-        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, Json(err.to_string())).into_response(),
+        Ok(mut accounting_category) => {
+            if new_category.hourly_rate.is_some()
+                || new_category.description.is_some()
+                || new_category.archived
+            {
+                accounting_category.set_hourly_rate(new_category.hourly_rate);
+                accounting_category.set_description(new_category.description.clone());
+                accounting_category.set_archived(new_category.archived);
+
+                if let Err(err) = accounting_categories_list
+                    .update(accounting_category.clone())
+                    .await
+                {
+                    return ServiceError::DatabaseError(err.to_string()).into_response();
+                }
+            }
+
+            (
+                StatusCode::CREATED,
+                Json(AccountingCategory::from_entity(&accounting_category)),
+            )
+                .into_response()
+        }
+        Err(err) => ServiceError::DatabaseError(err.to_string()).into_response(),
     }
 }
 
@@ -136,43 +248,44 @@ where
     request_body = AccountingCategory,
     responses(
         (status = 200, description = "Accounting category successfully updated", body = AccountingCategory),
-        (status = 400, description = "Invalid request", body = String),
-        (status = 404, description = "Accounting category not found", body = String),
-        (status = 500, description = "Internal server error", body = String)
+        (status = 400, description = "Invalid request", body = ValidationError),
+        (status = 404, description = "Accounting category not found", body = ValidationError),
+        (status = 500, description = "Internal server error", body = ValidationError)
     ),
 )]
-async fn update_accounting_category<R>(
-    State(store): State<Arc<Mutex<R>>>,
+async fn update_accounting_category<R, T>(
+    State(store): State<Arc<Mutex<AccountingCategoriesServiceState<R, T>>>>,
     Json(updated_category): Json<AccountingCategory>,
 ) -> impl IntoResponse
 where
     R: 'static + Send + Sync + AccountingCategoriesListRepository,
+    T: 'static + Send + Sync + ActivitiesListRepository,
 {
-    let mut accounting_categories_list = AccountingCategoriesList::new(store.clone());
+    let service_state = store.lock().await;
+    let mut accounting_categories_list =
+        AccountingCategoriesList::new(service_state.accounting_categories_repository.clone());
 
     if updated_category.id.is_none() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json("Category ID is required".to_string()),
-        )
-            .into_response();
+        return ServiceError::missing_field("id", "Category ID is required").into_response();
     }
 
     let category_id = match AccountingCategoryId::parse_str(&updated_category.id.unwrap()) {
         Ok(id) => id,
         Err(_) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json("Invalid category ID format".to_string()),
-            )
-                .into_response();
+            return ServiceError::invalid_id("id", "Invalid category ID format").into_response();
         }
     };
 
-    let updated_category = work_pulse_core::entities::accounting::AccountingCategory::with_id(
+    let hourly_rate = updated_category.hourly_rate;
+    let description = updated_category.description.clone();
+    let archived = updated_category.archived;
+    let mut updated_category = work_pulse_core::entities::accounting::AccountingCategory::with_id(
         category_id,
         updated_category.name.clone(),
     );
+    updated_category.set_hourly_rate(hourly_rate);
+    updated_category.set_description(description);
+    updated_category.set_archived(archived);
 
     match accounting_categories_list
         .update(updated_category.clone())
@@ -183,7 +296,7 @@ where
             Json(AccountingCategory::from_entity(&updated_category)),
         )
             .into_response(),
-        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, Json(err.to_string())).into_response(),
+        Err(err) => ServiceError::DatabaseError(err.to_string()).into_response(),
     }
 }
 
@@ -197,28 +310,440 @@ where
     ),
     responses(
         (status = 204, description = "Accounting category successfully deleted"),
-        (status = 400, description = "Invalid request", body = String),
-        (status = 500, description = "Internal server error", body = String)
+        (status = 400, description = "Invalid request", body = ValidationError),
+        (status = 500, description = "Internal server error", body = ValidationError)
     ),
 )]
-async fn delete_accounting_category<R>(
+async fn delete_accounting_category<R, T>(
     Path(id): Path<String>,
-    State(store): State<Arc<Mutex<R>>>,
+    State(store): State<Arc<Mutex<AccountingCategoriesServiceState<R, T>>>>,
 ) -> impl IntoResponse
 where
     R: 'static + Send + Sync + AccountingCategoriesListRepository,
+    T: 'static + Send + Sync + ActivitiesListRepository,
 {
-    let mut accounting_categories_list = AccountingCategoriesList::new(store.clone());
+    let service_state = store.lock().await;
+    let mut accounting_categories_list =
+        AccountingCategoriesList::new(service_state.accounting_categories_repository.clone());
 
     match AccountingCategoryId::parse_str(&id) {
         Ok(category_id) => match accounting_categories_list.delete(category_id).await {
             Ok(_) => StatusCode::NO_CONTENT.into_response(),
-            Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, Json(err.to_string())).into_response(),
+            Err(err) => ServiceError::DatabaseError(err.to_string()).into_response(),
         },
-        Err(_) => (
-            StatusCode::BAD_REQUEST,
-            Json("Invalid category ID format".to_string()),
+        Err(_) => ServiceError::invalid_id("id", "Invalid category ID format").into_response(),
+    }
+}
+
+/// Query parameters for listing the activities of an accounting category.
+#[derive(Deserialize, IntoParams)]
+struct ListCategoryActivitiesQuery {
+    /// The optional start date to filter activities by, in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-01")]
+    start_date: Option<String>,
+
+    /// The optional end date to filter activities by, in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-31")]
+    end_date: Option<String>,
+
+    /// The 1-based page number (defaults to 1).
+    #[param(example = 1)]
+    page: Option<u32>,
+
+    /// The number of activities per page (defaults to 50, maximum 500).
+    #[param(example = 50)]
+    page_size: Option<u32>,
+}
+
+/// Lists the activities booked on an accounting category.
+#[utoipa::path(
+    get,
+    path = "/{id}/activities",
+    tag = ACCOUNTING_CATEGORIES_SERVICE_TAG,
+    params(
+        ("id" = String, Path, description = "The unique identifier of the accounting category"),
+        ListCategoryActivitiesQuery,
+    ),
+    responses(
+        (status = 200, description = "Activities of the accounting category successfully retrieved", body = PaginatedResponse<Activity>),
+        (status = 400, description = "Invalid request", body = ValidationError)
+    ),
+)]
+async fn list_category_activities<R, T>(
+    Path(id): Path<String>,
+    State(store): State<Arc<Mutex<AccountingCategoriesServiceState<R, T>>>>,
+    query: Query<ListCategoryActivitiesQuery>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + AccountingCategoriesListRepository,
+    T: 'static + Send + Sync + ActivitiesListRepository,
+{
+    let category_id = match AccountingCategoryId::parse_str(&id) {
+        Ok(category_id) => category_id,
+        Err(_) => {
+            return ServiceError::invalid_id("id", "Invalid category ID format").into_response();
+        }
+    };
+
+    let start_date = match parse_optional_date_param("start_date", query.start_date.as_deref()) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    let end_date = match parse_optional_date_param("end_date", query.end_date.as_deref()) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+
+    let service_state = store.lock().await;
+    let activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+
+    let activities = match activities_list.activities_by_category(&category_id).await {
+        Ok(activities) => activities,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+    let activities = activities
+        .iter()
+        .filter(|activity| {
+            start_date.is_none_or(|start| *activity.date() >= start)
+                && end_date.is_none_or(|end| *activity.date() <= end)
+        })
+        .map(Activity::from_entity)
+        .collect::<Vec<_>>();
+
+    (
+        StatusCode::OK,
+        Json(PaginatedResponse::paginate(
+            activities,
+            query.page,
+            query.page_size,
+        )),
+    )
+        .into_response()
+}
+
+/// The request body for merging two accounting categories.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct MergeAccountingCategories {
+    /// The unique identifier of the accounting category to merge and delete.
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    from_id: String,
+
+    /// The unique identifier of the accounting category that receives the activities.
+    #[schema(example = "6ba7b810-9dad-11d1-80b4-00c04fd430c8")]
+    into_id: String,
+}
+
+/// The result of merging two accounting categories.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct MergedAccountingCategories {
+    /// The number of activities reassigned to the surviving accounting category.
+    #[schema(example = 12)]
+    reassigned: usize,
+}
+
+/// Merges an accounting category into another one. The activities of the merged category are
+/// reassigned to the surviving category, then the merged category is deleted.
+#[utoipa::path(
+    post,
+    path = "/merge",
+    tag = ACCOUNTING_CATEGORIES_SERVICE_TAG,
+    request_body = MergeAccountingCategories,
+    responses(
+        (status = 200, description = "Accounting categories successfully merged", body = MergedAccountingCategories),
+        (status = 400, description = "Invalid request - invalid ID or a category merged into itself", body = ValidationError),
+        (status = 404, description = "Accounting category not found", body = ValidationError),
+        (status = 500, description = "Internal server error", body = ValidationError)
+    ),
+)]
+async fn merge_accounting_categories<R, T>(
+    State(store): State<Arc<Mutex<AccountingCategoriesServiceState<R, T>>>>,
+    Json(merge): Json<MergeAccountingCategories>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + AccountingCategoriesListRepository,
+    T: 'static + Send + Sync + ActivitiesListRepository,
+{
+    let from_id = match AccountingCategoryId::parse_str(&merge.from_id) {
+        Ok(id) => id,
+        Err(_) => {
+            return ServiceError::invalid_id("from_id", "Invalid category ID format")
+                .into_response();
+        }
+    };
+    let into_id = match AccountingCategoryId::parse_str(&merge.into_id) {
+        Ok(id) => id,
+        Err(_) => {
+            return ServiceError::invalid_id("into_id", "Invalid category ID format")
+                .into_response();
+        }
+    };
+
+    let service_state = store.lock().await;
+    let mut accounting_categories_list =
+        AccountingCategoriesList::new(service_state.accounting_categories_repository.clone());
+    let mut activities_list_repository = service_state.activities_list_repository.lock().await;
+
+    match accounting_categories_list
+        .merge(from_id, into_id, &mut *activities_list_repository)
+        .await
+    {
+        Ok(reassigned) => (
+            StatusCode::OK,
+            Json(MergedAccountingCategories { reassigned }),
         )
             .into_response(),
+        Err(err @ AccountingCategoriesListError::MergeIntoItself(_)) => {
+            ServiceError::InvalidRequest(err.to_string()).into_response()
+        }
+        Err(err @ AccountingCategoriesListError::NotFound(_)) => {
+            ServiceError::NotFound(err.to_string()).into_response()
+        }
+        Err(err) => ServiceError::DatabaseError(err.to_string()).into_response(),
+    }
+}
+
+/// Imports accounting categories from a CSV file with a `name` column and the optional columns
+/// `description` and `hourly_rate`. Categories with existing names are skipped.
+#[utoipa::path(
+    post,
+    path = "/import-csv",
+    tag = ACCOUNTING_CATEGORIES_SERVICE_TAG,
+    request_body(content = String, content_type = "text/csv", description = "CSV file containing accounting categories"),
+    responses(
+        (status = 201, description = "Newly created accounting categories", body = Vec<AccountingCategory>),
+        (status = 400, description = "Invalid CSV format", body = ValidationError),
+        (status = 500, description = "Internal server error", body = ValidationError)
+    ),
+)]
+async fn import_accounting_categories_csv<R, T>(
+    State(store): State<Arc<Mutex<AccountingCategoriesServiceState<R, T>>>>,
+    body: String,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + AccountingCategoriesListRepository,
+    T: 'static + Send + Sync + ActivitiesListRepository,
+{
+    if body.is_empty() {
+        return ServiceError::InvalidRequest("No CSV data provided".to_string()).into_response();
+    }
+
+    let service_state = store.lock().await;
+    let mut accounting_categories_list =
+        AccountingCategoriesList::new(service_state.accounting_categories_repository.clone());
+
+    match accounting_categories_list
+        .import_from_csv(body.as_bytes())
+        .await
+    {
+        Ok(created) => (
+            StatusCode::CREATED,
+            Json(
+                created
+                    .iter()
+                    .map(AccountingCategory::from_entity)
+                    .collect::<Vec<_>>(),
+            ),
+        )
+            .into_response(),
+        Err(err @ CsvImportError::RepositoryError(_)) => {
+            ServiceError::DatabaseError(err.to_string()).into_response()
+        }
+        Err(err) => ServiceError::InvalidRequest(err.to_string()).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use chrono::{NaiveDate, NaiveTime};
+    use tower::ServiceExt;
+    use work_pulse_core::{
+        entities::activity::Activity as ActivityEntity,
+        infra::repositories::in_memory::{
+            accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+            activities_list::InMemoryActivitiesListRepository,
+        },
+    };
+
+    use super::*;
+
+    async fn send_merge_request(
+        router: axum::Router,
+        from_id: &str,
+        into_id: &str,
+    ) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/accounting-categories/merge")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "from_id": from_id, "into_id": into_id }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn merge_accounting_categories_should_reassign_activities() {
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+
+        let (meeting, meetings) = {
+            let mut repository = accounting_categories_repository.lock().await;
+            (
+                repository.get_or_create_by_name("Meeting").await.unwrap(),
+                repository.get_or_create_by_name("Meetings").await.unwrap(),
+            )
+        };
+        activities_list_repository
+            .lock()
+            .await
+            .add(ActivityEntity::new(
+                NaiveDate::from_ymd_opt(2025, 10, 13).unwrap(),
+                NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                meeting.id().clone(),
+                "Daily".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let router = axum::Router::new().nest(
+            "/api/v1/accounting-categories",
+            router(
+                accounting_categories_repository.clone(),
+                activities_list_repository.clone(),
+            )
+            .into(),
+        );
+
+        let response = send_merge_request(
+            router,
+            &meeting.id().to_string(),
+            &meetings.id().to_string(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let merged: MergedAccountingCategories = serde_json::from_slice(&body).unwrap();
+        assert_eq!(merged.reassigned, 1);
+        assert_eq!(
+            activities_list_repository
+                .lock()
+                .await
+                .get_by_accounting_category_id(meetings.id())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(
+            accounting_categories_repository
+                .lock()
+                .await
+                .get_by_id(meeting.id().clone())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn merge_accounting_categories_should_return_not_found_for_unknown_category() {
+        let router = axum::Router::new().nest(
+            "/api/v1/accounting-categories",
+            router(
+                Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new())),
+                Arc::new(Mutex::new(InMemoryActivitiesListRepository::new())),
+            )
+            .into(),
+        );
+
+        let response = send_merge_request(
+            router,
+            &AccountingCategoryId::new().to_string(),
+            &AccountingCategoryId::new().to_string(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    async fn send_import_csv_request(
+        router: axum::Router,
+        csv_data: &str,
+    ) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/accounting-categories/import-csv")
+                    .header("content-type", "text/csv")
+                    .body(Body::from(csv_data.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn import_accounting_categories_csv_should_not_create_duplicates() {
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let router = axum::Router::new().nest(
+            "/api/v1/accounting-categories",
+            router(
+                accounting_categories_repository.clone(),
+                Arc::new(Mutex::new(InMemoryActivitiesListRepository::new())),
+            )
+            .into(),
+        );
+        let csv_data = "name,description,hourly_rate\nDevelopment,Coding,120\nMeetings,,\n";
+
+        for expected_created in [2, 0] {
+            let response = send_import_csv_request(router.clone(), csv_data).await;
+
+            assert_eq!(response.status(), StatusCode::CREATED);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let created: Vec<AccountingCategory> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(created.len(), expected_created);
+        }
+        assert_eq!(
+            accounting_categories_repository
+                .lock()
+                .await
+                .get_all(true)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn import_accounting_categories_csv_should_reject_malformed_csv() {
+        let router = axum::Router::new().nest(
+            "/api/v1/accounting-categories",
+            router(
+                Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new())),
+                Arc::new(Mutex::new(InMemoryActivitiesListRepository::new())),
+            )
+            .into(),
+        );
+
+        let response =
+            send_import_csv_request(router, "name,hourly_rate\nDevelopment,a lot\n").await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 }
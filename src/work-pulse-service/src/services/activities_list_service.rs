@@ -1,10 +1,13 @@
-use std::sync::Arc;
+use std::{cmp::Ordering, sync::Arc};
 
 use axum::{
-    Json,
+    Extension, Json,
     extract::{Multipart, Path, Query, State},
+    http::{HeaderMap, header},
+    middleware,
     response::IntoResponse,
 };
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
 use hyper::StatusCode;
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
@@ -12,13 +15,36 @@ use tokio::sync::Mutex;
 use utoipa::{IntoParams, ToSchema};
 use utoipa_axum::{router::OpenApiRouter, routes};
 use work_pulse_core::{
-    adapters::{AccountingCategoriesListRepository, ActivitiesListRepository},
-    entities::{accounting::AccountingCategoryId, activity::ActivityId},
-    infra::importers::csv_activities_importer::CsvActivitiesImporter,
+    adapters::{
+        AccountingCategoriesListRepository, ActivitiesExporter, ActivitiesImporterError,
+        ActivitiesListRepository, ImportResult,
+    },
+    entities::{
+        accounting::{AccountingCategory, AccountingCategoryId},
+        activity::{ActivityBuilder, ActivityId},
+    },
+    infra::{
+        exporters::{
+            csv_activities_exporter::CsvActivitiesExporter,
+            json_activities_exporter::JsonActivitiesExporter,
+        },
+        importers::{
+            csv_activities_importer::CsvActivitiesImporter,
+            json_activities_importer::JsonActivitiesImporter,
+        },
+    },
     use_cases::activities_list::{ActivitiesList, ReplaceMode},
 };
 
-use crate::prelude::ACTIVITIES_LIST_SERVICE_TAG;
+use crate::{
+    middleware::user_id::{UserId, extract_user_id},
+    prelude::ACTIVITIES_LIST_SERVICE_TAG,
+    services::{
+        error::{BulkCreateFailure, ServiceError, ValidationError},
+        pagination::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE, PaginatedResponse, offset, page_and_size},
+        params::{parse_date_param, parse_optional_date_param, parse_time_param},
+    },
+};
 
 /// Shared state for the activities service.
 struct ActivitiesServiceState<R, T>
@@ -35,7 +61,7 @@ where
 
 /// The Activity.
 #[derive(Serialize, Deserialize, ToSchema, Clone)]
-struct Activity {
+pub(crate) struct Activity {
     /// The unique identifier for the activity.
     #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
     id: Option<String>,
@@ -44,6 +70,11 @@ struct Activity {
     #[schema(example = "2023-01-10")]
     date: String,
 
+    /// The ISO 8601 calendar week of the date, computed by the service and ignored on input.
+    #[serde(default)]
+    #[schema(example = 2)]
+    week_number: Option<u32>,
+
     /// The start time of the activity in ISO 8601 format (HH:MM:SS).
     #[schema(example = "14:30:00")]
     start_time: String,
@@ -56,6 +87,11 @@ struct Activity {
     #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
     accounting_category_id: String,
 
+    /// The name of the accounting category, only included on request and ignored on input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "Development")]
+    accounting_category_name: Option<String>,
+
     /// The task itself.
     #[schema(example = "Code Review")]
     task: String,
@@ -63,6 +99,29 @@ struct Activity {
     /// An optional comment for the activity.
     #[schema(example = "some comment")]
     comment: Option<String>,
+
+    /// The user (username or email) who created the activity.
+    #[schema(example = "jdoe@example.com")]
+    created_by: Option<String>,
+
+    /// The origin of the activity record (e.g., "csv", "toggl", "manual").
+    #[schema(example = "csv")]
+    import_source: Option<String>,
+
+    /// Free-form tags of the activity.
+    #[serde(default)]
+    #[schema(example = json!(["sprint-42", "backend"]))]
+    tags: Vec<String>,
+
+    /// When the activity was first stored in RFC 3339 format, set by the service and ignored on input.
+    #[serde(default)]
+    #[schema(read_only, example = "2023-01-10T15:31:02Z")]
+    created_at: Option<String>,
+
+    /// When the activity was last modified in RFC 3339 format, set by the service and ignored on input.
+    #[serde(default)]
+    #[schema(read_only, example = "2023-01-10T15:31:02Z")]
+    updated_at: Option<String>,
 }
 
 impl Activity {
@@ -75,15 +134,43 @@ impl Activity {
     /// # Returns
     ///
     /// - An `Activity` DTO containing the data from the entity.
-    fn from_entity(entity: &work_pulse_core::entities::activity::Activity) -> Self {
+    pub(crate) fn from_entity(entity: &work_pulse_core::entities::activity::Activity) -> Self {
         Self {
             id: Some(entity.id().to_string()),
             date: entity.date().to_string(),
+            week_number: Some(entity.week_number()),
             start_time: entity.start_time().to_string(),
             end_time: entity.end_time().map(|t| t.to_string()),
             accounting_category_id: entity.accounting_category_id().to_string(),
+            accounting_category_name: None,
             task: entity.task().to_string(),
             comment: entity.comment().map(str::to_owned),
+            created_by: entity.created_by().map(str::to_owned),
+            import_source: entity.import_source().map(str::to_owned),
+            tags: entity.tags().to_vec(),
+            created_at: entity.created_at().map(|t| t.to_rfc3339()),
+            updated_at: entity.updated_at().map(|t| t.to_rfc3339()),
+        }
+    }
+
+    /// Converts a `work_pulse_core::entities::activity::Activity` entity to an `Activity` DTO
+    /// including the name of its accounting category.
+    ///
+    /// # Arguments
+    ///
+    /// - `entity`: A reference to the `work_pulse_core::entities::activity::Activity` entity.
+    /// - `category`: The accounting category of the activity, if it was found in the repository.
+    ///
+    /// # Returns
+    ///
+    /// - An `Activity` DTO containing the data from the entity and the category name, if known.
+    pub(crate) fn from_entity_with_repo(
+        entity: &work_pulse_core::entities::activity::Activity,
+        category: Option<&AccountingCategory>,
+    ) -> Self {
+        Self {
+            accounting_category_name: category.map(|category| category.name().to_string()),
+            ..Self::from_entity(entity)
         }
     }
 
@@ -91,32 +178,89 @@ impl Activity {
     ///
     /// # Returns
     ///
-    /// - A `work_pulse_core::entities::activity::Activity` entity constructed from the DTO.
-    fn to_entity(&self) -> work_pulse_core::entities::activity::Activity {
-        // TODO Handle potential parsing errors more gracefully
-
-        let mut activity = work_pulse_core::entities::activity::Activity::with_id(
-            ActivityId::parse_str(self.id.clone().unwrap().as_str())
-                .expect("Invalid activity ID format"),
-            self.date.parse().expect("Invalid activity date format"),
-            self.start_time.parse().expect("Invalid start time format"),
-            AccountingCategoryId::parse_str(self.accounting_category_id.as_str())
-                .expect("Invalid accounting category ID format"),
-            self.task.clone(),
-        );
-
-        if let Some(end_time) = &self.end_time {
-            activity.set_end_time(Some(
-                end_time.parse().expect("Invalid activitiy end time format"),
+    /// - `Ok(work_pulse_core::entities::activity::Activity)`: The entity constructed from the DTO.
+    /// - `Err(ServiceError)`: If the ID is missing, a field cannot be parsed or the end time is not
+    ///   after the start time.
+    fn to_entity(&self) -> Result<work_pulse_core::entities::activity::Activity, ServiceError> {
+        let Some(id) = self.id.as_deref() else {
+            return Err(ServiceError::missing_field(
+                "id",
+                "An ID for Activity is required",
             ));
-        }
+        };
+        let id = ActivityId::parse_str(id)
+            .map_err(|_| ServiceError::invalid_id("id", "Invalid activity ID format"))?;
 
-        activity.set_comment(self.comment.clone());
+        Ok(self
+            .to_builder()?
+            .id(id)
+            .created_by(self.created_by.clone())
+            .build()?)
+    }
 
-        activity
+    /// Converts the `Activity` DTO to a new `work_pulse_core::entities::activity::Activity` entity
+    /// with a server-generated ID. The `id` of the DTO is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// - `created_by`: The user who created the activity, if known.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(work_pulse_core::entities::activity::Activity)`: The entity constructed from the DTO.
+    /// - `Err(ServiceError)`: If a field cannot be parsed or the end time is not after the start time.
+    fn to_new_entity(
+        &self,
+        created_by: Option<String>,
+    ) -> Result<work_pulse_core::entities::activity::Activity, ServiceError> {
+        Ok(self.to_builder()?.created_by(created_by).build()?)
+    }
+
+    /// Parses the fields of the `Activity` DTO shared by new and existing activities into an
+    /// `ActivityBuilder`. The `id` and `created_by` of the DTO are not set.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(ActivityBuilder)`: The builder with the parsed fields.
+    /// - `Err(ServiceError)`: If a field cannot be parsed.
+    fn to_builder(&self) -> Result<ActivityBuilder, ServiceError> {
+        let date = parse_date_param("date", &self.date)?;
+        let start_time = parse_time_param("start_time", &self.start_time)?;
+        let end_time = self
+            .end_time
+            .as_deref()
+            .map(|end_time| parse_time_param("end_time", end_time))
+            .transpose()?;
+        let accounting_category_id = AccountingCategoryId::parse_str(&self.accounting_category_id)
+            .map_err(|_| {
+                ServiceError::invalid_id(
+                    "accounting_category_id",
+                    "Invalid accounting category ID format",
+                )
+            })?;
+
+        Ok(work_pulse_core::entities::activity::Activity::builder()
+            .date(date)
+            .start_time(start_time)
+            .end_time(end_time)
+            .accounting_category_id(accounting_category_id)
+            .task(self.task.clone())
+            .comment(self.comment.clone())
+            .import_source(self.import_source.clone())
+            .tags(self.tags.clone()))
     }
 }
 
+/// The result of a bulk create request in which only some of the activities could be created.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct BulkCreateActivitiesResponse {
+    /// The created activities with their server-generated IDs.
+    created: Vec<Activity>,
+
+    /// The activities that could not be created.
+    failures: Vec<BulkCreateFailure>,
+}
+
 /// Creates an OpenAPI router for activities service.
 ///
 /// # Arguments
@@ -141,17 +285,97 @@ where
     }));
 
     OpenApiRouter::new()
-        .routes(routes!(list_activities, create_activity))
+        .routes(routes!(
+            list_activities,
+            create_activity,
+            delete_activities_in_date_range
+        ))
+        .routes(routes!(create_activities_bulk))
         .routes(routes!(get_activity_by_id))
         .routes(routes!(update_activity))
         .routes(routes!(delete_activity))
+        .routes(routes!(complete_activity))
+        .routes(routes!(start_timer))
+        .routes(routes!(stop_timer))
+        .routes(routes!(get_time_distribution))
+        .routes(routes!(get_category_summary))
+        .routes(routes!(get_total_duration))
+        .routes(routes!(export_activities_csv))
+        .routes(routes!(export_activities_json))
         .routes(routes!(
             upload_activities_csv_raw,
             upload_activities_csv_multipart
         ))
+        .routes(routes!(upload_activities_json))
+        .layer(middleware::from_fn(extract_user_id))
         .with_state(store)
 }
 
+/// The field to sort listed activities by.
+#[derive(Deserialize, ToSchema, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum ActivitySortField {
+    /// Sort by date, then by start time.
+    Date,
+
+    /// Sort by start time.
+    StartTime,
+
+    /// Sort by duration. Activities without an end time are always sorted to the end.
+    Duration,
+
+    /// Sort by task.
+    Task,
+}
+
+/// The direction to sort listed activities in.
+#[derive(Deserialize, ToSchema, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum SortOrder {
+    /// Ascending order.
+    #[default]
+    Asc,
+
+    /// Descending order.
+    Desc,
+}
+
+/// Sorts activities in place by the given field and order. The sort is stable, so activities
+/// with equal keys keep their original order.
+///
+/// # Arguments
+///
+/// - `activities`: The activities to sort.
+/// - `sort_by`: The field to sort by.
+/// - `sort_order`: The direction to sort in.
+fn sort_activities(
+    activities: &mut [work_pulse_core::entities::activity::Activity],
+    sort_by: ActivitySortField,
+    sort_order: SortOrder,
+) {
+    activities.sort_by(|a, b| {
+        if sort_by == ActivitySortField::Duration {
+            match (a.is_completed(), b.is_completed()) {
+                (true, false) => return Ordering::Less,
+                (false, true) => return Ordering::Greater,
+                _ => {}
+            }
+        }
+
+        let ordering = match sort_by {
+            ActivitySortField::Date => (a.date(), a.start_time()).cmp(&(b.date(), b.start_time())),
+            ActivitySortField::StartTime => a.start_time().cmp(b.start_time()),
+            ActivitySortField::Duration => a.duration().cmp(&b.duration()),
+            ActivitySortField::Task => a.task().cmp(b.task()),
+        };
+
+        match sort_order {
+            SortOrder::Asc => ordering,
+            SortOrder::Desc => ordering.reverse(),
+        }
+    });
+}
+
 /// Query parameters for listing activities.
 #[derive(Deserialize, IntoParams)]
 struct ListActivitiesQuery {
@@ -162,9 +386,89 @@ struct ListActivitiesQuery {
     /// The optional end date to filter activities by, in ISO 8601 format (YYYY-MM-DD).
     #[param(example = "2025-10-12")]
     end_date: Option<String>,
+
+    /// The optional user (username or email) to filter activities by creator.
+    #[param(example = "jdoe@example.com")]
+    user_id: Option<String>,
+
+    /// The optional import source (e.g., "csv") to filter activities by origin.
+    #[param(example = "csv")]
+    import_source: Option<String>,
+
+    /// The optional accounting category ID to filter activities by.
+    #[param(example = "4b2e8f3a-1c9d-4e5f-8a7b-6c5d4e3f2a1b")]
+    category_id: Option<String>,
+
+    /// The optional keyword to search for in the task of the activities, ignoring case.
+    #[param(example = "review")]
+    task: Option<String>,
+
+    /// The optional tag to filter activities by.
+    #[param(example = "sprint-42")]
+    tag: Option<String>,
+
+    /// The optional ISO 8601 calendar week to filter activities by. Requires `year`.
+    #[param(example = 42)]
+    week_number: Option<u32>,
+
+    /// The ISO week-numbering year of `week_number`. Requires `week_number`.
+    #[param(example = 2025)]
+    year: Option<i32>,
+
+    /// The 1-based page number (defaults to 1).
+    #[param(example = 1)]
+    page: Option<u32>,
+
+    /// The number of activities per page (defaults to 50, maximum 500).
+    #[param(example = 50)]
+    page_size: Option<u32>,
+
+    /// The optional field to sort activities by (date, start_time, duration or task).
+    /// Without it activities are returned newest first, or in insertion order if filtered.
+    #[param(inline, example = "date")]
+    sort_by: Option<ActivitySortField>,
+
+    /// The sort direction (asc or desc, defaults to asc). Only used together with `sort_by`.
+    #[param(inline, example = "desc")]
+    sort_order: Option<SortOrder>,
+
+    /// The cursor (ID of the last activity of the previous page) for cursor-based pagination.
+    /// Cannot be combined with filters, sorting or page-based parameters.
+    #[param(example = "550e8400-e29b-41d4-a716-446655440000")]
+    after: Option<String>,
+
+    /// The number of activities per page for cursor-based pagination (defaults to 50, maximum 500).
+    #[param(example = 50)]
+    limit: Option<u32>,
+}
+
+impl ListActivitiesQuery {
+    /// Returns `true` if the query requests cursor-based pagination.
+    fn is_cursor_request(&self) -> bool {
+        self.after.is_some() || self.limit.is_some()
+    }
+
+    /// Returns `true` if the query contains any filter or sorting parameter.
+    fn has_filters(&self) -> bool {
+        self.start_date.is_some()
+            || self.end_date.is_some()
+            || self.user_id.is_some()
+            || self.import_source.is_some()
+            || self.category_id.is_some()
+            || self.task.is_some()
+            || self.tag.is_some()
+            || self.week_number.is_some()
+            || self.year.is_some()
+            || self.sort_by.is_some()
+    }
+
+    /// Returns `true` if the query contains any filter, sorting or page-based parameter.
+    fn has_page_parameters(&self) -> bool {
+        self.has_filters() || self.page.is_some() || self.page_size.is_some()
+    }
 }
 
-/// Lists all activities.
+/// Lists all activities, one page at a time.
 #[utoipa::path(
     get,
     path = "",
@@ -173,8 +477,9 @@ struct ListActivitiesQuery {
         ListActivitiesQuery,
     ),
     responses(
-        (status = 200, description = "List all activities successfully", body = Vec<Activity>),
-        (status = 400, description = "Invalid request - both start_date and end_date are required", body = String)
+        (status = 200, description = "List all activities successfully", body = PaginatedResponse<Activity>),
+        (status = 400, description = "Invalid request - start_date, end_date, category_id, week_number, year or after is not valid", body = ValidationError),
+        (status = 500, description = "Activities could not be loaded", body = ValidationError)
     )
 )]
 async fn list_activities<R, T>(
@@ -188,186 +493,988 @@ where
     let service_state = store.lock().await;
     let activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
 
-    let activities = activities_list
-        .activities()
-        .await
-        .iter()
-        .map(Activity::from_entity)
-        .collect::<Vec<_>>();
+    if query.is_cursor_request() {
+        return list_activities_after_cursor(&activities_list, &query).await;
+    }
+
+    if !query.has_filters() {
+        return list_activities_at_offset(&activities_list, &query).await;
+    }
 
-    // Filter activities by date range if provided
-    match (&query.start_date, &query.end_date) {
-        (Some(start_date), Some(end_date)) => {
-            let filtered_activities = activities
-                .into_iter()
-                .filter(|activity| activity.date >= *start_date && activity.date <= *end_date)
-                .collect::<Vec<_>>();
-            Json(filtered_activities).into_response()
+    let start_date = match parse_optional_date_param("start_date", query.start_date.as_deref()) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    let end_date = match parse_optional_date_param("end_date", query.end_date.as_deref()) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+
+    let category_id = match query
+        .category_id
+        .as_deref()
+        .map(AccountingCategoryId::parse_str)
+        .transpose()
+    {
+        Ok(category_id) => category_id,
+        Err(_) => {
+            return ServiceError::invalid_id("category_id", "Invalid category ID format")
+                .into_response();
         }
+    };
 
-        (Some(_), None) | (None, Some(_)) => (
-            StatusCode::BAD_REQUEST,
-            Json("Both start_date and end_date are required when filtering by date.".to_string()),
-        )
-            .into_response(),
+    let week = match (query.week_number, query.year) {
+        (Some(week_number), Some(year)) if (1..=53).contains(&week_number) => {
+            Some((year, week_number))
+        }
+        (Some(week_number), Some(_)) => {
+            return ServiceError::invalid_parameter(
+                "week_number",
+                format!("Invalid week number: {}", week_number),
+            )
+            .into_response();
+        }
+        (Some(_), None) => {
+            return ServiceError::missing_field("year", "year is required with week_number")
+                .into_response();
+        }
+        (None, Some(_)) => {
+            return ServiceError::missing_field("week_number", "week_number is required with year")
+                .into_response();
+        }
+        (None, None) => None,
+    };
+
+    let activities = match (
+        &query.user_id,
+        &category_id,
+        &query.import_source,
+        &query.task,
+        &query.tag,
+    ) {
+        (Some(user_id), _, _, _, _) => activities_list.activities_by_user(user_id).await,
+        (None, Some(category_id), _, _, _) => {
+            activities_list.activities_by_category(category_id).await
+        }
+        (None, None, Some(import_source), _, _) => {
+            activities_list
+                .activities_by_import_source(import_source)
+                .await
+        }
+        (None, None, None, Some(task), _) => activities_list.search(task).await,
+        (None, None, None, None, Some(tag)) => activities_list.activities_by_tag(tag).await,
+        (None, None, None, None, None) => match week {
+            Some((year, week_number)) => {
+                activities_list
+                    .activities_for_week_number(year, week_number)
+                    .await
+            }
+            None => {
+                activities_list
+                    .activities_in_date_range(start_date, end_date)
+                    .await
+            }
+        },
+    };
+    let activities = match activities {
+        Ok(activities) => activities,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+
+    let mut activities = activities
+        .into_iter()
+        .filter(|activity| {
+            start_date.is_none_or(|start| *activity.date() >= start)
+                && end_date.is_none_or(|end| *activity.date() <= end)
+                && query
+                    .import_source
+                    .as_deref()
+                    .is_none_or(|source| activity.import_source() == Some(source))
+                && category_id
+                    .as_ref()
+                    .is_none_or(|id| activity.accounting_category_id() == id)
+                && query.task.as_deref().is_none_or(|task| {
+                    activity
+                        .task()
+                        .to_lowercase()
+                        .contains(&task.to_lowercase())
+                })
+                && query
+                    .tag
+                    .as_ref()
+                    .is_none_or(|tag| activity.tags().contains(tag))
+                && week.is_none_or(|(year, week_number)| {
+                    activity.date().iso_week().year() == year
+                        && activity.week_number() == week_number
+                })
+        })
+        .collect::<Vec<_>>();
 
-        (None, None) => Json(activities).into_response(),
+    if let Some(sort_by) = query.sort_by {
+        sort_activities(
+            &mut activities,
+            sort_by,
+            query.sort_order.unwrap_or_default(),
+        );
     }
+
+    let activities = activities.iter().map(Activity::from_entity).collect();
+    let page = PaginatedResponse::paginate(activities, query.page, query.page_size);
+
+    Json(page).into_response()
 }
 
-/// Gets an activity by ID.
+/// Lists one page of all activities, newest first, by translating the page number into an offset
+/// and fetching only that page from the repository.
+///
+/// # Arguments
+///
+/// - `activities_list`: The activities list to read from.
+/// - `query`: The query with the `page` and `page_size`.
+async fn list_activities_at_offset<R>(
+    activities_list: &ActivitiesList<R>,
+    query: &ListActivitiesQuery,
+) -> axum::response::Response
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+{
+    let (page, page_size) = page_and_size(query.page, query.page_size);
+
+    let activities = match activities_list
+        .activities_paginated(offset(page, page_size), page_size as usize)
+        .await
+    {
+        Ok(activities) => activities,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+    let total = match activities_list.count().await {
+        Ok(total) => total,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+
+    let page = PaginatedResponse::with_offset(
+        activities.iter().map(Activity::from_entity).collect(),
+        total,
+        page,
+        page_size,
+    );
+
+    Json(page).into_response()
+}
+
+/// Lists one page of activities using cursor-based pagination.
+///
+/// # Arguments
+///
+/// - `activities_list`: The activities list to read from.
+/// - `query`: The query with the `after` cursor and `limit`.
+async fn list_activities_after_cursor<R>(
+    activities_list: &ActivitiesList<R>,
+    query: &ListActivitiesQuery,
+) -> axum::response::Response
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+{
+    if query.has_page_parameters() {
+        return ServiceError::invalid_parameter(
+            "after",
+            "after and limit cannot be combined with filters, sorting or paging",
+        )
+        .into_response();
+    }
+
+    let after_id = match query
+        .after
+        .as_deref()
+        .map(ActivityId::parse_str)
+        .transpose()
+    {
+        Ok(after_id) => after_id,
+        Err(_) => {
+            return ServiceError::invalid_id("after", "Invalid activity ID format for after")
+                .into_response();
+        }
+    };
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+
+    let activities = match activities_list
+        .activities_page_after(after_id, limit as usize)
+        .await
+    {
+        Ok(activities) => activities,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+    let next_cursor = activities
+        .last()
+        .filter(|_| activities.len() == limit as usize)
+        .map(|activity| activity.id().to_string());
+    let total = match activities_list.count().await {
+        Ok(total) => total,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+
+    let page = PaginatedResponse::with_cursor(
+        activities.iter().map(Activity::from_entity).collect(),
+        total,
+        limit,
+        next_cursor,
+    );
+
+    Json(page).into_response()
+}
+
+/// The number of activities starting in a specific hour of the day.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct HourBucket {
+    /// The hour of the day (0-23).
+    #[schema(example = 9)]
+    hour: u32,
+
+    /// The number of activities that started in this hour.
+    #[schema(example = 42)]
+    count: usize,
+
+    /// The average duration of these activities in seconds.
+    #[schema(example = 3600)]
+    avg_duration_seconds: i64,
+}
+
+/// Query parameters for the time distribution of activities.
+#[derive(Deserialize, IntoParams)]
+struct TimeDistributionQuery {
+    /// The optional start date of the range, in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-01")]
+    start_date: Option<String>,
+
+    /// The optional end date of the range, in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-31")]
+    end_date: Option<String>,
+}
+
+/// Gets a histogram of activity start times by hour of day.
 #[utoipa::path(
     get,
-    path = "/{id}",
+    path = "/time-distribution",
     tag = ACTIVITIES_LIST_SERVICE_TAG,
     params(
-        ("id" = String, Path, description = "The unique identifier of the activity to retrieve")
+        TimeDistributionQuery,
     ),
     responses(
-        (status = 200, description = "Activity successfully retrieved", body = Activity),
-        (status = 404, description = "Activity not found", body = String),
-        (status = 500, description = "Internal server error", body = String)
-    ),
+        (status = 200, description = "Time distribution successfully calculated", body = Vec<HourBucket>),
+        (status = 400, description = "Invalid request - start_date or end_date is not a valid date", body = ValidationError),
+        (status = 500, description = "Activities could not be loaded", body = ValidationError)
+    )
 )]
-async fn get_activity_by_id<R, T>(
-    Path(id): Path<String>,
+async fn get_time_distribution<R, T>(
     State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    query: Query<TimeDistributionQuery>,
 ) -> impl IntoResponse
 where
     R: 'static + Send + Sync + ActivitiesListRepository,
     T: 'static + Send + Sync + AccountingCategoriesListRepository,
 {
+    let start_date = match parse_optional_date_param("start_date", query.start_date.as_deref()) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    let end_date = match parse_optional_date_param("end_date", query.end_date.as_deref()) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+
     let service_state = store.lock().await;
     let activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
 
-    match ActivityId::parse_str(&id) {
-        Ok(activity_id) => match activities_list.get_by_id(&activity_id).await {
-            Some(activity) => {
-                (StatusCode::OK, Json(Activity::from_entity(&activity))).into_response()
-            }
-            None => (
-                StatusCode::NOT_FOUND,
-                Json("Activity not found".to_string()),
-            )
-                .into_response(),
-        },
-        Err(_) => (
-            StatusCode::BAD_REQUEST,
-            Json("Invalid activity ID format".to_string()),
-        )
-            .into_response(),
-    }
+    let distribution = match activities_list
+        .time_distribution(start_date, end_date)
+        .await
+    {
+        Ok(distribution) => distribution,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+    let distribution = distribution
+        .iter()
+        .map(|bucket| HourBucket {
+            hour: bucket.hour(),
+            count: bucket.count(),
+            avg_duration_seconds: bucket.avg_duration_seconds(),
+        })
+        .collect::<Vec<_>>();
+
+    (StatusCode::OK, Json(distribution)).into_response()
 }
 
-/// Creates a new Activity.
+/// The number and total duration of the activities of an accounting category.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct CategorySummary {
+    /// The accounting category ID of the activities.
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    category_id: String,
+
+    /// The number of activities of the category.
+    #[schema(example = 12)]
+    activity_count: usize,
+
+    /// The total duration of the activities in ISO 8601 format (PT1H).
+    #[schema(example = "PT19800S")]
+    total_duration: String,
+}
+
+/// Query parameters for the category summary of activities.
+#[derive(Deserialize, IntoParams)]
+struct CategorySummaryQuery {
+    /// The start date of the range, in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-01")]
+    start_date: Option<String>,
+
+    /// The end date of the range, in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-31")]
+    end_date: Option<String>,
+}
+
+/// Gets the number and total duration of the activities within a date range per accounting category.
+///
+/// Activities without an end time are counted, but do not contribute to the total duration.
 #[utoipa::path(
-    post,
-    path = "",
+    get,
+    path = "/category-summary",
     tag = ACTIVITIES_LIST_SERVICE_TAG,
-    request_body = Activity,
-    responses(
-        (status = 201, description = "New Activity successfully created", body = Activity),
-        (status = 500, description = "Internal server error", body = String)
+    params(
+        CategorySummaryQuery,
     ),
+    responses(
+        (status = 200, description = "Category summary successfully calculated", body = Vec<CategorySummary>),
+        (status = 400, description = "Invalid request - start_date or end_date is missing, not a valid date, or start_date is after end_date", body = ValidationError),
+        (status = 500, description = "Activities could not be loaded", body = ValidationError)
+    )
 )]
-async fn create_activity<R, T>(
+async fn get_category_summary<R, T>(
     State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
-    Json(new_activity): Json<Activity>,
+    query: Query<CategorySummaryQuery>,
 ) -> impl IntoResponse
 where
     R: 'static + Send + Sync + ActivitiesListRepository,
     T: 'static + Send + Sync + AccountingCategoriesListRepository,
 {
+    let Some(start_date) = query.start_date.as_deref() else {
+        return ServiceError::missing_field("start_date", "start_date is required").into_response();
+    };
+    let Some(end_date) = query.end_date.as_deref() else {
+        return ServiceError::missing_field("end_date", "end_date is required").into_response();
+    };
+    let start_date = match parse_date_param("start_date", start_date) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    let end_date = match parse_date_param("end_date", end_date) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    if start_date > end_date {
+        return ServiceError::invalid_parameter(
+            "start_date",
+            "start_date must not be after end_date",
+        )
+        .into_response();
+    }
+
     let service_state = store.lock().await;
-    let mut activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+    let activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
 
-    let date = new_activity.date.parse().expect("Invalid date format");
-    let start_time = new_activity
-        .start_time
-        .parse()
-        .expect("Invalid start time format");
-    let end_time = new_activity
-        .end_time
-        .as_ref()
-        .map(|t| t.parse().expect("Invalid end time format"));
-    let accounting_category_id =
-        AccountingCategoryId::parse_str(new_activity.accounting_category_id.as_str())
-            .expect("Invalid Accounting category ID format");
+    let summary = match activities_list.category_summary(start_date, end_date).await {
+        Ok(summary) => summary,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+    let summary = summary
+        .iter()
+        .map(|summary| CategorySummary {
+            category_id: summary.category_id().to_string(),
+            activity_count: summary.activity_count(),
+            total_duration: summary.total_duration().to_string(),
+        })
+        .collect::<Vec<_>>();
 
-    let activity = activities_list
-        .record(
-            date,
-            start_time,
-            end_time,
-            accounting_category_id,
-            new_activity.task.clone(),
-            new_activity.comment.clone(),
-        )
-        .await;
+    (StatusCode::OK, Json(summary)).into_response()
+}
 
-    (StatusCode::CREATED, Json(Activity::from_entity(&activity)))
+/// The total duration of the activities on a date or within a date range.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct TotalDuration {
+    /// The total duration in ISO 8601 format (PT1H).
+    #[schema(example = "PT19800S")]
+    duration: String,
 }
 
-/// Updates an existing activity.
+/// Query parameters for the total duration of activities.
+///
+/// Either `date` or both `start_date` and `end_date` must be provided.
+#[derive(Deserialize, IntoParams)]
+struct TotalDurationQuery {
+    /// The date to sum the activity durations for, in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-01-15")]
+    date: Option<String>,
+
+    /// The start date of the range, in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-01-13")]
+    start_date: Option<String>,
+
+    /// The end date of the range, in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-01-17")]
+    end_date: Option<String>,
+}
+
+/// Gets the total duration of the activities on a date or within a date range.
+///
+/// Activities without an end time do not contribute to the total.
 #[utoipa::path(
-    put,
-    path = "",
+    get,
+    path = "/duration",
     tag = ACTIVITIES_LIST_SERVICE_TAG,
-    request_body = Activity,
-    responses(
-        (status = 200, description = "Activity successfully updated", body = Activity),
-        (status = 400, description = "Invalid request", body = String),
-        (status = 404, description = "Activity not found", body = String),
-        (status = 500, description = "Internal server error", body = String)
+    params(
+        TotalDurationQuery,
     ),
+    responses(
+        (status = 200, description = "Total duration successfully calculated", body = TotalDuration),
+        (status = 400, description = "Invalid request - neither date nor start_date and end_date are given, or a date is not valid", body = ValidationError),
+        (status = 500, description = "Activities could not be loaded", body = ValidationError)
+    )
 )]
-async fn update_activity<R, T>(
+async fn get_total_duration<R, T>(
     State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
-    Json(updated_activity): Json<Activity>,
+    query: Query<TotalDurationQuery>,
 ) -> impl IntoResponse
 where
     R: 'static + Send + Sync + ActivitiesListRepository,
     T: 'static + Send + Sync + AccountingCategoriesListRepository,
 {
+    let date = match parse_optional_date_param("date", query.date.as_deref()) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    let start_date = match parse_optional_date_param("start_date", query.start_date.as_deref()) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    let end_date = match parse_optional_date_param("end_date", query.end_date.as_deref()) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+
     let service_state = store.lock().await;
-    let mut activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+    let activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
 
-    if updated_activity.id.is_none() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json("An ID for Activity is required".to_string()),
-        )
+    let total_duration = match (date, start_date, end_date) {
+        (Some(date), None, None) => activities_list.total_duration_for_date(date).await,
+        (None, Some(start_date), Some(end_date)) => {
+            activities_list
+                .total_duration_for_range(start_date, end_date)
+                .await
+        }
+        _ => {
+            return ServiceError::InvalidRequest(
+                "Either date or both start_date and end_date must be provided".to_string(),
+            )
             .into_response();
-    }
-
-    let updated_activity = updated_activity.to_entity();
+        }
+    };
 
-    match activities_list.update(updated_activity.clone()).await {
-        Ok(_) => (
+    match total_duration {
+        Ok(duration) => (
             StatusCode::OK,
-            Json(Activity::from_entity(&updated_activity)),
+            Json(TotalDuration {
+                duration: duration.to_string(),
+            }),
         )
             .into_response(),
-        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, Json(err.to_string())).into_response(),
+        Err(err) => ServiceError::from(err).into_response(),
     }
 }
 
-/// Deletes an activity by ID.
+/// Query parameters for exporting activities.
+#[derive(Deserialize, IntoParams)]
+struct ExportActivitiesQuery {
+    /// The optional start date of the range, in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-01")]
+    start_date: Option<String>,
+
+    /// The optional end date of the range, in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-31")]
+    end_date: Option<String>,
+}
+
+/// Exports activities as a CSV file, sorted by date and start time. The file uses the same
+/// column layout as the CSV upload.
 #[utoipa::path(
-    delete,
-    path = "/{id}",
+    get,
+    path = "/export-csv",
     tag = ACTIVITIES_LIST_SERVICE_TAG,
     params(
-        ("id" = String, Path, description = "The unique identifier of the activity to delete")
+        ExportActivitiesQuery,
     ),
     responses(
-        (status = 204, description = "Activity successfully deleted"),
-        (status = 400, description = "Invalid request", body = String),
-        (status = 500, description = "Internal server error", body = String)
-    ),
+        (status = 200, description = "Activities successfully exported", body = String, content_type = "text/csv"),
+        (status = 400, description = "Invalid request - start_date or end_date is not a valid date", body = ValidationError),
+        (status = 500, description = "Activities could not be exported", body = ValidationError)
+    )
 )]
-async fn delete_activity<R, T>(
-    Path(id): Path<String>,
+async fn export_activities_csv<R, T>(
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    query: Query<ExportActivitiesQuery>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let service_state = store.lock().await;
+    let activities = match activities_to_export(&service_state, &query).await {
+        Ok(activities) => activities,
+        Err(response) => return response,
+    };
+
+    let csv_exporter =
+        CsvActivitiesExporter::new(service_state.accounting_categories_repository.clone());
+    let mut csv_data = Vec::new();
+
+    match csv_exporter.export(&activities, &mut csv_data).await {
+        Ok(()) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/csv"),
+                (
+                    header::CONTENT_DISPOSITION,
+                    "attachment; filename=activities.csv",
+                ),
+            ],
+            csv_data,
+        )
+            .into_response(),
+        Err(err) => ServiceError::InternalError(err.to_string()).into_response(),
+    }
+}
+
+/// Exports activities as a JSON array, sorted by date and start time.
+#[utoipa::path(
+    get,
+    path = "/export-json",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    params(
+        ExportActivitiesQuery,
+    ),
+    responses(
+        (status = 200, description = "Activities successfully exported", body = String, content_type = "application/json"),
+        (status = 400, description = "Invalid request - start_date or end_date is not a valid date", body = ValidationError),
+        (status = 500, description = "Activities could not be exported", body = ValidationError)
+    )
+)]
+async fn export_activities_json<R, T>(
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    query: Query<ExportActivitiesQuery>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let service_state = store.lock().await;
+    let activities = match activities_to_export(&service_state, &query).await {
+        Ok(activities) => activities,
+        Err(response) => return response,
+    };
+
+    let mut json_data = Vec::new();
+
+    match JsonActivitiesExporter::new()
+        .export(&activities, &mut json_data)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "application/json"),
+                (
+                    header::CONTENT_DISPOSITION,
+                    "attachment; filename=activities.json",
+                ),
+            ],
+            json_data,
+        )
+            .into_response(),
+        Err(err) => ServiceError::InternalError(err.to_string()).into_response(),
+    }
+}
+
+/// Loads the activities within the date range of an export request, sorted by date and start
+/// time.
+///
+/// # Returns
+///
+/// - `Ok(Vec<Activity>)`: The activities to export.
+/// - `Err(Response)`: The error response if a date is invalid or the activities could not be loaded.
+async fn activities_to_export<R, T>(
+    service_state: &ActivitiesServiceState<R, T>,
+    query: &ExportActivitiesQuery,
+) -> Result<Vec<work_pulse_core::entities::activity::Activity>, axum::response::Response>
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let start_date = parse_optional_date_param("start_date", query.start_date.as_deref())
+        .map_err(IntoResponse::into_response)?;
+    let end_date = parse_optional_date_param("end_date", query.end_date.as_deref())
+        .map_err(IntoResponse::into_response)?;
+
+    let activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+
+    let mut activities = activities_list
+        .activities_in_date_range(start_date, end_date)
+        .await
+        .map_err(|err| ServiceError::from(err).into_response())?;
+    sort_activities(&mut activities, ActivitySortField::Date, SortOrder::Asc);
+
+    Ok(activities)
+}
+
+/// Query parameters for getting an activity by ID.
+#[derive(Deserialize, IntoParams)]
+struct GetActivityQuery {
+    /// Whether to include the name of the accounting category (defaults to false).
+    #[param(example = true)]
+    include_category_name: Option<bool>,
+}
+
+/// Gets an activity by ID.
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    params(
+        ("id" = String, Path, description = "The unique identifier of the activity to retrieve"),
+        GetActivityQuery,
+    ),
+    responses(
+        (status = 200, description = "Activity successfully retrieved", body = Activity),
+        (status = 400, description = "Invalid activity ID format", body = ValidationError),
+        (status = 404, description = "Activity not found", body = ValidationError),
+        (status = 500, description = "Internal server error", body = ValidationError)
+    ),
+)]
+async fn get_activity_by_id<R, T>(
+    Path(id): Path<String>,
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    Query(query): Query<GetActivityQuery>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let service_state = store.lock().await;
+    let activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+
+    let activity_id = match ActivityId::parse_str(&id) {
+        Ok(activity_id) => activity_id,
+        Err(_) => {
+            return ServiceError::invalid_id("id", "Invalid activity ID format").into_response();
+        }
+    };
+
+    let activity = match activities_list.get_by_id(&activity_id).await {
+        Ok(Some(activity)) => activity,
+        Ok(None) => {
+            return ServiceError::NotFound("Activity not found".to_string()).into_response();
+        }
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+
+    let activity = if query.include_category_name.unwrap_or(false) {
+        let category = match service_state
+            .accounting_categories_repository
+            .lock()
+            .await
+            .get_by_id(activity.accounting_category_id().clone())
+            .await
+        {
+            Ok(category) => category,
+            Err(err) => return ServiceError::DatabaseError(err.to_string()).into_response(),
+        };
+        Activity::from_entity_with_repo(&activity, category.as_ref())
+    } else {
+        Activity::from_entity(&activity)
+    };
+
+    (StatusCode::OK, Json(activity)).into_response()
+}
+
+/// Creates a new Activity.
+#[utoipa::path(
+    post,
+    path = "",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    request_body = Activity,
+    responses(
+        (status = 201, description = "New Activity successfully created", body = Activity),
+        (status = 400, description = "Invalid request - a field cannot be parsed or the end time is not after the start time", body = ValidationError),
+        (status = 500, description = "Internal server error", body = ValidationError)
+    ),
+)]
+async fn create_activity<R, T>(
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    Extension(user_id): Extension<UserId>,
+    Json(new_activity): Json<Activity>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let activity = match new_activity.to_new_entity(user_id.0) {
+        Ok(activity) => activity,
+        Err(err) => return err.into_response(),
+    };
+
+    let service_state = store.lock().await;
+    let mut activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+
+    match activities_list.record_activity(activity).await {
+        Ok(activity) => {
+            (StatusCode::CREATED, Json(Activity::from_entity(&activity))).into_response()
+        }
+        Err(err) => ServiceError::from(err).into_response(),
+    }
+}
+
+/// Creates several new Activities at once.
+///
+/// The `id` fields of the activities are ignored, every created activity gets a server-generated ID.
+/// The activities that are valid are created even if others are not.
+#[utoipa::path(
+    post,
+    path = "/bulk",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    request_body = Vec<Activity>,
+    responses(
+        (status = 201, description = "All activities successfully created", body = Vec<Activity>),
+        (status = 207, description = "Some of the activities could not be created", body = BulkCreateActivitiesResponse),
+        (status = 400, description = "Invalid request - no activities given or none of them is valid", body = ValidationError),
+        (status = 500, description = "Internal server error", body = ValidationError)
+    ),
+)]
+async fn create_activities_bulk<R, T>(
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    Extension(user_id): Extension<UserId>,
+    Json(new_activities): Json<Vec<Activity>>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    if new_activities.is_empty() {
+        return ServiceError::InvalidRequest("At least one activity is required".to_string())
+            .into_response();
+    }
+
+    let mut activities = Vec::new();
+    let mut failures = Vec::new();
+    for (index, new_activity) in new_activities.iter().enumerate() {
+        match new_activity.to_new_entity(user_id.0.clone()) {
+            Ok(activity) => activities.push(activity),
+            Err(err) => failures.push(BulkCreateFailure {
+                index,
+                error: err.to_validation_error(),
+            }),
+        }
+    }
+
+    if activities.is_empty() {
+        return ServiceError::BulkCreateActivitiesError(failures).into_response();
+    }
+
+    let service_state = store.lock().await;
+    let mut activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+
+    let created: Vec<Activity> = match activities_list.record_all(activities).await {
+        Ok(activities) => activities.iter().map(Activity::from_entity).collect(),
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+
+    if failures.is_empty() {
+        (StatusCode::CREATED, Json(created)).into_response()
+    } else {
+        (
+            StatusCode::MULTI_STATUS,
+            Json(BulkCreateActivitiesResponse { created, failures }),
+        )
+            .into_response()
+    }
+}
+
+/// Updates an existing activity.
+#[utoipa::path(
+    put,
+    path = "",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    request_body = Activity,
+    responses(
+        (status = 200, description = "Activity successfully updated", body = Activity),
+        (status = 400, description = "Invalid request", body = ValidationError),
+        (status = 404, description = "Activity not found", body = ValidationError),
+        (status = 500, description = "Internal server error", body = ValidationError)
+    ),
+)]
+async fn update_activity<R, T>(
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    Json(updated_activity): Json<Activity>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let updated_activity = match updated_activity.to_entity() {
+        Ok(activity) => activity,
+        Err(err) => return err.into_response(),
+    };
+
+    let service_state = store.lock().await;
+    let mut activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+
+    match activities_list.update(updated_activity.clone()).await {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(Activity::from_entity(&updated_activity)),
+        )
+            .into_response(),
+        Err(err) => ServiceError::from(err).into_response(),
+    }
+}
+
+/// Deletes an activity by ID.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    params(
+        ("id" = String, Path, description = "The unique identifier of the activity to delete")
+    ),
+    responses(
+        (status = 204, description = "Activity successfully deleted"),
+        (status = 400, description = "Invalid request", body = ValidationError),
+        (status = 500, description = "Internal server error", body = ValidationError)
+    ),
+)]
+async fn delete_activity<R, T>(
+    Path(id): Path<String>,
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let service_state = store.lock().await;
+    let mut activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+
+    match ActivityId::parse_str(&id) {
+        Ok(activity_id) => match activities_list.delete(activity_id).await {
+            Ok(_) => StatusCode::NO_CONTENT.into_response(),
+            Err(err) => ServiceError::from(err).into_response(),
+        },
+        Err(_) => ServiceError::invalid_id("id", "Invalid activity ID format").into_response(),
+    }
+}
+
+/// Query parameters for deleting the activities within a date range.
+#[derive(Deserialize, IntoParams)]
+struct DeleteActivitiesQuery {
+    /// The start date of the range (inclusive), in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-01")]
+    start_date: Option<String>,
+
+    /// The end date of the range (inclusive), in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-31")]
+    end_date: Option<String>,
+}
+
+/// The result of deleting the activities within a date range.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct DeletedActivities {
+    /// The number of deleted activities.
+    #[schema(example = 42)]
+    deleted: usize,
+}
+
+/// Deletes all activities within a date range, e.g. before re-importing a month.
+#[utoipa::path(
+    delete,
+    path = "",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    params(
+        DeleteActivitiesQuery,
+    ),
+    responses(
+        (status = 200, description = "Activities successfully deleted", body = DeletedActivities),
+        (status = 400, description = "Invalid request - start_date or end_date is missing or not a valid date, or start_date is after end_date", body = ValidationError),
+        (status = 500, description = "Internal server error", body = ValidationError)
+    ),
+)]
+async fn delete_activities_in_date_range<R, T>(
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    query: Query<DeleteActivitiesQuery>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let Some(start_date) = query.start_date.as_deref() else {
+        return ServiceError::missing_field("start_date", "start_date is required").into_response();
+    };
+    let Some(end_date) = query.end_date.as_deref() else {
+        return ServiceError::missing_field("end_date", "end_date is required").into_response();
+    };
+    let start_date = match parse_date_param("start_date", start_date) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    let end_date = match parse_date_param("end_date", end_date) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    if start_date > end_date {
+        return ServiceError::invalid_parameter(
+            "start_date",
+            "start_date must not be after end_date",
+        )
+        .into_response();
+    }
+
+    let service_state = store.lock().await;
+    let mut activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+
+    match activities_list
+        .delete_by_date_range(start_date, end_date)
+        .await
+    {
+        Ok(deleted) => (StatusCode::OK, Json(DeletedActivities { deleted })).into_response(),
+        Err(err) => ServiceError::from(err).into_response(),
+    }
+}
+
+/// Marks an activity as complete by setting its end time to the current time.
+#[utoipa::path(
+    patch,
+    path = "/{id}/complete",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    params(
+        ("id" = String, Path, description = "The unique identifier of the activity to complete")
+    ),
+    responses(
+        (status = 200, description = "Activity successfully completed", body = Activity),
+        (status = 400, description = "Invalid request", body = ValidationError),
+        (status = 404, description = "Activity not found", body = ValidationError),
+        (status = 409, description = "Activity is already completed", body = ValidationError),
+        (status = 500, description = "Internal server error", body = ValidationError)
+    ),
+)]
+async fn complete_activity<R, T>(
+    Path(id): Path<String>,
     State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
 ) -> impl IntoResponse
 where
@@ -377,193 +1484,1990 @@ where
     let service_state = store.lock().await;
     let mut activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
 
-    match ActivityId::parse_str(&id) {
-        Ok(activity_id) => match activities_list.delete(activity_id).await {
-            Ok(_) => StatusCode::NO_CONTENT.into_response(),
-            Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, Json(err.to_string())).into_response(),
-        },
-        Err(_) => (
-            StatusCode::BAD_REQUEST,
-            Json("Invalid activity ID format".to_string()),
-        )
-            .into_response(),
+    let activity_id = match ActivityId::parse_str(&id) {
+        Ok(activity_id) => activity_id,
+        Err(_) => {
+            return ServiceError::invalid_id("id", "Invalid activity ID format").into_response();
+        }
+    };
+
+    let mut activity = match activities_list.get_by_id(&activity_id).await {
+        Ok(Some(activity)) => activity,
+        Ok(None) => {
+            return ServiceError::NotFound("Activity not found".to_string()).into_response();
+        }
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+
+    if activity.is_completed() {
+        return ServiceError::Conflict("Activity is already completed".to_string()).into_response();
+    }
+
+    if let Err(err) = activity.set_end_time(Some(Utc::now().time())) {
+        return ServiceError::from(err).into_response();
+    }
+
+    match activities_list.update(activity.clone()).await {
+        Ok(_) => (StatusCode::OK, Json(Activity::from_entity(&activity))).into_response(),
+        Err(err) => ServiceError::from(err).into_response(),
+    }
+}
+
+/// A started timer, as handed out by the server and kept by the client.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct TimerStarted {
+    /// The time when the timer was started in ISO 8601 format (HH:MM:SS).
+    #[schema(example = "14:30:00")]
+    start_time: String,
+
+    /// The date when the timer was started in ISO 8601 format (YYYY-MM-DD).
+    #[schema(example = "2023-01-10")]
+    date: String,
+}
+
+/// The request to stop a timer and record the completed activity.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct StopTimerRequest {
+    /// The time when the timer was started in ISO 8601 format (HH:MM:SS).
+    #[schema(example = "14:30:00")]
+    start_time: String,
+
+    /// The date when the timer was started in ISO 8601 format (YYYY-MM-DD).
+    #[schema(example = "2023-01-10")]
+    date: String,
+
+    /// The accounting category ID associated with the activity.
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    category_id: String,
+
+    /// The task itself.
+    #[schema(example = "Code Review")]
+    task: String,
+}
+
+impl StopTimerRequest {
+    /// Parses the date, start time and accounting category ID of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((NaiveDate, NaiveTime, AccountingCategoryId))` if all values are valid.
+    /// - `Err(ServiceError)` describing the first invalid value otherwise.
+    fn parse(&self) -> Result<(NaiveDate, NaiveTime, AccountingCategoryId), ServiceError> {
+        let date = self.date.parse().map_err(|_| {
+            ServiceError::invalid_parameter("date", format!("Invalid date format: '{}'", self.date))
+        })?;
+        let start_time = self.start_time.parse().map_err(|_| {
+            ServiceError::invalid_parameter(
+                "start_time",
+                format!("Invalid start time format: '{}'", self.start_time),
+            )
+        })?;
+        let category_id = AccountingCategoryId::parse_str(&self.category_id).map_err(|_| {
+            ServiceError::invalid_id(
+                "category_id",
+                format!(
+                    "Invalid accounting category ID format: '{}'",
+                    self.category_id
+                ),
+            )
+        })?;
+
+        Ok((date, start_time, category_id))
+    }
+}
+
+/// Determines the end time of a timer that is stopped at `now`.
+///
+/// # Arguments
+///
+/// - `date`: The date when the timer was started.
+/// - `start_time`: The time when the timer was started.
+/// - `now`: The date and time when the timer is stopped.
+///
+/// # Returns
+///
+/// - `Ok(NaiveTime)`: The end time, truncated to whole seconds like the start time of a timer.
+/// - `Err(ServiceError::InvalidParameter)`: If `now` is not after the start of the timer or on a
+///   later date than the timer was started.
+fn timer_end_time(
+    date: NaiveDate,
+    start_time: NaiveTime,
+    now: NaiveDateTime,
+) -> Result<NaiveTime, ServiceError> {
+    let now = now.with_nanosecond(0).unwrap_or(now);
+    if now <= date.and_time(start_time) {
+        return Err(ServiceError::invalid_parameter(
+            "start_time",
+            "The timer must be stopped after it was started",
+        ));
+    }
+    if now.date() != date {
+        return Err(ServiceError::invalid_parameter(
+            "date",
+            "The timer must be stopped on the date it was started",
+        ));
+    }
+
+    Ok(now.time())
+}
+
+/// Starts a timer. The server does not keep any state, the client has to remember the returned values.
+#[utoipa::path(
+    post,
+    path = "/timer/start",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    responses(
+        (status = 200, description = "Timer successfully started", body = TimerStarted)
+    ),
+)]
+async fn start_timer() -> impl IntoResponse {
+    let now = Utc::now();
+
+    (
+        StatusCode::OK,
+        Json(TimerStarted {
+            start_time: now.time().format("%H:%M:%S").to_string(),
+            date: now.date_naive().to_string(),
+        }),
+    )
+}
+
+/// Stops a timer and records the completed activity with the current time as end time. A timer
+/// has to be stopped on the date it was started.
+#[utoipa::path(
+    post,
+    path = "/timer/stop",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    request_body = StopTimerRequest,
+    responses(
+        (status = 201, description = "Activity successfully recorded", body = Activity),
+        (status = 400, description = "Invalid request", body = ValidationError)
+    ),
+)]
+async fn stop_timer<R, T>(
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    Extension(user_id): Extension<UserId>,
+    Json(request): Json<StopTimerRequest>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let (date, start_time, accounting_category_id) = match request.parse() {
+        Ok(parsed) => parsed,
+        Err(err) => return err.into_response(),
+    };
+
+    let end_time = match timer_end_time(date, start_time, Utc::now().naive_utc()) {
+        Ok(end_time) => end_time,
+        Err(err) => return err.into_response(),
+    };
+
+    let service_state = store.lock().await;
+    let mut activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+
+    let activity = activities_list
+        .record(
+            date,
+            start_time,
+            Some(end_time),
+            accounting_category_id,
+            request.task.clone(),
+            None,
+            user_id.0,
+        )
+        .await;
+
+    match activity {
+        Ok(activity) => {
+            (StatusCode::CREATED, Json(Activity::from_entity(&activity))).into_response()
+        }
+        Err(err) => ServiceError::from(err).into_response(),
+    }
+}
+
+/// Query parameters for uploading activities.
+#[derive(Deserialize, IntoParams)]
+struct UploadActivitiesQuery {
+    /// The year of the activities being uploaded.
+    #[param(example = "2025")]
+    activities_year: u16,
+
+    /// How to handle existing activities when importing. Options are:
+    ///
+    /// - "none" (default): Do not delete any existing activities.
+    /// - "all": Delete all existing activities before import.
+    /// - "import_date_range": Delete existing activities within the date range of the imported activities.
+    #[param(example = "all")]
+    replace_mode: Option<String>,
+
+    /// Whether to delete all existing activities before the import (defaults to false).
+    ///
+    /// **This is destructive.** To guard against accidental use, the request must also send the
+    /// header `X-Confirm-Replace: yes`.
+    #[param(example = false)]
+    replace_all: Option<bool>,
+}
+
+/// The header used to declare the origin of uploaded activities (e.g., "toggl").
+const IMPORT_SOURCE_HEADER: &str = "x-import-source";
+
+/// The header confirming that an upload with `replace_all=true` may delete all existing activities.
+const CONFIRM_REPLACE_HEADER: &str = "x-confirm-replace";
+
+/// Returns the import source given in the `X-Import-Source` header, if present and not empty.
+///
+/// # Arguments
+///
+/// - `headers`: The headers of the upload request.
+fn import_source_from_headers(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(IMPORT_SOURCE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+}
+
+/// Creates a CSV importer recording the import source given in the request headers.
+/// Falls back to "csv" if the `X-Import-Source` header is not present.
+///
+/// # Arguments
+///
+/// - `headers`: The headers of the upload request.
+/// - `accounting_categories_repository`: The repository used to resolve accounting categories.
+fn csv_importer_for_request<T>(
+    headers: &HeaderMap,
+    accounting_categories_repository: Arc<Mutex<T>>,
+) -> CsvActivitiesImporter
+where
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let csv_importer = CsvActivitiesImporter::new(accounting_categories_repository);
+
+    match import_source_from_headers(headers) {
+        Some(import_source) => csv_importer.with_import_source(import_source),
+        None => csv_importer,
+    }
+}
+
+/// Creates a JSON importer recording the import source given in the request headers.
+/// Falls back to "json" if the `X-Import-Source` header is not present.
+///
+/// # Arguments
+///
+/// - `headers`: The headers of the upload request.
+/// - `accounting_categories_repository`: The repository used to resolve accounting categories.
+fn json_importer_for_request<T>(
+    headers: &HeaderMap,
+    accounting_categories_repository: Arc<Mutex<T>>,
+) -> JsonActivitiesImporter
+where
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let json_importer = JsonActivitiesImporter::new(accounting_categories_repository);
+
+    match import_source_from_headers(headers) {
+        Some(import_source) => json_importer.with_import_source(import_source),
+        None => json_importer,
+    }
+}
+
+impl UploadActivitiesQuery {
+    /// Resolves the replace mode from `replace_all` and `replace_mode`. `replace_all=true`
+    /// requires the header `X-Confirm-Replace: yes`.
+    ///
+    /// # Arguments
+    ///
+    /// - `headers`: The headers of the upload request.
+    fn parse_replace_mode(&self, headers: &HeaderMap) -> Result<ReplaceMode, ServiceError> {
+        if self.replace_all == Some(true) {
+            if !matches!(self.replace_mode.as_deref(), None | Some("all")) {
+                return Err(ServiceError::invalid_parameter(
+                    "replace_all",
+                    "replace_all=true cannot be combined with another replace_mode",
+                ));
+            }
+
+            let confirmed = headers
+                .get(CONFIRM_REPLACE_HEADER)
+                .and_then(|value| value.to_str().ok())
+                == Some("yes");
+            if !confirmed {
+                return Err(ServiceError::missing_field(
+                    "X-Confirm-Replace",
+                    "replace_all=true deletes all existing activities and requires the header 'X-Confirm-Replace: yes'",
+                ));
+            }
+
+            return Ok(ReplaceMode::All);
+        }
+
+        match self.replace_mode.as_deref() {
+            None | Some("none") => Ok(ReplaceMode::None),
+            Some("all") => Ok(ReplaceMode::All),
+            Some("import_date_range") => Ok(ReplaceMode::ImportDateRange),
+            Some(other) => Err(ServiceError::invalid_parameter(
+                "replace_mode",
+                format!(
+                    "Invalid replace_mode: '{}'. Valid options are 'none', 'all', 'import_date_range'",
+                    other
+                ),
+            )),
+        }
+    }
+}
+
+/// Trims leading and trailing whitespace from the task and comment of an imported activity.
+/// Comments that are empty after trimming are removed.
+///
+/// # Arguments
+///
+/// - `activity`: The imported activity.
+///
+/// # Returns
+///
+/// - The activity with trimmed task and comment.
+fn trim_imported_activity(
+    mut activity: work_pulse_core::entities::activity::Activity,
+) -> work_pulse_core::entities::activity::Activity {
+    let task = activity.task().trim().to_string();
+    let comment = activity
+        .comment()
+        .map(|comment| comment.trim().to_string())
+        .filter(|comment| !comment.is_empty());
+
+    activity.set_task(task);
+    activity.set_comment(comment);
+
+    activity
+}
+
+/// A row of an uploaded file that could not be imported.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, PartialEq, Eq)]
+struct RowImportError {
+    /// The 1-based number of the row in the file, not counting the header row.
+    #[schema(example = 3)]
+    row_number: usize,
+
+    /// The name of the field that could not be parsed.
+    #[schema(example = "Date")]
+    field: String,
+
+    /// Why the row could not be imported.
+    #[schema(example = "Invalid date '31.02.', expected dd.mm.")]
+    reason: String,
+}
+
+/// The result of an upload in which only some of the rows could be imported.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct PartialImportResponse {
+    /// The imported activities with their server-generated IDs.
+    imported: Vec<Activity>,
+
+    /// The rows that could not be imported.
+    errors: Vec<RowImportError>,
+}
+
+/// Converts the result of an import into the response of an upload request.
+///
+/// # Arguments
+///
+/// - `result`: The result of the import.
+/// - `success_message`: The message returned if all rows were imported.
+///
+/// # Returns
+///
+/// - `200 OK` with the success message if all rows were imported.
+/// - `207 Multi-Status` with the imported activities and the skipped rows if some rows could not be imported.
+/// - `400 Bad Request` if the source could not be parsed or an accounting category could not be resolved.
+/// - `500 Internal Server Error` if the source could not be read or the repository failed.
+fn import_response(
+    result: Result<ImportResult, ActivitiesImporterError>,
+    success_message: &str,
+) -> axum::response::Response {
+    match result {
+        Ok(result) if result.errors.is_empty() => {
+            (StatusCode::OK, Json(success_message.to_string())).into_response()
+        }
+        Ok(result) => (
+            StatusCode::MULTI_STATUS,
+            Json(PartialImportResponse {
+                imported: result
+                    .activities
+                    .iter()
+                    .map(Activity::from_entity)
+                    .collect(),
+                errors: result
+                    .errors
+                    .into_iter()
+                    .map(|error| RowImportError {
+                        row_number: error.row_number,
+                        field: error.field,
+                        reason: error.reason,
+                    })
+                    .collect(),
+            }),
+        )
+            .into_response(),
+        Err(err @ ActivitiesImporterError::RepositoryError(_)) => {
+            ServiceError::DatabaseError(err.to_string()).into_response()
+        }
+        Err(err @ ActivitiesImporterError::IoError(_)) => {
+            ServiceError::InternalError(err.to_string()).into_response()
+        }
+        Err(err) => ServiceError::InvalidRequest(err.to_string()).into_response(),
+    }
+}
+
+/// Uploads activities from a CSV file provided as raw text in the request body.
+#[utoipa::path(
+    put,
+    path = "/upload-csv",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    params(
+        UploadActivitiesQuery,
+        ("X-Import-Source" = Option<String>, Header, description = "The origin of the uploaded activities (defaults to \"csv\")"),
+        ("X-Confirm-Replace" = Option<String>, Header, description = "Must be \"yes\" if `replace_all=true`, which deletes all existing activities")
+    ),
+    request_body(content = String, content_type = "text/csv", description = "CSV file containing activities data"),
+    responses(
+        (status = 200, description = "CSV file processed successfully"),
+        (status = 207, description = "Some rows of the CSV file could not be imported", body = PartialImportResponse),
+        (status = 400, description = "Invalid CSV format", body = ValidationError),
+        (status = 500, description = "Activities could not be stored", body = ValidationError)
+    )
+)]
+async fn upload_activities_csv_raw<R, T>(
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    query: Query<UploadActivitiesQuery>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    if body.is_empty() {
+        ServiceError::InvalidRequest("No CSV data provided".to_string()).into_response()
+    } else {
+        let service_state = store.lock().await;
+        let mut activities_list =
+            ActivitiesList::new(service_state.activities_list_repository.clone());
+
+        let mut csv_importer = csv_importer_for_request(
+            &headers,
+            service_state.accounting_categories_repository.clone(),
+        );
+        let reader = body.as_bytes();
+
+        let replace_mode = match query.parse_replace_mode(&headers) {
+            Ok(mode) => mode,
+            Err(err) => {
+                return err.into_response();
+            }
+        };
+
+        let result = activities_list
+            .import(
+                &mut csv_importer,
+                reader,
+                query.activities_year,
+                replace_mode,
+                Some(Box::new(trim_imported_activity)),
+            )
+            .await;
+
+        import_response(result, "CSV file processed successfully")
+    }
+}
+
+/// Uploads activities from a CSV file provided as multipart/form-data.
+#[utoipa::path(
+    post,
+    path = "/upload-csv",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    params(
+        UploadActivitiesQuery,
+        ("X-Import-Source" = Option<String>, Header, description = "The origin of the uploaded activities (defaults to \"csv\")"),
+        ("X-Confirm-Replace" = Option<String>, Header, description = "Must be \"yes\" if `replace_all=true`, which deletes all existing activities")
+    ),
+    request_body(content_type = "multipart/form-data", description = "CSV file upload"),
+    responses(
+        (status = 200, description = "CSV file processed successfully"),
+        (status = 207, description = "Some rows of the CSV file could not be imported", body = PartialImportResponse),
+        (status = 400, description = "Invalid CSV format", body = ValidationError),
+        (status = 500, description = "Activities could not be stored", body = ValidationError)
+    )
+)]
+async fn upload_activities_csv_multipart<R, T>(
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    query: Query<UploadActivitiesQuery>,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let mut csv_content: Option<String> = None;
+
+    while let Some(field) = multipart.next_field().await.unwrap_or(None) {
+        if field.name() == Some("file") {
+            csv_content =
+                Some(String::from_utf8_lossy(&field.bytes().await.unwrap_or_default()).to_string());
+            break;
+        }
+    }
+
+    if let Some(csv_content) = csv_content {
+        let service_state = store.lock().await;
+        let mut activities_list =
+            ActivitiesList::new(service_state.activities_list_repository.clone());
+
+        let mut csv_importer = csv_importer_for_request(
+            &headers,
+            service_state.accounting_categories_repository.clone(),
+        );
+        let reader = csv_content.as_bytes();
+
+        let replace_mode = match query.parse_replace_mode(&headers) {
+            Ok(mode) => mode,
+            Err(err) => {
+                return err.into_response();
+            }
+        };
+
+        let result = activities_list
+            .import(
+                &mut csv_importer,
+                reader,
+                query.activities_year,
+                replace_mode,
+                Some(Box::new(trim_imported_activity)),
+            )
+            .await;
+
+        import_response(result, "CSV file processed successfully")
+    } else {
+        ServiceError::missing_field("file", "No file field in multipart data").into_response()
+    }
+}
+
+/// Uploads activities from a JSON array provided in the request body.
+#[utoipa::path(
+    post,
+    path = "/upload-json",
+    tag = ACTIVITIES_LIST_SERVICE_TAG,
+    params(
+        UploadActivitiesQuery,
+        ("X-Import-Source" = Option<String>, Header, description = "The origin of the uploaded activities (defaults to \"json\")"),
+        ("X-Confirm-Replace" = Option<String>, Header, description = "Must be \"yes\" if `replace_all=true`, which deletes all existing activities")
+    ),
+    request_body(content = String, content_type = "application/json", description = "JSON array of activities with date, start_time, end_time, pam_category and task"),
+    responses(
+        (status = 200, description = "JSON data processed successfully"),
+        (status = 400, description = "Invalid JSON format", body = ValidationError),
+        (status = 500, description = "Activities could not be stored", body = ValidationError)
+    )
+)]
+async fn upload_activities_json<R, T>(
+    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
+    query: Query<UploadActivitiesQuery>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    if body.is_empty() {
+        return ServiceError::InvalidRequest("No JSON data provided".to_string()).into_response();
+    }
+
+    let replace_mode = match query.parse_replace_mode(&headers) {
+        Ok(mode) => mode,
+        Err(err) => {
+            return err.into_response();
+        }
+    };
+
+    let service_state = store.lock().await;
+    let mut activities_list = ActivitiesList::new(service_state.activities_list_repository.clone());
+
+    let mut json_importer = json_importer_for_request(
+        &headers,
+        service_state.accounting_categories_repository.clone(),
+    );
+
+    let result = activities_list
+        .import(
+            &mut json_importer,
+            body.as_bytes(),
+            query.activities_year,
+            replace_mode,
+            Some(Box::new(trim_imported_activity)),
+        )
+        .await;
+
+    import_response(result, "JSON data processed successfully")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::body::Body;
+    use chrono::{NaiveDate, NaiveTime};
+    use tower::ServiceExt;
+    use work_pulse_core::{
+        entities::{accounting::AccountingCategoryId, activity::ActivityId},
+        infra::repositories::in_memory::{
+            accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+            activities_list::InMemoryActivitiesListRepository,
+        },
+    };
+
+    /// Creates the activities router backed by in-memory repositories, with one recorded activity.
+    async fn router_with_activity(
+        end_time: Option<NaiveTime>,
+    ) -> (axum::Router, work_pulse_core::entities::activity::Activity) {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+
+        let activity = ActivitiesList::new(activities_list_repository.clone())
+            .record(
+                Utc::now().date_naive(),
+                NaiveTime::MIN,
+                end_time,
+                AccountingCategoryId::new(),
+                "Test Task".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+
+        let router = axum::Router::new().nest(
+            "/api/v1/activities",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        );
+
+        (router, activity)
+    }
+
+    /// Creates the activities router backed by in-memory repositories, with one recorded activity
+    /// in the accounting category "Development".
+    async fn router_with_categorized_activity()
+    -> (axum::Router, work_pulse_core::entities::activity::Activity) {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+
+        let category = AccountingCategory::new("Development".to_string());
+        accounting_categories_repository
+            .lock()
+            .await
+            .add(category.clone())
+            .await
+            .unwrap();
+
+        let activity = ActivitiesList::new(activities_list_repository.clone())
+            .record(
+                Utc::now().date_naive(),
+                NaiveTime::MIN,
+                None,
+                category.id().clone(),
+                "Test Task".to_string(),
+                None,
+                None,
+            )
+            .await
+            .expect("Valid activity");
+
+        let router = axum::Router::new().nest(
+            "/api/v1/activities",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        );
+
+        (router, activity)
+    }
+
+    async fn send_get_request(router: axum::Router, uri: &str) -> serde_json::Value {
+        let response = router
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("GET")
+                    .uri(uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    /// Creates the activities router backed by in-memory repositories, with the activities
+    /// "Task 1" to "Task {count}" recorded at consecutive minutes.
+    async fn router_with_activities(count: u32) -> axum::Router {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+
+        let mut activities_list = ActivitiesList::new(activities_list_repository.clone());
+        for i in 1..=count {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(),
+                    NaiveTime::from_hms_opt(9, i, 0).unwrap(),
+                    None,
+                    AccountingCategoryId::new(),
+                    format!("Task {}", i),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        axum::Router::new().nest(
+            "/api/v1/activities",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        )
+    }
+
+    async fn send_list_request(router: axum::Router, query: &str) -> PaginatedResponse<Activity> {
+        let response = router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/api/v1/activities?{}", query))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), 65536)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    fn tasks(page: &PaginatedResponse<Activity>) -> Vec<&str> {
+        page.data.iter().map(|a| a.task.as_str()).collect()
+    }
+
+    /// Creates an activity entity on the given day of October 2023 with whole-hour times.
+    fn activity_entity(
+        task: &str,
+        day: u32,
+        start_hour: u32,
+        end_hour: Option<u32>,
+    ) -> work_pulse_core::entities::activity::Activity {
+        let mut activity = work_pulse_core::entities::activity::Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, day).unwrap(),
+            NaiveTime::from_hms_opt(start_hour, 0, 0).unwrap(),
+            AccountingCategoryId::new(),
+            task.to_string(),
+        );
+        activity
+            .set_end_time(end_hour.map(|hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap()))
+            .unwrap();
+        activity
+    }
+
+    /// Sorts the activities "A" (Oct 3, 10-12), "B" (Oct 1, 14-15), "C" (Oct 2, 9-open) and
+    /// "D" (Oct 2, 8-11) and returns the resulting task order.
+    fn sorted_tasks(sort_by: ActivitySortField, sort_order: SortOrder) -> Vec<String> {
+        let mut activities = vec![
+            activity_entity("A", 3, 10, Some(12)),
+            activity_entity("B", 1, 14, Some(15)),
+            activity_entity("C", 2, 9, None),
+            activity_entity("D", 2, 8, Some(11)),
+        ];
+
+        sort_activities(&mut activities, sort_by, sort_order);
+
+        activities.iter().map(|a| a.task().to_string()).collect()
+    }
+
+    async fn send_complete_request(router: axum::Router, id: &str) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("PATCH")
+                    .uri(format!("/api/v1/activities/{}/complete", id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn trim_imported_activity_should_trim_task_and_comment() {
+        let mut activity = work_pulse_core::entities::activity::Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            AccountingCategoryId::new(),
+            "  Code Review \t".to_string(),
+        );
+        activity.set_comment(Some("   ".to_string()));
+
+        let activity = trim_imported_activity(activity);
+
+        assert_eq!(activity.task(), "Code Review");
+        assert_eq!(activity.comment(), None);
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_return_first_page_by_default() {
+        let router = router_with_activities(3).await;
+
+        let page = send_list_request(router, "").await;
+
+        assert_eq!(page.total, 3);
+        assert_eq!(page.page, 1);
+        assert_eq!(page.page_size, 50);
+        assert_eq!(page.total_pages, 1);
+        assert_eq!(tasks(&page), vec!["Task 3", "Task 2", "Task 1"]);
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_return_requested_page() {
+        let router = router_with_activities(5).await;
+
+        let page = send_list_request(router, "page=2&page_size=2").await;
+
+        assert_eq!(page.total, 5);
+        assert_eq!(page.total_pages, 3);
+        assert_eq!(tasks(&page), vec!["Task 3", "Task 2"]);
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_return_partial_last_page() {
+        let router = router_with_activities(5).await;
+
+        let page = send_list_request(router, "page=3&page_size=2").await;
+
+        assert_eq!(page.page, 3);
+        assert_eq!(tasks(&page), vec!["Task 1"]);
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_return_empty_page_beyond_end() {
+        let router = router_with_activities(5).await;
+
+        let page = send_list_request(router, "page=4&page_size=2").await;
+
+        assert_eq!(page.total, 5);
+        assert_eq!(page.page, 4);
+        assert_eq!(page.total_pages, 3);
+        assert!(page.data.is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_return_first_page_newest_first() {
+        let router = router_with_activities(5).await;
+
+        let page = send_list_request(router, "page=1&page_size=2").await;
+
+        assert_eq!(page.total, 5);
+        assert_eq!(page.page, 1);
+        assert_eq!(tasks(&page), vec!["Task 5", "Task 4"]);
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_clamp_page_size() {
+        let router = router_with_activities(1).await;
+
+        let page = send_list_request(router, "page_size=1000").await;
+
+        assert_eq!(page.page_size, 500);
+    }
+
+    #[test]
+    fn sort_activities_by_date_should_sort_by_date_and_start_time() {
+        assert_eq!(
+            sorted_tasks(ActivitySortField::Date, SortOrder::Asc),
+            vec!["B", "D", "C", "A"]
+        );
+        assert_eq!(
+            sorted_tasks(ActivitySortField::Date, SortOrder::Desc),
+            vec!["A", "C", "D", "B"]
+        );
+    }
+
+    #[test]
+    fn sort_activities_by_start_time_should_sort_by_start_time() {
+        assert_eq!(
+            sorted_tasks(ActivitySortField::StartTime, SortOrder::Asc),
+            vec!["D", "C", "A", "B"]
+        );
+        assert_eq!(
+            sorted_tasks(ActivitySortField::StartTime, SortOrder::Desc),
+            vec!["B", "A", "C", "D"]
+        );
+    }
+
+    #[test]
+    fn sort_activities_by_duration_should_sort_open_activities_to_the_end() {
+        assert_eq!(
+            sorted_tasks(ActivitySortField::Duration, SortOrder::Asc),
+            vec!["B", "A", "D", "C"]
+        );
+        assert_eq!(
+            sorted_tasks(ActivitySortField::Duration, SortOrder::Desc),
+            vec!["D", "A", "B", "C"]
+        );
+    }
+
+    #[test]
+    fn sort_activities_by_task_should_sort_alphabetically() {
+        assert_eq!(
+            sorted_tasks(ActivitySortField::Task, SortOrder::Asc),
+            vec!["A", "B", "C", "D"]
+        );
+        assert_eq!(
+            sorted_tasks(ActivitySortField::Task, SortOrder::Desc),
+            vec!["D", "C", "B", "A"]
+        );
+    }
+
+    #[test]
+    fn list_activities_query_should_deserialize_sort_parameters() {
+        let uri: axum::http::Uri = "/?sort_by=start_time&sort_order=desc".parse().unwrap();
+
+        let query = Query::<ListActivitiesQuery>::try_from_uri(&uri).unwrap();
+
+        assert_eq!(query.sort_by, Some(ActivitySortField::StartTime));
+        assert_eq!(query.sort_order, Some(SortOrder::Desc));
+    }
+
+    #[test]
+    fn list_activities_query_should_reject_unknown_sort_field() {
+        let uri: axum::http::Uri = "/?sort_by=category".parse().unwrap();
+
+        assert!(Query::<ListActivitiesQuery>::try_from_uri(&uri).is_err());
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_sort_before_paginating() {
+        let router = router_with_activities(5).await;
+
+        let page =
+            send_list_request(router, "sort_by=start_time&sort_order=desc&page_size=2").await;
+
+        assert_eq!(tasks(&page), vec!["Task 5", "Task 4"]);
+    }
+
+    #[tokio::test]
+    async fn list_activities_with_cursor_should_yield_all_activities() {
+        let router = router_with_activities(7).await;
+
+        let mut cursor_tasks = Vec::new();
+        let mut query = "limit=3".to_string();
+        loop {
+            let page = send_list_request(router.clone(), &query).await;
+            assert_eq!(page.total, 7);
+            assert!(page.data.len() <= 3);
+            cursor_tasks.extend(tasks(&page).into_iter().map(str::to_string));
+
+            match page.next_cursor {
+                Some(cursor) => query = format!("after={}&limit=3", cursor),
+                None => break,
+            }
+        }
+
+        let all = send_list_request(router, "page_size=500").await;
+        let mut all_tasks = tasks(&all);
+        all_tasks.sort();
+        cursor_tasks.sort();
+        assert_eq!(cursor_tasks, all_tasks);
+    }
+
+    #[tokio::test]
+    async fn list_activities_with_cursor_should_return_next_cursor_for_full_page() {
+        let router = router_with_activities(4).await;
+
+        let first_page = send_list_request(router.clone(), "limit=2").await;
+        assert_eq!(tasks(&first_page), vec!["Task 1", "Task 2"]);
+        assert_eq!(first_page.next_cursor, first_page.data[1].id);
+
+        let last_page = send_list_request(
+            router,
+            &format!("after={}&limit=3", first_page.next_cursor.unwrap()),
+        )
+        .await;
+        assert_eq!(tasks(&last_page), vec!["Task 3", "Task 4"]);
+        assert_eq!(last_page.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn list_activities_with_cursor_should_reject_filters() {
+        let router = router_with_activities(1).await;
+
+        let response = router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/api/v1/activities?limit=2&start_date=2023-10-01")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_filter_by_category_id() {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let category_id = AccountingCategoryId::new();
+
+        let mut activities_list = ActivitiesList::new(activities_list_repository.clone());
+        for (task, category_id) in [
+            ("Billable Task", category_id.clone()),
+            ("Other Task", AccountingCategoryId::new()),
+        ] {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(),
+                    NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                    None,
+                    category_id,
+                    task.to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        let router = axum::Router::new().nest(
+            "/api/v1/activities",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        );
+
+        let page = send_list_request(router, &format!("category_id={}", category_id)).await;
+
+        assert_eq!(page.total, 1);
+        assert_eq!(tasks(&page), vec!["Billable Task"]);
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_filter_by_tag() {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+
+        for (task, tags) in [
+            ("Sprint Task", vec!["sprint-42", "backend"]),
+            ("Other Task", vec!["backend"]),
+            ("Untagged Task", vec![]),
+        ] {
+            let mut activity = activity_entity(task, 2, 9, Some(10));
+            activity.set_tags(tags.into_iter().map(str::to_string).collect());
+            activities_list_repository
+                .lock()
+                .await
+                .add(activity)
+                .await
+                .unwrap();
+        }
+
+        let router = axum::Router::new().nest(
+            "/api/v1/activities",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        );
+
+        let page = send_list_request(router.clone(), "tag=sprint-42").await;
+        assert_eq!(page.total, 1);
+        assert_eq!(tasks(&page), vec!["Sprint Task"]);
+        assert_eq!(page.data[0].tags, vec!["sprint-42", "backend"]);
+
+        let page = send_list_request(router, "tag=backend").await;
+        assert_eq!(tasks(&page), vec!["Sprint Task", "Other Task"]);
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_filter_by_week_number() {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+
+        // 2023-10-01 is the Sunday of week 39, 2023-10-02 and 2023-10-08 are in week 40.
+        for (task, day) in [
+            ("Sunday Task", 1),
+            ("Monday Task", 2),
+            ("Next Sunday Task", 8),
+            ("Later Task", 9),
+        ] {
+            activities_list_repository
+                .lock()
+                .await
+                .add(activity_entity(task, day, 9, Some(10)))
+                .await
+                .unwrap();
+        }
+
+        let router = axum::Router::new().nest(
+            "/api/v1/activities",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        );
+
+        let page = send_list_request(router.clone(), "week_number=40&year=2023").await;
+        assert_eq!(tasks(&page), vec!["Monday Task", "Next Sunday Task"]);
+        assert!(
+            page.data
+                .iter()
+                .all(|activity| activity.week_number == Some(40))
+        );
+
+        let page = send_list_request(router, "week_number=40&year=2024").await;
+        assert!(page.data.is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_fail_with_bad_request_for_incomplete_week_filter() {
+        for query in ["week_number=40", "year=2023", "week_number=54&year=2023"] {
+            let response = router_with_activities(1)
+                .await
+                .oneshot(
+                    axum::http::Request::builder()
+                        .uri(format!("/api/v1/activities?{}", query))
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_search_by_task() {
+        let router = router_with_activities(12).await;
+
+        let page = send_list_request(router, "task=task%201").await;
+
+        assert_eq!(page.total, 4);
+        assert_eq!(
+            tasks(&page),
+            vec!["Task 1", "Task 10", "Task 11", "Task 12"]
+        );
+    }
+
+    #[tokio::test]
+    async fn list_activities_should_fail_with_bad_request_for_invalid_category_id() {
+        let router = router_with_activities(1).await;
+
+        let response = router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/api/v1/activities?category_id=not-a-uuid")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    async fn send_upload_json_request(
+        router: axum::Router,
+        body: &str,
+    ) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/activities/upload-json?activities_year=2023")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn upload_activities_json_should_import_activities() {
+        let router = router_with_activities(0).await;
+        let json_data = r#"[{"date": "2023-03-15", "start_time": "09:00", "end_time": "17:00",
+            "pam_category": "Development", "task": "  Coding  "}]"#;
+
+        let response = send_upload_json_request(router.clone(), json_data).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let page = send_list_request(router, "").await;
+        assert_eq!(tasks(&page), vec!["Coding"]);
+        assert_eq!(page.data[0].import_source, Some("json".to_string()));
+    }
+
+    #[tokio::test]
+    async fn upload_activities_json_should_fail_with_bad_request_for_malformed_json() {
+        let router = router_with_activities(0).await;
+
+        let response = send_upload_json_request(router, r#"[{"date": "2023-03-15""#).await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn import_response_should_map_import_errors_to_status_codes() {
+        for (err, status) in [
+            (ActivitiesImporterError::ParseError, StatusCode::BAD_REQUEST),
+            (
+                ActivitiesImporterError::CategoryResolutionError("unknown".to_string()),
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                ActivitiesImporterError::IoError("connection reset".to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                ActivitiesImporterError::RepositoryError("connection lost".to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+        ] {
+            assert_eq!(import_response(Err(err), "").status(), status);
+        }
+    }
+
+    #[tokio::test]
+    async fn upload_activities_csv_should_report_skipped_rows_with_multi_status() {
+        let router = router_with_activities(0).await;
+        let csv_data = "\
+CW,Date,Check In,Check Out,PAM Category,Topic,Comment
+11,15.03.,09:00,12:00,Development,Coding,
+11,31.02.,09:00,12:00,Development,Coding,
+";
+
+        let response = router
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("PUT")
+                    .uri("/api/v1/activities/upload-csv?activities_year=2023")
+                    .header("content-type", "text/csv")
+                    .body(Body::from(csv_data))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::MULTI_STATUS);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: PartialImportResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result.imported.len(), 1);
+        assert_eq!(
+            result.errors,
+            vec![RowImportError {
+                row_number: 2,
+                field: "Date".to_string(),
+                reason: "Invalid date '31.02.', expected dd.mm.".to_string(),
+            }]
+        );
+
+        let page = send_list_request(router, "").await;
+        assert_eq!(tasks(&page), vec!["Coding"]);
+    }
+
+    const UPLOAD_CSV_DATA: &str = "\
+CW,Date,Check In,Check Out,PAM Category,Topic,Comment
+11,15.03.,09:00,12:00,Development,Coding,
+";
+
+    async fn send_csv_upload_request(
+        router: axum::Router,
+        query: &str,
+        confirm_replace: Option<&str>,
+    ) -> axum::response::Response {
+        let mut request = axum::http::Request::builder()
+            .method("PUT")
+            .uri(format!(
+                "/api/v1/activities/upload-csv?activities_year=2023&{}",
+                query
+            ))
+            .header("content-type", "text/csv");
+        if let Some(confirm_replace) = confirm_replace {
+            request = request.header("X-Confirm-Replace", confirm_replace);
+        }
+
+        router
+            .oneshot(request.body(Body::from(UPLOAD_CSV_DATA)).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn upload_activities_csv_should_keep_existing_activities_by_default() {
+        let router = router_with_activities(2).await;
+
+        let response = send_csv_upload_request(router.clone(), "replace_all=false", None).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = send_list_request(router, "").await;
+        assert_eq!(tasks(&page), vec!["Task 2", "Task 1", "Coding"]);
+    }
+
+    #[tokio::test]
+    async fn upload_activities_csv_should_replace_all_activities_when_confirmed() {
+        let router = router_with_activities(2).await;
+
+        let response =
+            send_csv_upload_request(router.clone(), "replace_all=true", Some("yes")).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = send_list_request(router, "").await;
+        assert_eq!(tasks(&page), vec!["Coding"]);
+    }
+
+    #[tokio::test]
+    async fn upload_activities_csv_should_reject_replace_all_without_confirmation() {
+        for confirm_replace in [None, Some("no")] {
+            let router = router_with_activities(2).await;
+
+            let response =
+                send_csv_upload_request(router.clone(), "replace_all=true", confirm_replace).await;
+
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            let page = send_list_request(router, "").await;
+            assert_eq!(tasks(&page), vec!["Task 2", "Task 1"]);
+        }
+    }
+
+    #[tokio::test]
+    async fn upload_activities_csv_should_not_require_confirmation_for_replace_mode_all() {
+        let router = router_with_activities(2).await;
+
+        let response = send_csv_upload_request(router.clone(), "replace_mode=all", None).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = send_list_request(router, "").await;
+        assert_eq!(tasks(&page), vec!["Coding"]);
+    }
+
+    #[tokio::test]
+    async fn upload_activities_csv_should_reject_replace_all_with_other_replace_mode() {
+        let router = router_with_activities(2).await;
+
+        let response = send_csv_upload_request(
+            router,
+            "replace_all=true&replace_mode=import_date_range",
+            Some("yes"),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn upload_activities_csv_multipart_should_replace_all_activities_when_confirmed() {
+        let router = router_with_activities(2).await;
+        let body = format!(
+            "--boundary\r\n\
+Content-Disposition: form-data; name=\"file\"; filename=\"activities.csv\"\r\n\
+Content-Type: text/csv\r\n\r\n\
+{}\r\n\
+--boundary--\r\n",
+            UPLOAD_CSV_DATA
+        );
+
+        let response = router
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/activities/upload-csv?activities_year=2023&replace_all=true")
+                    .header("content-type", "multipart/form-data; boundary=boundary")
+                    .header("X-Confirm-Replace", "yes")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = send_list_request(router, "").await;
+        assert_eq!(tasks(&page), vec!["Coding"]);
+    }
+
+    async fn send_delete_range_request(
+        router: axum::Router,
+        query: &str,
+    ) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/api/v1/activities?{}", query))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn delete_activities_in_date_range_should_delete_only_activities_in_range() {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        for activity in [
+            activity_entity("A", 3, 10, Some(12)),
+            activity_entity("B", 1, 14, Some(15)),
+            activity_entity("C", 2, 9, None),
+        ] {
+            activities_list_repository
+                .lock()
+                .await
+                .add(activity)
+                .await
+                .unwrap();
+        }
+        let router = axum::Router::new().nest(
+            "/api/v1/activities",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        );
+
+        let response =
+            send_delete_range_request(router.clone(), "start_date=2023-10-02&end_date=2023-10-03")
+                .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let result: DeletedActivities = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result.deleted, 2);
+
+        let page = send_list_request(router, "").await;
+        assert_eq!(tasks(&page), vec!["B"]);
+    }
+
+    #[tokio::test]
+    async fn delete_activities_in_date_range_should_require_both_dates() {
+        let router = router_with_activities(1).await;
+
+        for query in [
+            "start_date=2023-10-01",
+            "end_date=2023-10-31",
+            "start_date=2023-10-31&end_date=2023-10-01",
+            "start_date=2023-10-01&end_date=2023-10-32",
+        ] {
+            let response = send_delete_range_request(router.clone(), query).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", query);
+        }
+
+        let page = send_list_request(router, "").await;
+        assert_eq!(page.data.len(), 1);
+    }
+
+    async fn send_duration_request(router: axum::Router, query: &str) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/api/v1/activities/duration?{}", query))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn total_duration_of(router: axum::Router, query: &str) -> String {
+        let response = send_duration_request(router, query).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let total: TotalDuration = serde_json::from_slice(&body).unwrap();
+        total.duration
+    }
+
+    #[tokio::test]
+    async fn get_total_duration_should_sum_activities_of_date_and_range() {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        for activity in [
+            activity_entity("A", 3, 10, Some(12)),
+            activity_entity("B", 1, 14, Some(15)),
+            activity_entity("C", 2, 9, None),
+            activity_entity("D", 2, 8, Some(11)),
+        ] {
+            activities_list_repository
+                .lock()
+                .await
+                .add(activity)
+                .await
+                .unwrap();
+        }
+        let router = axum::Router::new().nest(
+            "/api/v1/activities",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        );
+
+        assert_eq!(
+            total_duration_of(router.clone(), "date=2023-10-02").await,
+            "PT10800S"
+        );
+        assert_eq!(
+            total_duration_of(router.clone(), "date=2023-10-04").await,
+            "P0D"
+        );
+        assert_eq!(
+            total_duration_of(router, "start_date=2023-10-01&end_date=2023-10-03").await,
+            "PT21600S"
+        );
+    }
+
+    #[tokio::test]
+    async fn get_total_duration_should_reject_incomplete_range() {
+        let router = router_with_activities(0).await;
+
+        let response = send_duration_request(router.clone(), "start_date=2023-10-01").await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = send_duration_request(router, "date=2023-10-32").await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    async fn send_category_summary_request(
+        router: axum::Router,
+        query: &str,
+    ) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/api/v1/activities/category-summary?{}", query))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn get_category_summary_should_summarize_activities_in_range() {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+        let category_id = AccountingCategoryId::new();
+        for mut activity in [
+            activity_entity("A", 3, 10, Some(12)),
+            activity_entity("B", 1, 14, Some(15)),
+            activity_entity("C", 2, 9, None),
+            activity_entity("D", 2, 8, Some(11)),
+        ] {
+            activity.set_accounting_category_id(category_id.clone());
+            activities_list_repository
+                .lock()
+                .await
+                .add(activity)
+                .await
+                .unwrap();
+        }
+        let router = axum::Router::new().nest(
+            "/api/v1/activities",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        );
+
+        let response =
+            send_category_summary_request(router, "start_date=2023-10-02&end_date=2023-10-03")
+                .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let summary: Vec<CategorySummary> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(summary.len(), 1);
+        assert_eq!(summary[0].category_id, category_id.to_string());
+        assert_eq!(summary[0].activity_count, 3);
+        assert_eq!(summary[0].total_duration, "PT18000S");
+    }
+
+    #[tokio::test]
+    async fn get_category_summary_should_require_valid_range() {
+        let router = router_with_activities(1).await;
+
+        for query in [
+            "start_date=2023-10-01",
+            "end_date=2023-10-31",
+            "start_date=2023-10-31&end_date=2023-10-01",
+            "start_date=2023-10-01&end_date=2023-10-32",
+        ] {
+            let response = send_category_summary_request(router.clone(), query).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", query);
+        }
+    }
+
+    async fn send_bulk_create_request(
+        router: axum::Router,
+        body: &str,
+    ) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/activities/bulk")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn create_activities_bulk_should_create_all_activities() {
+        let router = router_with_activities(0).await;
+        let body = r#"[
+            {"date": "2023-10-02", "start_time": "09:00:00", "end_time": "10:00:00",
+             "accounting_category_id": "550e8400-e29b-41d4-a716-446655440000", "task": "Task 1"},
+            {"date": "2023-10-02", "start_time": "10:00:00",
+             "accounting_category_id": "550e8400-e29b-41d4-a716-446655440000", "task": "Task 2"}
+        ]"#;
+
+        let response = send_bulk_create_request(router.clone(), body).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let created: Vec<Activity> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(created.len(), 2);
+        assert!(created.iter().all(|activity| activity.id.is_some()));
+
+        let page = send_list_request(router, "").await;
+        assert_eq!(tasks(&page), vec!["Task 2", "Task 1"]);
     }
-}
 
-/// Query parameters for uploading activities.
-#[derive(Deserialize, IntoParams)]
-struct UploadActivitiesQuery {
-    /// The year of the activities being uploaded.
-    #[param(example = "2025")]
-    activities_year: u16,
+    #[tokio::test]
+    async fn create_activities_bulk_should_report_partial_success() {
+        let router = router_with_activities(0).await;
+        let body = r#"[
+            {"date": "2023-10-02", "start_time": "09:00:00", "end_time": "10:00:00",
+             "accounting_category_id": "550e8400-e29b-41d4-a716-446655440000", "task": "Task 1"},
+            {"date": "2023-13-02", "start_time": "10:00:00",
+             "accounting_category_id": "550e8400-e29b-41d4-a716-446655440000", "task": "Task 2"},
+            {"date": "2023-10-02", "start_time": "11:00:00", "end_time": "10:00:00",
+             "accounting_category_id": "550e8400-e29b-41d4-a716-446655440000", "task": "Task 3"}
+        ]"#;
 
-    /// How to handle existing activities when importing. Options are:
-    ///
-    /// - "none" (default): Do not delete any existing activities.
-    /// - "all": Delete all existing activities before import.
-    /// - "import_date_range": Delete existing activities within the date range of the imported activities.
-    #[param(example = "all")]
-    replace_mode: Option<String>,
-}
+        let response = send_bulk_create_request(router.clone(), body).await;
+        assert_eq!(response.status(), StatusCode::MULTI_STATUS);
 
-impl UploadActivitiesQuery {
-    fn parse_replace_mode(&self) -> Result<ReplaceMode, String> {
-        match self.replace_mode.as_deref() {
-            None | Some("none") => Ok(ReplaceMode::None),
-            Some("all") => Ok(ReplaceMode::All),
-            Some("import_date_range") => Ok(ReplaceMode::ImportDateRange),
-            Some(other) => Err(format!(
-                "Invalid replace_mode: '{}'. Valid options are 'none', 'all', 'import_date_range'",
-                other
-            )),
-        }
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let result: BulkCreateActivitiesResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result.created.len(), 1);
+        assert_eq!(result.created[0].task, "Task 1");
+        assert_eq!(
+            result
+                .failures
+                .iter()
+                .map(|failure| (failure.index, failure.error.field.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![(1, Some("date")), (2, Some("end_time"))]
+        );
+
+        let page = send_list_request(router, "").await;
+        assert_eq!(tasks(&page), vec!["Task 1"]);
     }
-}
 
-/// Uploads activities from a CSV file provided as raw text in the request body.
-#[utoipa::path(
-    put,
-    path = "/upload-csv",
-    tag = ACTIVITIES_LIST_SERVICE_TAG,
-    params(
-        UploadActivitiesQuery,
-    ),
-    request_body(content = String, content_type = "text/csv", description = "CSV file containing activities data"),
-    responses(
-        (status = 200, description = "CSV file processed successfully"),
-        (status = 400, description = "Invalid CSV format", body = String)
-    )
-)]
-async fn upload_activities_csv_raw<R, T>(
-    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
-    query: Query<UploadActivitiesQuery>,
-    body: String,
-) -> impl IntoResponse
-where
-    R: 'static + Send + Sync + ActivitiesListRepository,
-    T: 'static + Send + Sync + AccountingCategoriesListRepository,
-{
-    if body.is_empty() {
-        (
-            StatusCode::BAD_REQUEST,
-            Json("No CSV data provided".to_string()),
-        )
-            .into_response()
-    } else {
-        let service_state = store.lock().await;
-        let mut activities_list =
-            ActivitiesList::new(service_state.activities_list_repository.clone());
+    #[tokio::test]
+    async fn create_activities_bulk_should_fail_with_bad_request_for_empty_body() {
+        let router = router_with_activities(0).await;
 
-        let mut csv_importer =
-            CsvActivitiesImporter::new(service_state.accounting_categories_repository.clone());
-        let reader = body.as_bytes();
+        let response = send_bulk_create_request(router, "[]").await;
 
-        let replace_mode = match query.parse_replace_mode() {
-            Ok(mode) => mode,
-            Err(err) => {
-                return (StatusCode::BAD_REQUEST, Json(err)).into_response();
-            }
-        };
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
 
-        match activities_list
-            .import(
-                &mut csv_importer,
-                reader,
-                query.activities_year,
-                replace_mode,
-            )
+    #[tokio::test]
+    async fn create_activities_bulk_should_fail_with_bad_request_if_no_activity_is_valid() {
+        let router = router_with_activities(0).await;
+        let body = r#"[{"date": "2023-10-02", "start_time": "09:00:00",
+            "accounting_category_id": "not-a-uuid", "task": "Task 1"}]"#;
+
+        let response = send_bulk_create_request(router.clone(), body).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
             .await
-        {
-            Ok(_) => (
-                StatusCode::OK,
-                Json("CSV file processed successfully".to_string()),
+            .unwrap();
+        let error: ValidationError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "BULK_CREATE_FAILED");
+
+        let page = send_list_request(router, "").await;
+        assert!(page.data.is_empty());
+    }
+
+    async fn send_activity_request(
+        router: axum::Router,
+        method: &str,
+        body: &str,
+    ) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .method(method)
+                    .uri("/api/v1/activities")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
             )
-                .into_response(),
-            Err(err) => (StatusCode::BAD_REQUEST, Json(err.to_string())).into_response(),
-        }
+            .await
+            .unwrap()
     }
-}
 
-/// Uploads activities from a CSV file provided as multipart/form-data.
-#[utoipa::path(
-    post,
-    path = "/upload-csv",
-    tag = ACTIVITIES_LIST_SERVICE_TAG,
-    params(
-        UploadActivitiesQuery,
-    ),
-    request_body(content_type = "multipart/form-data", description = "CSV file upload"),
-    responses(
-        (status = 200, description = "CSV file processed successfully"),
-        (status = 400, description = "Invalid CSV format", body = String)
-    )
-)]
-async fn upload_activities_csv_multipart<R, T>(
-    State(store): State<Arc<Mutex<ActivitiesServiceState<R, T>>>>,
-    query: Query<UploadActivitiesQuery>,
-    mut multipart: Multipart,
-) -> impl IntoResponse
-where
-    R: 'static + Send + Sync + ActivitiesListRepository,
-    T: 'static + Send + Sync + AccountingCategoriesListRepository,
-{
-    let mut csv_content: Option<String> = None;
+    #[tokio::test]
+    async fn create_activity_should_store_tags() {
+        let router = router_with_activities(0).await;
+        let body = r#"{"date": "2023-10-02", "start_time": "09:00:00",
+            "accounting_category_id": "550e8400-e29b-41d4-a716-446655440000", "task": "Task 1",
+            "tags": ["sprint-42", "backend"]}"#;
 
-    while let Some(field) = multipart.next_field().await.unwrap_or(None) {
-        if field.name() == Some("file") {
-            csv_content =
-                Some(String::from_utf8_lossy(&field.bytes().await.unwrap_or_default()).to_string());
-            break;
+        let response = send_activity_request(router.clone(), "POST", body).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let created: Activity = serde_json::from_slice(&body).unwrap();
+        assert_eq!(created.tags, vec!["sprint-42", "backend"]);
+
+        let activity = send_get_request(
+            router,
+            &format!("/api/v1/activities/{}", created.id.unwrap()),
+        )
+        .await;
+        assert_eq!(
+            activity["tags"],
+            serde_json::json!(["sprint-42", "backend"])
+        );
+    }
+
+    #[tokio::test]
+    async fn create_activity_should_fail_with_bad_request_for_malformed_fields() {
+        let router = router_with_activities(0).await;
+
+        for (body, field) in [
+            (
+                r#"{"date": "2023-13-02", "start_time": "09:00:00",
+                 "accounting_category_id": "550e8400-e29b-41d4-a716-446655440000", "task": "Task 1"}"#,
+                "date",
+            ),
+            (
+                r#"{"date": "2023-10-02", "start_time": "9 o'clock",
+                 "accounting_category_id": "550e8400-e29b-41d4-a716-446655440000", "task": "Task 1"}"#,
+                "start_time",
+            ),
+        ] {
+            let response = send_activity_request(router.clone(), "POST", body).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", field);
+
+            let body = axum::body::to_bytes(response.into_body(), 4096)
+                .await
+                .unwrap();
+            let error: ValidationError = serde_json::from_slice(&body).unwrap();
+            assert_eq!(error.field.as_deref(), Some(field));
         }
+
+        let page = send_list_request(router, "").await;
+        assert!(page.data.is_empty());
     }
 
-    if let Some(csv_content) = csv_content {
-        let service_state = store.lock().await;
-        let mut activities_list =
-            ActivitiesList::new(service_state.activities_list_repository.clone());
+    #[tokio::test]
+    async fn update_activity_should_fail_with_bad_request_for_malformed_fields() {
+        let (router, activity) = router_with_activity(None).await;
 
-        let mut csv_importer =
-            CsvActivitiesImporter::new(service_state.accounting_categories_repository.clone());
-        let reader = csv_content.as_bytes();
+        for (date, end_time, field) in [
+            ("2023-02-30", "10:00:00", "date"),
+            ("2023-10-02", "25:00:00", "end_time"),
+        ] {
+            let body = serde_json::json!({
+                "id": activity.id().to_string(),
+                "date": date,
+                "start_time": "09:00:00",
+                "end_time": end_time,
+                "accounting_category_id": activity.accounting_category_id().to_string(),
+                "task": "Updated Task",
+            })
+            .to_string();
 
-        let replace_mode = match query.parse_replace_mode() {
-            Ok(mode) => mode,
-            Err(err) => {
-                return (StatusCode::BAD_REQUEST, Json(err)).into_response();
-            }
-        };
+            let response = send_activity_request(router.clone(), "PUT", &body).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", field);
 
-        match activities_list
-            .import(
-                &mut csv_importer,
-                reader,
-                query.activities_year,
-                replace_mode,
+            let body = axum::body::to_bytes(response.into_body(), 4096)
+                .await
+                .unwrap();
+            let error: ValidationError = serde_json::from_slice(&body).unwrap();
+            assert_eq!(error.field.as_deref(), Some(field));
+        }
+    }
+
+    #[tokio::test]
+    async fn export_activities_csv_should_return_csv_attachment() {
+        let router = router_with_activities(2).await;
+
+        let response = router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/api/v1/activities/export-csv")
+                    .body(Body::empty())
+                    .unwrap(),
             )
             .await
-        {
-            Ok(_) => (
-                StatusCode::OK,
-                Json("CSV file processed successfully".to_string()),
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/csv");
+        assert_eq!(
+            response.headers()[header::CONTENT_DISPOSITION],
+            "attachment; filename=activities.csv"
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let csv_data = String::from_utf8(body.to_vec()).unwrap();
+        let lines = csv_data.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            "CW,Date,Check In,Check Out,PAM Category,Topic,Comment"
+        );
+        assert_eq!(lines[1], "40,02.10.,09:01,,,Task 1,");
+    }
+
+    async fn send_export_json_request(
+        router: axum::Router,
+        query: &str,
+    ) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/api/v1/activities/export-json?{}", query))
+                    .body(Body::empty())
+                    .unwrap(),
             )
-                .into_response(),
-            Err(err) => (StatusCode::BAD_REQUEST, Json(err.to_string())).into_response(),
-        }
-    } else {
-        (
-            StatusCode::BAD_REQUEST,
-            Json("No file field in multipart data".to_string()),
-        )
-            .into_response()
+            .await
+            .unwrap()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[tokio::test]
+    async fn export_activities_json_should_return_json_array() {
+        let router = router_with_activities(2).await;
 
-    use chrono::{NaiveDate, NaiveTime};
-    use work_pulse_core::entities::{accounting::AccountingCategoryId, activity::ActivityId};
+        let response = send_export_json_request(router, "").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        assert_eq!(
+            response.headers()[header::CONTENT_DISPOSITION],
+            "attachment; filename=activities.json"
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let activities: Vec<work_pulse_core::entities::activity::Activity> =
+            serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            activities.iter().map(|a| a.task()).collect::<Vec<_>>(),
+            vec!["Task 1", "Task 2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn export_activities_json_should_filter_by_date_range() {
+        let router = router_with_activities(1).await;
+
+        let response =
+            send_export_json_request(router.clone(), "start_date=2023-10-03&end_date=2023-10-31")
+                .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"[]");
+
+        let response = send_export_json_request(router, "start_date=2023-10-32").await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn complete_activity_should_set_end_time() {
+        let (router, activity) = router_with_activity(None).await;
+
+        let response = send_complete_request(router, &activity.id().to_string()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let completed: Activity = serde_json::from_slice(&body).unwrap();
+        assert!(completed.end_time.is_some());
+    }
+
+    #[tokio::test]
+    async fn complete_activity_should_fail_with_conflict_when_already_completed() {
+        let (router, activity) =
+            router_with_activity(Some(NaiveTime::from_hms_opt(0, 30, 0).unwrap())).await;
+
+        let response = send_complete_request(router, &activity.id().to_string()).await;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn complete_activity_should_fail_with_not_found_for_unknown_activity() {
+        let (router, _) = router_with_activity(None).await;
+
+        let response = send_complete_request(router, &ActivityId::new().to_string()).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn complete_activity_should_fail_with_validation_error_for_invalid_id() {
+        let (router, _) = router_with_activity(None).await;
+
+        let response = send_complete_request(router, "not-a-uuid").await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let error: ValidationError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "INVALID_UUID");
+        assert_eq!(error.field, Some("id".to_string()));
+    }
 
     #[test]
     fn activity_from_entity_should_convert_correctly() {
@@ -574,8 +3478,11 @@ mod tests {
             AccountingCategoryId::new(),
             "Test Task".to_string(),
         );
-        entity.set_end_time(Some(NaiveTime::from_hms_opt(15, 30, 0).unwrap()));
+        entity
+            .set_end_time(Some(NaiveTime::from_hms_opt(15, 30, 0).unwrap()))
+            .unwrap();
         entity.set_comment(Some("test comment".to_string()));
+        entity.set_created_by(Some("jdoe".to_string()));
 
         let activity = Activity::from_entity(&entity);
 
@@ -589,6 +3496,90 @@ mod tests {
         );
         assert_eq!(activity.task, "Test Task");
         assert_eq!(activity.comment, Some("test comment".to_string()));
+        assert_eq!(activity.created_by, Some("jdoe".to_string()));
+    }
+
+    #[test]
+    fn stop_timer_request_parse_should_parse_valid_values() {
+        let category_id = AccountingCategoryId::new();
+        let request = StopTimerRequest {
+            start_time: "14:30:00".to_string(),
+            date: "2023-01-10".to_string(),
+            category_id: category_id.to_string(),
+            task: "Test Task".to_string(),
+        };
+
+        let (date, start_time, parsed_category_id) = request.parse().unwrap();
+
+        assert_eq!(date, NaiveDate::from_ymd_opt(2023, 1, 10).unwrap());
+        assert_eq!(start_time, NaiveTime::from_hms_opt(14, 30, 0).unwrap());
+        assert_eq!(parsed_category_id, category_id);
+    }
+
+    #[test]
+    fn stop_timer_request_parse_should_fail_with_invalid_start_time() {
+        let request = StopTimerRequest {
+            start_time: "25:00:00".to_string(),
+            date: "2023-01-10".to_string(),
+            category_id: AccountingCategoryId::new().to_string(),
+            task: "Test Task".to_string(),
+        };
+
+        assert_eq!(
+            request.parse().unwrap_err(),
+            ServiceError::invalid_parameter("start_time", "Invalid start time format: '25:00:00'")
+        );
+    }
+
+    fn timer_started_at(hour: u32) -> (NaiveDate, NaiveTime) {
+        (
+            NaiveDate::from_ymd_opt(2023, 1, 10).unwrap(),
+            NaiveTime::from_hms_opt(hour, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn timer_end_time_should_truncate_to_whole_seconds() {
+        let (date, start_time) = timer_started_at(14);
+        let now = date.and_hms_milli_opt(15, 30, 10, 750).unwrap();
+
+        assert_eq!(
+            timer_end_time(date, start_time, now),
+            Ok(NaiveTime::from_hms_opt(15, 30, 10).unwrap())
+        );
+    }
+
+    #[test]
+    fn timer_end_time_should_fail_if_stopped_before_start() {
+        let (date, start_time) = timer_started_at(14);
+        let now = date.and_hms_milli_opt(14, 0, 0, 500).unwrap();
+
+        assert_eq!(
+            timer_end_time(date, start_time, now),
+            Err(ServiceError::invalid_parameter(
+                "start_time",
+                "The timer must be stopped after it was started"
+            ))
+        );
+    }
+
+    #[test]
+    fn timer_end_time_should_fail_if_stopped_on_a_later_date() {
+        for (stop_day, stop_hour) in [(11, 1), (11, 15), (12, 9)] {
+            let (date, start_time) = timer_started_at(14);
+            let now = NaiveDate::from_ymd_opt(2023, 1, stop_day)
+                .unwrap()
+                .and_hms_opt(stop_hour, 0, 0)
+                .unwrap();
+
+            assert_eq!(
+                timer_end_time(date, start_time, now),
+                Err(ServiceError::invalid_parameter(
+                    "date",
+                    "The timer must be stopped on the date it was started"
+                ))
+            );
+        }
     }
 
     #[test]
@@ -596,14 +3587,21 @@ mod tests {
         let activity = Activity {
             id: Some(ActivityId::new().to_string()),
             date: "2023-01-10".to_string(),
+            week_number: None,
             start_time: "14:30:00".to_string(),
             end_time: Some("15:30:00".to_string()),
             accounting_category_id: AccountingCategoryId::new().to_string(),
+            accounting_category_name: None,
             task: "Test Task".to_string(),
             comment: Some("test comment".to_string()),
+            created_by: Some("jdoe".to_string()),
+            import_source: Some("csv".to_string()),
+            tags: vec!["sprint-42".to_string()],
+            created_at: None,
+            updated_at: None,
         };
 
-        let entity = activity.to_entity();
+        let entity = activity.to_entity().unwrap();
 
         assert_eq!(entity.id().to_string(), activity.id.unwrap());
         assert_eq!(entity.date().to_string(), "2023-01-10");
@@ -618,5 +3616,70 @@ mod tests {
         );
         assert_eq!(entity.task(), "Test Task");
         assert_eq!(entity.comment(), Some("test comment"));
+        assert_eq!(entity.created_by(), Some("jdoe"));
+        assert_eq!(entity.import_source(), Some("csv"));
+        assert_eq!(entity.tags(), ["sprint-42"]);
+    }
+
+    #[test]
+    fn activity_from_entity_with_repo_should_include_category_name() {
+        let category = AccountingCategory::new("Development".to_string());
+        let entity = work_pulse_core::entities::activity::Activity::new(
+            NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            category.id().clone(),
+            "Code Review".to_string(),
+        );
+
+        let enriched = Activity::from_entity_with_repo(&entity, Some(&category));
+        let unknown = Activity::from_entity_with_repo(&entity, None);
+
+        assert_eq!(
+            enriched.accounting_category_name,
+            Some("Development".to_string())
+        );
+        assert_eq!(unknown.accounting_category_name, None);
+        assert_eq!(unknown.task, "Code Review");
+    }
+
+    #[tokio::test]
+    async fn get_activity_by_id_should_include_category_name_on_request() {
+        let (router, activity) = router_with_categorized_activity().await;
+
+        let json = send_get_request(
+            router,
+            &format!(
+                "/api/v1/activities/{}?include_category_name=true",
+                activity.id()
+            ),
+        )
+        .await;
+
+        assert_eq!(json["accounting_category_name"], "Development");
+        assert_eq!(json["task"], "Test Task");
+    }
+
+    #[tokio::test]
+    async fn get_activity_by_id_should_omit_category_name_by_default() {
+        let (router, activity) = router_with_categorized_activity().await;
+
+        let json = send_get_request(router, &format!("/api/v1/activities/{}", activity.id())).await;
+
+        assert!(json.get("accounting_category_name").is_none());
+        assert_eq!(
+            json["accounting_category_id"],
+            activity.accounting_category_id().to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_activity_by_id_should_include_timestamps() {
+        let (router, activity) = router_with_categorized_activity().await;
+
+        let json = send_get_request(router, &format!("/api/v1/activities/{}", activity.id())).await;
+
+        let created_at = json["created_at"].as_str().expect("created_at is set");
+        assert!(chrono::DateTime::parse_from_rfc3339(created_at).is_ok());
+        assert_eq!(json["updated_at"], created_at);
     }
 }
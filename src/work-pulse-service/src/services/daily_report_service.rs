@@ -5,14 +5,46 @@ use axum::{
     extract::{Query, State},
     response::IntoResponse,
 };
+use chrono::NaiveTime;
 use hyper::StatusCode;
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 use utoipa::{IntoParams, ToSchema};
 use utoipa_axum::{router::OpenApiRouter, routes};
-use work_pulse_core::{adapters::ActivitiesListRepository, use_cases};
+use work_pulse_core::{
+    adapters::{AccountingCategoriesListRepository, ActivitiesListRepository},
+    use_cases,
+};
+
+use crate::{
+    prelude::DAILY_REPORT_SERVICE_TAG,
+    services::{
+        error::{ServiceError, ValidationError},
+        params::{parse_date_param, parse_optional_date_param, parse_time_param},
+    },
+};
+
+/// The default start of the work day used for the gap calculation.
+const DEFAULT_WORK_START: NaiveTime = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+
+/// The default end of the work day used for the gap calculation.
+const DEFAULT_WORK_END: NaiveTime = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+
+/// The maximum number of days of a daily report range.
+const MAX_DATE_RANGE_DAYS: i64 = 31;
 
-use crate::prelude::DAILY_REPORT_SERVICE_TAG;
+/// Shared state for the daily report service.
+struct DailyReportServiceState<R, T>
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    /// The activities list repository.
+    activities_list_repository: Arc<Mutex<R>>,
+
+    /// The accounting categories repository.
+    accounting_categories_repository: Arc<Mutex<T>>,
+}
 
 /// The Daily Report Activity.
 #[derive(Serialize, Deserialize, ToSchema, Clone)]
@@ -42,6 +74,18 @@ struct DailyReportActivity {
     task: String,
 }
 
+/// A time window of the work day not covered by any completed activity.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct DailyReportGap {
+    /// The start time of the gap in ISO 8601 format (HH:MM:SS).
+    #[schema(example = "12:00:00")]
+    start_time: String,
+
+    /// The end time of the gap in ISO 8601 format (HH:MM:SS).
+    #[schema(example = "13:00:00")]
+    end_time: String,
+}
+
 /// The Daily Report.
 #[derive(Serialize, Deserialize, ToSchema, Clone)]
 struct DailyReport {
@@ -49,38 +93,126 @@ struct DailyReport {
     #[schema(example = "2023-01-10")]
     report_date: String,
 
+    /// Whether the report date is on a weekend (Saturday or Sunday).
+    #[schema(example = false)]
+    is_weekend: bool,
+
     /// The total duration of activities for the day.
     #[schema(example = "PT1800S")]
     total_duration: String,
 
     /// The list of activities for the day.
     activities: Vec<DailyReportActivity>,
+
+    /// The time windows of the work day not covered by any completed activity.
+    gaps: Vec<DailyReportGap>,
+
+    /// The total cost of the activities with a known hourly rate.
+    #[schema(example = 240.0)]
+    total_cost: Option<f64>,
+
+    /// An optional natural language summary of the day.
+    #[schema(example = "On 2023-01-10 you worked 7h 30m across 5 activities in 3 categories.")]
+    summary: Option<String>,
+}
+
+impl DailyReport {
+    /// Converts a `work_pulse_core::use_cases::daily_report::DailyReport` to a `DailyReport` DTO.
+    ///
+    /// # Arguments
+    ///
+    /// - `daily_report`: The report to convert.
+    /// - `work_start`: The start of the work day used to find untracked gaps.
+    /// - `work_end`: The end of the work day used to find untracked gaps.
+    /// - `include_summary`: Whether to include a natural language summary.
+    fn from_report(
+        daily_report: &use_cases::daily_report::DailyReport,
+        work_start: NaiveTime,
+        work_end: NaiveTime,
+        include_summary: bool,
+    ) -> Self {
+        let activities: Vec<DailyReportActivity> = daily_report
+            .activities()
+            .iter()
+            .map(|activity| DailyReportActivity {
+                id: Some(activity.id().to_string()),
+                duration: activity.duration().to_string(),
+                start_time: activity.start_time().to_string(),
+                end_time: activity.end_time().map(|t| t.to_string()),
+                accounting_category_id: activity.accounting_category_id().to_string(),
+                task: activity.task().to_string(),
+            })
+            .collect();
+        let gaps = daily_report
+            .untracked_gaps(work_start, work_end)
+            .into_iter()
+            .map(|(start_time, end_time)| DailyReportGap {
+                start_time: start_time.to_string(),
+                end_time: end_time.to_string(),
+            })
+            .collect();
+        let summary =
+            include_summary.then(|| use_cases::reports::summary::daily_summary(daily_report));
+
+        Self {
+            report_date: daily_report.date().to_string(),
+            is_weekend: daily_report.is_weekend(),
+            total_duration: daily_report.total_duration().to_string(),
+            activities,
+            gaps,
+            total_cost: daily_report.total_cost(),
+            summary,
+        }
+    }
 }
 
 /// Creates an OpenAPI router for the Daily Report Service.
 ///
 /// # Arguments
 ///
-/// - `repository`: An `Arc<Mutex<PsqlActivitiesListRepository>>` instance for accessing the activities repository.
+/// - `activities_list_repository`: An `Arc<Mutex<PsqlActivitiesListRepository>>` instance for accessing the activities repository.
+/// - `accounting_categories_repository`: An `Arc<Mutex<PsqlAccountingCategoriesListRepository>>` instance for accessing the accounting categories repository.
 ///
 /// # Returns
 ///
 /// - An `OpenApiRouter` configured with routes for generating daily reports.
-pub fn router<R>(repository: Arc<Mutex<R>>) -> OpenApiRouter
+pub fn router<R, T>(
+    activities_list_repository: Arc<Mutex<R>>,
+    accounting_categories_repository: Arc<Mutex<T>>,
+) -> OpenApiRouter
 where
     R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
 {
+    let store = Arc::new(Mutex::new(DailyReportServiceState {
+        activities_list_repository,
+        accounting_categories_repository,
+    }));
+
     OpenApiRouter::new()
         .routes(routes!(generate_daily_report))
-        .with_state(repository)
+        .routes(routes!(generate_daily_reports_for_range))
+        .with_state(store)
 }
 
 /// Query parameters for generating daily reports.
 #[derive(Deserialize, IntoParams)]
 struct GenerateDailyReportQuery {
-    /// The date of the activities being reported.
+    /// The date of the activities being reported (defaults to today).
     #[param(example = "2025-10-12")]
-    report_date: String,
+    report_date: Option<String>,
+
+    /// Whether to include a natural language summary in the report.
+    #[param(example = true)]
+    include_summary: Option<bool>,
+
+    /// The start of the work day used to find untracked gaps (defaults to 09:00:00).
+    #[param(example = "09:00:00")]
+    work_start: Option<String>,
+
+    /// The end of the work day used to find untracked gaps (defaults to 17:00:00).
+    #[param(example = "17:00:00")]
+    work_end: Option<String>,
 }
 
 /// Generates a daily report for the specified date.
@@ -92,39 +224,344 @@ struct GenerateDailyReportQuery {
         GenerateDailyReportQuery,
     ),
     responses(
-        (status = 201, description = "Daily report created successfully", body = DailyReport)
+        (status = 201, description = "Daily report created successfully", body = DailyReport),
+        (status = 400, description = "Invalid report date or work day bounds", body = ValidationError),
+        (status = 500, description = "Activities could not be loaded", body = ValidationError)
     )
 )]
-async fn generate_daily_report<R>(
-    State(repository): State<Arc<Mutex<R>>>,
+async fn generate_daily_report<R, T>(
+    State(store): State<Arc<Mutex<DailyReportServiceState<R, T>>>>,
     query: Query<GenerateDailyReportQuery>,
 ) -> impl IntoResponse
 where
     R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
 {
-    let report_date = query.report_date.parse().unwrap();
-    let repository = repository.lock().await;
-    let daily_report = use_cases::daily_report::DailyReport::new(report_date, &*repository).await;
+    let report_date = match parse_optional_date_param("report_date", query.report_date.as_deref()) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    let (work_start, work_end) =
+        match work_day_bounds(query.work_start.as_deref(), query.work_end.as_deref()) {
+            Ok(bounds) => bounds,
+            Err(err) => return err.into_response(),
+        };
+    let service_state = store.lock().await;
+    let repository = service_state.activities_list_repository.lock().await;
+    let categories_repository = service_state.accounting_categories_repository.lock().await;
+    let daily_report = match report_date {
+        Some(report_date) => {
+            use_cases::daily_report::DailyReport::new(
+                report_date,
+                &*repository,
+                &*categories_repository,
+            )
+            .await
+        }
+        None => {
+            use_cases::daily_report::DailyReport::for_today(&*repository, &*categories_repository)
+                .await
+        }
+    };
+    let daily_report = match daily_report {
+        Ok(report) => report,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+
+    let response = DailyReport::from_report(
+        &daily_report,
+        work_start,
+        work_end,
+        query.include_summary.unwrap_or(false),
+    );
+
+    (StatusCode::CREATED, Json(response)).into_response()
+}
+
+/// Parses the optional bounds of the work day used for the gap calculation.
+///
+/// # Arguments
+///
+/// - `work_start`: The optional start of the work day (defaults to 09:00:00).
+/// - `work_end`: The optional end of the work day (defaults to 17:00:00).
+///
+/// # Returns
+///
+/// - `Ok((NaiveTime, NaiveTime))`: The start and end of the work day.
+/// - `Err(ServiceError)`: If a bound is not a valid time.
+fn work_day_bounds(
+    work_start: Option<&str>,
+    work_end: Option<&str>,
+) -> Result<(NaiveTime, NaiveTime), ServiceError> {
+    let work_start = match work_start {
+        Some(value) => parse_time_param("work_start", value)?,
+        None => DEFAULT_WORK_START,
+    };
+    let work_end = match work_end {
+        Some(value) => parse_time_param("work_end", value)?,
+        None => DEFAULT_WORK_END,
+    };
+
+    Ok((work_start, work_end))
+}
 
-    let activities: Vec<DailyReportActivity> = daily_report
-        .activities()
+/// Query parameters for generating daily reports for a date range.
+#[derive(Deserialize, IntoParams)]
+struct GenerateDailyReportsForRangeQuery {
+    /// The first date of the range (inclusive).
+    #[param(example = "2025-10-13")]
+    start_date: String,
+
+    /// The last date of the range (inclusive). The range may span at most 31 days.
+    #[param(example = "2025-10-17")]
+    end_date: String,
+
+    /// Whether to include a natural language summary in each report.
+    #[param(example = true)]
+    include_summary: Option<bool>,
+
+    /// The start of the work day used to find untracked gaps (defaults to 09:00:00).
+    #[param(example = "09:00:00")]
+    work_start: Option<String>,
+
+    /// The end of the work day used to find untracked gaps (defaults to 17:00:00).
+    #[param(example = "17:00:00")]
+    work_end: Option<String>,
+}
+
+/// Generates one daily report per day of the specified date range.
+#[utoipa::path(
+    get,
+    path = "/range",
+    tag = DAILY_REPORT_SERVICE_TAG,
+    params(
+        GenerateDailyReportsForRangeQuery,
+    ),
+    responses(
+        (status = 201, description = "Daily reports created successfully", body = Vec<DailyReport>),
+        (status = 400, description = "Invalid date range or work day bounds, or the range spans more than 31 days", body = ValidationError),
+        (status = 500, description = "Activities could not be loaded", body = ValidationError)
+    )
+)]
+async fn generate_daily_reports_for_range<R, T>(
+    State(store): State<Arc<Mutex<DailyReportServiceState<R, T>>>>,
+    query: Query<GenerateDailyReportsForRangeQuery>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let start_date = match parse_date_param("start_date", &query.start_date) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    let end_date = match parse_date_param("end_date", &query.end_date) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    if end_date < start_date {
+        return ServiceError::invalid_parameter(
+            "end_date",
+            "end_date must not be before start_date",
+        )
+        .into_response();
+    }
+    if (end_date - start_date).num_days() >= MAX_DATE_RANGE_DAYS {
+        return ServiceError::invalid_parameter(
+            "end_date",
+            format!(
+                "The date range must not span more than {} days",
+                MAX_DATE_RANGE_DAYS
+            ),
+        )
+        .into_response();
+    }
+    let (work_start, work_end) =
+        match work_day_bounds(query.work_start.as_deref(), query.work_end.as_deref()) {
+            Ok(bounds) => bounds,
+            Err(err) => return err.into_response(),
+        };
+
+    let service_state = store.lock().await;
+    let repository = service_state.activities_list_repository.lock().await;
+    let categories_repository = service_state.accounting_categories_repository.lock().await;
+    let daily_reports = match use_cases::daily_report::DailyReport::for_date_range(
+        start_date,
+        end_date,
+        &*repository,
+        &*categories_repository,
+    )
+    .await
+    {
+        Ok(reports) => reports,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+
+    let include_summary = query.include_summary.unwrap_or(false);
+    let response: Vec<DailyReport> = daily_reports
         .iter()
-        .map(|activity| DailyReportActivity {
-            id: Some(activity.id().to_string()),
-            duration: activity.duration().to_string(),
-            start_time: activity.start_time().to_string(),
-            end_time: activity.end_time().map(|t| t.to_string()),
-            accounting_category_id: activity.accounting_category_id().to_string(),
-            task: activity.task().to_string(),
+        .map(|daily_report| {
+            DailyReport::from_report(daily_report, work_start, work_end, include_summary)
         })
         .collect();
-    let total_duration = daily_report.total_duration().to_string();
 
-    let response = DailyReport {
-        report_date: query.report_date.clone(),
-        total_duration,
-        activities,
+    (StatusCode::CREATED, Json(response)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use chrono::NaiveDate;
+    use tower::ServiceExt;
+    use work_pulse_core::{
+        entities::accounting::AccountingCategoryId,
+        infra::repositories::in_memory::{
+            accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+            activities_list::InMemoryActivitiesListRepository,
+        },
+        use_cases::activities_list::ActivitiesList,
     };
 
-    (StatusCode::CREATED, Json(response))
+    use super::*;
+
+    /// Creates the daily report router backed by in-memory repositories, with a one-hour
+    /// activity recorded at 09:00 on each of the given days of October 2025.
+    async fn router_with_activities(days: &[u32]) -> axum::Router {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+
+        let mut activities_list = ActivitiesList::new(activities_list_repository.clone());
+        for day in days {
+            activities_list
+                .record(
+                    NaiveDate::from_ymd_opt(2025, 10, *day).unwrap(),
+                    NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                    Some(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+                    AccountingCategoryId::new(),
+                    format!("Task {}", day),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        axum::Router::new().nest(
+            "/api/v1/daily-report",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        )
+    }
+
+    async fn send_request(router: axum::Router, uri: &str) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn created_json<D: serde::de::DeserializeOwned>(response: axum::response::Response) -> D {
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn generate_daily_reports_for_range_should_match_daily_report_for_single_day() {
+        let router = router_with_activities(&[13, 14]).await;
+
+        let reports: Vec<DailyReport> = created_json(
+            send_request(
+                router.clone(),
+                "/api/v1/daily-report/range?start_date=2025-10-13&end_date=2025-10-13",
+            )
+            .await,
+        )
+        .await;
+        let report: DailyReport =
+            created_json(send_request(router, "/api/v1/daily-report?report_date=2025-10-13").await)
+                .await;
+
+        assert_eq!(reports.len(), 1);
+        assert_eq!(
+            serde_json::to_value(&reports[0]).unwrap(),
+            serde_json::to_value(&report).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn generate_daily_reports_for_range_should_return_one_report_per_day() {
+        let router = router_with_activities(&[10, 13, 15, 18]).await;
+
+        let reports: Vec<DailyReport> = created_json(
+            send_request(
+                router,
+                "/api/v1/daily-report/range?start_date=2025-10-13&end_date=2025-10-17",
+            )
+            .await,
+        )
+        .await;
+
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| report.report_date.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "2025-10-13",
+                "2025-10-14",
+                "2025-10-15",
+                "2025-10-16",
+                "2025-10-17"
+            ]
+        );
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| report.activities.len())
+                .collect::<Vec<_>>(),
+            vec![1, 0, 1, 0, 0]
+        );
+    }
+
+    #[tokio::test]
+    async fn generate_daily_reports_for_range_should_cap_range_at_31_days() {
+        let router = router_with_activities(&[]).await;
+
+        let response = send_request(
+            router.clone(),
+            "/api/v1/daily-report/range?start_date=2025-10-01&end_date=2025-10-31",
+        )
+        .await;
+        let reports: Vec<DailyReport> = created_json(response).await;
+        assert_eq!(reports.len(), 31);
+
+        let response = send_request(
+            router,
+            "/api/v1/daily-report/range?start_date=2025-10-01&end_date=2025-11-01",
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn generate_daily_reports_for_range_should_reject_end_before_start() {
+        let router = router_with_activities(&[]).await;
+
+        let response = send_request(
+            router,
+            "/api/v1/daily-report/range?start_date=2025-10-02&end_date=2025-10-01",
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
 }
@@ -0,0 +1,354 @@
+use axum::{
+    Json,
+    response::{IntoResponse, Response},
+};
+use hyper::StatusCode;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use work_pulse_core::{
+    adapters::ActivitiesListRepositoryError, entities::activity::ActivityError,
+    use_cases::activities_list::ActivitiesListError,
+};
+
+/// The body returned by the service for every failed request.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// A machine-readable code identifying the kind of error.
+    #[schema(example = "INVALID_UUID")]
+    pub code: String,
+
+    /// The request field the error relates to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "id")]
+    pub field: Option<String>,
+
+    /// A human-readable description of the error.
+    #[schema(example = "Invalid activity ID format")]
+    pub message: String,
+}
+
+/// The failure of a single item of a bulk request.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BulkCreateFailure {
+    /// The zero-based position of the failed item in the request body.
+    #[schema(example = 2)]
+    pub index: usize,
+
+    /// The reason the item failed.
+    pub error: ValidationError,
+}
+
+/// The errors a service handler can respond with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ServiceError {
+    /// The value of `field` is not a valid UUID.
+    InvalidId { field: String, message: String },
+
+    /// The value of `field` is not valid, e.g. a malformed date.
+    InvalidParameter { field: String, message: String },
+
+    /// The required `field` was not provided.
+    MissingField { field: String, message: String },
+
+    /// The request is invalid as a whole, e.g. an unreadable upload.
+    InvalidRequest(String),
+
+    /// The requested resource does not exist.
+    NotFound(String),
+
+    /// The request conflicts with the current state of the resource.
+    Conflict(String),
+
+    /// The repository failed to persist or load data.
+    DatabaseError(String),
+
+    /// Any other failure on the side of the service.
+    InternalError(String),
+
+    /// None of the activities of a bulk request could be created.
+    BulkCreateActivitiesError(Vec<BulkCreateFailure>),
+}
+
+impl ServiceError {
+    /// Creates an `InvalidId` error for the given field.
+    ///
+    /// # Arguments
+    ///
+    /// - `field`: The name of the field holding the invalid ID.
+    /// - `message`: A human-readable description of the error.
+    pub fn invalid_id(field: &str, message: impl Into<String>) -> Self {
+        ServiceError::InvalidId {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an `InvalidParameter` error for the given field.
+    ///
+    /// # Arguments
+    ///
+    /// - `field`: The name of the field holding the invalid value.
+    /// - `message`: A human-readable description of the error.
+    pub fn invalid_parameter(field: &str, message: impl Into<String>) -> Self {
+        ServiceError::InvalidParameter {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates a `MissingField` error for the given field.
+    ///
+    /// # Arguments
+    ///
+    /// - `field`: The name of the missing field.
+    /// - `message`: A human-readable description of the error.
+    pub fn missing_field(field: &str, message: impl Into<String>) -> Self {
+        ServiceError::MissingField {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Returns the HTTP status code for this error.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            ServiceError::InvalidId { .. }
+            | ServiceError::InvalidParameter { .. }
+            | ServiceError::MissingField { .. }
+            | ServiceError::InvalidRequest(_)
+            | ServiceError::BulkCreateActivitiesError(_) => StatusCode::BAD_REQUEST,
+            ServiceError::NotFound(_) => StatusCode::NOT_FOUND,
+            ServiceError::Conflict(_) => StatusCode::CONFLICT,
+            ServiceError::DatabaseError(_) | ServiceError::InternalError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    /// Returns the body describing this error.
+    pub fn to_validation_error(&self) -> ValidationError {
+        let (code, field, message) = match self {
+            ServiceError::InvalidId { field, message } => {
+                ("INVALID_UUID", Some(field.clone()), message.clone())
+            }
+            ServiceError::InvalidParameter { field, message } => {
+                ("INVALID_PARAMETER", Some(field.clone()), message.clone())
+            }
+            ServiceError::MissingField { field, message } => {
+                ("MISSING_FIELD", Some(field.clone()), message.clone())
+            }
+            ServiceError::InvalidRequest(message) => ("INVALID_REQUEST", None, message.clone()),
+            ServiceError::NotFound(message) => ("NOT_FOUND", None, message.clone()),
+            ServiceError::Conflict(message) => ("CONFLICT", None, message.clone()),
+            ServiceError::DatabaseError(message) => ("DATABASE_ERROR", None, message.clone()),
+            ServiceError::InternalError(message) => ("INTERNAL_ERROR", None, message.clone()),
+            ServiceError::BulkCreateActivitiesError(failures) => (
+                "BULK_CREATE_FAILED",
+                None,
+                format!(
+                    "{} activities could not be created: {}",
+                    failures.len(),
+                    failures
+                        .iter()
+                        .map(|failure| format!("[{}] {}", failure.index, failure.error.message))
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ),
+            ),
+        };
+
+        ValidationError {
+            code: code.to_string(),
+            field,
+            message,
+        }
+    }
+}
+
+impl From<ActivityError> for ServiceError {
+    fn from(err: ActivityError) -> Self {
+        match err {
+            ActivityError::EndTimeBeforeStartTime { .. } => {
+                ServiceError::invalid_parameter("end_time", err.to_string())
+            }
+            ActivityError::SplitTimeOutOfRange { .. } => {
+                ServiceError::invalid_parameter("split_time", err.to_string())
+            }
+            ActivityError::MissingField(field) => {
+                ServiceError::missing_field(field, err.to_string())
+            }
+        }
+    }
+}
+
+impl From<ActivitiesListError> for ServiceError {
+    fn from(err: ActivitiesListError) -> Self {
+        match err {
+            ActivitiesListError::NotFound(_) => ServiceError::NotFound(err.to_string()),
+            ActivitiesListError::TechnicalError(_) => ServiceError::DatabaseError(err.to_string()),
+            ActivitiesListError::InvalidActivity(err) => err.into(),
+            ActivitiesListError::Duplicate(_) => ServiceError::Conflict(err.to_string()),
+        }
+    }
+}
+
+impl From<ActivitiesListRepositoryError> for ServiceError {
+    fn from(err: ActivitiesListRepositoryError) -> Self {
+        match err {
+            ActivitiesListRepositoryError::NotFound(_) => ServiceError::NotFound(err.to_string()),
+            ActivitiesListRepositoryError::DatabaseError(_) => {
+                ServiceError::DatabaseError(err.to_string())
+            }
+        }
+    }
+}
+
+impl IntoResponse for ServiceError {
+    fn into_response(self) -> Response {
+        (self.status_code(), Json(self.to_validation_error())).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+    use work_pulse_core::entities::activity::ActivityId;
+
+    use super::*;
+
+    #[test]
+    fn invalid_id_should_serialize_with_code_field_and_message() {
+        let error = ServiceError::invalid_id("id", "Invalid activity ID format");
+
+        let json = serde_json::to_value(error.to_validation_error()).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "code": "INVALID_UUID",
+                "field": "id",
+                "message": "Invalid activity ID format"
+            })
+        );
+    }
+
+    #[test]
+    fn error_without_field_should_omit_field() {
+        let error = ServiceError::NotFound("Activity not found".to_string());
+
+        let json = serde_json::to_value(error.to_validation_error()).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "code": "NOT_FOUND",
+                "message": "Activity not found"
+            })
+        );
+    }
+
+    #[test]
+    fn status_code_should_match_error_kind() {
+        assert_eq!(
+            ServiceError::missing_field("id", "An ID is required").status_code(),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            ServiceError::invalid_parameter("start_date", "Invalid date").status_code(),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            ServiceError::NotFound("Activity not found".to_string()).status_code(),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            ServiceError::Conflict("Activity is already completed".to_string()).status_code(),
+            StatusCode::CONFLICT
+        );
+        assert_eq!(
+            ServiceError::DatabaseError("connection lost".to_string()).status_code(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+
+    #[test]
+    fn activities_list_error_should_map_to_service_error() {
+        let not_found = ActivitiesListError::NotFound(ActivityId::new());
+        assert_eq!(
+            ServiceError::from(not_found).status_code(),
+            StatusCode::NOT_FOUND
+        );
+
+        let invalid = ActivitiesListError::InvalidActivity(ActivityError::EndTimeBeforeStartTime {
+            start: NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+        });
+        assert_eq!(
+            ServiceError::from(invalid).to_validation_error().field,
+            Some("end_time".to_string())
+        );
+
+        let technical = ActivitiesListError::TechnicalError("connection lost".to_string());
+        assert_eq!(
+            ServiceError::from(technical).to_validation_error().code,
+            "DATABASE_ERROR"
+        );
+    }
+
+    #[test]
+    fn activities_list_repository_error_should_map_to_service_error() {
+        let database = ActivitiesListRepositoryError::DatabaseError("connection lost".to_string());
+        let error = ServiceError::from(database);
+        assert_eq!(error.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(error.to_validation_error().code, "DATABASE_ERROR");
+
+        let not_found = ActivitiesListRepositoryError::NotFound(ActivityId::new());
+        assert_eq!(
+            ServiceError::from(not_found).status_code(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[test]
+    fn bulk_create_activities_error_should_list_failures() {
+        let error = ServiceError::BulkCreateActivitiesError(vec![
+            BulkCreateFailure {
+                index: 0,
+                error: ServiceError::invalid_parameter("date", "Invalid date")
+                    .to_validation_error(),
+            },
+            BulkCreateFailure {
+                index: 2,
+                error: ServiceError::invalid_id("accounting_category_id", "Invalid ID")
+                    .to_validation_error(),
+            },
+        ]);
+
+        assert_eq!(error.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            error.to_validation_error().message,
+            "2 activities could not be created: [0] Invalid date; [2] Invalid ID"
+        );
+    }
+
+    #[tokio::test]
+    async fn into_response_should_use_status_code_and_json_body() {
+        let response =
+            ServiceError::invalid_id("category_id", "Invalid category ID format").into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let error: ValidationError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            error,
+            ValidationError {
+                code: "INVALID_UUID".to_string(),
+                field: Some("category_id".to_string()),
+                message: "Invalid category ID format".to_string(),
+            }
+        );
+    }
+}
@@ -31,7 +31,7 @@ pub fn router(connection: Option<Arc<PsqlConnection>>) -> OpenApiRouter {
     )
 )]
 async fn health_check(State(connection): State<Option<Arc<PsqlConnection>>>) -> impl IntoResponse {
-    if connection.is_none() {
+    let Some(connection) = connection else {
         return (
             StatusCode::OK,
             Json(HealthStatus {
@@ -40,9 +40,9 @@ async fn health_check(State(connection): State<Option<Arc<PsqlConnection>>>) ->
             }),
         )
             .into_response();
-    }
+    };
 
-    match connection.as_ref().unwrap().ping().await {
+    match connection.ping().await {
         Ok(()) => (
             StatusCode::OK,
             Json(HealthStatus {
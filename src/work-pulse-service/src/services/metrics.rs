@@ -0,0 +1,250 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, OnceLock},
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use axum::{
+    Router,
+    extract::{MatchedPath, Request, State},
+    http::header,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use hyper::StatusCode;
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use tokio::sync::Mutex;
+use tower::{Layer, Service};
+use work_pulse_core::adapters::ActivitiesListRepository;
+
+use crate::services::error::ServiceError;
+
+/// The counter of handled HTTP requests, labeled by method, path and status.
+pub const HTTP_REQUESTS_TOTAL: &str = "http_requests_total";
+
+/// The histogram of HTTP request latencies in seconds, labeled by method and path.
+pub const HTTP_REQUEST_DURATION_SECONDS: &str = "http_request_duration_seconds";
+
+/// The gauge of the number of activities in the activities repository.
+pub const ACTIVITIES_REPOSITORY_SIZE: &str = "activities_repository_size";
+
+/// The path label used for requests that did not match any route.
+const UNMATCHED_PATH: &str = "unmatched";
+
+static PROMETHEUS_HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+
+/// Returns the handle of the global Prometheus recorder, installing the recorder on first use.
+pub fn prometheus_handle() -> PrometheusHandle {
+    PROMETHEUS_HANDLE
+        .get_or_init(|| {
+            PrometheusBuilder::new()
+                .install_recorder()
+                .expect("Failed to install the Prometheus recorder")
+        })
+        .clone()
+}
+
+/// Shared state for the metrics service.
+struct MetricsServiceState<R>
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+{
+    /// The activities list repository.
+    activities_list_repository: Arc<Mutex<R>>,
+
+    /// The handle to render the recorded metrics.
+    prometheus_handle: PrometheusHandle,
+}
+
+/// Creates a router serving the recorded metrics in the Prometheus text format at `/metrics`.
+///
+/// The router is a plain `Router` and not an `OpenApiRouter`, so the endpoint is not listed in
+/// the Swagger UI.
+///
+/// # Arguments
+///
+/// - `activities_list_repository`: An `Arc<Mutex<R>>` instance for reporting the size of the activities repository.
+///
+/// # Returns
+///
+/// - A `Router` configured with the metrics route and state.
+pub fn router<R>(activities_list_repository: Arc<Mutex<R>>) -> Router
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+{
+    let store = Arc::new(Mutex::new(MetricsServiceState {
+        activities_list_repository,
+        prometheus_handle: prometheus_handle(),
+    }));
+
+    Router::new()
+        .route("/metrics", get(render_metrics::<R>))
+        .with_state(store)
+}
+
+/// Renders all recorded metrics in the Prometheus text format.
+async fn render_metrics<R>(
+    State(store): State<Arc<Mutex<MetricsServiceState<R>>>>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+{
+    let service_state = store.lock().await;
+    let activities_count = service_state
+        .activities_list_repository
+        .lock()
+        .await
+        .count()
+        .await;
+    let activities_count = match activities_count {
+        Ok(count) => count,
+        Err(err) => return ServiceError::DatabaseError(err.to_string()).into_response(),
+    };
+    metrics::gauge!(ACTIVITIES_REPOSITORY_SIZE).set(activities_count as f64);
+
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        service_state.prometheus_handle.render(),
+    )
+        .into_response()
+}
+
+/// A `tower::Layer` that counts the handled requests and records their latencies.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MetricsLayer;
+
+impl<S> Layer<S> for MetricsLayer {
+    type Service = MetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsService { inner }
+    }
+}
+
+/// The service created by `MetricsLayer`.
+#[derive(Clone, Debug)]
+pub struct MetricsService<S> {
+    inner: S,
+}
+
+impl<S> Service<Request> for MetricsService<S>
+where
+    S: Service<Request, Response = Response>,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        let method = request.method().to_string();
+        let path = request
+            .extensions()
+            .get::<MatchedPath>()
+            .map_or(UNMATCHED_PATH, MatchedPath::as_str)
+            .to_string();
+        let start = Instant::now();
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let response = response.await?;
+
+            let status = response.status().as_u16().to_string();
+            metrics::counter!(
+                HTTP_REQUESTS_TOTAL,
+                "method" => method.clone(),
+                "path" => path.clone(),
+                "status" => status
+            )
+            .increment(1);
+            metrics::histogram!(
+                HTTP_REQUEST_DURATION_SECONDS,
+                "method" => method,
+                "path" => path
+            )
+            .record(start.elapsed().as_secs_f64());
+
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use tower::ServiceExt;
+    use work_pulse_core::infra::repositories::in_memory::{
+        accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+        activities_list::InMemoryActivitiesListRepository,
+    };
+
+    use crate::services::activities_list_service;
+
+    use super::*;
+
+    async fn send(router: &Router, method: &str, uri: &str, body: Body) -> Response {
+        router
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(body)
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn metrics_should_report_requests_latencies_and_repository_size() {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+
+        let router = Router::new()
+            .nest(
+                "/api/v1/activities",
+                activities_list_service::router(
+                    activities_list_repository.clone(),
+                    accounting_categories_repository,
+                )
+                .into(),
+            )
+            .layer(MetricsLayer)
+            .merge(router(activities_list_repository));
+
+        let new_activity = r#"{
+            "date": "2023-10-02",
+            "start_time": "09:00:00",
+            "end_time": "10:00:00",
+            "accounting_category_id": "550e8400-e29b-41d4-a716-446655440000",
+            "task": "Code Review"
+        }"#;
+        let response = send(&router, "POST", "/api/v1/activities", new_activity.into()).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let response = send(&router, "GET", "/api/v1/activities", Body::empty()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = send(&router, "GET", "/metrics", Body::empty()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), 65536)
+            .await
+            .unwrap();
+        let metrics = String::from_utf8(body.to_vec()).unwrap();
+        assert!(metrics.contains(HTTP_REQUESTS_TOTAL));
+        assert!(metrics.contains(HTTP_REQUEST_DURATION_SECONDS));
+        assert!(metrics.contains("path=\"/api/v1/activities\""));
+        assert!(metrics.contains(&format!("{} 1", ACTIVITIES_REPOSITORY_SIZE)));
+    }
+}
@@ -1,5 +1,11 @@
 pub mod accounting_categories_service;
 pub mod activities_list_service;
 pub mod daily_report_service;
+pub mod error;
 pub mod health_check_service;
-pub mod weekly_report_service;
\ No newline at end of file
+pub mod metrics;
+pub mod monthly_report_service;
+pub mod pagination;
+pub mod params;
+pub mod weekly_report_service;
+pub mod yearly_report_service;
@@ -0,0 +1,338 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    Json,
+    extract::{Query, State},
+    response::IntoResponse,
+};
+use hyper::StatusCode;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use utoipa::{IntoParams, ToSchema};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use work_pulse_core::{
+    adapters::{AccountingCategoriesListRepository, ActivitiesListRepository},
+    use_cases,
+};
+
+use crate::{
+    prelude::MONTHLY_REPORT_SERVICE_TAG,
+    services::error::{ServiceError, ValidationError},
+};
+
+/// Shared state for the monthly report service.
+struct MonthlyReportServiceState<R, T>
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    /// The activities list repository.
+    activities_list_repository: Arc<Mutex<R>>,
+
+    /// The accounting categories repository.
+    accounting_categories_repository: Arc<Mutex<T>>,
+}
+
+/// The total duration of one week within a month.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, PartialEq)]
+struct WeeklyDuration {
+    /// The start date of the week in ISO 8601 format (YYYY-MM-DD).
+    #[schema(example = "2025-10-06")]
+    pub week_start: String,
+
+    /// The total duration of the week in ISO 8601 format (PT1H).
+    #[schema(example = "PT36000S")]
+    pub total_duration: String,
+}
+
+/// A report summarizing activities for a specific calendar month.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct MonthlyReport {
+    /// The reported month in the format YYYY-MM.
+    #[schema(example = "2025-10")]
+    pub report_month: String,
+
+    /// The first day of the month in ISO 8601 format (YYYY-MM-DD).
+    #[schema(example = "2025-10-01")]
+    pub month_start: String,
+
+    /// The last day of the month in ISO 8601 format (YYYY-MM-DD).
+    #[schema(example = "2025-10-31")]
+    pub month_end: String,
+
+    /// The total duration of all activities in the month in ISO 8601 format (PT1H).
+    #[schema(example = "PT1800S")]
+    pub total_duration: String,
+
+    /// The total cost of the activities with a known hourly rate.
+    #[schema(example = 9600.0)]
+    pub total_cost: Option<f64>,
+
+    /// A map of accounting category IDs to total duration spent in that category in ISO 8601 format (PT1H).
+    #[schema(example = r#"{"category-1": "PT3600S", "category-2": "PT7200S"}"#)]
+    pub duration_per_category: HashMap<String, String>,
+
+    /// The total duration of each week within the month, in chronological order. The first week
+    /// starts on the first day of the month, every following week on a Monday.
+    pub weekly_breakdown: Vec<WeeklyDuration>,
+
+    /// The number of weekdays (Monday to Friday) in the month.
+    #[schema(example = 23)]
+    pub total_working_days: u32,
+
+    /// The number of days with at least one completed activity.
+    #[schema(example = 20)]
+    pub days_with_activities: u32,
+
+    /// The ratio of days with activities to working days.
+    #[schema(example = 0.87)]
+    pub attendance_rate: f64,
+}
+
+/// Creates an OpenAPI router for the monthly report service.
+///
+/// # Arguments
+///
+/// - `activities_list_repository`: An `Arc<Mutex<PsqlActivitiesListRepository>>` instance for accessing the activities repository.
+/// - `accounting_categories_repository`: An `Arc<Mutex<PsqlAccountingCategoriesListRepository>>` instance for accessing the accounting categories repository.
+///
+/// # Returns
+///
+/// - An `OpenApiRouter` configured with routes and state for the monthly report service.
+pub fn router<R, T>(
+    activities_list_repository: Arc<Mutex<R>>,
+    accounting_categories_repository: Arc<Mutex<T>>,
+) -> OpenApiRouter
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let store = Arc::new(Mutex::new(MonthlyReportServiceState {
+        activities_list_repository,
+        accounting_categories_repository,
+    }));
+
+    OpenApiRouter::new()
+        .routes(routes!(generate_monthly_report))
+        .with_state(store)
+}
+
+// Query parameters for generating monthly reports.
+#[derive(Deserialize, IntoParams)]
+struct GenerateMonthlyReportQuery {
+    /// The year of the month to report on.
+    #[param(example = 2025)]
+    year: i32,
+
+    /// The month (1-12) to report on.
+    #[param(example = 10)]
+    month: u32,
+}
+
+/// Generates a monthly report for the specified year and month.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = MONTHLY_REPORT_SERVICE_TAG,
+    params(
+        GenerateMonthlyReportQuery,
+    ),
+    responses(
+        (status = 201, description = "Monthly report created successfully", body = MonthlyReport),
+        (status = 400, description = "Invalid year or month", body = ValidationError),
+        (status = 500, description = "Activities could not be loaded", body = ValidationError)
+    )
+)]
+async fn generate_monthly_report<R, T>(
+    State(store): State<Arc<Mutex<MonthlyReportServiceState<R, T>>>>,
+    query: Query<GenerateMonthlyReportQuery>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let service_state = store.lock().await;
+    let repository = service_state.activities_list_repository.lock().await;
+    let categories_repository = service_state.accounting_categories_repository.lock().await;
+    let monthly_report = match use_cases::monthly_report::MonthlyReport::new(
+        query.year,
+        query.month,
+        &*repository,
+        &*categories_repository,
+    )
+    .await
+    {
+        Ok(Some(report)) => report,
+        Ok(None) => {
+            return ServiceError::invalid_parameter(
+                "month",
+                format!(
+                    "Invalid month '{}-{}'. Expected a month between 1 and 12.",
+                    query.year, query.month
+                ),
+            )
+            .into_response();
+        }
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+
+    let response = MonthlyReport {
+        report_month: monthly_report.month_start().format("%Y-%m").to_string(),
+        month_start: monthly_report.month_start().to_string(),
+        month_end: monthly_report.month_end().to_string(),
+        total_duration: monthly_report.total_duration().to_string(),
+        total_cost: monthly_report.total_cost(),
+        duration_per_category: monthly_report
+            .duration_per_category()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        weekly_breakdown: monthly_report
+            .weekly_breakdown()
+            .iter()
+            .map(|(week_start, duration)| WeeklyDuration {
+                week_start: week_start.to_string(),
+                total_duration: duration.to_string(),
+            })
+            .collect(),
+        total_working_days: monthly_report.total_working_days(),
+        days_with_activities: monthly_report.days_with_activities(),
+        attendance_rate: monthly_report.attendance_rate(),
+    };
+
+    (StatusCode::CREATED, Json(response)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use chrono::{NaiveDate, NaiveTime};
+    use tower::ServiceExt;
+    use work_pulse_core::{
+        entities::accounting::AccountingCategoryId,
+        infra::repositories::in_memory::{
+            accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+            activities_list::InMemoryActivitiesListRepository,
+        },
+        use_cases::activities_list::ActivitiesList,
+    };
+
+    use super::*;
+
+    /// Creates the monthly report router backed by in-memory repositories, with a one hour
+    /// activity recorded on each of the given dates.
+    async fn router_with_activities_on(dates: &[NaiveDate]) -> axum::Router {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+
+        let mut activities_list = ActivitiesList::new(activities_list_repository.clone());
+        let category_id = AccountingCategoryId::new();
+        for date in dates {
+            activities_list
+                .record(
+                    *date,
+                    NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                    Some(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+                    category_id.clone(),
+                    "Task".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        axum::Router::new().nest(
+            "/api/v1/monthly-report",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        )
+    }
+
+    async fn send_report_request(router: axum::Router, year: i32, month: u32) -> MonthlyReport {
+        let response = router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!(
+                        "/api/v1/monthly-report?year={}&month={}",
+                        year, month
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = axum::body::to_bytes(response.into_body(), 65536)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    fn week(week_start: &str, total_duration: &str) -> WeeklyDuration {
+        WeeklyDuration {
+            week_start: week_start.to_string(),
+            total_duration: total_duration.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn monthly_report_should_not_include_january_in_december() {
+        // December 29, 2025 is a Monday, so the last week of December runs into January.
+        let router = router_with_activities_on(&[
+            NaiveDate::from_ymd_opt(2025, 12, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 1, 2).unwrap(),
+        ])
+        .await;
+
+        let report = send_report_request(router, 2025, 12).await;
+
+        assert_eq!(report.report_month, "2025-12");
+        assert_eq!(report.month_start, "2025-12-01");
+        assert_eq!(report.month_end, "2025-12-31");
+        assert_eq!(report.total_duration, "PT7200S");
+        assert_eq!(report.weekly_breakdown.len(), 5);
+        assert_eq!(report.weekly_breakdown[0], week("2025-12-01", "P0D"));
+        assert_eq!(report.weekly_breakdown[4], week("2025-12-29", "PT7200S"));
+    }
+
+    #[tokio::test]
+    async fn monthly_report_should_not_include_december_in_january() {
+        let router = router_with_activities_on(&[
+            NaiveDate::from_ymd_opt(2025, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+        ])
+        .await;
+
+        let report = send_report_request(router, 2026, 1).await;
+
+        assert_eq!(report.report_month, "2026-01");
+        assert_eq!(report.month_start, "2026-01-01");
+        assert_eq!(report.total_duration, "PT7200S");
+        assert_eq!(report.duration_per_category.len(), 1);
+        assert_eq!(report.weekly_breakdown[0], week("2026-01-01", "PT3600S"));
+        assert_eq!(report.weekly_breakdown[1], week("2026-01-05", "PT3600S"));
+    }
+
+    #[tokio::test]
+    async fn monthly_report_should_reject_invalid_month() {
+        let router = router_with_activities_on(&[]).await;
+
+        let response = router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/api/v1/monthly-report?year=2025&month=13")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
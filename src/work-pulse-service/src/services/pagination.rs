@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The default number of items per page.
+pub const DEFAULT_PAGE_SIZE: u32 = 50;
+
+/// The maximum number of items per page.
+pub const MAX_PAGE_SIZE: u32 = 500;
+
+/// A single page of a list of items.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+pub struct PaginatedResponse<T> {
+    /// The items on this page.
+    pub data: Vec<T>,
+
+    /// The total number of items across all pages.
+    #[schema(example = 120)]
+    pub total: usize,
+
+    /// The 1-based page number.
+    #[schema(example = 1)]
+    pub page: u32,
+
+    /// The number of items per page.
+    #[schema(example = 50)]
+    pub page_size: u32,
+
+    /// The total number of pages.
+    #[schema(example = 3)]
+    pub total_pages: u32,
+
+    /// The cursor to request the next page with, if there may be more items. Only set for
+    /// cursor-based requests.
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub next_cursor: Option<String>,
+}
+
+impl<T> PaginatedResponse<T> {
+    /// Slices the requested page out of a list of items.
+    ///
+    /// # Arguments
+    ///
+    /// - `items`: All items matching the request.
+    /// - `page`: The optional 1-based page number (defaults to 1).
+    /// - `page_size`: The optional page size (defaults to 50, clamped to 1..=500).
+    ///
+    /// # Returns
+    ///
+    /// - A `PaginatedResponse` containing the items of the requested page. Pages beyond the
+    ///   last page are empty.
+    pub fn paginate(items: Vec<T>, page: Option<u32>, page_size: Option<u32>) -> Self {
+        let (page, page_size) = page_and_size(page, page_size);
+        let total = items.len();
+
+        let data = items
+            .into_iter()
+            .skip(offset(page, page_size))
+            .take(page_size as usize)
+            .collect();
+
+        Self::with_offset(data, total, page, page_size)
+    }
+
+    /// Creates a page for a page-based request whose items were already sliced, e.g. by the
+    /// repository.
+    ///
+    /// # Arguments
+    ///
+    /// - `data`: The items on this page.
+    /// - `total`: The total number of items across all pages.
+    /// - `page`: The 1-based page number.
+    /// - `page_size`: The number of items per page.
+    ///
+    /// # Returns
+    ///
+    /// - A `PaginatedResponse` for the page.
+    pub fn with_offset(data: Vec<T>, total: usize, page: u32, page_size: u32) -> Self {
+        Self {
+            data,
+            total,
+            page,
+            page_size,
+            total_pages: total.div_ceil(page_size.max(1) as usize) as u32,
+            next_cursor: None,
+        }
+    }
+
+    /// Creates a page for a cursor-based request.
+    ///
+    /// # Arguments
+    ///
+    /// - `data`: The items on this page.
+    /// - `total`: The total number of items across all pages.
+    /// - `page_size`: The requested number of items per page.
+    /// - `next_cursor`: The cursor to request the next page with, if any.
+    ///
+    /// # Returns
+    ///
+    /// - A `PaginatedResponse` for the page. As cursor-based pages are not numbered, `page` is 0.
+    pub fn with_cursor(
+        data: Vec<T>,
+        total: usize,
+        page_size: u32,
+        next_cursor: Option<String>,
+    ) -> Self {
+        Self {
+            data,
+            total,
+            page: 0,
+            page_size,
+            total_pages: total.div_ceil(page_size.max(1) as usize) as u32,
+            next_cursor,
+        }
+    }
+}
+
+/// Applies the defaults and limits to the requested page and page size.
+///
+/// # Arguments
+///
+/// - `page`: The optional 1-based page number (defaults to 1).
+/// - `page_size`: The optional page size (defaults to 50, clamped to 1..=500).
+///
+/// # Returns
+///
+/// - The page number and page size to use.
+pub fn page_and_size(page: Option<u32>, page_size: Option<u32>) -> (u32, u32) {
+    (
+        page.unwrap_or(1).max(1),
+        page_size
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE),
+    )
+}
+
+/// Returns the number of items preceding the given 1-based page.
+pub fn offset(page: u32, page_size: u32) -> usize {
+    (page as usize - 1) * page_size as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paginate_should_slice_requested_page() {
+        let page = PaginatedResponse::paginate((1..=5).collect(), Some(2), Some(2));
+
+        assert_eq!(page.total, 5);
+        assert_eq!(page.page, 2);
+        assert_eq!(page.page_size, 2);
+        assert_eq!(page.total_pages, 3);
+        assert_eq!(page.data, vec![3, 4]);
+    }
+
+    #[test]
+    fn paginate_should_return_partial_last_page() {
+        let page = PaginatedResponse::paginate((1..=5).collect(), Some(3), Some(2));
+
+        assert_eq!(page.total_pages, 3);
+        assert_eq!(page.data, vec![5]);
+    }
+
+    #[test]
+    fn paginate_should_use_defaults_and_return_empty_page_beyond_end() {
+        let items: Vec<u32> = (1..=3).collect();
+
+        let first_page = PaginatedResponse::paginate(items.clone(), None, None);
+        assert_eq!(first_page.page, 1);
+        assert_eq!(first_page.page_size, DEFAULT_PAGE_SIZE);
+        assert_eq!(first_page.data.len(), 3);
+
+        let beyond_end = PaginatedResponse::paginate(items, Some(5), Some(2));
+        assert_eq!(beyond_end.total, 3);
+        assert_eq!(beyond_end.total_pages, 2);
+        assert!(beyond_end.data.is_empty());
+    }
+
+    #[test]
+    fn offset_should_skip_preceding_pages() {
+        assert_eq!(offset(1, 25), 0);
+        assert_eq!(offset(2, 25), 25);
+        assert_eq!(offset(4, 10), 30);
+    }
+
+    #[test]
+    fn with_cursor_should_calculate_total_pages() {
+        let page = PaginatedResponse::with_cursor(vec![1, 2], 5, 2, Some("2".to_string()));
+
+        assert_eq!(page.page, 0);
+        assert_eq!(page.total_pages, 3);
+        assert_eq!(page.next_cursor, Some("2".to_string()));
+    }
+
+    #[test]
+    fn paginate_should_clamp_page_and_page_size() {
+        let items: Vec<u32> = (1..=600).collect();
+
+        let page = PaginatedResponse::paginate(items, Some(0), Some(1000));
+
+        assert_eq!(page.page, 1);
+        assert_eq!(page.page_size, MAX_PAGE_SIZE);
+        assert_eq!(page.total_pages, 2);
+        assert_eq!(page.data.len(), MAX_PAGE_SIZE as usize);
+    }
+}
@@ -0,0 +1,129 @@
+use chrono::{NaiveDate, NaiveTime};
+
+use crate::services::error::ServiceError;
+
+/// Parses a date query parameter in ISO 8601 format (YYYY-MM-DD).
+///
+/// # Arguments
+///
+/// - `field_name`: The name of the query parameter, used in the error message.
+/// - `value`: The raw value of the query parameter.
+///
+/// # Returns
+///
+/// - `Ok(NaiveDate)` if the value is a valid date.
+/// - `Err(ServiceError::InvalidParameter)` with a descriptive message otherwise.
+pub fn parse_date_param(field_name: &str, value: &str) -> Result<NaiveDate, ServiceError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        ServiceError::invalid_parameter(
+            field_name,
+            format!(
+                "Invalid date format for {}: expected YYYY-MM-DD, got '{}'",
+                field_name, value
+            ),
+        )
+    })
+}
+
+/// Parses an optional date query parameter in ISO 8601 format (YYYY-MM-DD).
+///
+/// # Arguments
+///
+/// - `field_name`: The name of the query parameter, used in the error message.
+/// - `value`: The raw value of the query parameter, if provided.
+///
+/// # Returns
+///
+/// - `Ok(Some(NaiveDate))` if the value is a valid date, `Ok(None)` if no value was provided.
+/// - `Err(ServiceError::InvalidParameter)` with a descriptive message otherwise.
+pub fn parse_optional_date_param(
+    field_name: &str,
+    value: Option<&str>,
+) -> Result<Option<NaiveDate>, ServiceError> {
+    value
+        .map(|value| parse_date_param(field_name, value))
+        .transpose()
+}
+
+/// Parses a time parameter in ISO 8601 format (HH:MM:SS). The seconds may be omitted (HH:MM), as
+/// sent by HTML time inputs.
+///
+/// # Arguments
+///
+/// - `field_name`: The name of the parameter, used in the error message.
+/// - `value`: The raw value of the parameter.
+///
+/// # Returns
+///
+/// - `Ok(NaiveTime)` if the value is a valid time.
+/// - `Err(ServiceError::InvalidParameter)` with a descriptive message otherwise.
+pub fn parse_time_param(field_name: &str, value: &str) -> Result<NaiveTime, ServiceError> {
+    NaiveTime::parse_from_str(value, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+        .map_err(|_| {
+            ServiceError::invalid_parameter(
+                field_name,
+                format!(
+                    "Invalid time format for {}: expected HH:MM:SS, got '{}'",
+                    field_name, value
+                ),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_date_param_should_parse_valid_date() {
+        let date = parse_date_param("report_date", "2025-10-12").unwrap();
+
+        assert_eq!(date, NaiveDate::from_ymd_opt(2025, 10, 12).unwrap());
+    }
+
+    #[test]
+    fn parse_optional_date_param_should_return_none_without_value() {
+        assert_eq!(parse_optional_date_param("start_date", None).unwrap(), None);
+    }
+
+    #[test]
+    fn parse_time_param_should_parse_valid_time() {
+        let time = parse_time_param("start_time", "09:30:00").unwrap();
+
+        assert_eq!(time, NaiveTime::from_hms_opt(9, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn parse_time_param_should_parse_time_without_seconds() {
+        let time = parse_time_param("start_time", "09:30").unwrap();
+
+        assert_eq!(time, NaiveTime::from_hms_opt(9, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn parse_time_param_should_fail_with_descriptive_error() {
+        let error = parse_time_param("end_time", "25:00:00").unwrap_err();
+
+        assert_eq!(
+            error,
+            ServiceError::invalid_parameter(
+                "end_time",
+                "Invalid time format for end_time: expected HH:MM:SS, got '25:00:00'"
+            )
+        );
+    }
+
+    #[test]
+    fn parse_date_param_should_fail_with_descriptive_error() {
+        let error = parse_date_param("week_start_date", "2025-13-01").unwrap_err();
+
+        assert_eq!(
+            error,
+            ServiceError::invalid_parameter(
+                "week_start_date",
+                "Invalid date format for week_start_date: expected YYYY-MM-DD, got '2025-13-01'"
+            )
+        );
+    }
+}
@@ -1,70 +1,269 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::BTreeMap, sync::Arc};
 
 use axum::{
     Json,
     extract::{Query, State},
-    response::IntoResponse,
+    http::{HeaderMap, HeaderValue, header},
+    response::{IntoResponse, Response},
 };
+use chrono::Duration;
 use hyper::StatusCode;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tokio::sync::Mutex;
 use utoipa::{IntoParams, ToSchema};
 use utoipa_axum::{router::OpenApiRouter, routes};
-use work_pulse_core::{adapters::ActivitiesListRepository, use_cases};
+use work_pulse_core::{
+    adapters::{AccountingCategoriesListRepository, ActivitiesListRepository},
+    infra::exporters::html_report_exporter,
+    use_cases,
+};
+
+use crate::{
+    prelude::WEEKLY_REPORT_SERVICE_TAG,
+    services::{
+        error::{ServiceError, ValidationError},
+        params::{parse_date_param, parse_optional_date_param},
+    },
+};
+
+/// Shared state for the weekly report service.
+struct WeeklyReportServiceState<R, T>
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    /// The activities list repository.
+    activities_list_repository: Arc<Mutex<R>>,
+
+    /// The accounting categories repository.
+    accounting_categories_repository: Arc<Mutex<T>>,
+}
+
+/// An activity of the weekly report.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct WeeklyReportActivity {
+    /// The unique identifier for the activity.
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    id: String,
+
+    /// The duration of the activity in ISO 8601 format (PT1H).
+    #[schema(example = "PT1800S")]
+    duration: String,
+
+    /// The start time of the activity in ISO 8601 format (HH:MM:SS).
+    #[schema(example = "14:30:00")]
+    start_time: String,
+
+    /// The end time of the activity in ISO 8601 format (HH:MM:SS).
+    #[schema(example = "15:30:00")]
+    end_time: Option<String>,
+
+    /// The accounting category ID associated with the activity.
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    accounting_category_id: String,
+
+    /// The task itself.
+    #[schema(example = "Code Review")]
+    task: String,
+}
 
-use crate::prelude::WEEKLY_REPORT_SERVICE_TAG;
+/// A day of the weekly report with its activities.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct WeeklyReportDay {
+    /// The date of the day in ISO 8601 format (YYYY-MM-DD).
+    #[schema(example = "2025-10-13")]
+    date: String,
+
+    /// The activities of the day, ordered by start time.
+    activities: Vec<WeeklyReportActivity>,
+
+    /// The total duration of the activities of the day in ISO 8601 format (PT1H).
+    #[schema(example = "PT28800S")]
+    total_duration: String,
+}
 
-/// A report summarizing activities for a specific week.
+/// A report summarizing activities for a specific week. All maps are ordered, so that equal reports
+/// serialize to the same JSON and get the same ETag.
 #[derive(Serialize, Deserialize, ToSchema, Clone)]
 struct WeeklyReport {
-    /// The date (a sunday) when the week started in ISO 8601 format (YYYY-MM-DD).
-    #[schema(example = "2025-10-12")]
+    /// The date (a monday) when the week started in ISO 8601 format (YYYY-MM-DD).
+    #[schema(example = "2025-10-13")]
     pub week_start: String,
 
-    /// The date (a saturday) when the week ended in ISO 8601 format (YYYY-MM-DD).
-    #[schema(example = "2025-10-18")]
+    /// The date (a sunday) when the week ended in ISO 8601 format (YYYY-MM-DD).
+    #[schema(example = "2025-10-19")]
     pub week_end: String,
 
     /// The total duration of all activities in the week in ISO 8601 format (PT1H).
     #[schema(example = "PT1800S")]
     pub total_duration: String,
 
+    /// The total cost of the activities with a known hourly rate.
+    #[schema(example = 2400.0)]
+    pub total_cost: Option<f64>,
+
     /// A map of accounting category IDs to total duration spent in that category in ISO 8601 format (PT1H).
     #[schema(example = r#"{"category-1": "PT3600S", "category-2": "PT7200S"}"#)]
-    pub duration_per_category: HashMap<String, String>,
+    pub duration_per_category: BTreeMap<String, String>,
 
     /// A nested map where the outer key is the date (YYYY-MM-DD) and the inner map contains
     /// accounting category IDs to total duration spent in that category on that day in ISO 8601 format (PT1H).
     #[schema(
-        example = r#"{"2025-10-12": {"category-1": "PT3600S"}, "2025-10-13": {"category-2": "PT7200S"}}"#
+        example = r#"{"2025-10-13": {"category-1": "PT3600S"}, "2025-10-14": {"category-2": "PT7200S"}}"#
+    )]
+    pub daily_durations_per_category: BTreeMap<String, BTreeMap<String, String>>,
+
+    /// A map of the accounting category IDs of the report to their names.
+    #[schema(example = r#"{"category-1": "Development", "category-2": "Meetings"}"#)]
+    pub category_names: BTreeMap<String, String>,
+
+    /// A map of accounting category names to total duration spent in that category in ISO 8601 format (PT1H).
+    #[schema(example = r#"{"Development": "PT3600S", "Meetings": "PT7200S"}"#)]
+    pub duration_per_category_name: BTreeMap<String, String>,
+
+    /// A nested map where the outer key is the date (YYYY-MM-DD) and the inner map contains
+    /// accounting category names to total duration spent in that category on that day in ISO 8601 format (PT1H).
+    #[schema(
+        example = r#"{"2025-10-13": {"Development": "PT3600S"}, "2025-10-14": {"Meetings": "PT7200S"}}"#
     )]
-    pub daily_durations_per_category: HashMap<String, HashMap<String, String>>,
+    pub daily_durations_per_category_name: BTreeMap<String, BTreeMap<String, String>>,
+
+    /// A map of each date (YYYY-MM-DD) of the week to whether it is on a weekend (Saturday or Sunday).
+    #[schema(example = r#"{"2025-10-13": false, "2025-10-19": true}"#)]
+    pub is_weekend: BTreeMap<String, bool>,
+
+    /// Each day of the week with its activities and their total duration.
+    pub days: Vec<WeeklyReportDay>,
+
+    /// The signed difference between the total duration and the target hours of the week in ISO 8601 format (PT1H),
+    /// if target hours were requested. Positive when the target was exceeded, negative when it was missed.
+    #[schema(example = "PT7200S")]
+    pub overtime: Option<String>,
+
+    /// A map of each date (YYYY-MM-DD) of the week to the signed difference between the duration of that day and
+    /// the daily target in ISO 8601 format (PT1H), if target hours were requested. The daily target is the weekly
+    /// target spread evenly across the working days, weekend days have a target of zero.
+    #[schema(example = r#"{"2025-10-13": "PT3600S", "2025-10-14": "-PT1800S"}"#)]
+    pub daily_overtime: Option<BTreeMap<String, String>>,
+
+    /// An optional natural language summary of the week.
+    #[schema(example = "Week 42: 37.5h total across 5 active days.")]
+    pub summary: Option<String>,
+}
+
+/// The differences between two weekly reports.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct WeeklyComparison {
+    /// The date (a monday) when the week compared against started in ISO 8601 format (YYYY-MM-DD).
+    #[schema(example = "2025-10-06")]
+    pub week1_start: String,
+
+    /// The date (a monday) when the compared week started in ISO 8601 format (YYYY-MM-DD).
+    #[schema(example = "2025-10-13")]
+    pub week2_start: String,
+
+    /// The signed difference between the total durations of the second and the first week in ISO 8601 format (PT1H).
+    #[schema(example = "-PT3600S")]
+    pub total_duration_delta: String,
+
+    /// The accounting category IDs with activities in the second week only.
+    #[schema(example = json!(["550e8400-e29b-41d4-a716-446655440000"]))]
+    pub categories_gained: Vec<String>,
+
+    /// The accounting category IDs with activities in the first week only.
+    #[schema(example = json!(["550e8400-e29b-41d4-a716-446655440001"]))]
+    pub categories_lost: Vec<String>,
+
+    /// A map of the accounting category IDs of both weeks to the signed differences of their total durations in
+    /// ISO 8601 format (PT1H).
+    #[schema(example = r#"{"category-1": "PT3600S", "category-2": "-PT7200S"}"#)]
+    pub duration_delta_per_category: BTreeMap<String, String>,
 }
 
 /// Creates an OpenAPI router for the weekly report service.
 ///
 /// # Arguments
 ///
-/// - `repository`: An `Arc<Mutex<PsqlActivitiesListRepository>>` instance for accessing the activities repository.
+/// - `activities_list_repository`: An `Arc<Mutex<PsqlActivitiesListRepository>>` instance for accessing the activities repository.
+/// - `accounting_categories_repository`: An `Arc<Mutex<PsqlAccountingCategoriesListRepository>>` instance for accessing the accounting categories repository.
 ///
 /// # Returns
 ///
 /// - An `OpenApiRouter` configured with routes and state for the weekly report service.
-pub fn router<R>(repository: Arc<Mutex<R>>) -> OpenApiRouter
+pub fn router<R, T>(
+    activities_list_repository: Arc<Mutex<R>>,
+    accounting_categories_repository: Arc<Mutex<T>>,
+) -> OpenApiRouter
 where
     R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
 {
+    let store = Arc::new(Mutex::new(WeeklyReportServiceState {
+        activities_list_repository,
+        accounting_categories_repository,
+    }));
+
     OpenApiRouter::new()
         .routes(routes!(generate_weekly_report))
-        .with_state(repository)
+        .routes(routes!(compare_weekly_reports))
+        .with_state(store)
 }
 
 // Query parameters for generating weekly reports.
 #[derive(Deserialize, IntoParams)]
 struct GenerateWeeklyReportQuery {
-    /// The date (a sunday) when the week started in ISO 8601 format (YYYY-MM-DD).
-    #[param(example = "2025-10-12")]
-    week_start_date: String,
+    /// The date (a monday) when the week started in ISO 8601 format (YYYY-MM-DD). Defaults to the
+    /// current week, starting on monday.
+    #[param(example = "2025-10-13")]
+    week_start_date: Option<String>,
+
+    /// Whether to include a natural language summary in the report.
+    #[param(example = true)]
+    include_summary: Option<bool>,
+
+    /// The number of hours that should be worked during the week, used to calculate the overtime.
+    /// Must be between 0 and 168 (the hours of a week).
+    #[param(example = 40.0)]
+    target_hours: Option<f64>,
+
+    /// The format of the report, either `json` (default) or `html` for a printable page.
+    #[param(example = "html")]
+    format: Option<String>,
+}
+
+/// The number of working days in a week, used to derive the daily target from the weekly target hours.
+const WORKING_DAYS_PER_WEEK: i32 = 5;
+
+/// The number of hours in a week, the upper bound for the target hours of a week.
+const HOURS_PER_WEEK: f64 = 168.0;
+
+/// Converts the target hours of a week into a duration.
+///
+/// # Arguments
+///
+/// - `hours`: The number of hours that should be worked during the week.
+///
+/// # Returns
+///
+/// - `Ok(Duration)`: The target as a duration, rounded to whole seconds.
+/// - `Err(ServiceError::InvalidParameter)`: If the hours are not a number between 0 and 168.
+fn parse_target_hours(hours: f64) -> Result<Duration, ServiceError> {
+    let invalid = || {
+        ServiceError::invalid_parameter(
+            "target_hours",
+            format!(
+                "Invalid target_hours: {}. Must be between 0 and {}",
+                hours, HOURS_PER_WEEK
+            ),
+        )
+    };
+
+    if !(0.0..=HOURS_PER_WEEK).contains(&hours) {
+        return Err(invalid());
+    }
+
+    Duration::try_seconds((hours * 3600.0).round() as i64).ok_or_else(invalid)
 }
 
 /// Generates a weekly report for the specified week starting date.
@@ -76,24 +275,93 @@ struct GenerateWeeklyReportQuery {
         GenerateWeeklyReportQuery,
     ),
     responses(
-        (status = 201, description = "Weekly report created successfully", body = WeeklyReport)
+        (status = 201, description = "Weekly report created successfully", body = WeeklyReport),
+        (status = 200, description = "Weekly report rendered as HTML page", body = String, content_type = "text/html"),
+        (status = 304, description = "Weekly report not modified since the ETag sent in If-None-Match"),
+        (status = 400, description = "Invalid week start date or format", body = ValidationError),
+        (status = 500, description = "Activities could not be loaded", body = ValidationError)
     )
 )]
-async fn generate_weekly_report<R>(
-    State(store): State<Arc<Mutex<R>>>,
+async fn generate_weekly_report<R, T>(
+    State(store): State<Arc<Mutex<WeeklyReportServiceState<R, T>>>>,
     query: Query<GenerateWeeklyReportQuery>,
+    headers: HeaderMap,
 ) -> impl IntoResponse
 where
     R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
 {
-    let week_start_date = query.week_start_date.parse().unwrap();
-    let store = store.lock().await;
-    let weekly_report = use_cases::weekly_report::WeeklyReport::new(week_start_date, &*store).await;
+    let week_start_date =
+        match parse_optional_date_param("week_start_date", query.week_start_date.as_deref()) {
+            Ok(date) => date,
+            Err(err) => return err.into_response(),
+        };
+    let render_html = match query.format.as_deref() {
+        None | Some("json") => false,
+        Some("html") => true,
+        Some(format) => {
+            return ServiceError::invalid_parameter(
+                "format",
+                format!("Unsupported report format: {}", format),
+            )
+            .into_response();
+        }
+    };
+    let target = match query.target_hours.map(parse_target_hours).transpose() {
+        Ok(target) => target,
+        Err(err) => return err.into_response(),
+    };
+
+    let service_state = store.lock().await;
+    let repository = service_state.activities_list_repository.lock().await;
+    let categories_repository = service_state.accounting_categories_repository.lock().await;
+    let weekly_report = match week_start_date {
+        Some(week_start_date) => {
+            use_cases::weekly_report::WeeklyReport::new(
+                week_start_date,
+                &*repository,
+                &*categories_repository,
+            )
+            .await
+        }
+        None => {
+            use_cases::weekly_report::WeeklyReport::for_current_week(
+                &*repository,
+                &*categories_repository,
+            )
+            .await
+        }
+    };
+    let weekly_report = match weekly_report {
+        Ok(report) => report,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+    let report_with_names = match weekly_report.resolve_names(&*categories_repository).await {
+        Ok(report_with_names) => report_with_names,
+        Err(err) => return ServiceError::DatabaseError(err.to_string()).into_response(),
+    };
+
+    if render_html {
+        let html = html_report_exporter::render_weekly_report(
+            &weekly_report,
+            report_with_names.category_names(),
+        );
+        let etag = etag_for(html.as_bytes());
+        return respond_with_etag(
+            &headers,
+            etag,
+            (
+                StatusCode::OK,
+                [(header::CONTENT_TYPE, "text/html; charset=utf-8")],
+                html,
+            ),
+        );
+    }
 
     let daily_durations_per_category = weekly_report
         .daily_durations_per_category()
         .iter()
-        .map(|(date, category_map)| {
+        .map(|(date, _, category_map)| {
             (
                 date.to_string(),
                 category_map
@@ -108,13 +376,583 @@ where
         week_start: weekly_report.week_start().to_string(),
         week_end: weekly_report.week_end().to_string(),
         total_duration: weekly_report.total_duration().to_string(),
+        total_cost: weekly_report.total_cost(),
         duration_per_category: weekly_report
             .duration_per_category()
             .iter()
             .map(|(k, v)| (k.to_string(), v.to_string()))
             .collect(),
         daily_durations_per_category,
+        category_names: report_with_names
+            .category_names()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect(),
+        duration_per_category_name: report_with_names
+            .duration_per_category()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.to_string()))
+            .collect(),
+        daily_durations_per_category_name: report_with_names
+            .daily_durations_per_category()
+            .iter()
+            .map(|(date, category_map)| {
+                (
+                    date.to_string(),
+                    category_map
+                        .iter()
+                        .map(|(k, v)| (k.clone(), v.to_string()))
+                        .collect(),
+                )
+            })
+            .collect(),
+        is_weekend: weekly_report
+            .daily_durations_per_category()
+            .iter()
+            .map(|(date, is_weekend, _)| (date.to_string(), *is_weekend))
+            .collect(),
+        days: weekly_report
+            .activities_per_day()
+            .into_iter()
+            .map(|(date, activities)| WeeklyReportDay {
+                date: date.to_string(),
+                activities: activities
+                    .iter()
+                    .map(|activity| WeeklyReportActivity {
+                        id: activity.id().to_string(),
+                        duration: activity.duration().to_string(),
+                        start_time: activity.start_time().to_string(),
+                        end_time: activity.end_time().map(|t| t.to_string()),
+                        accounting_category_id: activity.accounting_category_id().to_string(),
+                        task: activity.task().to_string(),
+                    })
+                    .collect(),
+                total_duration: activities
+                    .iter()
+                    .map(|activity| activity.duration())
+                    .sum::<Duration>()
+                    .to_string(),
+            })
+            .collect(),
+        overtime: target.map(|target| weekly_report.overtime(target).to_string()),
+        daily_overtime: target.map(|target| {
+            weekly_report
+                .overtime_per_day(target / WORKING_DAYS_PER_WEEK)
+                .iter()
+                .map(|(date, overtime)| (date.to_string(), overtime.to_string()))
+                .collect()
+        }),
+        summary: query
+            .include_summary
+            .unwrap_or(false)
+            .then(|| use_cases::reports::summary::weekly_summary(&weekly_report)),
+    };
+
+    let etag = etag_for_report(&response);
+    respond_with_etag(&headers, etag, (StatusCode::CREATED, Json(response)))
+}
+
+// Query parameters for comparing two weekly reports.
+#[derive(Deserialize, IntoParams)]
+struct CompareWeeklyReportsQuery {
+    /// The date (a monday) when the week to compare against started in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-06")]
+    week1_start: String,
+
+    /// The date (a monday) when the week to compare started in ISO 8601 format (YYYY-MM-DD).
+    #[param(example = "2025-10-13")]
+    week2_start: String,
+}
+
+/// Compares the weekly report of the second week to the weekly report of the first week.
+#[utoipa::path(
+    get,
+    path = "/compare",
+    tag = WEEKLY_REPORT_SERVICE_TAG,
+    params(
+        CompareWeeklyReportsQuery,
+    ),
+    responses(
+        (status = 200, description = "Weekly reports compared successfully", body = WeeklyComparison),
+        (status = 400, description = "Invalid week start date", body = ValidationError),
+        (status = 500, description = "Activities could not be loaded", body = ValidationError)
+    )
+)]
+async fn compare_weekly_reports<R, T>(
+    State(store): State<Arc<Mutex<WeeklyReportServiceState<R, T>>>>,
+    query: Query<CompareWeeklyReportsQuery>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+    T: 'static + Send + Sync + AccountingCategoriesListRepository,
+{
+    let week1_start = match parse_date_param("week1_start", &query.week1_start) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+    let week2_start = match parse_date_param("week2_start", &query.week2_start) {
+        Ok(date) => date,
+        Err(err) => return err.into_response(),
+    };
+
+    let service_state = store.lock().await;
+    let repository = service_state.activities_list_repository.lock().await;
+    let categories_repository = service_state.accounting_categories_repository.lock().await;
+    let week1_report = match use_cases::weekly_report::WeeklyReport::new(
+        week1_start,
+        &*repository,
+        &*categories_repository,
+    )
+    .await
+    {
+        Ok(report) => report,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+    let week2_report = match use_cases::weekly_report::WeeklyReport::new(
+        week2_start,
+        &*repository,
+        &*categories_repository,
+    )
+    .await
+    {
+        Ok(report) => report,
+        Err(err) => return ServiceError::from(err).into_response(),
+    };
+
+    let comparison = week2_report.compare(&week1_report);
+    let response = WeeklyComparison {
+        week1_start: week1_start.to_string(),
+        week2_start: week2_start.to_string(),
+        total_duration_delta: comparison.total_duration_delta().to_string(),
+        categories_gained: comparison
+            .categories_gained()
+            .iter()
+            .map(|category_id| category_id.to_string())
+            .collect(),
+        categories_lost: comparison
+            .categories_lost()
+            .iter()
+            .map(|category_id| category_id.to_string())
+            .collect(),
+        duration_delta_per_category: comparison
+            .duration_delta_per_category()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// Calculates the ETag of a report from the SHA-256 of its JSON serialization.
+///
+/// # Arguments
+///
+/// - `report`: The report to calculate the ETag for.
+///
+/// # Returns
+///
+/// - The strong ETag, including the surrounding quotes.
+fn etag_for_report(report: &WeeklyReport) -> String {
+    etag_for(&serde_json::to_vec(report).expect("Weekly reports serialize to JSON"))
+}
+
+/// Calculates a strong ETag from the SHA-256 of the given content.
+fn etag_for(content: &[u8]) -> String {
+    format!("\"{:x}\"", Sha256::digest(content))
+}
+
+/// Returns `304 Not Modified` if the `If-None-Match` header of the request matches the ETag,
+/// otherwise the given response. Both carry the ETag header.
+///
+/// # Arguments
+///
+/// - `headers`: The headers of the request.
+/// - `etag`: The ETag of the current representation of the report.
+/// - `response`: The response to send if the client has no up-to-date copy.
+fn respond_with_etag(headers: &HeaderMap, etag: String, response: impl IntoResponse) -> Response {
+    let not_modified = headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .any(|tag| tag == etag || tag == "*");
+
+    let mut response = if not_modified {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        response.into_response()
     };
+    response.headers_mut().insert(
+        header::ETAG,
+        HeaderValue::from_str(&etag).expect("ETags are valid header values"),
+    );
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use chrono::{NaiveDate, NaiveTime};
+    use tower::ServiceExt;
+    use work_pulse_core::{
+        infra::repositories::in_memory::{
+            accounting_categories_list::InMemoryAccountingCategoriesListRepository,
+            activities_list::InMemoryActivitiesListRepository,
+        },
+        use_cases::activities_list::ActivitiesList,
+    };
+
+    use super::*;
+
+    /// Creates the weekly report router backed by in-memory repositories, with an activity of
+    /// the "Development" category recorded for each of the given `(date, task)` pairs.
+    async fn router_with_activities(activities: &[(NaiveDate, &str)]) -> axum::Router {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+        let accounting_categories_repository =
+            Arc::new(Mutex::new(InMemoryAccountingCategoriesListRepository::new()));
+
+        let category = accounting_categories_repository
+            .lock()
+            .await
+            .get_or_create_by_name("Development")
+            .await
+            .unwrap();
+        let mut activities_list = ActivitiesList::new(activities_list_repository.clone());
+        for (date, task) in activities {
+            activities_list
+                .record(
+                    *date,
+                    NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                    Some(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+                    category.id().clone(),
+                    task.to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        axum::Router::new().nest(
+            "/api/v1/weekly-report",
+            router(activities_list_repository, accounting_categories_repository).into(),
+        )
+    }
+
+    async fn send_report_request(router: axum::Router, query: &str) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/api/v1/weekly-report?{}", query))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn generate_weekly_report_should_render_html() {
+        let router = router_with_activities(&[
+            (
+                NaiveDate::from_ymd_opt(2025, 10, 13).unwrap(),
+                "Code Review",
+            ),
+            (
+                NaiveDate::from_ymd_opt(2025, 10, 15).unwrap(),
+                "Sprint Planning",
+            ),
+        ])
+        .await;
+
+        let response = send_report_request(router, "week_start_date=2025-10-12&format=html").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/html; charset=utf-8"
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("2025-10-12"));
+        assert!(html.contains("Code Review"));
+        assert!(html.contains("Sprint Planning"));
+        assert!(html.contains("Development"));
+    }
+
+    #[tokio::test]
+    async fn generate_weekly_report_should_return_json_by_default() {
+        for query in [
+            "week_start_date=2025-10-12",
+            "week_start_date=2025-10-12&format=json",
+        ] {
+            let router = router_with_activities(&[(
+                NaiveDate::from_ymd_opt(2025, 10, 13).unwrap(),
+                "Code Review",
+            )])
+            .await;
+
+            let response = send_report_request(router, query).await;
+
+            assert_eq!(response.status(), StatusCode::CREATED);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let report: WeeklyReport = serde_json::from_slice(&body).unwrap();
+            assert_eq!(report.week_start, "2025-10-12");
+        }
+    }
+
+    async fn send_json_report_request(router: axum::Router, query: &str) -> WeeklyReport {
+        let response = send_report_request(router, query).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn generate_weekly_report_should_be_empty_for_week_without_activities() {
+        let router = router_with_activities(&[(
+            NaiveDate::from_ymd_opt(2025, 10, 20).unwrap(),
+            "Code Review",
+        )])
+        .await;
+
+        let report = send_json_report_request(router, "week_start_date=2025-10-12").await;
+
+        assert_eq!(report.week_start, "2025-10-12");
+        assert_eq!(report.week_end, "2025-10-18");
+        assert_eq!(report.total_duration, Duration::zero().to_string());
+        assert!(report.duration_per_category.is_empty());
+        assert!(report.duration_per_category_name.is_empty());
+    }
+
+    #[tokio::test]
+    async fn generate_weekly_report_should_aggregate_single_activity() {
+        let router = router_with_activities(&[(
+            NaiveDate::from_ymd_opt(2025, 10, 14).unwrap(),
+            "Code Review",
+        )])
+        .await;
+
+        let report = send_json_report_request(router, "week_start_date=2025-10-12").await;
+
+        assert_eq!(report.week_start, "2025-10-12");
+        assert_eq!(report.week_end, "2025-10-18");
+        assert_eq!(report.total_duration, Duration::hours(1).to_string());
+        assert_eq!(
+            report.duration_per_category.values().collect::<Vec<_>>(),
+            vec![&Duration::hours(1).to_string()]
+        );
+        assert_eq!(
+            report.duration_per_category_name,
+            BTreeMap::from([("Development".to_string(), Duration::hours(1).to_string())])
+        );
+    }
+
+    #[tokio::test]
+    async fn generate_weekly_report_should_include_activities_across_month_boundary() {
+        let router = router_with_activities(&[
+            (NaiveDate::from_ymd_opt(2025, 9, 27).unwrap(), "Before"),
+            (NaiveDate::from_ymd_opt(2025, 9, 30).unwrap(), "September"),
+            (NaiveDate::from_ymd_opt(2025, 10, 2).unwrap(), "October"),
+            (NaiveDate::from_ymd_opt(2025, 10, 5).unwrap(), "After"),
+        ])
+        .await;
+
+        let report = send_json_report_request(router, "week_start_date=2025-09-28").await;
+
+        assert_eq!(report.week_start, "2025-09-28");
+        assert_eq!(report.week_end, "2025-10-04");
+        assert_eq!(report.total_duration, Duration::hours(2).to_string());
+        assert_eq!(
+            report.duration_per_category.values().collect::<Vec<_>>(),
+            vec![&Duration::hours(2).to_string()]
+        );
+        assert_eq!(
+            report
+                .days
+                .iter()
+                .flat_map(|day| day.activities.iter().map(|activity| activity.task.as_str()))
+                .collect::<Vec<_>>(),
+            vec!["September", "October"]
+        );
+    }
+
+    fn report() -> WeeklyReport {
+        WeeklyReport {
+            week_start: "2025-10-12".to_string(),
+            week_end: "2025-10-18".to_string(),
+            total_duration: "PT3600S".to_string(),
+            total_cost: None,
+            duration_per_category: BTreeMap::from([
+                ("category-1".to_string(), "PT1800S".to_string()),
+                ("category-2".to_string(), "PT1800S".to_string()),
+            ]),
+            daily_durations_per_category: BTreeMap::new(),
+            category_names: BTreeMap::from([
+                ("category-1".to_string(), "Development".to_string()),
+                ("category-2".to_string(), "Meetings".to_string()),
+            ]),
+            duration_per_category_name: BTreeMap::new(),
+            daily_durations_per_category_name: BTreeMap::new(),
+            is_weekend: BTreeMap::new(),
+            days: Vec::new(),
+            overtime: None,
+            daily_overtime: None,
+            summary: None,
+        }
+    }
+
+    #[test]
+    fn etag_for_report_should_be_equal_for_identical_reports() {
+        assert_eq!(etag_for_report(&report()), etag_for_report(&report()));
+    }
+
+    #[test]
+    fn etag_for_report_should_change_when_any_field_changes() {
+        let etag = etag_for_report(&report());
+        let modifications: [fn(&mut WeeklyReport); 4] = [
+            |report| report.total_duration = "PT7200S".to_string(),
+            |report| report.total_cost = Some(120.0),
+            |report| {
+                report
+                    .category_names
+                    .insert("category-2".to_string(), "Meeting".to_string());
+            },
+            |report| report.summary = Some("Week 42".to_string()),
+        ];
+
+        for modify in modifications {
+            let mut modified = report();
+            modify(&mut modified);
+            assert_ne!(etag_for_report(&modified), etag);
+        }
+    }
+
+    #[tokio::test]
+    async fn generate_weekly_report_should_return_not_modified_for_matching_etag() {
+        let router = router_with_activities(&[(
+            NaiveDate::from_ymd_opt(2025, 10, 13).unwrap(),
+            "Code Review",
+        )])
+        .await;
+
+        let response = send_report_request(router.clone(), "week_start_date=2025-10-12").await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let etag = response.headers()[header::ETAG].clone();
+
+        let response = router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/api/v1/weekly-report?week_start_date=2025-10-12")
+                    .header(header::IF_NONE_MATCH, etag.clone())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[header::ETAG], etag);
+    }
+
+    #[tokio::test]
+    async fn generate_weekly_report_should_reject_unknown_format() {
+        let router = router_with_activities(&[]).await;
+
+        let response = send_report_request(router, "week_start_date=2025-10-12&format=pdf").await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    async fn send_compare_request(router: axum::Router, query: &str) -> axum::response::Response {
+        router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/api/v1/weekly-report/compare?{}", query))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn generate_weekly_report_should_reject_invalid_target_hours() {
+        for target_hours in ["1e13", "-1", "NaN", "inf", "168.5"] {
+            let router = router_with_activities(&[]).await;
+
+            let response = send_report_request(
+                router,
+                &format!("week_start_date=2025-10-12&target_hours={}", target_hours),
+            )
+            .await;
+
+            assert_eq!(
+                response.status(),
+                StatusCode::BAD_REQUEST,
+                "{}",
+                target_hours
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn compare_weekly_reports_should_return_deltas_between_weeks() {
+        let router = router_with_activities(&[
+            (NaiveDate::from_ymd_opt(2025, 10, 6).unwrap(), "Code Review"),
+            (
+                NaiveDate::from_ymd_opt(2025, 10, 13).unwrap(),
+                "Code Review",
+            ),
+            (
+                NaiveDate::from_ymd_opt(2025, 10, 14).unwrap(),
+                "Sprint Planning",
+            ),
+        ])
+        .await;
+
+        let response =
+            send_compare_request(router, "week1_start=2025-10-05&week2_start=2025-10-12").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let comparison: WeeklyComparison = serde_json::from_slice(&body).unwrap();
+        assert_eq!(comparison.week1_start, "2025-10-05");
+        assert_eq!(comparison.week2_start, "2025-10-12");
+        assert_eq!(
+            comparison.total_duration_delta,
+            Duration::hours(1).to_string()
+        );
+        assert!(comparison.categories_gained.is_empty());
+        assert!(comparison.categories_lost.is_empty());
+        assert_eq!(
+            comparison
+                .duration_delta_per_category
+                .values()
+                .collect::<Vec<_>>(),
+            vec![&Duration::hours(1).to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn compare_weekly_reports_should_reject_invalid_week_start() {
+        let router = router_with_activities(&[]).await;
+
+        let response =
+            send_compare_request(router, "week1_start=2025-10-05&week2_start=12.10.2025").await;
 
-    (StatusCode::CREATED, Json(response))
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
 }
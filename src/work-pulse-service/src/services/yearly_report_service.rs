@@ -0,0 +1,255 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    Json,
+    extract::{Query, State},
+    response::IntoResponse,
+};
+use hyper::StatusCode;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use utoipa::{IntoParams, ToSchema};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use work_pulse_core::{adapters::ActivitiesListRepository, use_cases};
+
+use crate::{
+    prelude::YEARLY_REPORT_SERVICE_TAG,
+    services::error::{ServiceError, ValidationError},
+};
+
+/// Shared state for the yearly report service.
+struct YearlyReportServiceState<R>
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+{
+    /// The activities list repository.
+    activities_list_repository: Arc<Mutex<R>>,
+}
+
+/// The total duration of one month within a year.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, PartialEq)]
+struct MonthlyDuration {
+    /// The month (1-12).
+    #[schema(example = 10)]
+    pub month: u32,
+
+    /// The total duration of the month in ISO 8601 format (PT1H).
+    #[schema(example = "PT540000S")]
+    pub total_duration: String,
+}
+
+/// A report summarizing activities for a whole calendar year.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+struct YearlyReport {
+    /// The reported year.
+    #[schema(example = 2025)]
+    pub year: i32,
+
+    /// The total duration of all activities in the year in ISO 8601 format (PT1H).
+    #[schema(example = "PT6480000S")]
+    pub total_duration: String,
+
+    /// A map of accounting category IDs to total duration spent in that category in ISO 8601 format (PT1H).
+    #[schema(example = r#"{"category-1": "PT3600S", "category-2": "PT7200S"}"#)]
+    pub duration_per_category: HashMap<String, String>,
+
+    /// The total duration of each month of the year, from January to December.
+    pub monthly_breakdown: Vec<MonthlyDuration>,
+}
+
+/// Creates an OpenAPI router for the yearly report service.
+///
+/// # Arguments
+///
+/// - `activities_list_repository`: An `Arc<Mutex<PsqlActivitiesListRepository>>` instance for accessing the activities repository.
+///
+/// # Returns
+///
+/// - An `OpenApiRouter` configured with routes and state for the yearly report service.
+pub fn router<R>(activities_list_repository: Arc<Mutex<R>>) -> OpenApiRouter
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+{
+    let store = Arc::new(Mutex::new(YearlyReportServiceState {
+        activities_list_repository,
+    }));
+
+    OpenApiRouter::new()
+        .routes(routes!(generate_yearly_report))
+        .with_state(store)
+}
+
+// Query parameters for generating yearly reports.
+#[derive(Deserialize, IntoParams)]
+struct GenerateYearlyReportQuery {
+    /// The year to report on.
+    #[param(example = 2025)]
+    year: i32,
+}
+
+/// Generates a yearly report for the specified year.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = YEARLY_REPORT_SERVICE_TAG,
+    params(
+        GenerateYearlyReportQuery,
+    ),
+    responses(
+        (status = 201, description = "Yearly report created successfully", body = YearlyReport),
+        (status = 400, description = "Invalid year", body = ValidationError),
+        (status = 500, description = "Activities could not be loaded", body = ValidationError)
+    )
+)]
+async fn generate_yearly_report<R>(
+    State(store): State<Arc<Mutex<YearlyReportServiceState<R>>>>,
+    query: Query<GenerateYearlyReportQuery>,
+) -> impl IntoResponse
+where
+    R: 'static + Send + Sync + ActivitiesListRepository,
+{
+    let service_state = store.lock().await;
+    let repository = service_state.activities_list_repository.lock().await;
+    let yearly_report =
+        match use_cases::yearly_report::YearlyReport::new(query.year, &*repository).await {
+            Ok(Some(report)) => report,
+            Ok(None) => {
+                return ServiceError::invalid_parameter(
+                    "year",
+                    format!("Invalid year '{}'.", query.year),
+                )
+                .into_response();
+            }
+            Err(err) => return ServiceError::from(err).into_response(),
+        };
+
+    let response = YearlyReport {
+        year: yearly_report.year(),
+        total_duration: yearly_report.total_duration().to_string(),
+        duration_per_category: yearly_report
+            .duration_per_category()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        monthly_breakdown: yearly_report
+            .monthly_breakdown()
+            .iter()
+            .map(|(month, duration)| MonthlyDuration {
+                month: *month,
+                total_duration: duration.to_string(),
+            })
+            .collect(),
+    };
+
+    (StatusCode::CREATED, Json(response)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use chrono::{NaiveDate, NaiveTime};
+    use tower::ServiceExt;
+    use work_pulse_core::{
+        entities::accounting::AccountingCategoryId,
+        infra::repositories::in_memory::activities_list::InMemoryActivitiesListRepository,
+        use_cases::activities_list::ActivitiesList,
+    };
+
+    use super::*;
+
+    /// Creates the yearly report router backed by an in-memory repository, with a one hour
+    /// activity recorded on each of the given dates.
+    async fn router_with_activities_on(dates: &[NaiveDate]) -> axum::Router {
+        let activities_list_repository =
+            Arc::new(Mutex::new(InMemoryActivitiesListRepository::new()));
+
+        let mut activities_list = ActivitiesList::new(activities_list_repository.clone());
+        let category_id = AccountingCategoryId::new();
+        for date in dates {
+            activities_list
+                .record(
+                    *date,
+                    NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                    Some(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+                    category_id.clone(),
+                    "Task".to_string(),
+                    None,
+                    None,
+                )
+                .await
+                .expect("Valid activity");
+        }
+
+        axum::Router::new().nest(
+            "/api/v1/yearly-report",
+            router(activities_list_repository).into(),
+        )
+    }
+
+    async fn send_report_request(router: axum::Router, year: i32) -> YearlyReport {
+        let response = router
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/api/v1/yearly-report?year={}", year))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = axum::body::to_bytes(response.into_body(), 65536)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    fn month(month: u32, total_duration: &str) -> MonthlyDuration {
+        MonthlyDuration {
+            month,
+            total_duration: total_duration.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn yearly_report_should_break_down_activities_by_month() {
+        let router = router_with_activities_on(&[
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 1, 3).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 7, 14).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 12, 31).unwrap(),
+        ])
+        .await;
+
+        let report = send_report_request(router, 2025).await;
+
+        assert_eq!(report.year, 2025);
+        assert_eq!(report.total_duration, "PT14400S");
+        assert_eq!(report.duration_per_category.len(), 1);
+        assert_eq!(report.monthly_breakdown.len(), 12);
+        assert_eq!(report.monthly_breakdown[0], month(1, "PT7200S"));
+        assert_eq!(report.monthly_breakdown[1], month(2, "P0D"));
+        assert_eq!(report.monthly_breakdown[6], month(7, "PT3600S"));
+        assert_eq!(report.monthly_breakdown[11], month(12, "PT3600S"));
+    }
+
+    #[tokio::test]
+    async fn yearly_report_should_be_empty_for_year_without_activities() {
+        let router =
+            router_with_activities_on(&[NaiveDate::from_ymd_opt(2024, 6, 3).unwrap()]).await;
+
+        let report = send_report_request(router, 2025).await;
+
+        assert_eq!(report.year, 2025);
+        assert_eq!(report.total_duration, "P0D");
+        assert!(report.duration_per_category.is_empty());
+        assert_eq!(report.monthly_breakdown.len(), 12);
+        assert!(
+            report
+                .monthly_breakdown
+                .iter()
+                .all(|month| month.total_duration == "P0D")
+        );
+    }
+}